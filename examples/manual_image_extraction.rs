@@ -59,7 +59,7 @@ fn main() -> Result<()> {
                     .expect("parser returned invalid base64 image data");
 
                 // Extract image extension if the image is not compressed, otherwise its always `.jpg`
-                let ext = if slide.config.compress_images {
+                let ext = if slide.render_options.compress_images {
                     "jpg".to_string()
                 } else {
                     slide.get_image_extension(&image.img_ref.target.clone())
@@ -0,0 +1,774 @@
+use crate::constants::{A_NAMESPACE, P_NAMESPACE};
+use crate::container::{comments_part, notes_part};
+use crate::limits::check_slide_limits;
+use crate::parse_rels::{Relationship, hyperlink_targets};
+use crate::parse_xml::{
+    InheritedPositions, LevelFormatting, ParagraphData, PlaceholderKey, infer_levels_from_margins,
+    merge_level_formatting, parse_level_formatting, parse_paragraph_properties,
+    parse_run_properties, placeholder_role, read_paragraph_attributes, run_properties,
+};
+use crate::xml::{XmlReader, attr, element_is, end_is, event, push_text, reader, repair_utf8};
+use crate::{
+    Error, Formatting, OnSlideError, PartialFormatting, PptxContainer, Result, Slide, TextRole,
+};
+use bumpalo::Bump;
+use bumpalo::collections::{String as BumpString, Vec as BumpVec};
+use quick_xml::events::Event;
+use std::collections::HashMap;
+
+/// A slide whose content is allocated in a [`Bump`] arena, see
+/// [`PptxContainer::load_slide_in`].
+///
+/// All blocks, runs and strings borrow from the arena, so they are freed at once when the
+/// arena is reset or dropped. Styles inherited from the layout and master and alternate content
+/// are resolved like for [`Slide`], but only the text, tables and pictures of the slide are
+/// kept, without positions, charts, layout content or image data.
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaSlide<'a> {
+    pub slide_number: u32,
+    /// The text, table and picture blocks in document order.
+    pub blocks: &'a [ArenaBlock<'a>],
+    /// The paragraphs of every non-empty body of the speaker notes, see [`Slide::notes`].
+    pub notes: &'a [&'a [ArenaParagraph<'a>]],
+    /// The paragraphs of every non-empty slide comment, see [`Slide::comments`].
+    pub comments: &'a [&'a [ArenaParagraph<'a>]],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ArenaBlock<'a> {
+    Text(ArenaTextBlock<'a>),
+    Table(&'a [ArenaRow<'a>]),
+    Image(ArenaImage<'a>),
+}
+
+/// The paragraphs of a shape.
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaTextBlock<'a> {
+    /// Whether the shape is a title or centered title placeholder.
+    pub is_title: bool,
+    pub paragraphs: &'a [ArenaParagraph<'a>],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaRow<'a> {
+    pub cells: &'a [ArenaCell<'a>],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaCell<'a> {
+    pub paragraphs: &'a [ArenaParagraph<'a>],
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaParagraph<'a> {
+    /// The zero-based list level, `0` for paragraphs outside of lists.
+    pub level: u32,
+    pub runs: &'a [ArenaRun<'a>],
+}
+
+/// A text run. Line breaks (`a:br`) are runs with the text `"\n"`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ArenaRun<'a> {
+    pub text: &'a str,
+    pub bold: bool,
+    pub italic: bool,
+    pub underlined: bool,
+    pub strikethrough: bool,
+    /// The resolved hyperlink target, with slide jumps as `#slide-N` anchors.
+    pub link_target: Option<&'a str>,
+}
+
+/// A picture referencing its image through a slide relationship.
+#[derive(Debug, Clone, Copy)]
+pub struct ArenaImage<'a> {
+    pub id: &'a str,
+    /// The relationship target of the image, if the relationship exists.
+    pub target: Option<&'a str>,
+    pub alt_text: Option<&'a str>,
+}
+
+impl ArenaSlide<'_> {
+    /// Returns the plain text of the slide in document order.
+    ///
+    /// Paragraphs are separated by line breaks and blocks by blank lines. Table cells are
+    /// separated by tabs, images contribute no text.
+    pub fn text(&self) -> String {
+        let mut parts = Vec::new();
+        for block in self.blocks {
+            let mut text = String::new();
+            match block {
+                ArenaBlock::Text(block) => push_paragraphs(&mut text, block.paragraphs),
+                ArenaBlock::Table(rows) => {
+                    for (row_index, row) in rows.iter().enumerate() {
+                        if row_index > 0 {
+                            text.push('\n');
+                        }
+                        for (cell_index, cell) in row.cells.iter().enumerate() {
+                            if cell_index > 0 {
+                                text.push('\t');
+                            }
+                            push_paragraphs(&mut text, cell.paragraphs);
+                        }
+                    }
+                }
+                ArenaBlock::Image(_) => {}
+            }
+            if !text.trim().is_empty() {
+                parts.push(text);
+            }
+        }
+        parts.join("\n\n")
+    }
+}
+
+impl ArenaParagraph<'_> {
+    /// Returns the concatenated text of all runs.
+    pub fn text(&self) -> String {
+        self.runs.iter().map(|run| run.text).collect()
+    }
+}
+
+fn push_paragraphs(text: &mut String, paragraphs: &[ArenaParagraph<'_>]) {
+    for (index, paragraph) in paragraphs.iter().enumerate() {
+        if index > 0 {
+            text.push('\n');
+        }
+        text.extend(paragraph.runs.iter().map(|run| run.text));
+    }
+}
+
+impl PptxContainer {
+    /// Loads the content of a slide into `arena`.
+    ///
+    /// The slide is read straight from the XML events into `arena`, without building the
+    /// owned [`Slide`] first. This is meant for read-convert-discard workflows over many
+    /// slides: the views handed to the caller live in one arena that is freed at once by
+    /// [`Bump::reset`], instead of a tree of separately allocated runs and strings.
+    ///
+    /// Like [`PptxContainer::load_slide`], a slide that can not be parsed is handled according
+    /// to [`crate::ParserConfig::on_slide_error`]: the error is returned, the slide is skipped
+    /// with `Ok(None)`, or a placeholder slide holding the error message is returned.
+    ///
+    /// ```no_run
+    /// # fn main() -> pptx_to_md::Result<()> {
+    /// use pptx_to_md::{Bump, ParserConfig, PptxContainer};
+    /// use std::path::Path;
+    ///
+    /// let mut container = PptxContainer::open(Path::new("deck.pptx"), ParserConfig::default())?;
+    /// let mut arena = Bump::new();
+    /// for slide_path in container.slide_paths.clone() {
+    ///     if let Some(slide) = container.load_slide_in(&slide_path, &arena)? {
+    ///         println!("{}", slide.text());
+    ///     }
+    ///     arena.reset();
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn load_slide_in<'a>(
+        &mut self,
+        slide_path: &str,
+        arena: &'a Bump,
+    ) -> Result<Option<ArenaSlide<'a>>> {
+        let mut slide_data = std::mem::take(&mut self.slide_buffer);
+        let slide = self.parse_slide_in(slide_path, &mut slide_data, arena);
+        self.slide_buffer = slide_data;
+        let slide_number = Slide::extract_slide_number(slide_path).unwrap_or(0);
+        match slide {
+            Ok(slide) => Ok(Some(slide)),
+            Err(error) => match self.config.on_slide_error {
+                OnSlideError::Fail => Err(error),
+                OnSlideError::Skip => Ok(None),
+                OnSlideError::Placeholder => {
+                    Ok(Some(ArenaSlide::placeholder(slide_number, &error, arena)))
+                }
+            },
+        }
+    }
+
+    fn parse_slide_in<'a>(
+        &mut self,
+        slide_path: &str,
+        slide_data: &mut Vec<u8>,
+        arena: &'a Bump,
+    ) -> Result<ArenaSlide<'a>> {
+        self.read_slide_into(slide_path, slide_data)?;
+        check_slide_limits(slide_data, slide_path, &self.config.resource_limits)?;
+        if self.config.lossy_utf8 {
+            repair_utf8(slide_data, slide_path);
+        }
+        let relationships = self.relationships(slide_path)?;
+        let inherited = self.resolve_layout_positions(slide_path, &relationships)?;
+        let parser = ArenaParser {
+            arena,
+            inherited: &inherited,
+            hyperlinks: &hyperlink_targets(&relationships),
+            relationships: &relationships,
+        };
+        let blocks = parser.parse_slide(slide_data)?;
+
+        let notes = match notes_part(slide_path, &relationships) {
+            Some(notes_path) => {
+                let notes_xml = self.read_file_from_archive(&notes_path)?;
+                let relationships = self.relationships(&notes_path)?;
+                ArenaParser {
+                    arena,
+                    inherited: &InheritedPositions::default(),
+                    hyperlinks: &hyperlink_targets(&relationships),
+                    relationships: &relationships,
+                }
+                .parse_notes(&notes_xml)?
+            }
+            None => &[],
+        };
+        let comments = match comments_part(slide_path, &relationships) {
+            Some(comment_path) => {
+                let comment_xml = self.read_file_from_archive(&comment_path)?;
+                let relationships = self.relationships(&comment_path)?;
+                ArenaParser {
+                    arena,
+                    inherited: &InheritedPositions::default(),
+                    hyperlinks: &hyperlink_targets(&relationships),
+                    relationships: &relationships,
+                }
+                .parse_comments(&comment_xml)?
+            }
+            None => &[],
+        };
+
+        Ok(ArenaSlide {
+            slide_number: Slide::extract_slide_number(slide_path).unwrap_or(0),
+            blocks,
+            notes,
+            comments,
+        })
+    }
+}
+
+impl<'a> ArenaSlide<'a> {
+    /// Returns a slide with a single italic paragraph describing `error`, as rendered for
+    /// [`OnSlideError::Placeholder`].
+    fn placeholder(slide_number: u32, error: &Error, arena: &'a Bump) -> Self {
+        let run = ArenaRun {
+            text: bumpalo::format!(in arena, "Slide could not be parsed: {}", error)
+                .into_bump_str(),
+            italic: true,
+            ..Default::default()
+        };
+        let paragraph = ArenaParagraph {
+            level: 0,
+            runs: arena.alloc_slice_copy(&[run]),
+        };
+        let block = ArenaBlock::Text(ArenaTextBlock {
+            is_title: false,
+            paragraphs: arena.alloc_slice_copy(&[paragraph]),
+        });
+        ArenaSlide {
+            slide_number,
+            blocks: arena.alloc_slice_copy(&[block]),
+            notes: &[],
+            comments: &[],
+        }
+    }
+}
+
+/// Reads slide, notes and comment parts into arena views.
+///
+/// Paragraph properties, list styles and run properties are read by the same functions as in
+/// the owned parser, so both resolve inherited formatting and list levels alike.
+struct ArenaParser<'a, 'p> {
+    arena: &'a Bump,
+    inherited: &'p InheritedPositions,
+    hyperlinks: &'p HashMap<String, String>,
+    relationships: &'p [Relationship],
+}
+
+/// The content of a `p:sp` or `p:cxnSp`.
+struct ArenaShape<'a> {
+    placeholder: Option<PlaceholderKey>,
+    image: Option<ArenaImage<'a>>,
+    paragraphs: Option<&'a [ArenaParagraph<'a>]>,
+}
+
+impl<'a> ArenaParser<'a, '_> {
+    fn parse_slide(&self, xml_data: &[u8]) -> Result<&'a [ArenaBlock<'a>]> {
+        let mut xml = reader(xml_data);
+        let mut blocks = BumpVec::new_in(self.arena);
+        let mut in_common_slide = false;
+        loop {
+            match event(&mut xml, "PPTX slide")? {
+                Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"cSld") => {
+                    in_common_slide = true;
+                }
+                Event::Start(element)
+                    if in_common_slide && element_is(&xml, &element, P_NAMESPACE, b"spTree") =>
+                {
+                    self.parse_shape_tree(&mut xml, b"spTree", &mut blocks)?;
+                    break;
+                }
+                Event::Empty(element)
+                    if in_common_slide && element_is(&xml, &element, P_NAMESPACE, b"spTree") =>
+                {
+                    break;
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"cSld") => {
+                    in_common_slide = false;
+                }
+                Event::Eof => return Err(Error::ParseError("PPTX slide shape tree not found")),
+                _ => {}
+            }
+        }
+        Ok(blocks.into_bump_slice())
+    }
+
+    /// Reads the blocks of a shape tree or group in document order, flattening nested groups.
+    /// Like in the owned parser, other elements such as `mc:AlternateContent` are skipped.
+    fn parse_shape_tree(
+        &self,
+        xml: &mut XmlReader<'_>,
+        end: &[u8],
+        blocks: &mut BumpVec<'a, ArenaBlock<'a>>,
+    ) -> Result<()> {
+        loop {
+            match event(xml, "PPTX slide")? {
+                Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"sp") => {
+                    let shape = self.parse_shape(xml, b"sp")?;
+                    blocks.extend(shape.image.map(ArenaBlock::Image));
+                    blocks.extend(shape.paragraphs.map(|paragraphs| {
+                        ArenaBlock::Text(ArenaTextBlock {
+                            is_title: placeholder_role(shape.placeholder.as_ref())
+                                == TextRole::Title,
+                            paragraphs,
+                        })
+                    }));
+                }
+                Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"cxnSp") => {
+                    let connector = self.parse_shape(xml, b"cxnSp")?;
+                    blocks.extend(connector.paragraphs.map(|paragraphs| {
+                        ArenaBlock::Text(ArenaTextBlock {
+                            is_title: false,
+                            paragraphs,
+                        })
+                    }));
+                }
+                Event::Start(element)
+                    if element_is(xml, &element, P_NAMESPACE, b"graphicFrame") =>
+                {
+                    blocks.extend(self.parse_graphic_frame(xml)?.map(ArenaBlock::Table));
+                }
+                Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"pic") => {
+                    blocks.push(ArenaBlock::Image(self.parse_picture(xml)?));
+                }
+                Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"grpSp") => {
+                    self.parse_shape_tree(xml, b"grpSp", blocks)?;
+                }
+                Event::Start(element) => {
+                    let end = element.name().as_ref().to_vec();
+                    crate::xml::skip_element(xml, &end, "PPTX slide")?;
+                }
+                Event::End(element) if end_is(element.name().as_ref(), end) => break,
+                Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX shape tree")),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_shape(&self, xml: &mut XmlReader<'_>, end: &[u8]) -> Result<ArenaShape<'a>> {
+        let mut placeholder = None;
+        let mut image_id = None;
+        let mut alt_text = None;
+        let mut paragraphs = None;
+        let mut in_shape_properties = false;
+        loop {
+            match event(xml, "PPTX shape")? {
+                Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"spPr") => {
+                    in_shape_properties = true;
+                }
+                Event::End(element)
+                    if in_shape_properties && end_is(element.name().as_ref(), b"spPr") =>
+                {
+                    in_shape_properties = false;
+                }
+                Event::Start(element) | Event::Empty(element)
+                    if in_shape_properties && element_is(xml, &element, A_NAMESPACE, b"blip") =>
+                {
+                    image_id = attr(&element, b"embed");
+                }
+                Event::Start(element) | Event::Empty(element)
+                    if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
+                {
+                    alt_text = attr(&element, b"descr").or_else(|| attr(&element, b"title"));
+                }
+                Event::Start(element) | Event::Empty(element)
+                    if element_is(xml, &element, P_NAMESPACE, b"ph") =>
+                {
+                    placeholder = Some(PlaceholderKey::from_element(&element));
+                }
+                Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"txBody") => {
+                    let styles = self.inherited.resolve_run_styles(placeholder.as_ref());
+                    paragraphs = Some(self.parse_text_body(xml, styles, placeholder.as_ref())?);
+                }
+                Event::End(element) if end_is(element.name().as_ref(), end) => break,
+                Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX shape")),
+                _ => {}
+            }
+        }
+        Ok(ArenaShape {
+            image: image_id.map(|id| self.image(&id, alt_text)),
+            placeholder,
+            paragraphs,
+        })
+    }
+
+    fn parse_picture(&self, xml: &mut XmlReader<'_>) -> Result<ArenaImage<'a>> {
+        let mut image_id = None;
+        let mut alt_text = None;
+        loop {
+            match event(xml, "PPTX picture")? {
+                Event::Start(element) | Event::Empty(element)
+                    if element_is(xml, &element, A_NAMESPACE, b"blip") =>
+                {
+                    image_id = attr(&element, b"embed");
+                }
+                Event::Start(element) | Event::Empty(element)
+                    if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
+                {
+                    alt_text = attr(&element, b"descr")
+                        .or_else(|| attr(&element, b"title"))
+                        .or_else(|| attr(&element, b"name"));
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"pic") => break,
+                Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX picture")),
+                _ => {}
+            }
+        }
+        Ok(self.image(&image_id.ok_or(Error::ImageNotFound)?, alt_text))
+    }
+
+    fn image(&self, id: &str, alt_text: Option<String>) -> ArenaImage<'a> {
+        ArenaImage {
+            id: self.arena.alloc_str(id),
+            target: self
+                .relationships
+                .iter()
+                .find(|relationship| relationship.id == id)
+                .map(|relationship| &*self.arena.alloc_str(&relationship.target)),
+            alt_text: alt_text
+                .filter(|alt_text| !alt_text.is_empty())
+                .map(|alt_text| &*self.arena.alloc_str(&alt_text)),
+        }
+    }
+
+    /// Reads the table of a graphic frame. Charts and other graphics are skipped.
+    fn parse_graphic_frame(&self, xml: &mut XmlReader<'_>) -> Result<Option<&'a [ArenaRow<'a>]>> {
+        let mut in_table_data = false;
+        let mut table = None;
+        loop {
+            match event(xml, "PPTX graphic frame")? {
+                Event::Start(element) | Event::Empty(element)
+                    if element_is(xml, &element, A_NAMESPACE, b"graphicData") =>
+                {
+                    in_table_data = attr(&element, b"uri").as_deref()
+                        == Some("http://schemas.openxmlformats.org/drawingml/2006/table");
+                }
+                Event::Start(element)
+                    if in_table_data && element_is(xml, &element, A_NAMESPACE, b"tbl") =>
+                {
+                    table = Some(self.parse_table(xml)?);
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"graphicData") => {
+                    in_table_data = false;
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"graphicFrame") => break,
+                Event::Eof => {
+                    return Err(Error::ParseError("Unexpected end of PPTX graphic frame"));
+                }
+                _ => {}
+            }
+        }
+        Ok(table)
+    }
+
+    /// Reads the rows of a table, without the cells covered by a merged cell.
+    fn parse_table(&self, xml: &mut XmlReader<'_>) -> Result<&'a [ArenaRow<'a>]> {
+        let mut rows = BumpVec::new_in(self.arena);
+        let mut cells = BumpVec::new_in(self.arena);
+        let mut covered = false;
+        loop {
+            match event(xml, "DrawingML table")? {
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"tc") => {
+                    covered = attr(&element, b"hMerge").as_deref() == Some("1")
+                        || attr(&element, b"vMerge").as_deref() == Some("1");
+                    if !covered {
+                        cells.push(ArenaCell { paragraphs: &[] });
+                    }
+                }
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"txBody") => {
+                    let paragraphs = self.parse_text_body(xml, LevelFormatting::new(), None)?;
+                    if let Some(cell) = cells.last_mut().filter(|_| !covered) {
+                        cell.paragraphs = paragraphs;
+                    }
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"tr") => {
+                    let row = std::mem::replace(&mut cells, BumpVec::new_in(self.arena));
+                    rows.push(ArenaRow {
+                        cells: row.into_bump_slice(),
+                    });
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"tbl") => break,
+                Event::Eof => return Err(Error::ParseError("Unexpected end of DrawingML table")),
+                _ => {}
+            }
+        }
+        Ok(rows.into_bump_slice())
+    }
+
+    /// Reads the paragraphs of a text body, resolving their list levels like the owned parser.
+    fn parse_text_body(
+        &self,
+        xml: &mut XmlReader<'_>,
+        mut styles: LevelFormatting,
+        placeholder: Option<&PlaceholderKey>,
+    ) -> Result<&'a [ArenaParagraph<'a>]> {
+        let mut paragraphs = BumpVec::new_in(self.arena);
+        let mut runs = BumpVec::new_in(self.arena);
+        loop {
+            match event(xml, "DrawingML text body")? {
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"lstStyle") => {
+                    let list_styles = parse_level_formatting(xml, b"lstStyle")?;
+                    merge_level_formatting(&mut styles, &list_styles);
+                }
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"p") => {
+                    let (paragraph, paragraph_runs) = self.parse_paragraph(xml, &styles)?;
+                    paragraphs.push(paragraph);
+                    runs.push(paragraph_runs);
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"txBody") => break,
+                Event::Eof => {
+                    return Err(Error::ParseError("Unexpected end of DrawingML text body"));
+                }
+                _ => {}
+            }
+        }
+        infer_levels_from_margins(&mut paragraphs);
+        let paragraphs = paragraphs
+            .iter()
+            .zip(runs)
+            .map(|(paragraph, runs)| ArenaParagraph {
+                level: self.list_level(paragraph, placeholder),
+                runs,
+            });
+        Ok(BumpVec::from_iter_in(paragraphs, self.arena).into_bump_slice())
+    }
+
+    /// Returns the list level of a paragraph, or `0` if it is not a list item once the list
+    /// styles of its placeholder are applied.
+    fn list_level(&self, paragraph: &ParagraphData, placeholder: Option<&PlaceholderKey>) -> u32 {
+        let level = if paragraph.list.is_some() {
+            paragraph.level
+        } else {
+            0
+        };
+        let inherited = placeholder
+            .filter(|_| !paragraph.list_explicit)
+            .and_then(|key| self.inherited.resolve_list_kind(key, level));
+        match inherited {
+            Some(None) => 0,
+            Some(Some(_)) | None => level,
+        }
+    }
+
+    fn parse_paragraph(
+        &self,
+        xml: &mut XmlReader<'_>,
+        styles: &LevelFormatting,
+    ) -> Result<(ParagraphData, &'a [ArenaRun<'a>])> {
+        let mut paragraph = ParagraphData::default();
+        paragraph.resolve_default_formatting(styles);
+        let mut runs = BumpVec::new_in(self.arena);
+        loop {
+            match event(xml, "DrawingML paragraph")? {
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"pPr") => {
+                    parse_paragraph_properties(xml, &element, &mut paragraph)?;
+                    paragraph.resolve_default_formatting(styles);
+                }
+                Event::Empty(element) if element_is(xml, &element, A_NAMESPACE, b"pPr") => {
+                    read_paragraph_attributes(&element, &mut paragraph);
+                    paragraph.resolve_default_formatting(styles);
+                }
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"r") => {
+                    runs.push(self.parse_run(xml, b"r", &paragraph.default_formatting)?);
+                }
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"fld") => {
+                    runs.push(self.parse_run(xml, b"fld", &paragraph.default_formatting)?);
+                }
+                Event::Start(element) | Event::Empty(element)
+                    if element_is(xml, &element, A_NAMESPACE, b"br") =>
+                {
+                    runs.push(ArenaRun {
+                        text: "\n",
+                        ..formatted_run(&paragraph.default_formatting)
+                    });
+                }
+                Event::End(element) if end_is(element.name().as_ref(), b"p") => break,
+                Event::Eof => {
+                    return Err(Error::ParseError("Unexpected end of DrawingML paragraph"));
+                }
+                _ => {}
+            }
+        }
+        Ok((paragraph, runs.into_bump_slice()))
+    }
+
+    fn parse_run(
+        &self,
+        xml: &mut XmlReader<'_>,
+        end: &[u8],
+        defaults: &Formatting,
+    ) -> Result<ArenaRun<'a>> {
+        let mut run = formatted_run(defaults);
+        let mut text = BumpString::new_in(self.arena);
+        let mut link_id = None;
+        let mut typeface = None;
+        loop {
+            match event(xml, "DrawingML run")? {
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"rPr") => {
+                    apply_run_properties(&mut run, &run_properties(&element));
+                    let properties = parse_run_properties(xml, link_id)?;
+                    link_id = properties.link_id;
+                    typeface = properties.typeface;
+                }
+                Event::Empty(element) if element_is(xml, &element, A_NAMESPACE, b"rPr") => {
+                    apply_run_properties(&mut run, &run_properties(&element));
+                }
+                Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"t") => {
+                    read_text_in(xml, b"t", &mut text)?;
+                }
+                Event::End(element) if end_is(element.name().as_ref(), end) => break,
+                Event::Eof => return Err(Error::ParseError("Unexpected end of DrawingML run")),
+                _ => {}
+            }
+        }
+        #[cfg(feature = "symbol-fonts")]
+        if let Some(typeface) = typeface.as_deref() {
+            let mapped = crate::symbol_fonts::map_symbol_text(typeface, &text);
+            text = BumpString::from_str_in(&mapped, self.arena);
+        }
+        #[cfg(not(feature = "symbol-fonts"))]
+        let _ = typeface;
+        run.text = text.into_bump_str();
+        run.link_target = link_id
+            .and_then(|id| self.hyperlinks.get(&id))
+            .map(|target| &*self.arena.alloc_str(target));
+        Ok(run)
+    }
+
+    /// Reads the text bodies of the body placeholders of a notes slide.
+    fn parse_notes(&self, xml_data: &[u8]) -> Result<&'a [&'a [ArenaParagraph<'a>]]> {
+        let mut xml = reader(xml_data);
+        let mut notes = BumpVec::new_in(self.arena);
+        loop {
+            match event(&mut xml, "PPTX notes")? {
+                Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"sp") => {
+                    let shape = self.parse_shape(&mut xml, b"sp")?;
+                    if shape.placeholder.as_ref().and_then(PlaceholderKey::kind) == Some("body") {
+                        notes.extend(shape.paragraphs.filter(|paragraphs| has_runs(paragraphs)));
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(notes.into_bump_slice())
+    }
+
+    /// Reads the text of every comment of a comments part. Legacy comments only hold plain
+    /// `p:text` and are used if no comment has a text body.
+    fn parse_comments(&self, xml_data: &[u8]) -> Result<&'a [&'a [ArenaParagraph<'a>]]> {
+        let mut xml = reader(xml_data);
+        let mut comments = BumpVec::new_in(self.arena);
+        let mut legacy = BumpVec::new_in(self.arena);
+        loop {
+            match event(&mut xml, "PPTX comments")? {
+                Event::Start(element)
+                    if crate::xml::local(element.name().as_ref()) == b"txBody" =>
+                {
+                    let paragraphs =
+                        self.parse_text_body(&mut xml, LevelFormatting::new(), None)?;
+                    if has_runs(paragraphs) {
+                        comments.push(paragraphs);
+                    }
+                }
+                Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"text") => {
+                    let mut text = BumpString::new_in(self.arena);
+                    read_text_in(&mut xml, b"text", &mut text)?;
+                    if !text.is_empty() {
+                        let run = ArenaRun {
+                            text: text.into_bump_str(),
+                            ..Default::default()
+                        };
+                        let paragraph = ArenaParagraph {
+                            level: 0,
+                            runs: self.arena.alloc_slice_copy(&[run]),
+                        };
+                        legacy.push(&*self.arena.alloc_slice_copy(&[paragraph]));
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(if comments.is_empty() {
+            legacy.into_bump_slice()
+        } else {
+            comments.into_bump_slice()
+        })
+    }
+}
+
+/// Returns a run without text carrying the formatting flags of `formatting`.
+fn formatted_run<'a>(formatting: &Formatting) -> ArenaRun<'a> {
+    ArenaRun {
+        bold: formatting.bold,
+        italic: formatting.italic,
+        underlined: formatting.underlined,
+        strikethrough: formatting.strikethrough,
+        ..Default::default()
+    }
+}
+
+fn apply_run_properties(run: &mut ArenaRun<'_>, properties: &PartialFormatting) {
+    run.bold = properties.bold.unwrap_or(run.bold);
+    run.italic = properties.italic.unwrap_or(run.italic);
+    run.underlined = properties.underlined.unwrap_or(run.underlined);
+    run.strikethrough = properties.strikethrough.unwrap_or(run.strikethrough);
+}
+
+/// Returns whether any paragraph has runs, like [`crate::TextElement::is_empty`] negated.
+fn has_runs(paragraphs: &[ArenaParagraph<'_>]) -> bool {
+    paragraphs
+        .iter()
+        .any(|paragraph| !paragraph.runs.is_empty())
+}
+
+/// Appends the text content of the element ending with `end` to `text`.
+fn read_text_in(xml: &mut XmlReader<'_>, end: &[u8], text: &mut BumpString<'_>) -> Result<()> {
+    loop {
+        match event(xml, "DrawingML text")? {
+            Event::Text(content) => push_text(&content, "DrawingML text", text)?,
+            Event::GeneralRef(content) => {
+                text.push_str(&crate::xml::reference(&content, "DrawingML text")?);
+            }
+            Event::CData(content) => text.push_str(&String::from_utf8_lossy(content.as_ref())),
+            Event::End(element) if end_is(element.name().as_ref(), end) => break,
+            Event::Eof => return Err(Error::ParseError("Unexpected end of XML text")),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/arena.rs"]
+mod tests;
@@ -0,0 +1,113 @@
+use crate::{Error, ImageHandlingMode, ParserConfig, PptxContainer, Result};
+use rayon::prelude::*;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The outcome of [`convert_directory`].
+#[derive(Debug, Default)]
+pub struct BatchReport {
+    pub converted: Vec<BatchConversion>,
+    pub failed: Vec<BatchFailure>,
+}
+
+impl BatchReport {
+    /// Returns whether every discovered file was converted.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// A presentation converted by [`convert_directory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchConversion {
+    pub input: PathBuf,
+    pub output: PathBuf,
+}
+
+/// A presentation [`convert_directory`] could not convert.
+#[derive(Debug)]
+pub struct BatchFailure {
+    pub input: PathBuf,
+    pub error: Error,
+}
+
+/// Converts every `.pptx` and macro-enabled `.pptm` file below `input_dir` to Markdown.
+///
+/// Files are discovered recursively and converted in parallel. Each output is written to the
+/// same relative path below `output_dir` with the extension replaced by `.md`, creating
+/// directories as needed. A file that fails to convert is recorded in
+/// [`BatchReport::failed`] and does not stop the remaining conversions.
+///
+/// With [`ImageHandlingMode::Save`], the images of each file are saved to its own directory
+/// below [`ParserConfig::image_output_path`], named like the output without the extension,
+/// so images of different files do not overwrite each other. Symbolic links to directories
+/// are not followed.
+///
+/// # Errors
+///
+/// Returns an error only if `input_dir` can not be traversed.
+pub fn convert_directory(
+    input_dir: &Path,
+    output_dir: &Path,
+    config: ParserConfig,
+) -> Result<BatchReport> {
+    let mut inputs = Vec::new();
+    collect_presentations(input_dir, &mut inputs)?;
+    inputs.sort();
+
+    let results: Vec<(PathBuf, Result<PathBuf>)> = inputs
+        .into_par_iter()
+        .map(|input| {
+            let relative = input.strip_prefix(input_dir).unwrap_or(&input);
+            let output = output_dir.join(relative).with_extension("md");
+            let result =
+                convert_file(&input, &output, file_config(&config, relative)).map(|_| output);
+            (input, result)
+        })
+        .collect();
+
+    let mut report = BatchReport::default();
+    for (input, result) in results {
+        match result {
+            Ok(output) => report.converted.push(BatchConversion { input, output }),
+            Err(error) => report.failed.push(BatchFailure { input, error }),
+        }
+    }
+    Ok(report)
+}
+
+fn convert_file(input: &Path, output: &Path, config: ParserConfig) -> Result<()> {
+    let markdown = PptxContainer::open(input, config)?.convert_to_md()?;
+    if let Some(parent) = output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(output, markdown)?;
+    Ok(())
+}
+
+/// Returns the config for the file at `relative`, with its own image directory when images
+/// are saved.
+fn file_config(config: &ParserConfig, relative: &Path) -> ParserConfig {
+    let mut config = config.clone();
+    if config.image_handling_mode == ImageHandlingMode::Save
+        && let Some(images) = &config.image_output_path
+    {
+        config.image_output_path = Some(images.join(relative.with_extension("")));
+    }
+    config
+}
+
+fn collect_presentations(directory: &Path, inputs: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            collect_presentations(&path, inputs)?;
+        } else if path.extension().is_some_and(|extension| {
+            extension.eq_ignore_ascii_case("pptx") || extension.eq_ignore_ascii_case("pptm")
+        }) {
+            inputs.push(path);
+        }
+    }
+    Ok(())
+}
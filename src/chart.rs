@@ -0,0 +1,179 @@
+use crate::constants::{A_NAMESPACE, C_NAMESPACE};
+use crate::parse_xml::read_simple_text;
+use crate::xml::{XmlReader, attr, element_is, end_is, event, reader, skip_element};
+use crate::{ChartElement, ChartSeries, Error, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::ResolveResult;
+
+const PART: &str = "DrawingML chart";
+
+/// Parses the title, categories and series of a chart part from the values cached in it.
+///
+/// The series of all plots of the plot area are collected, so combination charts return
+/// the series of every chart type. The categories are taken from the first series that has
+/// any. The relationship id of the returned chart is left empty.
+pub(crate) fn parse_chart(xml_data: &[u8]) -> Result<ChartElement> {
+    let mut xml = reader(xml_data);
+    let mut chart = ChartElement::default();
+    loop {
+        match event(&mut xml, PART)? {
+            Event::Start(element) if element_is(&xml, &element, C_NAMESPACE, b"title") => {
+                let title = read_text(&mut xml, b"title")?;
+                if chart.title.is_none() && !title.is_empty() {
+                    chart.title = Some(title);
+                }
+            }
+            Event::Start(element)
+                if element_is(&xml, &element, C_NAMESPACE, b"valAx")
+                    || element_is(&xml, &element, C_NAMESPACE, b"catAx")
+                    || element_is(&xml, &element, C_NAMESPACE, b"dateAx")
+                    || element_is(&xml, &element, C_NAMESPACE, b"serAx") =>
+            {
+                // Axis titles are not the chart title.
+                let end = element.local_name().as_ref().to_vec();
+                skip_element(&mut xml, &end, PART)?;
+            }
+            Event::Start(element) if chart.chart_type.is_none() && is_plot(&xml, &element) => {
+                chart.chart_type =
+                    Some(String::from_utf8_lossy(element.local_name().as_ref()).into_owned());
+            }
+            Event::Start(element) if element_is(&xml, &element, C_NAMESPACE, b"ser") => {
+                let (series, categories) = parse_series(&mut xml)?;
+                if chart.categories.is_empty() {
+                    chart.categories = categories;
+                }
+                chart.series.push(series);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(chart)
+}
+
+/// Returns whether the element is a plot of the plot area, such as `c:barChart`.
+fn is_plot(xml: &XmlReader<'_>, element: &BytesStart<'_>) -> bool {
+    let (namespace, name) = xml.resolver().resolve_element(element.name());
+    name.as_ref().ends_with(b"Chart")
+        && matches!(namespace, ResolveResult::Bound(value) if value.as_ref() == C_NAMESPACE.as_bytes())
+}
+
+/// Parses a `c:ser` element, returning the series and its category labels.
+fn parse_series(xml: &mut XmlReader<'_>) -> Result<(ChartSeries, Vec<String>)> {
+    let mut series = ChartSeries::default();
+    let mut categories = Vec::new();
+    loop {
+        match event(xml, PART)? {
+            Event::Start(element) if element_is(xml, &element, C_NAMESPACE, b"tx") => {
+                let name = read_text(xml, b"tx")?;
+                series.name = (!name.is_empty()).then_some(name);
+            }
+            Event::Start(element)
+                if element_is(xml, &element, C_NAMESPACE, b"cat")
+                    || element_is(xml, &element, C_NAMESPACE, b"xVal") =>
+            {
+                let end = element.local_name().as_ref().to_vec();
+                categories = read_points(xml, &end)?
+                    .into_iter()
+                    .map(Option::unwrap_or_default)
+                    .collect();
+            }
+            Event::Start(element)
+                if element_is(xml, &element, C_NAMESPACE, b"val")
+                    || element_is(xml, &element, C_NAMESPACE, b"yVal") =>
+            {
+                let end = element.local_name().as_ref().to_vec();
+                series.values = read_points(xml, &end)?
+                    .into_iter()
+                    .map(|point| point.and_then(|value| value.trim().parse().ok()))
+                    .collect();
+            }
+            Event::Start(element) => {
+                // Data labels, trendlines and formatting may hold text of their own.
+                let end = element.local_name().as_ref().to_vec();
+                skip_element(xml, &end, PART)?;
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"ser") => break,
+            Event::Eof => return Err(Error::ParseError("Unexpected end of chart series")),
+            _ => {}
+        }
+    }
+    Ok((series, categories))
+}
+
+/// Reads the cached points of a `c:cat` or `c:val` element by their `idx`.
+///
+/// The list has `c:ptCount` entries; points without a value are `None`. Of multi-level
+/// categories only the innermost level, which comes first, is kept.
+fn read_points(xml: &mut XmlReader<'_>, end: &[u8]) -> Result<Vec<Option<String>>> {
+    let mut points: Vec<Option<String>> = Vec::new();
+    let mut index = 0usize;
+    let mut next_index = 0usize;
+    loop {
+        match event(xml, PART)? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, C_NAMESPACE, b"ptCount") =>
+            {
+                let count = attr(&element, b"val")
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or(0);
+                if points.len() < count {
+                    points.resize(count, None);
+                }
+            }
+            Event::Start(element) if element_is(xml, &element, C_NAMESPACE, b"pt") => {
+                index = attr(&element, b"idx")
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or(next_index);
+                next_index = index + 1;
+            }
+            Event::Start(element) if element_is(xml, &element, C_NAMESPACE, b"v") => {
+                let value = read_simple_text(xml, b"v", PART)?;
+                if points.len() <= index {
+                    points.resize(index + 1, None);
+                }
+                if points[index].is_none() {
+                    points[index] = Some(value);
+                }
+            }
+            Event::End(element) if end_is(element.name().as_ref(), end) => break,
+            Event::Eof => return Err(Error::ParseError("Unexpected end of chart data")),
+            _ => {}
+        }
+    }
+    Ok(points)
+}
+
+/// Reads the text of a title or series name, given as rich text or as a cached string
+/// reference. Paragraphs are joined with spaces.
+fn read_text(xml: &mut XmlReader<'_>, end: &[u8]) -> Result<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        match event(xml, PART)? {
+            Event::Start(element)
+                if element_is(xml, &element, A_NAMESPACE, b"t")
+                    || element_is(xml, &element, C_NAMESPACE, b"v") =>
+            {
+                let end = element.local_name().as_ref().to_vec();
+                parts.push(read_simple_text(xml, &end, PART)?);
+            }
+            Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"p") => {
+                if !parts.last().is_none_or(|part| part.ends_with(' ')) {
+                    parts.push(" ".to_string());
+                }
+                depth += 1;
+            }
+            Event::Start(_) => depth += 1,
+            Event::End(element) if depth == 0 && end_is(element.name().as_ref(), end) => break,
+            Event::End(_) => depth = depth.saturating_sub(1),
+            Event::Eof => return Err(Error::ParseError("Unexpected end of chart text")),
+            _ => {}
+        }
+    }
+    Ok(parts.concat().trim().to_string())
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/chart.rs"]
+mod tests;
@@ -1,5 +1,9 @@
 pub const P_NAMESPACE: &str = "http://schemas.openxmlformats.org/presentationml/2006/main";
 pub const A_NAMESPACE: &str = "http://schemas.openxmlformats.org/drawingml/2006/main";
+pub const C_NAMESPACE: &str = "http://schemas.openxmlformats.org/drawingml/2006/chart";
+pub const P14_NAMESPACE: &str = "http://schemas.microsoft.com/office/powerpoint/2010/main";
+pub const RELATIONSHIPS_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
 pub const IMAGE_NAMESPACE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/image";
 pub const SLIDE_LAYOUT_NAMESPACE: &str =
@@ -10,5 +14,19 @@ pub const NOTES_SLIDE_NAMESPACE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesSlide";
 pub const COMMENTS_NAMESPACE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments";
+pub const SLIDE_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide";
+pub const CHART_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart";
+pub const PACKAGE_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/package";
 pub const HYPERLINK_NAMESPACE: &str =
     "http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink";
+pub const THEME_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/theme";
+pub const VBA_PROJECT_NAMESPACE: &str =
+    "http://schemas.microsoft.com/office/2006/relationships/vbaProject";
+pub const VIDEO_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/video";
+pub const AUDIO_NAMESPACE: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships/audio";
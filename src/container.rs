@@ -1,15 +1,43 @@
 use super::{Result, Slide};
-use crate::PresentationMetadata;
+use crate::chart::parse_chart;
 use crate::constants::{
-    COMMENTS_NAMESPACE, NOTES_SLIDE_NAMESPACE, SLIDE_LAYOUT_NAMESPACE, SLIDE_MASTER_NAMESPACE,
+    AUDIO_NAMESPACE, CHART_NAMESPACE, COMMENTS_NAMESPACE, NOTES_SLIDE_NAMESPACE, PACKAGE_NAMESPACE,
+    SLIDE_LAYOUT_NAMESPACE, SLIDE_MASTER_NAMESPACE, THEME_NAMESPACE, VBA_PROJECT_NAMESPACE,
+    VIDEO_NAMESPACE,
+};
+use crate::limits::{PackageBudget, check_package_limits, check_slide_limits, limit_exceeded};
+use crate::manifest::{ChangedSlides, PartManifest};
+use crate::metadata::{
+    parse_pptx_metadata, render_presentation_markdown, write_presentation_markdown,
+    write_rendered_markdown,
+};
+use crate::parse_presentation::{
+    TableStyles, parse_pptx_sections, parse_slide_order, parse_slide_size, parse_table_styles,
+};
+use crate::parse_rels::{Relationship, hyperlink_targets, image_references, parse_relationships};
+use crate::parse_xml::{
+    InheritedPositions, ParsedNotes, ParsedSlideDocument, extract_inherited_positions,
+    parse_layout_type, parse_ole_objects, parse_part_name, parse_shows_master_shapes,
+    parse_slide_tables, parse_slide_title, parse_static_content,
+};
+use crate::parser_config::{
+    OnSlideError, ParseOptions, ParserConfig, RenderOptions, ResourceLimits,
+};
+use crate::slide::detect_mime_type;
+use crate::theme::{Theme, parse_background, parse_theme, resolve_background};
+use crate::types::assign_element_ids;
+use crate::xml::repair_utf8;
+use crate::{
+    Background, ChartElement, ChartWorkbook, EmbeddedObject, MediaItem, MediaKind, MediaReference,
+    PresentationMetadata, PresentationSection, TableElement, ThemeSummary,
 };
-use crate::metadata::{parse_pptx_metadata, render_presentation_markdown};
-use crate::parse_rels::{parse_hyperlink_rels, parse_relationships};
-use crate::parse_xml::{InheritedPositions, extract_inherited_positions};
-use crate::parser_config::ParserConfig;
 use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::sync::Arc;
-use std::{collections::HashMap, io::Read, path::Path};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, SyncSender, sync_channel};
+use std::thread::JoinHandle;
+use std::{io::Read, path::Path};
 
 /// Holds the internal representation of a loaded PowerPoint (pptx) container.
 ///
@@ -22,6 +50,16 @@ pub struct PptxContainer {
     pub slide_paths: Vec<String>,
     pub slide_count: u32,
     metadata: PresentationMetadata,
+    sections: Vec<PresentationSection>,
+    /// The slide width and height in EMU from the `p:sldSz` of `ppt/presentation.xml`.
+    slide_size: Option<(i64, i64)>,
+    table_styles: Arc<TableStyles>,
+    media: MediaBudget,
+    relationships: HashMap<String, Arc<[Relationship]>>,
+    layouts: HashMap<String, Arc<LayoutInfo>>,
+    package: PackageBudget,
+    vba_project: Option<String>,
+    pub(crate) slide_buffer: Vec<u8>,
 }
 
 impl PptxContainer {
@@ -48,26 +86,79 @@ impl PptxContainer {
     ///
     /// Errors are returned on file access problems or failures during the unzipping process.
     pub fn open(path: &Path, config: ParserConfig) -> Result<Self> {
-        let file = std::fs::File::open(path)?;
+        Self::from_file(std::fs::File::open(path)?, config)
+    }
+
+    /// Opens a PPTX package from an already opened file.
+    pub(crate) fn from_file(file: std::fs::File, config: ParserConfig) -> Result<Self> {
         let mut archive = zip::ZipArchive::new(file)?;
+        check_package_limits(&mut archive, &config.resource_limits)?;
 
         let mut slide_paths: Vec<String> = Vec::new();
         let mut slide_count = 0;
+        let mut package = PackageBudget::default();
 
-        for i in 0..archive.len() {
-            let file = archive.by_index(i)?;
-            let name = file.name().to_string();
-
-            if name.starts_with("ppt/slides/slide") && name.ends_with(".xml") {
-                slide_paths.push(name);
+        for name in archive.file_names() {
+            if is_slide_part(name) {
+                slide_paths.push(name.to_string());
                 slide_count += 1;
             }
         }
 
-        sort_slide_paths(&mut slide_paths);
+        let core_xml = read_optional_archive_file(
+            &mut archive,
+            &mut package,
+            "docProps/core.xml",
+            &config.resource_limits,
+        )?;
+        let app_xml = read_optional_archive_file(
+            &mut archive,
+            &mut package,
+            "docProps/app.xml",
+            &config.resource_limits,
+        )?;
+        let metadata = parse_pptx_metadata(core_xml.as_deref(), app_xml.as_deref())?;
 
-        let core_xml = read_optional_archive_file(&mut archive, "docProps/core.xml")?;
-        let metadata = parse_pptx_metadata(core_xml.as_deref())?;
+        let presentation_xml = read_optional_archive_file(
+            &mut archive,
+            &mut package,
+            "ppt/presentation.xml",
+            &config.resource_limits,
+        )?;
+        let presentation_rels = read_optional_archive_file(
+            &mut archive,
+            &mut package,
+            "ppt/_rels/presentation.xml.rels",
+            &config.resource_limits,
+        )?;
+        let presentation_rels = presentation_rels
+            .map(|rels| parse_relationships(&rels))
+            .transpose()?
+            .unwrap_or_default();
+        let (sections, slide_order, slide_size) = match presentation_xml {
+            Some(xml) => (
+                parse_pptx_sections(&xml, &presentation_rels)?,
+                parse_slide_order(&xml, &presentation_rels)?,
+                parse_slide_size(&xml)?,
+            ),
+            None => (Vec::new(), Vec::new(), None),
+        };
+        order_slide_paths(&mut slide_paths, &slide_order);
+        let vba_project = presentation_rels
+            .iter()
+            .find(|rel| rel.rel_type == VBA_PROJECT_NAMESPACE)
+            .map(|rel| Self::resolve_target_path("ppt/presentation.xml", &rel.target))
+            .or_else(|| Some(DEFAULT_VBA_PROJECT_PART.to_string()))
+            .filter(|path| archive.index_for_name(path).is_some());
+        let table_styles = read_optional_archive_file(
+            &mut archive,
+            &mut package,
+            "ppt/tableStyles.xml",
+            &config.resource_limits,
+        )?
+        .map(|xml| parse_table_styles(&xml))
+        .transpose()?
+        .unwrap_or_default();
 
         Ok(Self {
             archive,
@@ -75,9 +166,27 @@ impl PptxContainer {
             config,
             slide_count,
             metadata,
+            sections,
+            slide_size,
+            table_styles: Arc::new(table_styles),
+            media: MediaBudget::default(),
+            relationships: HashMap::new(),
+            layouts: HashMap::new(),
+            package,
+            vba_project,
+            slide_buffer: Vec::new(),
         })
     }
 
+    /// Opens a PPTX file with only parse options. Slides are rendered with
+    /// [`PptxContainer::convert_to_md_with`] and explicit [`RenderOptions`].
+    pub fn open_with_options(path: &Path, options: ParseOptions) -> Result<Self> {
+        Self::open(
+            path,
+            ParserConfig::from_options(options, RenderOptions::default()),
+        )
+    }
+
     /// Parses the data of all slides for each path present in the containers' `slide_path` vector.
     ///
     /// # Note
@@ -96,26 +205,363 @@ impl PptxContainer {
         Ok(slides)
     }
 
+    /// Returns the CRC-32 checksums and sizes of all parts of the package.
+    ///
+    /// Only the central directory of the archive is read. Keep the manifest together with
+    /// the parsed slides and pass it to [`PptxContainer::parse_changed`] after the deck was
+    /// updated.
+    pub fn manifest(&mut self) -> Result<PartManifest> {
+        let mut manifest = PartManifest::default();
+        for index in 0..self.archive.len() {
+            let file = self.archive.by_index_raw(index)?;
+            manifest.insert(file.name().to_string(), file.crc32(), file.size());
+        }
+        Ok(manifest)
+    }
+
+    /// Parses only the slides that changed since `previous` was taken.
+    ///
+    /// A slide is parsed again if it is new or if its XML, its relationships or any part it
+    /// references, such as images, notes, comments, its layout, master and theme, or the
+    /// table styles of the presentation, was modified. Links to other slides do not count as
+    /// a dependency. The numbers of all other slides are returned as unchanged, so their
+    /// previous results can be reused as long as the [`ParserConfig`] is the same.
+    pub fn parse_changed(&mut self, previous: &PartManifest) -> Result<ChangedSlides> {
+        let manifest = self.manifest()?;
+        let mut changed = ChangedSlides {
+            parsed: Vec::new(),
+            unchanged: Vec::new(),
+            removed: previous
+                .parts()
+                .filter(|part| is_slide_part(part) && !self.has_part(part))
+                .filter_map(Slide::extract_slide_number)
+                .collect(),
+            manifest: PartManifest::default(),
+        };
+        changed.removed.sort_unstable();
+        for slide_path in self.slide_paths.clone() {
+            let modified = self
+                .slide_dependencies(&slide_path)?
+                .iter()
+                .any(|part| manifest.differs(previous, part));
+            if !modified {
+                changed
+                    .unchanged
+                    .extend(Slide::extract_slide_number(&slide_path));
+            } else if let Some(slide) = self.load_slide(&slide_path)? {
+                changed.parsed.push(slide);
+            }
+        }
+        changed.manifest = manifest;
+        Ok(changed)
+    }
+
+    /// Collects the parts a slide is parsed from by following its relationships through
+    /// notes, layouts and masters. Links to other slides are skipped; missing targets are
+    /// kept as dependencies, so adding them later marks the slide as changed.
+    fn slide_dependencies(&mut self, slide_path: &str) -> Result<Vec<String>> {
+        let mut dependencies = vec!["ppt/tableStyles.xml".to_string()];
+        let mut pending = vec![slide_path.to_string()];
+        let mut visited = HashSet::new();
+        while let Some(part) = pending.pop() {
+            if !visited.insert(part.clone()) {
+                continue;
+            }
+            dependencies.push(self.get_slide_rels_path(&part));
+            for relationship in self.relationships(&part)?.iter() {
+                let target = Self::resolve_target_path(&part, &relationship.target);
+                if target != slide_path && is_slide_part(&target) {
+                    continue;
+                }
+                if self.has_part(&target) {
+                    pending.push(target);
+                } else {
+                    dependencies.push(target);
+                }
+            }
+            dependencies.push(part);
+        }
+        Ok(dependencies)
+    }
+
     pub fn metadata(&self) -> &PresentationMetadata {
         &self.metadata
     }
 
+    /// Returns the slide number and title of every slide without parsing the slides.
+    ///
+    /// Only the text of the title placeholder is read; images, tables and other shapes are
+    /// skipped. Unlike [`Slide::title`], slides without a title placeholder return `None`
+    /// instead of falling back to their first heading.
+    pub fn titles(&mut self) -> Result<Vec<(u32, Option<String>)>> {
+        let mut titles = Vec::with_capacity(self.slide_paths.len());
+        for slide_path in self.slide_paths.clone() {
+            let Some(slide_number) = Slide::extract_slide_number(&slide_path) else {
+                continue;
+            };
+            let mut slide_xml = Vec::new();
+            self.read_slide_into(&slide_path, &mut slide_xml)?;
+            titles.push((slide_number, parse_slide_title(&slide_xml)?));
+        }
+        Ok(titles)
+    }
+
+    /// Returns the tables of all slides with their slide numbers.
+    ///
+    /// Only graphic frames are parsed; text shapes, pictures and image data are skipped, so
+    /// this is much cheaper than [`PptxContainer::parse_all`] for decks mined for tables.
+    pub fn extract_tables(&mut self) -> Result<Vec<(u32, TableElement)>> {
+        let mut tables = Vec::new();
+        for slide_path in self.slide_paths.clone() {
+            let slide_number = Slide::extract_slide_number(&slide_path).unwrap_or(0);
+            let mut slide_xml = Vec::new();
+            self.read_slide_into(&slide_path, &mut slide_xml)?;
+            check_slide_limits(&slide_xml, &slide_path, &self.config.resource_limits)?;
+            let hyperlinks = hyperlink_targets(&self.relationships(&slide_path)?);
+            tables.extend(
+                parse_slide_tables(&slide_xml, &hyperlinks, &self.table_styles)?
+                    .into_iter()
+                    .map(|table| (slide_number, table)),
+            );
+        }
+        Ok(tables)
+    }
+
+    /// Returns the slide number and speaker notes text of every slide with notes.
+    ///
+    /// Only the relationship and notes parts are read; slide shapes and images are not
+    /// parsed. The text frames of the notes are separated by line breaks.
+    pub fn extract_notes(&mut self) -> Result<Vec<(u32, String)>> {
+        let mut notes = Vec::new();
+        for slide_path in self.slide_paths.clone() {
+            let Some(slide_number) = Slide::extract_slide_number(&slide_path) else {
+                continue;
+            };
+            let relationships = self.relationships(&slide_path)?;
+            let text = self
+                .resolve_speaker_notes(&slide_path, &relationships)?
+                .text
+                .iter()
+                .map(|note| note.text().trim_end().to_string())
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join("\n");
+            if !text.is_empty() {
+                notes.push((slide_number, text));
+            }
+        }
+        Ok(notes)
+    }
+
+    /// Returns the sections of the presentation with the numbers of their slides, in the
+    /// order of the section list in `ppt/presentation.xml`.
+    ///
+    /// Sections are read from the `p14:sectionLst` extension when the container is opened.
+    /// Presentations without sections return an empty slice.
+    pub fn sections(&self) -> &[PresentationSection] {
+        &self.sections
+    }
+
+    /// Returns the width and height of the slides in EMU, as set in `ppt/presentation.xml`.
+    ///
+    /// Pass it to [`crate::ElementPosition::to_percent`] to place elements relative to the
+    /// slide. `None` if the presentation part declares no slide size.
+    pub fn slide_size(&self) -> Option<(i64, i64)> {
+        self.slide_size
+    }
+
+    /// Extracts the embedded workbooks that hold the source data of the charts on all slides.
+    ///
+    /// Charts reference their workbook through a package relationship, usually pointing to
+    /// an `.xlsx` file under `ppt/embeddings/`. Charts without an embedded workbook, e.g.
+    /// with linked external data, are skipped.
+    pub fn chart_workbooks(&mut self) -> Result<Vec<ChartWorkbook>> {
+        let mut workbooks = Vec::new();
+        for slide_path in self.slide_paths.clone() {
+            let slide_number = Slide::extract_slide_number(&slide_path).unwrap_or(0);
+            let relationships = self.relationships(&slide_path)?;
+            for chart in relationships
+                .iter()
+                .filter(|rel| rel.rel_type == CHART_NAMESPACE)
+            {
+                let chart_path = Self::resolve_target_path(&slide_path, &chart.target);
+                let chart_relationships = self.relationships(&chart_path)?;
+                for package in chart_relationships
+                    .iter()
+                    .filter(|rel| rel.rel_type == PACKAGE_NAMESPACE)
+                {
+                    let path = Self::resolve_target_path(&chart_path, &package.target);
+                    let data = match self.read_file_from_archive(&path) {
+                        Ok(data) => data,
+                        Err(crate::Error::Zip(zip::result::ZipError::FileNotFound)) => continue,
+                        Err(error) => return Err(error),
+                    };
+                    workbooks.push(ChartWorkbook {
+                        slide_number,
+                        chart_relationship_id: chart.id.clone(),
+                        chart_path: chart_path.clone(),
+                        path,
+                        data,
+                    });
+                }
+            }
+        }
+        Ok(workbooks)
+    }
+
+    /// Extracts the OLE objects embedded in the graphic frames of all slides, e.g. Excel
+    /// worksheets or Word documents, with their ProgID.
+    ///
+    /// Objects created by current Office versions are stored as the original `.xlsx` or
+    /// `.docx` file under `ppt/embeddings/`, older ones as an `oleObject*.bin` compound file.
+    /// Linked objects and objects whose part is missing are skipped.
+    pub fn embedded_objects(&mut self) -> Result<Vec<EmbeddedObject>> {
+        let mut objects = Vec::new();
+        for slide_path in self.slide_paths.clone() {
+            let slide_number = Slide::extract_slide_number(&slide_path).unwrap_or(0);
+            let mut slide_xml = Vec::new();
+            self.read_slide_into(&slide_path, &mut slide_xml)?;
+            let ole_objects = parse_ole_objects(&slide_xml)?;
+            if ole_objects.is_empty() {
+                continue;
+            }
+            let relationships = self.relationships(&slide_path)?;
+            for object in ole_objects {
+                let Some(relationship) = relationships
+                    .iter()
+                    .find(|rel| rel.id == object.relationship_id)
+                else {
+                    continue;
+                };
+                if relationship.external {
+                    continue;
+                }
+                let path = Self::resolve_target_path(&slide_path, &relationship.target);
+                let data = match self.read_file_from_archive(&path) {
+                    Ok(data) => data,
+                    Err(crate::Error::Zip(zip::result::ZipError::FileNotFound)) => continue,
+                    Err(error) => return Err(error),
+                };
+                objects.push(EmbeddedObject {
+                    slide_number,
+                    relationship_id: object.relationship_id,
+                    prog_id: object.prog_id,
+                    name: object.name,
+                    path,
+                    data,
+                });
+            }
+        }
+        Ok(objects)
+    }
+
+    /// Returns whether the package contains a VBA project, i.e. it is a macro-enabled
+    /// `.pptm` deck.
+    ///
+    /// The project is found through the relationship of type
+    /// `http://schemas.microsoft.com/office/2006/relationships/vbaProject` of
+    /// `ppt/presentation.xml`, falling back to `ppt/vbaProject.bin`. Parsing is not affected
+    /// by macros, which are never executed.
+    pub fn has_vba_project(&self) -> bool {
+        self.vba_project.is_some()
+    }
+
+    /// Returns the part name of the VBA project, e.g. `ppt/vbaProject.bin`.
+    pub fn vba_project_path(&self) -> Option<&str> {
+        self.vba_project.as_deref()
+    }
+
+    /// Extracts the binary VBA project of a macro-enabled deck, or `None` if the package has
+    /// none.
+    ///
+    /// The data is the raw OLE compound file as stored in the package, e.g. for a virus
+    /// scanner or a macro analysis tool.
+    pub fn vba_project(&mut self) -> Result<Option<Vec<u8>>> {
+        match self.vba_project.clone() {
+            Some(path) => self.read_file_from_archive(&path).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Lists every part under `ppt/media/` with its size, MIME type and referencing slides.
+    ///
+    /// Only the relationships of the slides and the first bytes of every media part are
+    /// read, so this is cheap even for large decks. Parts referenced only by layouts,
+    /// masters or notes have no slides.
+    pub fn media_inventory(&mut self) -> Result<Vec<MediaItem>> {
+        let mut slides: HashMap<String, Vec<u32>> = HashMap::new();
+        for slide_path in self.slide_paths.clone() {
+            let Some(slide_number) = Slide::extract_slide_number(&slide_path) else {
+                continue;
+            };
+            for relationship in self.relationships(&slide_path)?.iter() {
+                let path = Self::resolve_target_path(&slide_path, &relationship.target);
+                let numbers = slides.entry(path).or_default();
+                if !numbers.contains(&slide_number) {
+                    numbers.push(slide_number);
+                }
+            }
+        }
+        let paths: Vec<String> = self
+            .part_names()
+            .into_iter()
+            .filter(|name| name.starts_with("ppt/media/"))
+            .map(str::to_string)
+            .collect();
+        let mut inventory = Vec::with_capacity(paths.len());
+        for path in paths {
+            let file = self.archive.by_index(self.part_index(&path)?)?;
+            let size = file.size();
+            let mut header = Vec::with_capacity(64);
+            file.take(64).read_to_end(&mut header)?;
+            let mut slides = slides.remove(&path).unwrap_or_default();
+            slides.sort_unstable();
+            inventory.push(MediaItem {
+                mime_type: detect_mime_type(&header, &path).map(str::to_string),
+                path,
+                size,
+                slides,
+            });
+        }
+        Ok(inventory)
+    }
+
     pub fn convert_to_md(&mut self) -> Result<String> {
         let slides = self.parse_all()?;
-        render_presentation_markdown(
-            &self.metadata,
-            self.config.include_presentation_metadata,
-            slides,
-        )
+        render_presentation_markdown(&self.metadata, &self.config.render_options(), slides)
     }
 
     pub fn convert_to_md_multi_threaded(&mut self) -> Result<String> {
         let slides = self.parse_all_multi_threaded()?;
-        render_presentation_markdown(
-            &self.metadata,
-            self.config.include_presentation_metadata,
-            slides,
-        )
+        render_presentation_markdown(&self.metadata, &self.config.render_options(), slides)
+    }
+
+    /// Writes the Markdown of [`PptxContainer::convert_to_md`] to `writer`.
+    ///
+    /// Slides are parsed one at a time and written as soon as they are rendered, so neither
+    /// all slides nor the complete Markdown are held in memory.
+    pub fn render_md_to<W: std::io::Write + ?Sized>(&mut self, writer: &mut W) -> Result<()> {
+        let options = self.config.render_options();
+        self.render_md_with(writer, &options)
+    }
+
+    /// Converts all slides to one Markdown document with explicit render options instead of
+    /// the rendering part of the container's [`ParserConfig`].
+    pub fn convert_to_md_with(&mut self, options: &RenderOptions) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.render_md_with(&mut buffer, options)?;
+        String::from_utf8(buffer).map_err(|error| crate::Error::Utf8(error.utf8_error()))
+    }
+
+    /// Writes the Markdown of [`PptxContainer::convert_to_md_with`] to `writer`, one slide
+    /// at a time.
+    pub fn render_md_with<W: std::io::Write + ?Sized>(
+        &mut self,
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> Result<()> {
+        let metadata = self.metadata.clone();
+        write_presentation_markdown(&metadata, options, self.iter_slides(), writer)
     }
 
     /// Parses all slides in the presentation with optimized multithreaded processing.
@@ -129,126 +575,283 @@ impl PptxContainer {
     ///
     /// * `Result<Vec<Slide>>` - List of all parsed slides
     pub fn parse_all_multi_threaded(&mut self) -> Result<Vec<Slide>> {
-        // Clone paths upfront to avoid holding reference to self
+        let (raw_data, shared_image_data) = self.preload_all_slides();
+        let config = self.config.clone();
+
+        // Parallel processing starts here (CPU-bound tasks)
+        let slides: Result<Vec<_>> = raw_data
+            .into_par_iter()
+            .map(|(path, number, preloaded)| {
+                build_preloaded_slide(path, number, preloaded, &shared_image_data, &config)
+            })
+            .collect();
+
+        slides.map(|slides| slides.into_iter().flatten().collect())
+    }
+
+    /// Parses all slides with the strategy [`PptxContainer::parse_strategy`] picks for this
+    /// deck under the default [`AutoParseThresholds`].
+    ///
+    /// The result is the same as [`PptxContainer::parse_all`], only the time and memory it
+    /// takes differ.
+    pub fn parse_auto(&mut self) -> Result<Vec<Slide>> {
+        self.parse_auto_with(&AutoParseThresholds::default())
+    }
+
+    /// Parses all slides with the strategy [`PptxContainer::parse_strategy`] picks under
+    /// `thresholds`.
+    pub fn parse_auto_with(&mut self, thresholds: &AutoParseThresholds) -> Result<Vec<Slide>> {
+        match self.parse_strategy(thresholds)? {
+            ParseStrategy::Sequential => self.parse_all(),
+            ParseStrategy::Chunked { chunk_size } => self.parse_chunked(chunk_size),
+            ParseStrategy::Parallel => self.parse_all_multi_threaded(),
+        }
+    }
+
+    /// Chooses how [`PptxContainer::parse_auto_with`] parses this deck.
+    ///
+    /// Only the central directory of the archive is read. Small decks and single-core
+    /// machines are parsed sequentially, because spawning parallel work costs more than it
+    /// saves. Decks whose media exceed [`AutoParseThresholds::max_parallel_media_bytes`] are
+    /// parsed in chunks of a few slides per core, so the preloaded archive data of the whole
+    /// deck is never held at once. Everything else is parsed fully in parallel.
+    pub fn parse_strategy(&mut self, thresholds: &AutoParseThresholds) -> Result<ParseStrategy> {
+        let cores = thresholds.cores.unwrap_or_else(|| {
+            std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+        });
+        if cores <= 1 || self.slide_paths.len() <= thresholds.max_sequential_slides {
+            return Ok(ParseStrategy::Sequential);
+        }
+
+        let mut media_bytes = 0u64;
+        for index in 0..self.archive.len() {
+            let file = self.archive.by_index_raw(index)?;
+            if file.name().starts_with("ppt/media/") {
+                media_bytes = media_bytes.saturating_add(file.size());
+            }
+        }
+        if media_bytes > thresholds.max_parallel_media_bytes {
+            let chunk_size = cores.saturating_mul(thresholds.slides_per_core.max(1));
+            return Ok(ParseStrategy::Chunked { chunk_size });
+        }
+        Ok(ParseStrategy::Parallel)
+    }
+
+    /// Parses all slides in parallel, `chunk_size` slides at a time.
+    ///
+    /// Works like [`PptxContainer::parse_all_multi_threaded`], but only the archive data of
+    /// the current chunk is preloaded, which bounds the memory needed for decks with large
+    /// media.
+    pub fn parse_chunked(&mut self, chunk_size: usize) -> Result<Vec<Slide>> {
         let slide_paths = self.slide_paths.clone();
         let config = self.config.clone();
-        let mut raw_data = Vec::with_capacity(slide_paths.len());
-        let mut all_image_data = HashMap::new();
+        let mut slides = Vec::with_capacity(slide_paths.len());
 
-        for slide_path in &slide_paths {
-            // Read slide XML and relationships
-            let slide_xml = self.read_file_from_archive(slide_path)?;
-            let rels_path = self.get_slide_rels_path(slide_path);
-            let rels_data = self.read_file_from_archive(&rels_path).ok();
-            let hyperlinks = rels_data
-                .as_deref()
-                .map(parse_hyperlink_rels)
-                .transpose()?
-                .unwrap_or_default();
-            let slide_number = Slide::extract_slide_number(slide_path).unwrap_or(0);
-            let inherited_positions =
-                self.resolve_inherited_positions(slide_path, rels_data.as_deref())?;
-            let speaker_notes = self.resolve_speaker_notes(slide_path, rels_data.as_deref())?;
-            let comments = self.resolve_comments(slide_path, rels_data.as_deref())?;
-
-            // Preload images if enabled
-            let mut slide_images = Vec::new();
-            let mut resource_diagnostics = Vec::new();
-            if config.extract_images {
-                if let Some(ref data) = rels_data {
-                    slide_images = crate::parse_rels::parse_slide_rels(data)?;
-                }
+        for chunk in slide_paths.chunks(chunk_size.max(1)) {
+            let (raw_data, shared_image_data) = self.preload_slides(chunk);
+            let parsed: Result<Vec<_>> = raw_data
+                .into_par_iter()
+                .map(|(path, number, preloaded)| {
+                    build_preloaded_slide(path, number, preloaded, &shared_image_data, &config)
+                })
+                .collect();
+            slides.extend(parsed?.into_iter().flatten());
+        }
 
-                for img_ref in &slide_images {
-                    let path = PptxContainer::resolve_target_path(slide_path, &img_ref.target);
-                    match self.read_file_from_archive(&path) {
-                        Ok(data) => {
-                            all_image_data.entry(img_ref.target.clone()).or_insert(data);
-                        }
-                        Err(error) => resource_diagnostics.push(crate::ParseDiagnostic {
-                            severity: crate::DiagnosticSeverity::Warning,
-                            message: format!("Image resource could not be loaded: {error}"),
-                            source: Some(path),
-                        }),
-                    }
+        Ok(slides)
+    }
+
+    /// Parses all slides in parallel and yields them in presentation order as they complete.
+    ///
+    /// The archive is read up front like in [`PptxContainer::parse_all_multi_threaded`], then
+    /// the slides are parsed on the rayon thread pool. A slide is returned as soon as it and
+    /// all slides before it are parsed, so downstream writers can start before the whole
+    /// presentation is done. Slides that finish early wait in a reorder buffer. Dropping the
+    /// iterator skips the slides that were not started yet.
+    pub fn iter_slides_multi_threaded(&mut self) -> OrderedSlideIterator {
+        let (raw_data, shared_image_data) = self.preload_all_slides();
+        let config = self.config.clone();
+        OrderedSlideIterator::spawn(raw_data.len(), move |sender, cancelled| {
+            // Parsing one window at a time keeps the slides waiting for a slow predecessor
+            // in the reorder buffer bounded.
+            let mut entries = raw_data.into_iter().enumerate();
+            while !cancelled.load(Ordering::Relaxed) {
+                let window: Vec<_> = entries.by_ref().take(ORDERED_SLIDE_WINDOW).collect();
+                if window.is_empty() {
+                    break;
                 }
+                window.into_par_iter().for_each_with(
+                    sender.clone(),
+                    |sender, (index, (path, number, preloaded))| {
+                        if cancelled.load(Ordering::Relaxed) {
+                            return;
+                        }
+                        let slide = build_preloaded_slide(
+                            path,
+                            number,
+                            preloaded,
+                            &shared_image_data,
+                            &config,
+                        );
+                        if sender.send((index, slide)).is_err() {
+                            cancelled.store(true, Ordering::Relaxed);
+                        }
+                    },
+                );
             }
+        })
+    }
 
-            raw_data.push((
-                slide_path.clone(),
-                slide_number,
-                slide_xml,
-                slide_images,
-                inherited_positions,
-                speaker_notes,
-                comments,
-                hyperlinks,
-                resource_diagnostics,
-            ));
+    /// Converts all slides to Markdown in a single parallel pipeline.
+    ///
+    /// Unlike [`PptxContainer::convert_to_md_multi_threaded`], which parses all slides in
+    /// parallel and then renders them one after another, every slide is parsed and rendered
+    /// in the same rayon task, so image compression, XML parsing and Markdown generation of
+    /// different slides overlap. Only reading the archive and joining the rendered slides
+    /// happen on the calling thread. The output is the same as
+    /// [`PptxContainer::convert_to_md`].
+    pub fn convert_all_to_md(&mut self) -> Result<String> {
+        let (raw_data, shared_image_data) = self.preload_all_slides();
+        let config = self.config.clone();
+        let options = config.render_options();
+
+        let rendered: Result<Vec<_>> = raw_data
+            .into_par_iter()
+            .map(|(path, number, preloaded)| {
+                let Some(slide) =
+                    build_preloaded_slide(path, number, preloaded, &shared_image_data, &config)?
+                else {
+                    return Ok(None);
+                };
+                let mut markdown = Vec::new();
+                slide.render_md_with(&mut markdown, &options)?;
+                Ok(Some(markdown))
+            })
+            .collect();
+
+        let mut output = Vec::new();
+        write_rendered_markdown(
+            &self.metadata,
+            &options,
+            rendered?.into_iter().flatten(),
+            &mut output,
+        )?;
+        String::from_utf8(output).map_err(|error| crate::Error::Utf8(error.utf8_error()))
+    }
+
+    /// Reads the data of all slides for parallel parsing, together with the image data
+    /// shared between them.
+    ///
+    /// Image data is keyed by the resolved part path because relationship ids and relative
+    /// targets are only meaningful per slide.
+    fn preload_all_slides(&mut self) -> (Vec<PreloadedEntry>, Arc<HashMap<String, Vec<u8>>>) {
+        // Clone paths upfront to avoid holding reference to self
+        let slide_paths = self.slide_paths.clone();
+        self.preload_slides(&slide_paths)
+    }
+
+    /// Reads the data of the given slides for parallel parsing, like
+    /// [`PptxContainer::preload_all_slides`].
+    fn preload_slides(
+        &mut self,
+        slide_paths: &[String],
+    ) -> (Vec<PreloadedEntry>, Arc<HashMap<String, Vec<u8>>>) {
+        let mut raw_data = Vec::with_capacity(slide_paths.len());
+        let mut all_image_data = HashMap::new();
+
+        for slide_path in slide_paths {
+            let slide_number = Slide::extract_slide_number(slide_path).unwrap_or(0);
+            let preloaded = self.preload_slide(slide_path, &mut all_image_data);
+            raw_data.push((slide_path.clone(), slide_number, preloaded));
         }
 
-        // Share image data atomically across threads
-        let shared_image_data = Arc::new(all_image_data);
+        (raw_data, Arc::new(all_image_data))
+    }
 
-        // Parallel processing starts here (CPU-bound tasks)
-        let slides: Result<Vec<_>> = raw_data
-            .into_par_iter()
-            .map(
-                |(
-                    path,
-                    number,
-                    xml,
-                    images,
-                    inherited_positions,
-                    speaker_notes,
-                    comments,
-                    hyperlinks,
-                    resource_diagnostics,
-                )| {
-                    // Parse XML in parallel (CPU-intensive)
-                    let mut parsed = crate::parse_xml::parse_slide_document_with_hyperlinks(
-                        &xml,
-                        &inherited_positions,
-                        &hyperlinks,
-                    )?;
-                    parsed.diagnostics.extend(resource_diagnostics);
-
-                    // Resolve image data from shared registry
-                    let mut image_map = HashMap::new();
-                    if config.extract_images {
-                        for img_ref in &images {
-                            if let Some(data) = shared_image_data.get(&img_ref.target) {
-                                image_map.insert(img_ref.id.clone(), data.clone());
-                            }
-                        }
-                    }
+    /// Reads everything a slide needs from the archive, so it can be parsed without it.
+    ///
+    /// Image data is added to `all_image_data` once per resolved part path.
+    fn preload_slide(
+        &mut self,
+        slide_path: &str,
+        all_image_data: &mut HashMap<String, Vec<u8>>,
+    ) -> Result<PreloadedSlide> {
+        // Read slide XML and relationships
+        let mut xml = Vec::new();
+        self.read_slide_into(slide_path, &mut xml)?;
+        let relationships = self.relationships(slide_path)?;
+        let hyperlinks = hyperlink_targets(&relationships);
+        let inherited_positions = self.resolve_inherited_positions(slide_path, &relationships)?;
+        let speaker_notes = self.resolve_speaker_notes(slide_path, &relationships)?;
+        let comments = self.resolve_comments(slide_path, &relationships)?;
 
-                    // Build slide
-                    let mut slide = Slide::new_semantic(
+        // Preload images if enabled
+        let mut images = Vec::new();
+        let mut diagnostics = Vec::new();
+        if self.config.lossy_utf8 {
+            diagnostics.extend(repair_utf8(&mut xml, slide_path));
+        }
+        let appearance = self.resolve_appearance(slide_path, &relationships, &xml)?;
+        if self.config.extract_images {
+            images = image_references(&relationships);
+            images.extend(notes_image_references(&speaker_notes));
+
+            for img_ref in &images {
+                let path = PptxContainer::resolve_target_path(slide_path, &img_ref.target);
+                if all_image_data.contains_key(&path) {
+                    continue;
+                }
+                match self.read_media(&path) {
+                    Ok(Some(data)) => {
+                        all_image_data.insert(path, data);
+                    }
+                    Ok(None) => diagnostics.push(media_limit_diagnostic(
                         path,
-                        number,
-                        parsed.elements,
-                        parsed.blocks,
-                        speaker_notes,
-                        comments,
-                        images,
-                        image_map,
-                        config.clone(),
-                        parsed.diagnostics,
-                    );
-                    slide.link_images();
-                    Ok(slide)
-                },
-            )
-            .collect();
+                        self.config.max_total_media_bytes,
+                    )),
+                    Err(error) => diagnostics.push(crate::ParseDiagnostic {
+                        severity: crate::DiagnosticSeverity::Warning,
+                        message: format!("Image resource could not be loaded: {error}"),
+                        source: Some(path),
+                    }),
+                }
+            }
+        }
 
-        slides
+        Ok(PreloadedSlide {
+            xml,
+            images,
+            inherited_positions,
+            speaker_notes,
+            comments,
+            hyperlinks,
+            diagnostics,
+            appearance,
+        })
     }
 
     pub fn iter_slides(&mut self) -> SlideIterator<'_> {
         SlideIterator::new(self)
     }
 
+    /// Moves the container to a background thread that parses slides ahead of the consumer.
+    ///
+    /// Up to `prefetch` parsed slides wait in a bounded channel, so reading and parsing the
+    /// next slides overlaps with slow work of the consumer, such as network writes, while
+    /// memory stays bounded. With a `prefetch` of `0`, the next slide is parsed while the
+    /// consumer handles the current one. Slides are yielded in the same order and with the
+    /// same errors as [`PptxContainer::iter_slides`]; dropping the iterator stops the
+    /// background thread after the slide it is currently parsing.
+    pub fn prefetch_slides(mut self, prefetch: usize) -> PrefetchingSlideIterator {
+        PrefetchingSlideIterator::spawn(prefetch, move |sender| {
+            for slide in self.iter_slides() {
+                if sender.send(slide).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
     /// Loads a slide from the PPTX file by its index.
     ///
     /// # Arguments
@@ -257,9 +860,11 @@ impl PptxContainer {
     ///
     /// # Returns
     ///
-    /// * `Ok(Some(Slide))` - The parsed slide if found and successfully processed.
-    /// * `Ok(None)` - If the index is out of bounds.
-    /// * `Err(_)` - If there was an error loading or parsing the slide.
+    /// * `Ok(Some(Slide))` - The parsed slide, or a placeholder for a slide that could not be
+    ///   parsed with [`crate::OnSlideError::Placeholder`].
+    /// * `Ok(None)` - If the slide could not be parsed with [`crate::OnSlideError::Skip`].
+    /// * `Err(_)` - If there was an error loading or parsing the slide with
+    ///   [`crate::OnSlideError::Fail`].
     ///
     /// # Example
     ///
@@ -270,45 +875,67 @@ impl PptxContainer {
     /// // }
     /// ```
     pub fn load_slide(&mut self, slide_path: &str) -> Result<Option<Slide>> {
-        // load xml data
-        let slide_data = self.read_file_from_archive(slide_path)?;
+        match self.parse_slide(slide_path) {
+            Ok(slide) => Ok(Some(slide)),
+            Err(error) => recover_slide(
+                slide_path.to_string(),
+                Slide::extract_slide_number(slide_path).unwrap_or(0),
+                error,
+                &self.config,
+            ),
+        }
+    }
+
+    fn parse_slide(&mut self, slide_path: &str) -> Result<Slide> {
+        // load xml data, reusing the buffer of the previous slide
+        let mut slide_data = std::mem::take(&mut self.slide_buffer);
+        self.read_slide_into(slide_path, &mut slide_data)?;
+        check_slide_limits(&slide_data, slide_path, &self.config.resource_limits)?;
+        let encoding_diagnostic = self
+            .config
+            .lossy_utf8
+            .then(|| repair_utf8(&mut slide_data, slide_path))
+            .flatten();
 
         // load relationship file
-        let rels_path = self.get_slide_rels_path(slide_path);
-        let rels_data = self.read_file_from_archive(&rels_path).ok();
-        let hyperlinks = rels_data
-            .as_deref()
-            .map(parse_hyperlink_rels)
-            .transpose()?
-            .unwrap_or_default();
+        let relationships = self.relationships(slide_path)?;
+        let hyperlinks = hyperlink_targets(&relationships);
 
         // parse slide and preload images
         let slide_number = Slide::extract_slide_number(slide_path).unwrap_or(0);
-        let inherited_positions =
-            self.resolve_inherited_positions(slide_path, rels_data.as_deref())?;
-        let speaker_notes = self.resolve_speaker_notes(slide_path, rels_data.as_deref())?;
-        let comments = self.resolve_comments(slide_path, rels_data.as_deref())?;
+        let inherited_positions = self.resolve_inherited_positions(slide_path, &relationships)?;
+        let speaker_notes = self.resolve_speaker_notes(slide_path, &relationships)?;
+        let comments = self.resolve_comments(slide_path, &relationships)?;
+        let appearance = self.resolve_appearance(slide_path, &relationships, &slide_data)?;
         let mut parsed = crate::parse_xml::parse_slide_document_with_hyperlinks(
             &slide_data,
             &inherited_positions,
             &hyperlinks,
         )?;
+        if self.config.preserve_raw_xml {
+            parsed.attach_raw_xml(&slide_data);
+        }
+        parsed.diagnostics.extend(encoding_diagnostic);
+        self.slide_buffer = slide_data;
 
         let mut images = Vec::new();
         let mut image_data = HashMap::new();
 
         if self.config.extract_images {
             // extract images from relationships
-            if let Some(ref rels_bytes) = rels_data {
-                images = crate::parse_rels::parse_slide_rels(rels_bytes)?;
-            }
+            images = image_references(&relationships);
+            images.extend(notes_image_references(&speaker_notes));
 
             for img_ref in &images {
                 let img_path = Self::resolve_target_path(slide_path, &img_ref.target);
-                match self.read_file_from_archive(&img_path) {
-                    Ok(data) => {
+                match self.read_media(&img_path) {
+                    Ok(Some(data)) => {
                         image_data.insert(img_ref.id.clone(), data);
                     }
+                    Ok(None) => parsed.diagnostics.push(media_limit_diagnostic(
+                        img_path,
+                        self.config.max_total_media_bytes,
+                    )),
                     Err(error) => parsed.diagnostics.push(crate::ParseDiagnostic {
                         severity: crate::DiagnosticSeverity::Warning,
                         message: format!("Image resource could not be loaded: {error}"),
@@ -318,23 +945,23 @@ impl PptxContainer {
             }
         }
 
-        let config = self.config.clone();
-
         let mut slide = Slide::new_semantic(
             slide_path.to_string(),
             slide_number,
             parsed.elements,
             parsed.blocks,
-            speaker_notes,
+            speaker_notes.text,
             comments,
             images,
             image_data,
-            config,
+            self.config.render_options(),
             parsed.diagnostics,
         );
+        slide.notes_images = speaker_notes.images;
+        appearance.apply_to(&mut slide);
 
         slide.link_images();
-        Ok(Some(slide))
+        Ok(slide)
     }
 
     /// Reads a file from the PPTX archive by its internal path.
@@ -353,12 +980,80 @@ impl PptxContainer {
     /// This is an internal method used to extract individual files from the
     /// PPTX archive (which is essentially a ZIP file).
     pub fn read_file_from_archive(&mut self, path: &str) -> Result<Vec<u8>> {
-        let mut file = self.archive.by_name(path)?;
         let mut content = Vec::new();
-        file.read_to_end(&mut content)?;
+        self.read_part_into(path, &mut content)?;
         Ok(content)
     }
 
+    /// Reads a file from the PPTX archive into `buffer`, replacing its previous content.
+    ///
+    /// Unlike [`PptxContainer::read_file_from_archive`], the allocation of `buffer` is kept, so
+    /// reading many parts with the same buffer does not allocate for every part.
+    pub fn read_part_into(&mut self, path: &str, buffer: &mut Vec<u8>) -> Result<()> {
+        let file = self.archive.by_index(self.part_index(path)?)?;
+        let declared_size = file.size();
+        buffer.clear();
+        self.package.read(
+            file,
+            declared_size,
+            path,
+            &self.config.resource_limits,
+            buffer,
+        )
+    }
+
+    /// Reads the XML of a slide into `buffer` like [`PptxContainer::read_part_into`], stopping
+    /// as soon as it exceeds [`ResourceLimits::max_slide_xml_bytes`].
+    pub(crate) fn read_slide_into(&mut self, slide_path: &str, buffer: &mut Vec<u8>) -> Result<()> {
+        let file = self.archive.by_index(self.part_index(slide_path)?)?;
+        let declared_size = file.size();
+        let limits = self.config.resource_limits;
+        buffer.clear();
+        let read = self.package.read_within(
+            file,
+            declared_size,
+            slide_path,
+            &limits,
+            limits.max_slide_xml_bytes,
+            buffer,
+        )?;
+        if limits.max_slide_xml_bytes.is_some_and(|limit| read > limit) {
+            return Err(limit_exceeded(slide_path, "max_slide_xml_bytes"));
+        }
+        Ok(())
+    }
+
+    /// Returns the names of all parts in the archive in sorted order.
+    pub(crate) fn part_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.archive.file_names().collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns whether the archive contains a part with the given name.
+    pub(crate) fn has_part(&self, path: &str) -> bool {
+        self.archive.index_for_name(path).is_some()
+    }
+
+    /// Returns the index of a part in the archive from the name index of the archive.
+    fn part_index(&self, path: &str) -> Result<usize> {
+        self.archive
+            .index_for_name(path)
+            .ok_or(crate::Error::Zip(zip::result::ZipError::FileNotFound))
+    }
+
+    /// Reads an image part unless it exceeds [`ParserConfig::max_total_media_bytes`].
+    fn read_media(&mut self, path: &str) -> Result<Option<Vec<u8>>> {
+        let file = self.archive.by_index(self.part_index(path)?)?;
+        self.media.read(
+            file,
+            path,
+            self.config.max_total_media_bytes,
+            &self.config.resource_limits,
+            &mut self.package,
+        )
+    }
+
     /// Constructs the path to the relationships file for a given slide.
     ///
     /// # Arguments
@@ -383,16 +1078,137 @@ impl PptxContainer {
         rels_path
     }
 
-    fn resolve_inherited_positions(
+    /// Returns the relationships of the part at `part_path`.
+    ///
+    /// The `.rels` file of every part is read and parsed only once per container, so loading
+    /// a slide again or sharing a layout between slides reuses the parsed relationships. Parts
+    /// without a `.rels` file have no relationships.
+    pub(crate) fn relationships(&mut self, part_path: &str) -> Result<Arc<[Relationship]>> {
+        if let Some(relationships) = self.relationships.get(part_path) {
+            return Ok(Arc::clone(relationships));
+        }
+        let rels_path = self.get_slide_rels_path(part_path);
+        let relationships: Arc<[Relationship]> = match self.read_file_from_archive(&rels_path) {
+            Ok(rels_data) => parse_relationships(&rels_data)?.into(),
+            Err(_) => Arc::from([]),
+        };
+        self.relationships
+            .insert(part_path.to_string(), Arc::clone(&relationships));
+        Ok(relationships)
+    }
+
+    pub(crate) fn resolve_inherited_positions(
         &mut self,
         slide_path: &str,
-        slide_rels_data: Option<&[u8]>,
+        slide_relationships: &[Relationship],
     ) -> Result<InheritedPositions> {
-        let Some(slide_rels_data) = slide_rels_data else {
-            return Ok(InheritedPositions::default());
-        };
+        let mut inherited = self.resolve_layout_positions(slide_path, slide_relationships)?;
+        inherited.set_table_styles(Arc::clone(&self.table_styles));
+        inherited.set_demote_single_item_lists(self.config.demote_single_item_lists);
+        inherited.set_preserve_groups(self.config.preserve_groups);
+        inherited.set_charts(self.resolve_charts(slide_path, slide_relationships)?);
+        let (media, media_diagnostics) = self.resolve_media(slide_path, slide_relationships);
+        inherited.set_media(media, media_diagnostics);
+        Ok(inherited)
+    }
+
+    /// Resolves the video and audio relationships of a slide, keyed by relationship id. The
+    /// data of embedded clips is read with [`ParserConfig::extract_media`].
+    ///
+    /// Clip data counts against [`ParserConfig::max_total_media_bytes`] like image data. A
+    /// clip that exceeds it or can not be read keeps no data and is reported in the returned
+    /// warnings instead of failing the slide.
+    fn resolve_media(
+        &mut self,
+        slide_path: &str,
+        slide_relationships: &[Relationship],
+    ) -> (HashMap<String, MediaReference>, Vec<crate::ParseDiagnostic>) {
+        let mut media = HashMap::new();
+        let mut diagnostics = Vec::new();
+        for relationship in slide_relationships {
+            let kind = match relationship.rel_type.as_str() {
+                VIDEO_NAMESPACE => MediaKind::Video,
+                AUDIO_NAMESPACE => MediaKind::Audio,
+                _ => continue,
+            };
+            let external = relationship.external;
+            let target = if external {
+                relationship.target.clone()
+            } else {
+                Self::resolve_target_path(slide_path, &relationship.target)
+            };
+            let data = if self.config.extract_media && !external && self.has_part(&target) {
+                match self.read_media(&target) {
+                    Ok(Some(data)) => Some(data),
+                    Ok(None) => {
+                        diagnostics.push(crate::ParseDiagnostic {
+                            severity: crate::DiagnosticSeverity::Warning,
+                            message: format!(
+                                "Media clip skipped: media limit of {} bytes exceeded",
+                                self.config.max_total_media_bytes.unwrap_or_default()
+                            ),
+                            source: Some(target.clone()),
+                        });
+                        None
+                    }
+                    Err(error) => {
+                        diagnostics.push(crate::ParseDiagnostic {
+                            severity: crate::DiagnosticSeverity::Warning,
+                            message: format!("Media clip could not be loaded: {error}"),
+                            source: Some(target.clone()),
+                        });
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            let mime_type =
+                crate::slide::detect_mime_type(data.as_deref().unwrap_or_default(), &target);
+            media.insert(
+                relationship.id.clone(),
+                MediaReference {
+                    kind,
+                    id: relationship.id.clone(),
+                    target,
+                    external,
+                    mime_type: mime_type.map(str::to_string),
+                    poster: None,
+                    data,
+                },
+            );
+        }
+        (media, diagnostics)
+    }
 
-        let slide_relationships = parse_relationships(slide_rels_data)?;
+    /// Parses the chart parts referenced by a slide, keyed by relationship id. Relationships
+    /// to missing chart parts are skipped.
+    fn resolve_charts(
+        &mut self,
+        slide_path: &str,
+        slide_relationships: &[Relationship],
+    ) -> Result<HashMap<String, ChartElement>> {
+        let mut charts = HashMap::new();
+        for relationship in slide_relationships
+            .iter()
+            .filter(|rel| rel.rel_type == CHART_NAMESPACE)
+        {
+            let chart_path = Self::resolve_target_path(slide_path, &relationship.target);
+            if !self.has_part(&chart_path) {
+                continue;
+            }
+            let mut chart = parse_chart(&self.read_file_from_archive(&chart_path)?)?;
+            chart.relationship_id = relationship.id.clone();
+            charts.insert(relationship.id.clone(), chart);
+        }
+        Ok(charts)
+    }
+
+    pub(crate) fn resolve_layout_positions(
+        &mut self,
+        slide_path: &str,
+        slide_relationships: &[Relationship],
+    ) -> Result<InheritedPositions> {
         let Some(layout_target) = slide_relationships
             .iter()
             .find(|rel| rel.rel_type == SLIDE_LAYOUT_NAMESPACE)
@@ -403,22 +1219,16 @@ impl PptxContainer {
 
         let layout_path = Self::resolve_target_path(slide_path, layout_target);
         let layout_xml = self.read_file_from_archive(&layout_path)?;
-        let layout_rels_path = self.get_slide_rels_path(&layout_path);
-        let layout_rels_data = self.read_file_from_archive(&layout_rels_path).ok();
+        let layout_relationships = self.relationships(&layout_path)?;
 
-        let master_positions = if let Some(layout_rels_data) = layout_rels_data.as_deref() {
-            let layout_relationships = parse_relationships(layout_rels_data)?;
-            if let Some(master_target) = layout_relationships
-                .iter()
-                .find(|rel| rel.rel_type == SLIDE_MASTER_NAMESPACE)
-                .map(|rel| rel.target.as_str())
-            {
-                let master_path = Self::resolve_target_path(&layout_path, master_target);
-                let master_xml = self.read_file_from_archive(&master_path)?;
-                extract_inherited_positions(&master_xml, &InheritedPositions::default())?
-            } else {
-                InheritedPositions::default()
-            }
+        let master_positions = if let Some(master_target) = layout_relationships
+            .iter()
+            .find(|rel| rel.rel_type == SLIDE_MASTER_NAMESPACE)
+            .map(|rel| rel.target.as_str())
+        {
+            let master_path = Self::resolve_target_path(&layout_path, master_target);
+            let master_xml = self.read_file_from_archive(&master_path)?;
+            extract_inherited_positions(&master_xml, &InheritedPositions::default())?
         } else {
             InheritedPositions::default()
         };
@@ -426,61 +1236,171 @@ impl PptxContainer {
         extract_inherited_positions(&layout_xml, &master_positions)
     }
 
-    fn resolve_speaker_notes(
+    /// Returns the background of a slide, falling back to its layout and master, together with
+    /// the names of its layout and master and the theme of its master.
+    fn resolve_appearance(
         &mut self,
         slide_path: &str,
-        slide_rels_data: Option<&[u8]>,
-    ) -> Result<Vec<crate::TextElement>> {
-        let Some(slide_rels_data) = slide_rels_data else {
-            return Ok(Vec::new());
+        slide_relationships: &[Relationship],
+        slide_xml: &[u8],
+    ) -> Result<SlideAppearance> {
+        let layout = self.resolve_layout_info(slide_path, slide_relationships)?;
+        let background = match parse_background(slide_xml) {
+            Some(background) => resolve_background(
+                background,
+                slide_path,
+                slide_relationships,
+                layout.theme.as_ref(),
+            ),
+            None => layout.background.clone(),
         };
-        let relationships = parse_relationships(slide_rels_data)?;
-        let Some(notes_target) = relationships
+        Ok(SlideAppearance {
+            background,
+            theme: layout.theme.as_ref().map(|theme| theme.summary.clone()),
+            layout_name: layout.name.clone(),
+            layout_type: layout.layout_type.clone(),
+            master_name: layout.master_name.clone(),
+            slide_size: self.slide_size,
+            static_layout: (self.config.include_layout_content
+                && parse_shows_master_shapes(slide_xml, slide_path)?)
+            .then_some(layout),
+        })
+    }
+
+    /// Reads the name, background and theme of the layout of a slide once per layout.
+    fn resolve_layout_info(
+        &mut self,
+        slide_path: &str,
+        slide_relationships: &[Relationship],
+    ) -> Result<Arc<LayoutInfo>> {
+        let Some(layout_target) = slide_relationships
             .iter()
-            .find(|rel| rel.rel_type == NOTES_SLIDE_NAMESPACE)
+            .find(|rel| rel.rel_type == SLIDE_LAYOUT_NAMESPACE)
             .map(|rel| rel.target.as_str())
         else {
-            return Ok(Vec::new());
+            return Ok(Arc::default());
+        };
+        let layout_path = Self::resolve_target_path(slide_path, layout_target);
+        if let Some(layout) = self.layouts.get(&layout_path) {
+            return Ok(Arc::clone(layout));
+        }
+
+        let layout_relationships = self.relationships(&layout_path)?;
+        let mut theme = None;
+        let mut master_name = None;
+        let mut master_background = None;
+        let mut master_content = ParsedSlideDocument::default();
+        if let Some(master_target) = layout_relationships
+            .iter()
+            .find(|rel| rel.rel_type == SLIDE_MASTER_NAMESPACE)
+            .map(|rel| rel.target.as_str())
+        {
+            let master_path = Self::resolve_target_path(&layout_path, master_target);
+            let master_relationships = self.relationships(&master_path)?;
+            if let Some(theme_target) = master_relationships
+                .iter()
+                .find(|rel| rel.rel_type == THEME_NAMESPACE)
+                .map(|rel| rel.target.as_str())
+            {
+                let theme_path = Self::resolve_target_path(&master_path, theme_target);
+                let theme_xml = self.read_file_from_archive(&theme_path)?;
+                theme = Some(parse_theme(&theme_xml, &theme_path)?);
+            }
+            let master_xml = self.read_file_from_archive(&master_path)?;
+            // PowerPoint shows masters without a name under the name of their theme
+            master_name = parse_part_name(&master_xml, &master_path)?
+                .or_else(|| theme.as_ref().and_then(|theme| theme.summary.name.clone()));
+            master_background = parse_background(&master_xml).and_then(|background| {
+                resolve_background(
+                    background,
+                    &master_path,
+                    &master_relationships,
+                    theme.as_ref(),
+                )
+            });
+            if self.config.include_layout_content {
+                let hyperlinks = hyperlink_targets(&master_relationships);
+                master_content = parse_static_content(&master_xml, &hyperlinks)?;
+            }
+        }
+
+        let layout_xml = self.read_file_from_archive(&layout_path)?;
+        let layout_background = parse_background(&layout_xml).and_then(|background| {
+            resolve_background(
+                background,
+                &layout_path,
+                &layout_relationships,
+                theme.as_ref(),
+            )
+        });
+        let mut static_content = ParsedSlideDocument::default();
+        if self.config.include_layout_content {
+            if parse_shows_master_shapes(&layout_xml, &layout_path)? {
+                static_content = master_content;
+            }
+            let hyperlinks = hyperlink_targets(&layout_relationships);
+            let layout_content = parse_static_content(&layout_xml, &hyperlinks)?;
+            append_static_content(
+                &mut static_content.elements,
+                &mut static_content.blocks,
+                &layout_content.elements,
+                &layout_content.blocks,
+            );
+        }
+        let layout = Arc::new(LayoutInfo {
+            name: parse_part_name(&layout_xml, &layout_path)?,
+            layout_type: parse_layout_type(&layout_xml, &layout_path)?,
+            master_name,
+            background: layout_background.or(master_background),
+            theme,
+            static_elements: static_content.elements,
+            static_blocks: static_content.blocks,
+        });
+        self.layouts.insert(layout_path, Arc::clone(&layout));
+        Ok(layout)
+    }
+
+    fn resolve_speaker_notes(
+        &mut self,
+        slide_path: &str,
+        slide_relationships: &[Relationship],
+    ) -> Result<ParsedNotes> {
+        let Some(notes_path) = notes_part(slide_path, slide_relationships) else {
+            return Ok(ParsedNotes::default());
         };
-        let notes_path = Self::resolve_target_path(slide_path, notes_target);
         let notes_xml = self.read_file_from_archive(&notes_path)?;
-        let notes_rels = self
-            .read_file_from_archive(&self.get_slide_rels_path(&notes_path))
-            .ok();
-        let hyperlinks = notes_rels
-            .as_deref()
-            .map(parse_hyperlink_rels)
-            .transpose()?
-            .unwrap_or_default();
-        crate::parse_xml::parse_speaker_notes_xml_with_hyperlinks(&notes_xml, &hyperlinks)
+        let relationships = self.relationships(&notes_path)?;
+        let hyperlinks = hyperlink_targets(&relationships);
+        let mut notes =
+            crate::parse_xml::parse_speaker_notes_xml_with_hyperlinks(&notes_xml, &hyperlinks)?;
+
+        // Notes pictures reference the relationships of the notes part, whose ids overlap
+        // with those of the slide, so they are renamed and pointed at the absolute part.
+        let targets: HashMap<String, String> = image_references(&relationships)
+            .into_iter()
+            .map(|reference| (reference.id, reference.target))
+            .collect();
+        notes.images.retain_mut(|image| {
+            let Some(target) = targets.get(&image.reference.id) else {
+                return false;
+            };
+            image.reference.id = format!("{NOTES_IMAGE_PREFIX}{}", image.reference.id);
+            image.reference.target = format!("/{}", Self::resolve_target_path(&notes_path, target));
+            true
+        });
+        Ok(notes)
     }
 
     fn resolve_comments(
         &mut self,
         slide_path: &str,
-        slide_rels_data: Option<&[u8]>,
+        slide_relationships: &[Relationship],
     ) -> Result<Vec<crate::TextElement>> {
-        let Some(slide_rels_data) = slide_rels_data else {
-            return Ok(Vec::new());
-        };
-        let relationships = parse_relationships(slide_rels_data)?;
-        let Some(comment_target) = relationships
-            .iter()
-            .find(|rel| rel.rel_type == COMMENTS_NAMESPACE || rel.rel_type.ends_with("/comments"))
-            .map(|rel| rel.target.as_str())
-        else {
+        let Some(comment_path) = comments_part(slide_path, slide_relationships) else {
             return Ok(Vec::new());
         };
-        let comment_path = Self::resolve_target_path(slide_path, comment_target);
         let comment_xml = self.read_file_from_archive(&comment_path)?;
-        let comment_rels = self
-            .read_file_from_archive(&self.get_slide_rels_path(&comment_path))
-            .ok();
-        let hyperlinks = comment_rels
-            .as_deref()
-            .map(parse_hyperlink_rels)
-            .transpose()?
-            .unwrap_or_default();
+        let hyperlinks = hyperlink_targets(&self.relationships(&comment_path)?);
         crate::parse_xml::parse_comments_xml_with_hyperlinks(&comment_xml, &hyperlinks)
     }
 
@@ -509,19 +1429,280 @@ impl PptxContainer {
 
 fn read_optional_archive_file(
     archive: &mut zip::ZipArchive<std::fs::File>,
+    package: &mut PackageBudget,
     path: &str,
+    limits: &ResourceLimits,
 ) -> Result<Option<Vec<u8>>> {
-    let mut file = match archive.by_name(path) {
+    let file = match archive.by_name(path) {
         Ok(file) => file,
         Err(zip::result::ZipError::FileNotFound) => return Ok(None),
         Err(error) => return Err(error.into()),
     };
+    let declared_size = file.size();
     let mut content = Vec::new();
-    file.read_to_end(&mut content)?;
+    package.read(file, declared_size, path, limits, &mut content)?;
     Ok(Some(content))
 }
 
-fn sort_slide_paths(slide_paths: &mut [String]) {
+/// The image parts read from a package, counted against
+/// [`ParserConfig::max_total_media_bytes`].
+#[derive(Debug, Default)]
+pub(crate) struct MediaBudget {
+    parts: HashSet<String>,
+    total: u64,
+}
+
+impl MediaBudget {
+    /// Reads the media part `file` stored at `path`, or returns `None` if its uncompressed
+    /// size would exceed `limit` in total with the parts read before. Parts are only counted
+    /// once, so slides sharing an image or loaded repeatedly do not use up the budget.
+    ///
+    /// The budget is charged with the bytes actually read rather than the size the archive
+    /// declares, and reading stops as soon as the remaining budget or a limit of
+    /// [`ResourceLimits`] counted by `package` is exceeded.
+    pub(crate) fn read(
+        &mut self,
+        file: zip::read::ZipFile<'_, std::fs::File>,
+        path: &str,
+        limit: Option<u64>,
+        limits: &ResourceLimits,
+        package: &mut PackageBudget,
+    ) -> Result<Option<Vec<u8>>> {
+        let counted = self.parts.contains(path);
+        let remaining = limit
+            .filter(|_| !counted)
+            .map(|limit| limit.saturating_sub(self.total));
+        let declared_size = file.size();
+        let mut content = Vec::new();
+        let read =
+            package.read_within(file, declared_size, path, limits, remaining, &mut content)?;
+        if remaining.is_some_and(|remaining| read > remaining) {
+            return Ok(None);
+        }
+        if !counted {
+            self.total = self.total.saturating_add(read);
+            self.parts.insert(path.to_string());
+        }
+        Ok(Some(content))
+    }
+}
+
+/// The prefix of the [`crate::ImageReference::id`] of pictures in speaker notes.
+const NOTES_IMAGE_PREFIX: &str = "notes-";
+
+/// The part name PowerPoint uses for the VBA project of a `.pptm` deck.
+const DEFAULT_VBA_PROJECT_PART: &str = "ppt/vbaProject.bin";
+
+/// Returns the references of the notes pictures, which are loaded together with the slide
+/// images.
+fn notes_image_references(notes: &ParsedNotes) -> impl Iterator<Item = crate::ImageReference> {
+    notes.images.iter().map(|image| image.reference.clone())
+}
+
+/// The archive data of a slide, read before slides are parsed in parallel.
+struct PreloadedSlide {
+    xml: Vec<u8>,
+    images: Vec<crate::ImageReference>,
+    inherited_positions: InheritedPositions,
+    speaker_notes: ParsedNotes,
+    comments: Vec<crate::TextElement>,
+    hyperlinks: HashMap<String, String>,
+    diagnostics: Vec<crate::ParseDiagnostic>,
+    appearance: SlideAppearance,
+}
+
+/// The layout and master information stored on a slide, see [`SlideAppearance::apply_to`].
+struct SlideAppearance {
+    background: Option<Background>,
+    theme: Option<ThemeSummary>,
+    layout_name: Option<String>,
+    layout_type: Option<String>,
+    master_name: Option<String>,
+    slide_size: Option<(i64, i64)>,
+    /// The layout whose static content is added to the slide, see
+    /// [`ParserConfig::include_layout_content`].
+    static_layout: Option<Arc<LayoutInfo>>,
+}
+
+impl SlideAppearance {
+    fn apply_to(self, slide: &mut Slide) {
+        slide.background = self.background;
+        slide.theme = self.theme;
+        slide.layout_name = self.layout_name;
+        slide.layout_type = self.layout_type;
+        slide.master_name = self.master_name;
+        slide.slide_size = self.slide_size;
+        if let Some(layout) = self.static_layout {
+            append_static_content(
+                &mut slide.elements,
+                &mut slide.blocks,
+                &layout.static_elements,
+                &layout.static_blocks,
+            );
+            assign_element_ids(&mut slide.elements, slide.slide_number);
+        }
+    }
+}
+
+/// Appends static layout or master content after `elements` and `blocks`, continuing their
+/// source order.
+fn append_static_content(
+    elements: &mut Vec<crate::SlideElement>,
+    blocks: &mut Vec<crate::SlideBlock>,
+    static_elements: &[crate::SlideElement],
+    static_blocks: &[crate::SlideBlock],
+) {
+    let block_orders = blocks.iter().map(|block| block.source_order);
+    let element_orders = elements
+        .iter()
+        .flat_map(crate::SlideElement::leaves)
+        .filter_map(|element| element.meta().source_order);
+    let offset = block_orders
+        .chain(element_orders)
+        .max()
+        .map_or(0, |order| order + 1);
+    blocks.extend(static_blocks.iter().cloned().map(|mut block| {
+        block.source_order += offset;
+        block
+    }));
+    elements.extend(static_elements.iter().cloned().map(|mut element| {
+        if let Some(meta) = element.meta_mut() {
+            meta.source_order = meta.source_order.map(|order| order + offset);
+        }
+        element
+    }));
+}
+
+/// What a slide inherits from its layout and master besides placeholder positions.
+#[derive(Default)]
+struct LayoutInfo {
+    name: Option<String>,
+    layout_type: Option<String>,
+    master_name: Option<String>,
+    background: Option<Background>,
+    theme: Option<Theme>,
+    /// The text and tables of the master and layout outside of placeholders, only parsed with
+    /// [`ParserConfig::include_layout_content`].
+    static_elements: Vec<crate::SlideElement>,
+    static_blocks: Vec<crate::SlideBlock>,
+}
+
+/// The path, number and preloaded data of a slide read by
+/// [`PptxContainer::preload_all_slides`].
+type PreloadedEntry = (String, u32, Result<PreloadedSlide>);
+
+/// Parses a preloaded slide without access to the archive, applying the
+/// [`ParserConfig::on_slide_error`] policy if it fails.
+fn build_preloaded_slide(
+    path: String,
+    number: u32,
+    preloaded: Result<PreloadedSlide>,
+    shared_image_data: &HashMap<String, Vec<u8>>,
+    config: &ParserConfig,
+) -> Result<Option<Slide>> {
+    let slide = preloaded.and_then(|preloaded| {
+        // Parse XML in parallel (CPU-intensive)
+        check_slide_limits(&preloaded.xml, &path, &config.resource_limits)?;
+        let mut parsed = crate::parse_xml::parse_slide_document_with_hyperlinks(
+            &preloaded.xml,
+            &preloaded.inherited_positions,
+            &preloaded.hyperlinks,
+        )?;
+        if config.preserve_raw_xml {
+            parsed.attach_raw_xml(&preloaded.xml);
+        }
+        parsed.diagnostics.extend(preloaded.diagnostics);
+
+        // Resolve image data from shared registry
+        let mut image_map = HashMap::new();
+        if config.extract_images {
+            for img_ref in &preloaded.images {
+                let part = PptxContainer::resolve_target_path(&path, &img_ref.target);
+                if let Some(data) = shared_image_data.get(&part) {
+                    image_map.insert(img_ref.id.clone(), data.clone());
+                }
+            }
+        }
+
+        // Build slide
+        let mut slide = Slide::new_semantic(
+            path.clone(),
+            number,
+            parsed.elements,
+            parsed.blocks,
+            preloaded.speaker_notes.text,
+            preloaded.comments,
+            preloaded.images,
+            image_map,
+            config.render_options(),
+            parsed.diagnostics,
+        );
+        slide.notes_images = preloaded.speaker_notes.images;
+        preloaded.appearance.apply_to(&mut slide);
+        slide.link_images();
+        Ok(slide)
+    });
+    match slide {
+        Ok(slide) => Ok(Some(slide)),
+        Err(error) => recover_slide(path, number, error, config),
+    }
+}
+
+/// Handles a slide that failed to load according to [`ParserConfig::on_slide_error`].
+pub(crate) fn recover_slide(
+    rel_path: String,
+    slide_number: u32,
+    error: crate::Error,
+    config: &ParserConfig,
+) -> Result<Option<Slide>> {
+    match config.on_slide_error {
+        OnSlideError::Fail => Err(error),
+        OnSlideError::Skip => Ok(None),
+        OnSlideError::Placeholder => Ok(Some(Slide::placeholder(
+            rel_path,
+            slide_number,
+            &error,
+            config.render_options(),
+        ))),
+    }
+}
+
+/// Returns the notes slide part of a slide, if it has one.
+pub(crate) fn notes_part(slide_path: &str, slide_relationships: &[Relationship]) -> Option<String> {
+    slide_relationships
+        .iter()
+        .find(|rel| rel.rel_type == NOTES_SLIDE_NAMESPACE)
+        .map(|rel| PptxContainer::resolve_target_path(slide_path, &rel.target))
+}
+
+/// Returns the comments part of a slide, if it has one.
+pub(crate) fn comments_part(
+    slide_path: &str,
+    slide_relationships: &[Relationship],
+) -> Option<String> {
+    slide_relationships
+        .iter()
+        .find(|rel| rel.rel_type == COMMENTS_NAMESPACE || rel.rel_type.ends_with("/comments"))
+        .map(|rel| PptxContainer::resolve_target_path(slide_path, &rel.target))
+}
+
+pub(crate) fn media_limit_diagnostic(path: String, limit: Option<u64>) -> crate::ParseDiagnostic {
+    crate::ParseDiagnostic {
+        severity: crate::DiagnosticSeverity::Warning,
+        message: format!(
+            "Image resource skipped: media limit of {} bytes exceeded",
+            limit.unwrap_or_default()
+        ),
+        source: Some(path),
+    }
+}
+
+/// Returns whether a part name denotes a slide, e.g. `ppt/slides/slide1.xml`.
+fn is_slide_part(name: &str) -> bool {
+    name.starts_with("ppt/slides/slide") && name.ends_with(".xml")
+}
+
+pub(crate) fn sort_slide_paths(slide_paths: &mut [String]) {
     slide_paths.sort_by(|left, right| {
         Slide::extract_slide_number(left)
             .cmp(&Slide::extract_slide_number(right))
@@ -529,6 +1710,20 @@ fn sort_slide_paths(slide_paths: &mut [String]) {
     });
 }
 
+/// Orders slide paths like the `p:sldIdLst` of the presentation, given as `slide_order`.
+///
+/// Slide parts missing from the list, e.g. in packages without `ppt/presentation.xml`,
+/// follow in numeric order.
+pub(crate) fn order_slide_paths(slide_paths: &mut [String], slide_order: &[String]) {
+    sort_slide_paths(slide_paths);
+    let positions: HashMap<&str, usize> = slide_order
+        .iter()
+        .enumerate()
+        .map(|(position, path)| (path.as_str(), position))
+        .collect();
+    slide_paths.sort_by_key(|path| positions.get(path.as_str()).copied().unwrap_or(usize::MAX));
+}
+
 /// An iterator for streaming slides from a PPTX file.
 ///
 /// This iterator allows processing slides one by one, which is more
@@ -601,6 +1796,193 @@ impl<'a> Iterator for SlideIterator<'a> {
     }
 }
 
+/// An iterator over slides parsed ahead on a background thread, created by
+/// [`PptxContainer::prefetch_slides`] or
+/// [`crate::PresentationContainer::prefetch_slides`].
+pub struct PrefetchingSlideIterator {
+    receiver: Receiver<Result<Slide>>,
+    producer: Option<JoinHandle<()>>,
+}
+
+impl PrefetchingSlideIterator {
+    /// Runs `produce` on a new thread, which sends slides through a channel holding up to
+    /// `prefetch` slides.
+    pub(crate) fn spawn(
+        prefetch: usize,
+        produce: impl FnOnce(SyncSender<Result<Slide>>) + Send + 'static,
+    ) -> Self {
+        let (sender, receiver) = sync_channel(prefetch);
+        let producer = std::thread::spawn(move || produce(sender));
+        Self {
+            receiver,
+            producer: Some(producer),
+        }
+    }
+}
+
+impl Iterator for PrefetchingSlideIterator {
+    type Item = Result<Slide>;
+
+    /// Returns the next slide, waiting for the background thread if it is not parsed yet.
+    ///
+    /// A panic of the background thread is resumed on the consumer thread once all slides
+    /// parsed before it were returned.
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.receiver.recv() {
+            Ok(slide) => Some(slide),
+            Err(_) => {
+                if let Some(producer) = self.producer.take()
+                    && let Err(panic) = producer.join()
+                {
+                    std::panic::resume_unwind(panic);
+                }
+                None
+            }
+        }
+    }
+}
+
+/// How [`PptxContainer::parse_auto_with`] parses a deck, as chosen by
+/// [`PptxContainer::parse_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseStrategy {
+    /// One slide after another with [`PptxContainer::parse_all`].
+    Sequential,
+    /// In parallel, `chunk_size` slides at a time, with [`PptxContainer::parse_chunked`].
+    Chunked { chunk_size: usize },
+    /// All slides in parallel with [`PptxContainer::parse_all_multi_threaded`].
+    Parallel,
+}
+
+/// Thresholds for [`PptxContainer::parse_strategy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoParseThresholds {
+    /// Decks with at most this many slides are parsed sequentially. Defaults to 8.
+    pub max_sequential_slides: usize,
+    /// Decks whose `ppt/media` parts exceed this many uncompressed bytes are parsed in
+    /// chunks instead of all at once. Defaults to 256 MiB.
+    pub max_parallel_media_bytes: u64,
+    /// Number of slides per available core in one chunk. Defaults to 4.
+    pub slides_per_core: usize,
+    /// Number of cores to plan for. `None` uses [`std::thread::available_parallelism`].
+    pub cores: Option<usize>,
+}
+
+impl Default for AutoParseThresholds {
+    fn default() -> Self {
+        Self {
+            max_sequential_slides: 8,
+            max_parallel_media_bytes: 256 * 1024 * 1024,
+            slides_per_core: 4,
+            cores: None,
+        }
+    }
+}
+
+/// A slide parsed by [`OrderedSlideIterator`] together with its position in the presentation.
+type IndexedSlide = (usize, Result<Option<Slide>>);
+
+/// The number of slides an [`OrderedSlideIterator`] producer parses at once, and the capacity
+/// of its channel.
+const ORDERED_SLIDE_WINDOW: usize = 32;
+
+/// An iterator over slides parsed in parallel, yielded in presentation order, created by
+/// [`PptxContainer::iter_slides_multi_threaded`] or
+/// [`crate::PresentationContainer::iter_slides_multi_threaded`].
+pub struct OrderedSlideIterator {
+    receiver: Receiver<IndexedSlide>,
+    producer: Option<JoinHandle<()>>,
+    pending: BTreeMap<usize, Result<Option<Slide>>>,
+    next_index: usize,
+    slide_count: usize,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl OrderedSlideIterator {
+    /// Runs `produce` on a new thread, which sends each of the `slide_count` slides with its
+    /// index in any order. Producers should stop once `cancelled` is set, and should not get
+    /// more than [`ORDERED_SLIDE_WINDOW`] slides ahead of the first unsent one.
+    pub(crate) fn spawn(
+        slide_count: usize,
+        produce: impl FnOnce(SyncSender<IndexedSlide>, Arc<AtomicBool>) + Send + 'static,
+    ) -> Self {
+        let (sender, receiver) = sync_channel(ORDERED_SLIDE_WINDOW);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let producer_cancelled = Arc::clone(&cancelled);
+        let producer = std::thread::spawn(move || produce(sender, producer_cancelled));
+        Self {
+            receiver,
+            producer: Some(producer),
+            pending: BTreeMap::new(),
+            next_index: 0,
+            slide_count,
+            cancelled,
+        }
+    }
+
+    /// Handles the end of the producer while the slide at `next_index` is still missing.
+    ///
+    /// A panic of the producer is resumed on the consumer thread. Otherwise the missing
+    /// slides up to the next buffered one are reported as one error, so the buffered slides
+    /// are still returned afterwards.
+    fn producer_stopped(&mut self) -> Option<Result<Slide>> {
+        if let Some(producer) = self.producer.take()
+            && let Err(panic) = producer.join()
+        {
+            std::panic::resume_unwind(panic);
+        }
+        if self.next_index >= self.slide_count && self.pending.is_empty() {
+            return None;
+        }
+        self.next_index = self
+            .pending
+            .keys()
+            .next()
+            .copied()
+            .unwrap_or(self.slide_count);
+        Some(Err(crate::Error::ParseError(
+            "parallel parsing stopped before all slides were parsed",
+        )))
+    }
+}
+
+impl Iterator for OrderedSlideIterator {
+    type Item = Result<Slide>;
+
+    /// Returns the next slide in presentation order, waiting until it is parsed.
+    ///
+    /// Slides skipped with [`crate::OnSlideError::Skip`] are left out like in
+    /// [`PptxContainer::iter_slides`]. Slides the producer never sent are reported as an
+    /// error, and a panic while parsing is resumed on the consumer thread.
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let slide = match self.pending.remove(&self.next_index) {
+                Some(slide) => slide,
+                None => match self.receiver.recv() {
+                    Ok((index, slide)) if index != self.next_index => {
+                        self.pending.insert(index, slide);
+                        continue;
+                    }
+                    Ok((_, slide)) => slide,
+                    Err(_) => return self.producer_stopped(),
+                },
+            };
+            self.next_index += 1;
+            match slide {
+                Ok(Some(slide)) => return Some(Ok(slide)),
+                Ok(None) => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+    }
+}
+
+impl Drop for OrderedSlideIterator {
+    fn drop(&mut self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+}
+
 #[cfg(test)]
 #[path = "../tests/unit/container.rs"]
 mod tests;
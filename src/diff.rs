@@ -0,0 +1,372 @@
+use crate::slide::content_hash;
+use crate::{Presentation, Slide, SlideBlockContent};
+use std::fmt::Write as _;
+
+/// The differences between two revisions of a presentation, see [`diff`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PresentationDiff {
+    /// Added, removed and changed slides, ordered by their slide number in the new revision
+    /// followed by the removed slides.
+    pub slides: Vec<SlideDiff>,
+}
+
+/// The change of a single slide between two revisions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SlideDiff {
+    Added {
+        slide_number: u32,
+        title: Option<String>,
+    },
+    Removed {
+        slide_number: u32,
+        title: Option<String>,
+    },
+    /// A slide present in both revisions whose content changed or that moved relative to the
+    /// other slides.
+    Changed {
+        old_slide_number: u32,
+        new_slide_number: u32,
+        title: Option<String>,
+        moved: bool,
+        changes: Vec<ContentChange>,
+    },
+}
+
+/// A changed text block or image of a slide.
+///
+/// Text is compared per block, using the text of [`Slide::text`]. Images are compared by a
+/// hash of their data, or of their target path if the image data was not extracted.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ContentChange {
+    TextAdded(String),
+    TextRemoved(String),
+    TextChanged {
+        old: String,
+        new: String,
+    },
+    ImageAdded {
+        target: String,
+        hash: u64,
+    },
+    ImageRemoved {
+        target: String,
+        hash: u64,
+    },
+    ImageChanged {
+        old: String,
+        new: String,
+        old_hash: u64,
+        new_hash: u64,
+    },
+}
+
+/// Compares two revisions of a presentation.
+///
+/// Slides are matched by identical content first, then by title and finally by slide number.
+/// Matched slides that changed their order relative to the other matched slides are reported
+/// as moved; inserting or removing a slide does not move the following slides.
+pub fn diff(old: &Presentation, new: &Presentation) -> PresentationDiff {
+    let old_slides: Vec<SlideSummary> = old.slides.iter().map(SlideSummary::new).collect();
+    let new_slides: Vec<SlideSummary> = new.slides.iter().map(SlideSummary::new).collect();
+    let mut old_matches: Vec<Option<usize>> = vec![None; new_slides.len()];
+
+    let match_by = |old_matches: &mut Vec<Option<usize>>,
+                    same: &dyn Fn(&SlideSummary, &SlideSummary) -> bool| {
+        for (new_index, new_slide) in new_slides.iter().enumerate() {
+            if old_matches[new_index].is_some() {
+                continue;
+            }
+            old_matches[new_index] = (0..old_slides.len()).find(|old_index| {
+                !old_matches.contains(&Some(*old_index)) && same(&old_slides[*old_index], new_slide)
+            });
+        }
+    };
+    match_by(&mut old_matches, &|old, new| {
+        old.blocks == new.blocks && old.images == new.images
+    });
+    match_by(&mut old_matches, &|old, new| {
+        old.title.is_some() && old.title == new.title
+    });
+    match_by(&mut old_matches, &|old, new| {
+        old.slide_number == new.slide_number
+    });
+
+    let matched_old: Vec<usize> = old_matches.iter().flatten().copied().collect();
+    let in_order = longest_increasing_subsequence(&matched_old);
+
+    let mut slides = Vec::new();
+    for (new_slide, old_index) in new_slides.iter().zip(&old_matches) {
+        let Some(old_index) = *old_index else {
+            slides.push(SlideDiff::Added {
+                slide_number: new_slide.slide_number,
+                title: new_slide.title.clone(),
+            });
+            continue;
+        };
+        let old_slide = &old_slides[old_index];
+        let changes = content_changes(old_slide, new_slide);
+        let moved = !in_order.contains(&old_index);
+        if moved || !changes.is_empty() {
+            slides.push(SlideDiff::Changed {
+                old_slide_number: old_slide.slide_number,
+                new_slide_number: new_slide.slide_number,
+                title: new_slide.title.clone(),
+                moved,
+                changes,
+            });
+        }
+    }
+    for (old_index, old_slide) in old_slides.iter().enumerate() {
+        if !matched_old.contains(&old_index) {
+            slides.push(SlideDiff::Removed {
+                slide_number: old_slide.slide_number,
+                title: old_slide.title.clone(),
+            });
+        }
+    }
+    PresentationDiff { slides }
+}
+
+impl PresentationDiff {
+    /// Returns whether both revisions have the same slides in the same order.
+    pub fn is_empty(&self) -> bool {
+        self.slides.is_empty()
+    }
+
+    /// Renders the diff as Markdown with one section per changed slide.
+    ///
+    /// Text and image changes of a slide are listed in a `diff` code block, prefixing removed
+    /// content with `-` and added content with `+`.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# Presentation diff\n");
+        if self.is_empty() {
+            markdown.push_str("\nNo changes.\n");
+            return markdown;
+        }
+        for slide in &self.slides {
+            markdown.push('\n');
+            match slide {
+                SlideDiff::Added {
+                    slide_number,
+                    title,
+                } => {
+                    let _ = writeln!(
+                        markdown,
+                        "## Slide {slide_number}{} (added)",
+                        title_suffix(title)
+                    );
+                }
+                SlideDiff::Removed {
+                    slide_number,
+                    title,
+                } => {
+                    let _ = writeln!(
+                        markdown,
+                        "## Slide {slide_number}{} (removed)",
+                        title_suffix(title)
+                    );
+                }
+                SlideDiff::Changed {
+                    old_slide_number,
+                    new_slide_number,
+                    title,
+                    moved,
+                    changes,
+                } => {
+                    let _ = write!(
+                        markdown,
+                        "## Slide {new_slide_number}{}",
+                        title_suffix(title)
+                    );
+                    if *moved {
+                        let _ = write!(markdown, " (moved from slide {old_slide_number})");
+                    } else if old_slide_number != new_slide_number {
+                        let _ = write!(markdown, " (slide {old_slide_number} before)");
+                    }
+                    markdown.push('\n');
+                    if !changes.is_empty() {
+                        markdown.push_str("\n```diff\n");
+                        for change in changes {
+                            render_change(&mut markdown, change);
+                        }
+                        markdown.push_str("```\n");
+                    }
+                }
+            }
+        }
+        markdown
+    }
+}
+
+fn title_suffix(title: &Option<String>) -> String {
+    title
+        .as_deref()
+        .map(|title| format!(": {title}"))
+        .unwrap_or_default()
+}
+
+fn render_change(markdown: &mut String, change: &ContentChange) {
+    let mut lines = |prefix: char, text: &str| {
+        for line in text.lines() {
+            let _ = writeln!(markdown, "{prefix} {line}");
+        }
+    };
+    match change {
+        ContentChange::TextAdded(text) => lines('+', text),
+        ContentChange::TextRemoved(text) => lines('-', text),
+        ContentChange::TextChanged { old, new } => {
+            lines('-', old);
+            lines('+', new);
+        }
+        ContentChange::ImageAdded { target, .. } => lines('+', &format!("![image]({target})")),
+        ContentChange::ImageRemoved { target, .. } => lines('-', &format!("![image]({target})")),
+        ContentChange::ImageChanged { old, new, .. } => {
+            lines('-', &format!("![image]({old})"));
+            lines('+', &format!("![image]({new})"));
+        }
+    }
+}
+
+struct SlideSummary {
+    slide_number: u32,
+    title: Option<String>,
+    blocks: Vec<String>,
+    images: Vec<(String, u64)>,
+}
+
+impl SlideSummary {
+    fn new(slide: &Slide) -> Self {
+        let images = slide
+            .semantic_blocks()
+            .iter()
+            .filter_map(|block| match &block.content {
+                SlideBlockContent::Image(image) => Some(&image.reference),
+                _ => None,
+            })
+            .map(|reference| {
+                let hash = match slide.image_data.get(&reference.id) {
+                    Some(data) => content_hash(data),
+                    None => content_hash(reference.target.as_bytes()),
+                };
+                (reference.target.clone(), hash)
+            })
+            .collect();
+        Self {
+            slide_number: slide.slide_number,
+            title: slide.title(),
+            blocks: slide.block_texts(),
+            images,
+        }
+    }
+}
+
+fn content_changes(old: &SlideSummary, new: &SlideSummary) -> Vec<ContentChange> {
+    let mut changes = Vec::new();
+    for edit in paired_edits(&old.blocks, &new.blocks, |old, new| old == new) {
+        changes.push(match edit {
+            PairedEdit::Added(new) => ContentChange::TextAdded(new.clone()),
+            PairedEdit::Removed(old) => ContentChange::TextRemoved(old.clone()),
+            PairedEdit::Changed(old, new) => ContentChange::TextChanged {
+                old: old.clone(),
+                new: new.clone(),
+            },
+        });
+    }
+    for edit in paired_edits(&old.images, &new.images, |old, new| old.1 == new.1) {
+        changes.push(match edit {
+            PairedEdit::Added((target, hash)) => ContentChange::ImageAdded {
+                target: target.clone(),
+                hash: *hash,
+            },
+            PairedEdit::Removed((target, hash)) => ContentChange::ImageRemoved {
+                target: target.clone(),
+                hash: *hash,
+            },
+            PairedEdit::Changed((old, old_hash), (new, new_hash)) => ContentChange::ImageChanged {
+                old: old.clone(),
+                new: new.clone(),
+                old_hash: *old_hash,
+                new_hash: *new_hash,
+            },
+        });
+    }
+    changes
+}
+
+enum PairedEdit<'a, T> {
+    Added(&'a T),
+    Removed(&'a T),
+    Changed(&'a T, &'a T),
+}
+
+/// Aligns both sequences along their longest common subsequence and returns the differing
+/// items. Removed items directly followed by added items are paired up as changes.
+fn paired_edits<'a, T>(
+    old: &'a [T],
+    new: &'a [T],
+    same: impl Fn(&T, &T) -> bool,
+) -> Vec<PairedEdit<'a, T>> {
+    let mut lengths = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i][j] = if same(&old[i], &new[j]) {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+
+    let mut edits = Vec::new();
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let mut flush = |removed: &mut Vec<&'a T>, added: &mut Vec<&'a T>| {
+        let paired = removed.len().min(added.len());
+        for (old, new) in removed.iter().zip(added.iter()) {
+            edits.push(PairedEdit::Changed(*old, *new));
+        }
+        edits.extend(removed.drain(..).skip(paired).map(PairedEdit::Removed));
+        edits.extend(added.drain(..).skip(paired).map(PairedEdit::Added));
+    };
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && same(&old[i], &new[j]) {
+            flush(&mut removed, &mut added);
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            removed.push(&old[i]);
+            i += 1;
+        } else {
+            added.push(&new[j]);
+            j += 1;
+        }
+    }
+    flush(&mut removed, &mut added);
+    edits
+}
+
+/// Returns the values of the longest strictly increasing subsequence of `values`.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<usize> {
+    let mut lengths = vec![1usize; values.len()];
+    let mut previous = vec![None; values.len()];
+    for i in 0..values.len() {
+        for j in 0..i {
+            if values[j] < values[i] && lengths[j] + 1 > lengths[i] {
+                lengths[i] = lengths[j] + 1;
+                previous[i] = Some(j);
+            }
+        }
+    }
+    let mut index = (0..values.len()).max_by_key(|index| (lengths[*index], usize::MAX - index));
+    let mut subsequence = Vec::new();
+    while let Some(current) = index {
+        subsequence.push(values[current]);
+        index = previous[current];
+    }
+    subsequence.reverse();
+    subsequence
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/diff.rs"]
+mod tests;
@@ -0,0 +1,181 @@
+use crate::constants::A_NAMESPACE;
+use crate::container::sort_slide_paths;
+use crate::xml::{element_is, end_is, event, reader};
+use crate::{Error, Result, Slide};
+use quick_xml::escape::{partial_escape, unescape};
+use quick_xml::events::Event;
+use std::collections::BTreeMap;
+use std::io::{Read, Seek, Write};
+use std::ops::Range;
+use std::path::Path;
+use zip::CompressionMethod;
+use zip::write::SimpleFileOptions;
+
+/// Edits the text runs of a PowerPoint (pptx) file and writes the result as a new package.
+///
+/// Only the `a:t` text of edited runs is replaced. Everything else in an edited slide part is
+/// kept as is, and parts without changes are copied into the new package byte-for-byte
+/// without being recompressed.
+///
+/// ```no_run
+/// # fn main() -> pptx_to_md::Result<()> {
+/// use pptx_to_md::PptxEditor;
+/// use std::path::Path;
+///
+/// let mut editor = PptxEditor::open(Path::new("template.pptx"))?;
+/// editor.replace_text("{{customer}}", "ACME Corp.")?;
+/// editor.save(Path::new("offer.pptx"))?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct PptxEditor {
+    archive: zip::ZipArchive<std::fs::File>,
+    slide_paths: Vec<String>,
+    edited_parts: BTreeMap<String, Vec<u8>>,
+}
+
+impl PptxEditor {
+    /// Opens a pptx file for editing.
+    pub fn open(path: &Path) -> Result<Self> {
+        let archive = zip::ZipArchive::new(std::fs::File::open(path)?)?;
+        let mut slide_paths: Vec<String> = archive
+            .file_names()
+            .filter(|name| name.starts_with("ppt/slides/slide") && name.ends_with(".xml"))
+            .map(str::to_string)
+            .collect();
+        sort_slide_paths(&mut slide_paths);
+        Ok(Self {
+            archive,
+            slide_paths,
+            edited_parts: BTreeMap::new(),
+        })
+    }
+
+    /// Calls `edit` with the slide number and the text of every text run and replaces the
+    /// run text with the returned string.
+    ///
+    /// Runs for which `edit` returns `None` or the unchanged text are left untouched.
+    /// Repeated calls see the result of earlier edits.
+    ///
+    /// # Returns
+    ///
+    /// The number of runs whose text was changed.
+    pub fn edit_runs<F>(&mut self, mut edit: F) -> Result<usize>
+    where
+        F: FnMut(u32, &str) -> Option<String>,
+    {
+        let mut changed = 0;
+        for slide_path in self.slide_paths.clone() {
+            let slide_number = Slide::extract_slide_number(&slide_path).unwrap_or(0);
+            let xml_data = self.read_part(&slide_path)?;
+            let (edited, count) = replace_run_texts(&xml_data, |text| edit(slide_number, text))?;
+            if count > 0 {
+                changed += count;
+                self.edited_parts.insert(slide_path, edited);
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Replaces every occurrence of `from` in the text runs of all slides with `to`.
+    ///
+    /// Text that is split across several runs, for example because only a part of it is
+    /// formatted differently, is not matched.
+    ///
+    /// # Returns
+    ///
+    /// The number of runs whose text was changed.
+    pub fn replace_text(&mut self, from: &str, to: &str) -> Result<usize> {
+        if from.is_empty() {
+            return Ok(0);
+        }
+        self.edit_runs(|_, text| text.contains(from).then(|| text.replace(from, to)))
+    }
+
+    /// Writes the edited package to `path`.
+    pub fn save(&mut self, path: &Path) -> Result<()> {
+        self.write_to(std::fs::File::create(path)?)
+    }
+
+    /// Writes the edited package to `writer`.
+    pub fn write_to<W: Write + Seek>(&mut self, writer: W) -> Result<()> {
+        let mut package = zip::ZipWriter::new(writer);
+        for index in 0..self.archive.len() {
+            let file = self.archive.by_index_raw(index)?;
+            match self.edited_parts.get(file.name()) {
+                Some(data) => {
+                    let options = SimpleFileOptions::default()
+                        .compression_method(CompressionMethod::Deflated);
+                    package.start_file(file.name(), options)?;
+                    package.write_all(data)?;
+                }
+                None => package.raw_copy_file(file)?,
+            }
+        }
+        package.finish()?;
+        Ok(())
+    }
+
+    fn read_part(&mut self, path: &str) -> Result<Vec<u8>> {
+        if let Some(data) = self.edited_parts.get(path) {
+            return Ok(data.clone());
+        }
+        let mut file = self.archive.by_name(path)?;
+        let mut data = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Replaces the content of the `a:t` elements of `xml_data` for which `edit` returns new text.
+///
+/// Returns the edited XML and the number of replaced run texts. Bytes outside of the replaced
+/// text content are copied unchanged.
+pub(crate) fn replace_run_texts<F>(xml_data: &[u8], mut edit: F) -> Result<(Vec<u8>, usize)>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    // The reader skips a byte order mark without counting it in its positions.
+    let offset = if xml_data.starts_with(b"\xEF\xBB\xBF") {
+        3
+    } else {
+        0
+    };
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+    let mut xml = reader(&xml_data[offset..]);
+    let mut text_start = None;
+    loop {
+        let position = xml.buffer_position() as usize + offset;
+        match event(&mut xml, "PPTX slide")? {
+            Event::Start(element) if element_is(&xml, &element, A_NAMESPACE, b"t") => {
+                text_start = Some(xml.buffer_position() as usize + offset);
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"t") => {
+                let Some(start) = text_start.take() else {
+                    continue;
+                };
+                let raw = String::from_utf8_lossy(&xml_data[start..position]);
+                let text = unescape(&raw).map_err(|_| Error::ParseError("Invalid run text"))?;
+                if let Some(replacement) = edit(&text).filter(|replacement| *replacement != text) {
+                    replacements.push((start..position, partial_escape(&replacement).into_owned()));
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let mut edited = Vec::with_capacity(xml_data.len());
+    let mut copied = 0;
+    for (range, replacement) in &replacements {
+        edited.extend_from_slice(&xml_data[copied..range.start]);
+        edited.extend_from_slice(replacement.as_bytes());
+        copied = range.end;
+    }
+    edited.extend_from_slice(&xml_data[copied..]);
+    Ok((edited, replacements.len()))
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/editor.rs"]
+mod tests;
@@ -0,0 +1,84 @@
+use crate::limits::limit_exceeded;
+use crate::{ParserConfig, PptxContainer, Result};
+use std::io::{Read, Seek, Write};
+use std::time::Duration;
+
+/// Limits for downloading a presentation with [`PptxContainer::open_url_with_limits`].
+///
+/// Every limit is disabled with `None`. A download exceeding [`DownloadLimits::max_bytes`]
+/// fails with [`crate::Error::LimitExceeded`] naming the URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadLimits {
+    /// Maximum time for the whole request, from connecting until the last byte is received.
+    /// Defaults to 60 seconds.
+    pub timeout: Option<Duration>,
+    /// Maximum size of the downloaded file in bytes. Defaults to 512 MiB.
+    pub max_bytes: Option<u64>,
+}
+
+impl Default for DownloadLimits {
+    fn default() -> Self {
+        Self {
+            timeout: Some(Duration::from_secs(60)),
+            max_bytes: Some(512 * 1024 * 1024),
+        }
+    }
+}
+
+impl PptxContainer {
+    /// Downloads a PPTX file over HTTP(S) and opens it with the default [`DownloadLimits`].
+    ///
+    /// See [`PptxContainer::open_url_with_limits`].
+    pub fn open_url(url: &str, config: ParserConfig) -> Result<Self> {
+        Self::open_url_with_limits(url, config, &DownloadLimits::default())
+    }
+
+    /// Downloads a PPTX file over HTTP(S) and opens it.
+    ///
+    /// The response body is streamed into an anonymous temporary file, which is removed by
+    /// the operating system once the container is dropped, so large decks are not held in
+    /// memory. Signed URLs, e.g. pre-signed S3 or SharePoint download links, work as long as
+    /// they need no further headers.
+    ///
+    /// # Errors
+    ///
+    /// Connection failures, timeouts and HTTP error statuses are returned as
+    /// [`crate::Error::Io`]. Responses larger than [`DownloadLimits::max_bytes`] fail with
+    /// [`crate::Error::LimitExceeded`] before or while they are downloaded.
+    pub fn open_url_with_limits(
+        url: &str,
+        config: ParserConfig,
+        limits: &DownloadLimits,
+    ) -> Result<Self> {
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(limits.timeout)
+            .build()
+            .into();
+        let mut response = agent.get(url).call().map_err(std::io::Error::other)?;
+        let body = response.body_mut();
+        if let (Some(limit), Some(length)) = (limits.max_bytes, body.content_length())
+            && length > limit
+        {
+            return Err(limit_exceeded(url, "max_bytes"));
+        }
+
+        let mut file = tempfile::tempfile()?;
+        let mut reader = body.as_reader();
+        let written = match limits.max_bytes {
+            Some(limit) => {
+                std::io::copy(&mut (&mut reader).take(limit.saturating_add(1)), &mut file)?
+            }
+            None => std::io::copy(&mut reader, &mut file)?,
+        };
+        if limits.max_bytes.is_some_and(|limit| written > limit) {
+            return Err(limit_exceeded(url, "max_bytes"));
+        }
+        file.flush()?;
+        file.rewind()?;
+        Self::from_file(file, config)
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/http.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+use crate::slide::content_hash;
+use crate::{Result, Slide};
+use std::fmt::Write as _;
+use std::io::Write;
+
+/// Writes one JSON object per slide to `writer`, separated by line breaks (JSON Lines).
+///
+/// Slides are consumed from `slides` one at a time, so combined with
+/// [`crate::PresentationContainer::iter_slides`] only the current slide is held in memory.
+/// Every line has the form
+///
+/// ```json
+/// {"slide_number":1,"source":"ppt/slides/slide1.xml","title":"Intro","text":"…","markdown":"…","word_count":3,"images":[{"id":"rId2","target":"../media/image1.png","hash":"a1b2c3d4e5f60718"}]}
+/// ```
+///
+/// `title` is `null` if the slide has no title. The image `hash` is the hex-encoded 64-bit
+/// FNV-1a hash of the image data, or `null` if the data was not extracted.
+///
+/// Returns the number of written lines. The first failing slide aborts the export.
+pub fn write_jsonl<I, W>(slides: I, writer: &mut W) -> Result<usize>
+where
+    I: IntoIterator<Item = Result<Slide>>,
+    W: Write + ?Sized,
+{
+    let mut count = 0;
+    for slide in slides {
+        let line = slide_json(&slide?)?;
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+        count += 1;
+    }
+    writer.flush()?;
+    Ok(count)
+}
+
+/// Serializes a slide into the single-line JSON object written by [`write_jsonl`].
+pub(crate) fn slide_json(slide: &Slide) -> Result<String> {
+    let mut json = String::new();
+    let _ = write!(
+        json,
+        "{{\"slide_number\":{},\"source\":",
+        slide.slide_number
+    );
+    push_json_string(&mut json, &slide.rel_path);
+    json.push_str(",\"title\":");
+    match slide.title() {
+        Some(title) => push_json_string(&mut json, &title),
+        None => json.push_str("null"),
+    }
+    json.push_str(",\"text\":");
+    push_json_string(&mut json, &slide.text());
+    json.push_str(",\"markdown\":");
+    push_json_string(&mut json, &slide.convert_to_md()?);
+    let _ = write!(json, ",\"word_count\":{}", slide.stats().word_count);
+    json.push_str(",\"images\":[");
+    for (index, image) in slide.images.iter().enumerate() {
+        if index > 0 {
+            json.push(',');
+        }
+        json.push_str("{\"id\":");
+        push_json_string(&mut json, &image.id);
+        json.push_str(",\"target\":");
+        push_json_string(&mut json, &image.target);
+        json.push_str(",\"hash\":");
+        match slide.image_data.get(&image.id) {
+            Some(data) => {
+                let _ = write!(json, "\"{:016x}\"", content_hash(data));
+            }
+            None => json.push_str("null"),
+        }
+        json.push('}');
+    }
+    json.push_str("]}");
+    Ok(json)
+}
+
+/// Appends `value` as a quoted JSON string, escaping quotes, backslashes and control
+/// characters.
+pub(crate) fn push_json_string(json: &mut String, value: &str) {
+    json.push('"');
+    for character in value.chars() {
+        match character {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            control if control < '\u{20}' => {
+                let _ = write!(json, "\\u{:04x}", control as u32);
+            }
+            other => json.push(other),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/jsonl.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+use crate::{Presentation, Run, Slide, SlideBlockContent};
+use std::collections::HashMap;
+
+/// Result of the language detection for a slide or presentation.
+///
+/// `declared` holds the most frequent `lang` attribute of the text runs, while
+/// `detected` is derived from the text content itself. Both values are reported so
+/// callers can spot templates whose `lang` attributes do not match the content.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LanguageDetection {
+    /// Most frequent language tag of the text runs, e.g. `en-US`.
+    pub declared: Option<String>,
+    /// ISO 639-3 code of the detected language, e.g. `deu`.
+    pub detected: Option<String>,
+    /// English name of the detected language, e.g. `German`.
+    pub detected_name: Option<String>,
+    /// Detection confidence between `0.0` and `1.0`.
+    pub confidence: f64,
+    pub is_reliable: bool,
+}
+
+impl Slide {
+    /// Detects the language of the slide text.
+    ///
+    /// Speaker notes and comments are not taken into account.
+    pub fn detect_language(&self) -> LanguageDetection {
+        detect(&slide_text(self), &slide_runs(self))
+    }
+}
+
+impl Presentation {
+    /// Detects the language of the text of all slides.
+    pub fn detect_language(&self) -> LanguageDetection {
+        let text = self
+            .slides
+            .iter()
+            .map(slide_text)
+            .collect::<Vec<_>>()
+            .join("\n");
+        let runs: Vec<Run> = self.slides.iter().flat_map(slide_runs).collect();
+        detect(&text, &runs)
+    }
+}
+
+fn slide_text(slide: &Slide) -> String {
+    slide_runs(slide)
+        .iter()
+        .map(|run| run.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn slide_runs(slide: &Slide) -> Vec<Run> {
+    slide
+        .semantic_blocks()
+        .iter()
+        .flat_map(|block| match &block.content {
+            SlideBlockContent::Text(text) => text.paragraphs.clone(),
+            SlideBlockContent::Table(table) => table
+                .rows
+                .iter()
+                .flat_map(|row| &row.cells)
+                .flat_map(|cell| cell.paragraphs.clone())
+                .collect(),
+            SlideBlockContent::Image(_) | SlideBlockContent::Unsupported(_) => Vec::new(),
+        })
+        .flat_map(|paragraph| paragraph.runs)
+        .collect()
+}
+
+fn detect(text: &str, runs: &[Run]) -> LanguageDetection {
+    let info = whatlang::detect(text);
+    LanguageDetection {
+        declared: declared_language(runs),
+        detected: info.as_ref().map(|info| info.lang().code().to_string()),
+        detected_name: info.as_ref().map(|info| info.lang().eng_name().to_string()),
+        confidence: info.as_ref().map(|info| info.confidence()).unwrap_or(0.0),
+        is_reliable: info.as_ref().is_some_and(|info| info.is_reliable()),
+    }
+}
+
+fn declared_language(runs: &[Run]) -> Option<String> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for run in runs {
+        if !run.formatting.lang.is_empty() && !run.text.trim().is_empty() {
+            *counts.entry(run.formatting.lang.as_str()).or_default() += run.text.len();
+        }
+    }
+    counts
+        .into_iter()
+        .max_by(|(left_lang, left), (right_lang, right)| {
+            left.cmp(right).then_with(|| right_lang.cmp(left_lang))
+        })
+        .map(|(lang, _)| lang.to_string())
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/language.rs"]
+mod tests;
@@ -1,22 +1,80 @@
+#[cfg(feature = "arena")]
+mod arena;
+mod batch;
+mod chart;
 mod constants;
 mod container;
+mod diff;
+mod editor;
+#[cfg(feature = "http")]
+mod http;
+mod jsonl;
+#[cfg(feature = "lang-detect")]
+mod language;
+mod limits;
+mod manifest;
 mod markdown;
+mod merge;
 mod metadata;
+mod ocr;
 mod odp;
+mod parse_presentation;
 pub mod parse_rels;
 pub mod parse_xml;
 mod parser_config;
 mod presentation;
+#[cfg(feature = "redaction")]
+mod redaction;
 mod slide;
+#[cfg(feature = "symbol-fonts")]
+mod symbol_fonts;
+mod theme;
 mod types;
+mod validate;
+mod visitor;
 mod xml;
 
-pub use container::PptxContainer;
+#[cfg(test)]
+#[path = "../tests/unit/support.rs"]
+mod test_support;
+
+#[cfg(feature = "arena")]
+pub use arena::{
+    ArenaBlock, ArenaCell, ArenaImage, ArenaParagraph, ArenaRow, ArenaRun, ArenaSlide,
+    ArenaTextBlock,
+};
+pub use batch::{BatchConversion, BatchFailure, BatchReport, convert_directory};
+#[cfg(feature = "arena")]
+pub use bumpalo::Bump;
+pub use container::{
+    AutoParseThresholds, OrderedSlideIterator, ParseStrategy, PptxContainer,
+    PrefetchingSlideIterator,
+};
+pub use diff::{ContentChange, PresentationDiff, SlideDiff, diff};
+pub use editor::PptxEditor;
+#[cfg(feature = "http")]
+pub use http::DownloadLimits;
+pub use jsonl::write_jsonl;
+#[cfg(feature = "lang-detect")]
+pub use language::LanguageDetection;
+pub use manifest::{ChangedSlides, PartManifest};
+pub use merge::{merge_to_md, write_merged_md};
 pub use metadata::PresentationMetadata;
-pub use parser_config::{ImageHandlingMode, ParserConfig};
+pub use ocr::OcrProvider;
+pub use parser_config::{
+    ConfigError, ImageHandlingMode, ImageNaming, OnSlideError, ParagraphSpacing, ParseOptions,
+    ParseOptionsBuilder, ParserConfig, ParserConfigBuilder, RenderOptions, RenderOptionsBuilder,
+    ResourceLimits, SlideAnchors, UnderlineStyle,
+};
 pub use presentation::{PresentationContainer, PresentationFormat, PresentationSlideIterator};
+#[cfg(feature = "redaction")]
+pub use redaction::{REDACTION_PLACEHOLDER, RedactionHit, RedactionLocation};
+#[cfg(feature = "redaction")]
+pub use regex::Regex;
 pub use slide::Slide;
 pub use types::*;
+pub use validate::{ValidationIssue, ValidationIssueKind, ValidationReport};
+pub use visitor::SlideVisitor;
 
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -56,6 +114,9 @@ pub enum Error {
 
     #[error("Unknown Error")]
     Unknown,
+
+    #[error("Resource limit {limit} exceeded in {part}")]
+    LimitExceeded { part: String, limit: &'static str },
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,214 @@
+use crate::constants::P_NAMESPACE;
+use crate::parser_config::ResourceLimits;
+use crate::xml::{XmlReader, element_is, end_is, event, reader};
+use crate::{Error, Result};
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashSet;
+use std::io::Read;
+
+/// The slide elements counted against [`ResourceLimits::max_elements_per_slide`].
+const COUNTED_ELEMENTS: [&[u8]; 6] = [
+    b"sp",
+    b"pic",
+    b"graphicFrame",
+    b"cxnSp",
+    b"grpSp",
+    b"contentPart",
+];
+
+pub(crate) fn limit_exceeded(part: &str, limit: &'static str) -> Error {
+    Error::LimitExceeded {
+        part: part.to_string(),
+        limit,
+    }
+}
+
+/// Checks the declared uncompressed sizes of all package parts against
+/// [`ResourceLimits::max_part_size`] and [`ResourceLimits::max_total_uncompressed_bytes`].
+/// The bytes the parts actually decompress to are counted by [`PackageBudget`] while reading.
+pub(crate) fn check_package_limits<R: Read + std::io::Seek>(
+    archive: &mut zip::ZipArchive<R>,
+    limits: &ResourceLimits,
+) -> Result<()> {
+    if limits.max_part_size.is_none() && limits.max_total_uncompressed_bytes.is_none() {
+        return Ok(());
+    }
+    let mut total = 0u64;
+    for index in 0..archive.len() {
+        let file = archive.by_index_raw(index)?;
+        if limits
+            .max_part_size
+            .is_some_and(|limit| file.size() > limit)
+        {
+            return Err(limit_exceeded(file.name(), "max_part_size"));
+        }
+        total = total.saturating_add(file.size());
+        if limits
+            .max_total_uncompressed_bytes
+            .is_some_and(|limit| total > limit)
+        {
+            return Err(limit_exceeded(file.name(), "max_total_uncompressed_bytes"));
+        }
+    }
+    Ok(())
+}
+
+/// The largest buffer reserved for a part from the uncompressed size it declares. The declared
+/// size comes from the central directory and is not trusted for larger allocations.
+const MAX_RESERVATION: u64 = 16 * 1024 * 1024;
+
+/// Reads `file` into `buffer`, stopping after one byte more than `cap`, and returns the number
+/// of bytes actually read. At most `declared_size`, `cap` and [`MAX_RESERVATION`] bytes are
+/// reserved up front.
+fn read_capped(
+    mut file: impl Read,
+    declared_size: u64,
+    cap: Option<u64>,
+    buffer: &mut Vec<u8>,
+) -> Result<u64> {
+    let reservation = declared_size
+        .min(cap.map_or(u64::MAX, |cap| cap.saturating_add(1)))
+        .min(MAX_RESERVATION);
+    buffer.reserve(reservation as usize);
+    let read = match cap {
+        Some(cap) => file.take(cap.saturating_add(1)).read_to_end(buffer)?,
+        None => file.read_to_end(buffer)?,
+    };
+    Ok(read as u64)
+}
+
+/// The parts read from a package, counted with the bytes they actually decompress to against
+/// [`ResourceLimits::max_total_uncompressed_bytes`].
+///
+/// [`check_package_limits`] only sees the sizes the central directory declares; this catches
+/// parts that decompress to more. Parts are only counted once, so parts read repeatedly do not
+/// use up the limit.
+#[derive(Debug, Default)]
+pub(crate) struct PackageBudget {
+    parts: HashSet<String>,
+    total: u64,
+}
+
+impl PackageBudget {
+    /// Reads a part into `buffer`, stopping after [`ResourceLimits::max_part_size`] bytes or the
+    /// rest of [`ResourceLimits::max_total_uncompressed_bytes`], even if the part declares a
+    /// smaller size. No more than the limits is reserved for a larger declared size.
+    pub(crate) fn read(
+        &mut self,
+        file: impl Read,
+        declared_size: u64,
+        part: &str,
+        limits: &ResourceLimits,
+        buffer: &mut Vec<u8>,
+    ) -> Result<()> {
+        self.read_within(file, declared_size, part, limits, None, buffer)
+            .map(|_| ())
+    }
+
+    /// Reads a part like [`PackageBudget::read`], stopping after `cap` bytes as well, and
+    /// returns the number of bytes read. Parts exceeding `cap` are left to the caller and are
+    /// not counted.
+    pub(crate) fn read_within(
+        &mut self,
+        file: impl Read,
+        declared_size: u64,
+        part: &str,
+        limits: &ResourceLimits,
+        cap: Option<u64>,
+        buffer: &mut Vec<u8>,
+    ) -> Result<u64> {
+        let counted = self.parts.contains(part);
+        let remaining = limits
+            .max_total_uncompressed_bytes
+            .filter(|_| !counted)
+            .map(|limit| limit.saturating_sub(self.total));
+        let read_cap = [limits.max_part_size, remaining, cap]
+            .into_iter()
+            .flatten()
+            .min();
+        let read = read_capped(file, declared_size, read_cap, buffer)?;
+        if limits.max_part_size.is_some_and(|limit| read > limit) {
+            return Err(limit_exceeded(part, "max_part_size"));
+        }
+        if remaining.is_some_and(|remaining| read > remaining) {
+            return Err(limit_exceeded(part, "max_total_uncompressed_bytes"));
+        }
+        if !counted && cap.is_none_or(|cap| read <= cap) {
+            self.total = self.total.saturating_add(read);
+            self.parts.insert(part.to_string());
+        }
+        Ok(read)
+    }
+}
+
+/// Checks the XML of a slide against the slide limits of [`ResourceLimits`] before it is
+/// parsed. [`ResourceLimits::max_slide_xml_bytes`] is enforced while the slide is read.
+pub(crate) fn check_slide_limits(
+    xml_data: &[u8],
+    part: &str,
+    limits: &ResourceLimits,
+) -> Result<()> {
+    if limits.max_group_depth.is_none()
+        && limits.max_elements_per_slide.is_none()
+        && limits.max_slide_xml_nodes.is_none()
+    {
+        return Ok(());
+    }
+    let mut xml = reader(xml_data);
+    let mut depth = 0usize;
+    let mut elements = 0usize;
+    let mut nodes = 0usize;
+    loop {
+        let event = event(&mut xml, part)?;
+        if matches!(event, Event::Start(_) | Event::Empty(_)) {
+            nodes += 1;
+            if limits
+                .max_slide_xml_nodes
+                .is_some_and(|limit| nodes > limit)
+            {
+                return Err(limit_exceeded(part, "max_slide_xml_nodes"));
+            }
+        }
+        let counted = match event {
+            Event::Start(element) => {
+                let group = element_is(&xml, &element, P_NAMESPACE, b"grpSp");
+                if group {
+                    depth += 1;
+                    if limits.max_group_depth.is_some_and(|limit| depth > limit) {
+                        return Err(limit_exceeded(part, "max_group_depth"));
+                    }
+                }
+                group || is_counted(&xml, &element)
+            }
+            Event::Empty(element) => is_counted(&xml, &element),
+            Event::End(element) => {
+                if end_is(element.name().as_ref(), b"grpSp") {
+                    depth = depth.saturating_sub(1);
+                }
+                false
+            }
+            Event::Eof => break,
+            _ => false,
+        };
+        if counted {
+            elements += 1;
+            if limits
+                .max_elements_per_slide
+                .is_some_and(|limit| elements > limit)
+            {
+                return Err(limit_exceeded(part, "max_elements_per_slide"));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn is_counted(xml: &XmlReader<'_>, element: &BytesStart<'_>) -> bool {
+    COUNTED_ELEMENTS
+        .iter()
+        .any(|name| element_is(xml, element, P_NAMESPACE, name))
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/limits.rs"]
+mod tests;
@@ -0,0 +1,103 @@
+use crate::{Error, Result, Slide};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// The CRC-32 checksums and sizes of all parts of a package, as returned by
+/// [`crate::PptxContainer::manifest`].
+///
+/// A manifest is kept next to the results of a parse and passed to
+/// [`crate::PptxContainer::parse_changed`] once the deck was updated. It can be stored as
+/// text with [`ToString`] and read back with [`str::parse`]; every line holds the hex CRC,
+/// the uncompressed size and the part name:
+///
+/// ```text
+/// 1c291ca3 2410 ppt/slides/slide1.xml
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PartManifest {
+    parts: BTreeMap<String, PartEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PartEntry {
+    crc32: u32,
+    size: u64,
+}
+
+impl PartManifest {
+    pub(crate) fn insert(&mut self, part: String, crc32: u32, size: u64) {
+        self.parts.insert(part, PartEntry { crc32, size });
+    }
+
+    /// Returns the CRC-32 checksum of a part, or `None` if the package has no such part.
+    pub fn crc32(&self, part: &str) -> Option<u32> {
+        self.parts.get(part).map(|entry| entry.crc32)
+    }
+
+    /// Returns the uncompressed size of a part in bytes.
+    pub fn size(&self, part: &str) -> Option<u64> {
+        self.parts.get(part).map(|entry| entry.size)
+    }
+
+    /// Iterates over the part names in sorted order.
+    pub fn parts(&self) -> impl Iterator<Item = &str> {
+        self.parts.keys().map(String::as_str)
+    }
+
+    /// Returns whether `part` differs between this manifest and `previous`, including parts
+    /// that were added or removed.
+    pub(crate) fn differs(&self, previous: &PartManifest, part: &str) -> bool {
+        self.parts.get(part) != previous.parts.get(part)
+    }
+}
+
+impl fmt::Display for PartManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (part, entry) in &self.parts {
+            writeln!(f, "{:08x} {} {part}", entry.crc32, entry.size)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PartManifest {
+    type Err = Error;
+
+    fn from_str(text: &str) -> Result<Self> {
+        let mut manifest = PartManifest::default();
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            let mut fields = line.splitn(3, ' ');
+            let (Some(crc32), Some(size), Some(part)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(Error::ParseError("incomplete part manifest line"));
+            };
+            let crc32 = u32::from_str_radix(crc32, 16)
+                .map_err(|_| Error::ParseError("invalid CRC in part manifest"))?;
+            let size = size
+                .parse()
+                .map_err(|_| Error::ParseError("invalid size in part manifest"))?;
+            manifest.insert(part.to_string(), crc32, size);
+        }
+        Ok(manifest)
+    }
+}
+
+/// The result of [`crate::PptxContainer::parse_changed`].
+#[derive(Debug)]
+pub struct ChangedSlides {
+    /// The parsed slides that are new or depend on a modified part, in slide order.
+    pub parsed: Vec<Slide>,
+    /// The numbers of the slides whose parts are unchanged. Their previous results can be
+    /// reused.
+    pub unchanged: Vec<u32>,
+    /// The numbers of the slides of the previous manifest that no longer exist.
+    pub removed: Vec<u32>,
+    /// The manifest of the current package, to be kept for the next update.
+    pub manifest: PartManifest,
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/manifest.rs"]
+mod tests;
@@ -1,4 +1,5 @@
-use crate::{Baseline, Run};
+use crate::{Baseline, MarkdownOptions, Run, UnderlineStyle};
+use std::io::{self, Write};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum MarkdownContext {
@@ -139,7 +140,81 @@ impl MarkdownEscaper {
     }
 }
 
+/// Writes rendered Markdown to an [`io::Write`] while remembering the last bytes written.
+///
+/// The renderer inspects the end of the output to choose block separators, which this allows
+/// without keeping the whole document in memory.
+pub(crate) struct MarkdownWriter<'a, W: Write + ?Sized> {
+    inner: &'a mut W,
+    tail: Vec<u8>,
+}
+
+impl<'a, W: Write + ?Sized> MarkdownWriter<'a, W> {
+    const TAIL_LENGTH: usize = 2;
+
+    pub(crate) fn new(inner: &'a mut W) -> Self {
+        Self {
+            inner,
+            tail: Vec::with_capacity(Self::TAIL_LENGTH * 2),
+        }
+    }
+
+    pub(crate) fn push_str(&mut self, value: &str) -> io::Result<()> {
+        self.write_all(value.as_bytes())
+    }
+
+    pub(crate) fn push(&mut self, character: char) -> io::Result<()> {
+        self.push_str(character.encode_utf8(&mut [0; 4]))
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.tail.is_empty()
+    }
+
+    /// Returns whether the output ends with `suffix`, which must not exceed two bytes.
+    pub(crate) fn ends_with(&self, suffix: &str) -> bool {
+        debug_assert!(suffix.len() <= Self::TAIL_LENGTH);
+        self.tail.ends_with(suffix.as_bytes())
+    }
+}
+
+impl<W: Write + ?Sized> Write for MarkdownWriter<'_, W> {
+    fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buffer)?;
+        let tail_start = written.saturating_sub(Self::TAIL_LENGTH);
+        self.tail.extend_from_slice(&buffer[tail_start..written]);
+        if self.tail.len() > Self::TAIL_LENGTH {
+            self.tail.drain(..self.tail.len() - Self::TAIL_LENGTH);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// The options of [`MarkdownOptions`] that affect the formatting of individual runs.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RunStyle {
+    underline: UnderlineStyle,
+    html_emphasis: bool,
+}
+
+impl RunStyle {
+    pub(crate) fn from_options(options: &MarkdownOptions) -> Self {
+        Self {
+            underline: options.underline_style,
+            html_emphasis: options.html_emphasis,
+        }
+    }
+}
+
 pub(crate) fn render_runs(runs: &[Run], context: MarkdownContext) -> String {
+    render_runs_with(runs, context, RunStyle::default())
+}
+
+pub(crate) fn render_runs_with(runs: &[Run], context: MarkdownContext, style: RunStyle) -> String {
     let mut escaper = MarkdownEscaper::new(context);
     let mut rendered = String::new();
     let mut group_run: Option<&Run> = None;
@@ -159,7 +234,7 @@ pub(crate) fn render_runs(runs: &[Run], context: MarkdownContext) -> String {
                 continue;
             }
 
-            render_group(&mut rendered, previous_run, &group_text);
+            render_group(&mut rendered, previous_run, &group_text, style);
             group_text.clear();
         }
 
@@ -168,7 +243,7 @@ pub(crate) fn render_runs(runs: &[Run], context: MarkdownContext) -> String {
     }
 
     if let Some(previous_run) = group_run {
-        render_group(&mut rendered, previous_run, &group_text);
+        render_group(&mut rendered, previous_run, &group_text, style);
     }
 
     while rendered.ends_with(MarkdownEscaper::NEWLINE) {
@@ -191,7 +266,7 @@ pub(crate) fn render_run(run: &Run) -> String {
     render_runs(std::slice::from_ref(run), MarkdownContext::Flow)
 }
 
-fn render_fragment(run: &Run, escaped_text: &str) -> String {
+fn render_fragment(run: &Run, escaped_text: &str, style: RunStyle) -> String {
     let content_start = escaped_text
         .find(|character: char| !character.is_whitespace())
         .unwrap_or(escaped_text.len());
@@ -215,19 +290,31 @@ fn render_fragment(run: &Run, escaped_text: &str) -> String {
         return escaped_text.to_string();
     }
 
-    if run.formatting.bold && run.formatting.italic {
+    let underlined = run.formatting.underlined;
+    let italic =
+        run.formatting.italic || (underlined && style.underline == UnderlineStyle::Emphasis);
+    if style.html_emphasis {
+        if italic {
+            result = format!("<em>{result}</em>");
+        }
+        if run.formatting.bold {
+            result = format!("<strong>{result}</strong>");
+        }
+    } else if run.formatting.bold && italic {
         result = format!("***{result}***");
     } else {
         if run.formatting.bold {
             result = format!("**{result}**");
         }
-        if run.formatting.italic {
+        if italic {
             result = format!("_{result}_");
         }
     }
 
-    if run.formatting.underlined {
-        result = format!("<u>{result}</u>");
+    match style.underline {
+        UnderlineStyle::U if underlined => result = format!("<u>{result}</u>"),
+        UnderlineStyle::Ins if underlined => result = format!("<ins>{result}</ins>"),
+        _ => {}
     }
 
     if run.formatting.strikethrough {
@@ -247,7 +334,7 @@ fn render_fragment(run: &Run, escaped_text: &str) -> String {
     format!("{leading_whitespace}{result}{trailing_whitespace}")
 }
 
-fn markdown_link_destination(target: &str) -> String {
+pub(crate) fn markdown_link_destination(target: &str) -> String {
     if target
         .chars()
         .any(|character| character.is_whitespace() || matches!(character, '(' | ')' | '<' | '>'))
@@ -265,12 +352,12 @@ fn markdown_link_destination(target: &str) -> String {
     }
 }
 
-fn render_group(output: &mut String, run: &Run, escaped_text: &str) {
+fn render_group(output: &mut String, run: &Run, escaped_text: &str, style: RunStyle) {
     let mut fragments = escaped_text.split(MarkdownEscaper::NEWLINE).peekable();
 
     while let Some(fragment) = fragments.next() {
         if !fragment.is_empty() {
-            output.push_str(&render_fragment(run, fragment));
+            output.push_str(&render_fragment(run, fragment, style));
         }
         if fragments.peek().is_some() {
             output.push(MarkdownEscaper::NEWLINE);
@@ -0,0 +1,159 @@
+use crate::metadata::render_metadata_comment;
+use crate::slide::{SharedImages, slide_anchor};
+use crate::types::document_title;
+use crate::{
+    Error, ParserConfig, PresentationContainer, Result, Run, Slide, SlideBlockContent, SlideElement,
+};
+use std::io::Write;
+use std::path::Path;
+
+/// Converts several presentations into one Markdown document.
+///
+/// See [`write_merged_md`] for the layout of the document.
+pub fn merge_to_md<P: AsRef<Path>>(paths: &[P], config: ParserConfig) -> Result<String> {
+    let mut buffer = Vec::new();
+    write_merged_md(paths, config, &mut buffer)?;
+    String::from_utf8(buffer).map_err(|error| Error::Utf8(error.utf8_error()))
+}
+
+/// Writes several presentations as one Markdown document to `writer`.
+///
+/// Every presentation starts with a top-level heading holding its
+/// [document title](crate::Presentation::document_title), or the file name if it has none,
+/// followed by a comment naming the source file and its slide range. The title of the
+/// metadata comment is the document title as well. The slide numbers of every file are
+/// shifted past the highest slide number of the files before it, so slide anchors and
+/// slide-jump links stay unique even for decks whose slide parts are not numbered
+/// contiguously.
+///
+/// Images with identical data are only stored once: with [`crate::ImageHandlingMode::Save`]
+/// the first saved file is referenced again, and with [`crate::ImageHandlingMode::InMarkdown`]
+/// images are written as reference links whose base64 definitions follow at the end of the
+/// document.
+pub fn write_merged_md<P: AsRef<Path>, W: Write + ?Sized>(
+    paths: &[P],
+    config: ParserConfig,
+    writer: &mut W,
+) -> Result<()> {
+    let options = config.render_options();
+    let mut shared = SharedImages::default();
+    let mut offset = 0;
+    for (index, path) in paths.iter().enumerate() {
+        let path = path.as_ref();
+        let mut container = PresentationContainer::open(path, config.clone())?;
+        let metadata = container.metadata().clone();
+        let mut slides = container.parse_all()?;
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        if index > 0 {
+            writer.write_all(b"\n\n")?;
+        }
+        let document_title = document_title(&metadata, &slides);
+        let title = document_title
+            .as_deref()
+            .filter(|title| !title.trim().is_empty())
+            .unwrap_or(&file_name);
+        write!(writer, "# {title}\n\n<!-- Source: {file_name}")?;
+        let numbers = slides.iter().map(|slide| slide.slide_number);
+        let last = numbers.clone().max().unwrap_or(0);
+        if let Some(first) = numbers.min() {
+            write!(writer, ", slides {}-{}", offset + first, offset + last)?;
+        }
+        writer.write_all(b" -->")?;
+        if options.include_presentation_metadata
+            && let Some(comment) = render_metadata_comment(&metadata, document_title.as_deref())
+        {
+            write!(writer, "\n\n{comment}")?;
+        }
+
+        for slide in &mut slides {
+            renumber_slide(slide, offset);
+            writer.write_all(b"\n\n")?;
+            slide.render_md_shared(writer, &options, Some(&mut shared))?;
+        }
+        // Slide numbers come from the part names and may have gaps, so the next file
+        // starts after the highest number rather than after the slide count.
+        offset += last;
+    }
+
+    if !shared.definitions.is_empty() {
+        writer.write_all(b"\n")?;
+        for (label, url) in &shared.definitions {
+            write!(writer, "\n[{label}]: {url}")?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
+/// Shifts the slide number and the targets of slide-jump links by `offset`.
+fn renumber_slide(slide: &mut Slide, offset: u32) {
+    slide.slide_number += offset;
+    let shift = |run: &mut Run| {
+        let number = run
+            .link_target
+            .as_deref()
+            .and_then(|target| target.strip_prefix("#slide-"))
+            .and_then(|number| number.parse::<u32>().ok());
+        if let Some(number) = number {
+            run.link_target = Some(format!("#{}", slide_anchor(number + offset)));
+        }
+    };
+
+    for block in &mut slide.blocks {
+        match &mut block.content {
+            SlideBlockContent::Text(text) => text
+                .paragraphs
+                .iter_mut()
+                .flat_map(|paragraph| &mut paragraph.runs)
+                .for_each(shift),
+            SlideBlockContent::Table(table) => table
+                .rows
+                .iter_mut()
+                .flat_map(|row| &mut row.cells)
+                .flat_map(|cell| &mut cell.paragraphs)
+                .flat_map(|paragraph| &mut paragraph.runs)
+                .for_each(shift),
+            SlideBlockContent::Image(_) | SlideBlockContent::Unsupported(_) => {}
+        }
+    }
+    for element in slide.elements.iter_mut().flat_map(SlideElement::leaves_mut) {
+        match element {
+            SlideElement::Text(text, _, _) => text
+                .paragraphs
+                .iter_mut()
+                .flat_map(|paragraph| &mut paragraph.runs)
+                .for_each(shift),
+            SlideElement::Table(table, _, _) => table
+                .rows
+                .iter_mut()
+                .flat_map(|row| &mut row.cells)
+                .flat_map(|cell| &mut cell.paragraphs)
+                .flat_map(|paragraph| &mut paragraph.runs)
+                .for_each(shift),
+            SlideElement::List(list, _, _) => list
+                .items
+                .iter_mut()
+                .flat_map(|item| &mut item.runs)
+                .for_each(shift),
+            SlideElement::Connector(connector, _, _) => connector
+                .text
+                .iter_mut()
+                .flat_map(|text| &mut text.paragraphs)
+                .flat_map(|paragraph| &mut paragraph.runs)
+                .for_each(shift),
+            SlideElement::Image(..)
+            | SlideElement::Chart(..)
+            | SlideElement::Media(..)
+            | SlideElement::Group { .. }
+            | SlideElement::Unknown => {}
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/merge.rs"]
+mod tests;
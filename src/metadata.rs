@@ -1,12 +1,16 @@
 use crate::xml::{element_is, end_is, event, reader, reference, text};
-use crate::{Error, Result, Slide};
+use crate::{Error, RenderOptions, Result, Slide};
 use quick_xml::events::Event;
+use std::io::Write;
 
 const CORE_PROPERTIES_NS: &str =
     "http://schemas.openxmlformats.org/package/2006/metadata/core-properties";
 const DC_NS: &str = "http://purl.org/dc/elements/1.1/";
 const DCTERMS_NS: &str = "http://purl.org/dc/terms/";
 const META_NS: &str = "urn:oasis:names:tc:opendocument:xmlns:meta:1.0";
+const EXTENDED_PROPERTIES_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties";
+const VT_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes";
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct PresentationMetadata {
@@ -18,13 +22,117 @@ pub struct PresentationMetadata {
     pub keywords: Vec<String>,
     pub created_at: Option<String>,
     pub modified_at: Option<String>,
+    /// The company from the extended properties in `docProps/app.xml`. PPTX only.
+    pub company: Option<String>,
+    /// The number of slides recorded in `docProps/app.xml` by the last application that saved
+    /// the deck. PPTX only.
+    pub slide_count: Option<u32>,
+    /// The slide titles listed in the `TitlesOfParts` of `docProps/app.xml`, in slide order.
+    /// PPTX only.
+    pub slide_titles: Vec<String>,
 }
 
-pub(crate) fn parse_pptx_metadata(core_xml: Option<&[u8]>) -> Result<PresentationMetadata> {
-    core_xml.map_or_else(
+pub(crate) fn parse_pptx_metadata(
+    core_xml: Option<&[u8]>,
+    app_xml: Option<&[u8]>,
+) -> Result<PresentationMetadata> {
+    let mut metadata = core_xml.map_or_else(
         || Ok(PresentationMetadata::default()),
         |xml| parse_metadata(xml, MetadataKind::Pptx, "PPTX core properties"),
-    )
+    )?;
+    if let Some(xml) = app_xml {
+        parse_app_properties(xml, &mut metadata)?;
+    }
+    Ok(metadata)
+}
+
+/// Reads the company, slide count and slide titles from the extended properties of a PPTX
+/// package.
+///
+/// `TitlesOfParts` lists the fonts, themes and slide titles of the deck in the groups named by
+/// `HeadingPairs`. The slide titles are the group named `Slide Titles`, or the last group if
+/// the application wrote localized group names.
+fn parse_app_properties(data: &[u8], metadata: &mut PresentationMetadata) -> Result<()> {
+    let part = "PPTX extended properties";
+    let mut xml = reader(data);
+    let mut headings: Vec<(String, usize)> = Vec::new();
+    let mut titles = Vec::new();
+    let mut in_headings = false;
+    let mut in_titles = false;
+    let mut depth = 0usize;
+    loop {
+        match event(&mut xml, part)? {
+            Event::Start(element)
+                if element_is(&xml, &element, EXTENDED_PROPERTIES_NS, b"Company") =>
+            {
+                metadata.company = read_element_text(&mut xml, b"Company", part)?;
+            }
+            Event::Start(element)
+                if element_is(&xml, &element, EXTENDED_PROPERTIES_NS, b"Slides") =>
+            {
+                metadata.slide_count = read_element_text(&mut xml, b"Slides", part)?
+                    .and_then(|value| value.parse().ok());
+            }
+            Event::Start(element)
+                if element_is(&xml, &element, EXTENDED_PROPERTIES_NS, b"HeadingPairs") =>
+            {
+                in_headings = true;
+                depth += 1;
+            }
+            Event::Start(element)
+                if element_is(&xml, &element, EXTENDED_PROPERTIES_NS, b"TitlesOfParts") =>
+            {
+                in_titles = true;
+                depth += 1;
+            }
+            Event::Start(element) if element_is(&xml, &element, VT_NS, b"lpstr") => {
+                let value = read_element_text(&mut xml, b"lpstr", part)?.unwrap_or_default();
+                if in_headings {
+                    headings.push((value, 0));
+                } else if in_titles {
+                    titles.push(value);
+                }
+            }
+            Event::Empty(element) if in_titles && element_is(&xml, &element, VT_NS, b"lpstr") => {
+                titles.push(String::new());
+            }
+            Event::Start(element) if in_headings && element_is(&xml, &element, VT_NS, b"i4") => {
+                let count = read_element_text(&mut xml, b"i4", part)?
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or(0);
+                if let Some((_, heading_count)) = headings.last_mut() {
+                    *heading_count = count;
+                }
+            }
+            Event::Start(_) => depth += 1,
+            Event::End(element) if depth > 0 => {
+                depth -= 1;
+                if end_is(element.name().as_ref(), b"HeadingPairs") {
+                    in_headings = false;
+                } else if end_is(element.name().as_ref(), b"TitlesOfParts") {
+                    in_titles = false;
+                }
+            }
+            Event::Eof if depth > 0 => {
+                return Err(Error::ParseError("Unexpected end of metadata XML"));
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    let group = headings
+        .iter()
+        .position(|(name, _)| name == "Slide Titles")
+        .or_else(|| headings.len().checked_sub(1));
+    if let Some(group) = group {
+        let start: usize = headings[..group].iter().map(|(_, count)| count).sum();
+        metadata.slide_titles = titles
+            .into_iter()
+            .skip(start)
+            .take(headings[group].1)
+            .collect();
+    }
+    Ok(())
 }
 
 pub(crate) fn parse_odp_metadata(meta_xml: Option<&[u8]>) -> Result<PresentationMetadata> {
@@ -154,22 +262,79 @@ fn assign_metadata(
 
 pub(crate) fn render_presentation_markdown(
     metadata: &PresentationMetadata,
-    include_metadata: bool,
+    options: &RenderOptions,
     slides: Vec<Slide>,
 ) -> Result<String> {
-    let mut parts = Vec::with_capacity(slides.len() + usize::from(include_metadata));
-    if include_metadata && let Some(comment) = render_metadata_comment(metadata) {
-        parts.push(comment);
+    let mut buffer = Vec::new();
+    write_presentation_markdown(metadata, options, slides.into_iter().map(Ok), &mut buffer)?;
+    String::from_utf8(buffer).map_err(|error| Error::Utf8(error.utf8_error()))
+}
+
+/// Writes the metadata comment and the Markdown of each slide to `writer`, separated by
+/// blank lines, parsing the next slide only after the previous one has been written.
+pub(crate) fn write_presentation_markdown<W: Write + ?Sized>(
+    metadata: &PresentationMetadata,
+    options: &RenderOptions,
+    slides: impl IntoIterator<Item = Result<Slide>>,
+    writer: &mut W,
+) -> Result<()> {
+    let mut first = write_metadata_prelude(metadata, options, writer)?;
+    for slide in slides {
+        let slide = slide?;
+        if !first {
+            writer.write_all(b"\n\n")?;
+        }
+        slide.render_md_with(writer, options)?;
+        first = false;
     }
+    Ok(())
+}
+
+/// Writes the metadata comment and the already rendered Markdown of each slide to `writer`,
+/// separated like [`write_presentation_markdown`].
+pub(crate) fn write_rendered_markdown<W: Write + ?Sized>(
+    metadata: &PresentationMetadata,
+    options: &RenderOptions,
+    slides: impl IntoIterator<Item = Vec<u8>>,
+    writer: &mut W,
+) -> Result<()> {
+    let mut first = write_metadata_prelude(metadata, options, writer)?;
     for slide in slides {
-        parts.push(slide.convert_to_md()?);
+        if !first {
+            writer.write_all(b"\n\n")?;
+        }
+        writer.write_all(&slide)?;
+        first = false;
     }
-    Ok(parts.join("\n\n"))
+    Ok(())
 }
 
-fn render_metadata_comment(metadata: &PresentationMetadata) -> Option<String> {
+/// Writes the metadata comment if enabled and returns whether nothing was written yet.
+fn write_metadata_prelude<W: Write + ?Sized>(
+    metadata: &PresentationMetadata,
+    options: &RenderOptions,
+    writer: &mut W,
+) -> Result<bool> {
+    if options.include_presentation_metadata
+        && let Some(comment) = render_metadata_comment(metadata, None)
+    {
+        writer.write_all(comment.as_bytes())?;
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+/// Renders the metadata comment, with `title` as title if given and the metadata title
+/// otherwise.
+///
+/// Markdown of a single presentation passes `None`: it is streamed slide by slide, so its
+/// comment is written before the title slide is known.
+pub(crate) fn render_metadata_comment(
+    metadata: &PresentationMetadata,
+    title: Option<&str>,
+) -> Option<String> {
     let mut fields = Vec::new();
-    push_field(&mut fields, "Title", metadata.title.as_deref());
+    push_field(&mut fields, "Title", title.or(metadata.title.as_deref()));
     push_field(&mut fields, "Author", metadata.author.as_deref());
     push_field(
         &mut fields,
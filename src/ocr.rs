@@ -0,0 +1,28 @@
+use std::fmt;
+
+/// Recognizes the text shown in an image, for example a screenshot of a document.
+///
+/// Set a provider with [`crate::RenderOptionsBuilder::ocr_provider`] to run it on every image
+/// with extracted data while rendering. The recognized text is written as a blockquote below
+/// the image. Providers are shared between threads by the multi-threaded conversion.
+pub trait OcrProvider: Send + Sync {
+    /// Returns the text in `image`, or `None` if there is none or recognition failed.
+    ///
+    /// `mime` is the MIME type of the original image data, e.g. `image/png`.
+    fn ocr(&self, image: &[u8], mime: &str) -> Option<String>;
+}
+
+impl<F> OcrProvider for F
+where
+    F: Fn(&[u8], &str) -> Option<String> + Send + Sync,
+{
+    fn ocr(&self, image: &[u8], mime: &str) -> Option<String> {
+        self(image, mime)
+    }
+}
+
+impl fmt::Debug for dyn OcrProvider {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("OcrProvider")
+    }
+}
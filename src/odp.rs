@@ -1,16 +1,17 @@
+use crate::container::{MediaBudget, media_limit_diagnostic, recover_slide};
+use crate::limits::{PackageBudget, check_package_limits};
 use crate::metadata::{parse_odp_metadata, render_presentation_markdown};
 use crate::xml::{
     XmlReader, attr, element_is, end_is, event, reader, reference, skip_element, text,
 };
 use crate::{
-    ElementPosition, Error, Formatting, ImageReference, ListElement, ListItem, Paragraph,
-    ParseDiagnostic, ParserConfig, PresentationMetadata, Result, Run, Slide, SlideBlock,
-    SlideBlockContent, SlideElement, TableCell, TableElement, TableRow, TextBlock, TextElement,
-    TextRole,
+    ElementMeta, ElementPosition, Error, Formatting, ImageReference, ListElement, ListItem,
+    Paragraph, ParagraphProperties, ParseDiagnostic, ParserConfig, PartialFormatting,
+    PresentationMetadata, ResourceLimits, Result, Run, Slide, SlideBlock, SlideBlockContent,
+    SlideElement, TableCell, TableElement, TableRow, TextBlock, TextElement, TextRole,
 };
 use quick_xml::events::{BytesStart, Event};
 use std::collections::HashMap;
-use std::io::Read;
 use std::ops::Range;
 use std::path::Path;
 
@@ -26,21 +27,28 @@ struct PageIndex {
 }
 
 pub(crate) struct OdpContainer {
-    config: ParserConfig,
+    pub(crate) config: ParserConfig,
     archive: zip::ZipArchive<std::fs::File>,
     content: Vec<u8>,
     pages: Vec<PageIndex>,
     styles: StyleResolver,
     metadata: PresentationMetadata,
+    media: MediaBudget,
+    package: PackageBudget,
 }
 
 impl OdpContainer {
     pub(crate) fn open(path: &Path, config: ParserConfig) -> Result<Self> {
         let file = std::fs::File::open(path)?;
         let mut archive = zip::ZipArchive::new(file)?;
-        let content = read_archive_file(&mut archive, "content.xml")?;
-        let style_xml = read_archive_file(&mut archive, "styles.xml").unwrap_or_default();
-        let meta = read_optional_archive_file(&mut archive, "meta.xml")?;
+        check_package_limits(&mut archive, &config.resource_limits)?;
+        let limits = config.resource_limits;
+        let mut package = PackageBudget::default();
+        let content = read_archive_file(&mut archive, &mut package, "content.xml", &limits)?;
+        let style_xml =
+            read_optional_archive_file(&mut archive, &mut package, "styles.xml", &limits)?
+                .unwrap_or_default();
+        let meta = read_optional_archive_file(&mut archive, &mut package, "meta.xml", &limits)?;
         let styles = StyleResolver::from_documents(&content, &style_xml)?;
         let pages = index_pages(&content)?;
         let metadata = parse_odp_metadata(meta.as_deref())?;
@@ -51,12 +59,14 @@ impl OdpContainer {
             pages,
             styles,
             metadata,
+            media: MediaBudget::default(),
+            package,
         })
     }
 
     pub(crate) fn parse_all(&mut self) -> Result<Vec<Slide>> {
         (0..self.pages.len())
-            .map(|index| self.load_slide(index))
+            .filter_map(|index| self.load_slide(index).transpose())
             .collect()
     }
 
@@ -66,14 +76,17 @@ impl OdpContainer {
 
     pub(crate) fn convert_to_md(&mut self) -> Result<String> {
         let slides = self.parse_all()?;
-        render_presentation_markdown(
-            &self.metadata,
-            self.config.include_presentation_metadata,
-            slides,
-        )
+        render_presentation_markdown(&self.metadata, &self.config.render_options(), slides)
+    }
+
+    fn load_slide(&mut self, index: usize) -> Result<Option<Slide>> {
+        match self.parse_slide(index) {
+            Ok(slide) => Ok(Some(slide)),
+            Err(error) => recover_slide(page_path(index), (index + 1) as u32, error, &self.config),
+        }
     }
 
-    fn load_slide(&mut self, index: usize) -> Result<Slide> {
+    fn parse_slide(&mut self, index: usize) -> Result<Slide> {
         let page = self.pages.get(index).ok_or(Error::SlideNotFound)?;
         let fragment = page_fragment(&self.content[page.range.clone()], &page.namespaces);
         let mut parsed = parse_page_fragment(&fragment, &self.styles)?;
@@ -81,17 +94,34 @@ impl OdpContainer {
             .elements
             .iter()
             .filter_map(|element| match element {
-                SlideElement::Image(image, _) => Some(image.clone()),
+                SlideElement::Image(image, _, _) => Some(image.clone()),
                 _ => None,
             })
             .collect();
         let mut image_data = HashMap::new();
         if self.config.extract_images {
             for image in &images {
-                match read_archive_file(&mut self.archive, &image.target) {
-                    Ok(data) => {
+                let limit = self.config.max_total_media_bytes;
+                let data = self
+                    .archive
+                    .by_name(&image.target)
+                    .map_err(Error::from)
+                    .and_then(|file| {
+                        self.media.read(
+                            file,
+                            &image.target,
+                            limit,
+                            &self.config.resource_limits,
+                            &mut self.package,
+                        )
+                    });
+                match data {
+                    Ok(Some(data)) => {
                         image_data.insert(image.id.clone(), data);
                     }
+                    Ok(None) => parsed
+                        .diagnostics
+                        .push(media_limit_diagnostic(image.target.clone(), limit)),
                     Err(error) => parsed.diagnostics.push(ParseDiagnostic {
                         severity: crate::DiagnosticSeverity::Warning,
                         message: format!("Image resource could not be loaded: {error}"),
@@ -101,7 +131,7 @@ impl OdpContainer {
             }
         }
         Ok(Slide::new_semantic(
-            format!("content.xml#page{}", index + 1),
+            page_path(index),
             (index + 1) as u32,
             parsed.elements,
             parsed.blocks,
@@ -109,7 +139,7 @@ impl OdpContainer {
             parsed.comments,
             images,
             image_data,
-            self.config.clone(),
+            self.config.render_options(),
             parsed.diagnostics,
         ))
     }
@@ -131,34 +161,44 @@ impl Iterator for OdpSlideIterator<'_> {
     type Item = Result<Slide>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_index >= self.container.pages.len() {
-            return None;
+        while self.current_index < self.container.pages.len() {
+            let index = self.current_index;
+            self.current_index += 1;
+            if let Some(slide) = self.container.load_slide(index).transpose() {
+                return Some(slide);
+            }
         }
-        let index = self.current_index;
-        self.current_index += 1;
-        Some(self.container.load_slide(index))
+        None
     }
 }
 
-fn read_archive_file(archive: &mut zip::ZipArchive<std::fs::File>, path: &str) -> Result<Vec<u8>> {
-    let mut file = archive.by_name(path)?;
+fn page_path(index: usize) -> String {
+    format!("content.xml#page{}", index + 1)
+}
+
+fn read_archive_file(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+    package: &mut PackageBudget,
+    path: &str,
+    limits: &ResourceLimits,
+) -> Result<Vec<u8>> {
+    let file = archive.by_name(path)?;
+    let declared_size = file.size();
     let mut bytes = Vec::new();
-    file.read_to_end(&mut bytes)?;
+    package.read(file, declared_size, path, limits, &mut bytes)?;
     Ok(bytes)
 }
 
 fn read_optional_archive_file(
     archive: &mut zip::ZipArchive<std::fs::File>,
+    package: &mut PackageBudget,
     path: &str,
+    limits: &ResourceLimits,
 ) -> Result<Option<Vec<u8>>> {
-    let mut file = match archive.by_name(path) {
-        Ok(file) => file,
-        Err(zip::result::ZipError::FileNotFound) => return Ok(None),
-        Err(error) => return Err(error.into()),
-    };
-    let mut bytes = Vec::new();
-    file.read_to_end(&mut bytes)?;
-    Ok(Some(bytes))
+    match read_archive_file(archive, package, path, limits) {
+        Err(Error::Zip(zip::result::ZipError::FileNotFound)) => Ok(None),
+        result => result.map(Some),
+    }
 }
 
 fn index_pages(content: &[u8]) -> Result<Vec<PageIndex>> {
@@ -287,43 +327,6 @@ fn page_fragment(page: &[u8], namespaces: &[(String, String)]) -> Vec<u8> {
     fragment
 }
 
-#[derive(Default, Clone)]
-struct PartialFormatting {
-    bold: Option<bool>,
-    italic: Option<bool>,
-    underlined: Option<bool>,
-    strikethrough: Option<bool>,
-    baseline: Option<crate::Baseline>,
-    font_size_points: Option<f32>,
-    lang: Option<String>,
-}
-
-impl PartialFormatting {
-    fn merge_into(&self, formatting: &mut Formatting) {
-        if let Some(value) = self.bold {
-            formatting.bold = value;
-        }
-        if let Some(value) = self.italic {
-            formatting.italic = value;
-        }
-        if let Some(value) = self.underlined {
-            formatting.underlined = value;
-        }
-        if let Some(value) = self.strikethrough {
-            formatting.strikethrough = value;
-        }
-        if let Some(value) = self.baseline {
-            formatting.baseline = value;
-        }
-        if let Some(value) = self.font_size_points {
-            formatting.font_size_points = Some(value);
-        }
-        if let Some(value) = &self.lang {
-            formatting.lang = value.clone();
-        }
-    }
-}
-
 struct StyleDefinition {
     parent: Option<String>,
     formatting: PartialFormatting,
@@ -467,6 +470,10 @@ fn parse_formatting_properties(element: &BytesStart<'_>) -> PartialFormatting {
             .and_then(|value| value.strip_suffix("pt").map(str::to_string))
             .and_then(|value| value.parse().ok()),
         lang: attr(element, b"language"),
+        font: attr(element, b"font-name"),
+        color: attr(element, b"color")
+            .and_then(|value| value.strip_prefix('#').map(str::to_ascii_uppercase))
+            .map(crate::Color::Rgb),
     }
 }
 
@@ -543,7 +550,7 @@ fn parse_container(
                     )?;
                 } else if crate::xml::local(element.name().as_ref()) == b"annotation" {
                     let position = add_position(parent_position, node_position(&element));
-                    let bounds = node_bounds(&element, position);
+                    let bounds = position.into();
                     parse_text_container(
                         xml,
                         b"annotation",
@@ -580,7 +587,7 @@ fn parse_node(
     page: &mut ParsedPage,
 ) -> Result<()> {
     let position = add_position(parent_position, node_position(start));
-    let bounds = node_bounds(start, position);
+    let bounds = position.into();
     if element_is(xml, start, DRAW_NS, b"g") {
         parse_container(xml, b"g", position, clone_section(section), styles, page)
     } else if element_is(xml, start, DRAW_NS, b"frame") {
@@ -599,8 +606,12 @@ fn parse_node(
             page,
         )
     } else if element_is(xml, start, TABLE_NS, b"table") {
-        let table = parse_table(xml, styles)?;
-        push_element(SlideElement::Table(table, position), section, page);
+        let table = parse_table(xml, start, styles)?;
+        push_element(
+            SlideElement::Table(table, position, ElementMeta::default()),
+            section,
+            page,
+        );
         set_last_bounds(page, section, bounds);
         Ok(())
     } else {
@@ -634,6 +645,7 @@ fn parse_empty_node(
             SlideElement::Image(
                 reference,
                 add_position(parent_position, node_position(element)),
+                ElementMeta::default(),
             ),
             section,
             page,
@@ -641,10 +653,7 @@ fn parse_empty_node(
         set_last_bounds(
             page,
             section,
-            node_bounds(
-                element,
-                add_position(parent_position, node_position(element)),
-            ),
+            add_position(parent_position, node_position(element)).into(),
         );
     }
 }
@@ -664,7 +673,11 @@ fn parse_frame(
         match event(xml, "ODP frame")? {
             Event::Start(element) if element_is(xml, &element, DRAW_NS, b"image") => {
                 if let Some(reference) = parse_image(&element) {
-                    push_element(SlideElement::Image(reference, position), section, page);
+                    push_element(
+                        SlideElement::Image(reference, position, ElementMeta::default()),
+                        section,
+                        page,
+                    );
                     set_last_bounds(page, section, bounds);
                     set_last_image_alt(page, section, alt_text.clone());
                 }
@@ -672,7 +685,11 @@ fn parse_frame(
             }
             Event::Empty(element) if element_is(xml, &element, DRAW_NS, b"image") => {
                 if let Some(reference) = parse_image(&element) {
-                    push_element(SlideElement::Image(reference, position), section, page);
+                    push_element(
+                        SlideElement::Image(reference, position, ElementMeta::default()),
+                        section,
+                        page,
+                    );
                     set_last_bounds(page, section, bounds);
                     set_last_image_alt(page, section, alt_text.clone());
                 }
@@ -705,8 +722,12 @@ fn parse_frame(
                 )?;
             }
             Event::Start(element) if element_is(xml, &element, TABLE_NS, b"table") => {
-                let table = parse_table(xml, styles)?;
-                push_element(SlideElement::Table(table, position), section, page);
+                let table = parse_table(xml, &element, styles)?;
+                push_element(
+                    SlideElement::Table(table, position, ElementMeta::default()),
+                    section,
+                    page,
+                );
                 set_last_bounds(page, section, bounds);
             }
             Event::End(element) if end_is(element.name().as_ref(), b"frame") => break,
@@ -775,10 +796,7 @@ fn parse_text_container(
                 if paragraph_role != role {
                     flush_odp_text(&mut paragraphs, position, bounds, role, &section, page);
                 }
-                let mut runs = parse_paragraph(xml, &element, styles)?;
-                if let Some(last) = runs.last_mut() {
-                    last.text.push('\n');
-                }
+                let runs = parse_paragraph(xml, &element, styles)?;
                 if paragraph_role != role {
                     let mut heading = vec![Paragraph::plain(runs)];
                     flush_odp_text(
@@ -796,7 +814,11 @@ fn parse_text_container(
             Event::Start(element) if element_is(xml, &element, TEXT_NS, b"list") => {
                 flush_odp_text(&mut paragraphs, position, bounds, role, &section, page);
                 let list = parse_list(xml, &element, styles, 0)?;
-                push_element(SlideElement::List(list, position), &section, page);
+                push_element(
+                    SlideElement::List(list, position, ElementMeta::default()),
+                    &section,
+                    page,
+                );
             }
             Event::End(element) if end_is(element.name().as_ref(), end) => break,
             Event::Eof => return Err(Error::ParseError("Unexpected end of ODP text container")),
@@ -818,12 +840,9 @@ fn flush_odp_text(
     if paragraphs.is_empty() {
         return;
     }
-    let runs = paragraphs
-        .iter()
-        .flat_map(|paragraph| paragraph.runs.clone())
-        .collect();
+    let text = TextElement::from_paragraphs(paragraphs.clone());
     push_element(
-        SlideElement::Text(TextElement { runs }, position),
+        SlideElement::Text(text, position, ElementMeta::default()),
         section,
         page,
     );
@@ -837,27 +856,31 @@ fn flush_odp_text(
         *text = TextBlock {
             role,
             paragraphs: std::mem::take(paragraphs),
+            from_template: false,
         };
     } else {
         paragraphs.clear();
     }
 }
 
-fn push_element(element: SlideElement, section: &PageSection, page: &mut ParsedPage) {
+fn push_element(mut element: SlideElement, section: &PageSection, page: &mut ParsedPage) {
     match section {
         PageSection::Main => {
             let source_order = page.blocks.len();
+            if let Some(meta) = element.meta_mut() {
+                meta.source_order = Some(source_order);
+            }
             page.blocks
-                .push(crate::slide::legacy_block(&element, source_order));
+                .extend(crate::slide::legacy_block(&element, source_order));
             page.elements.push(element);
         }
         PageSection::Notes => {
-            if let SlideElement::Text(text, _) = element {
+            if let SlideElement::Text(text, _, _) = element {
                 page.speaker_notes.push(text);
             }
         }
         PageSection::Comment => {
-            if let SlideElement::Text(text, _) = element {
+            if let SlideElement::Text(text, _, _) = element {
                 page.comments.push(text);
             }
         }
@@ -875,9 +898,11 @@ fn push_unsupported_odp(page: &mut ParsedPage, kind: &[u8]) {
     page.blocks.push(SlideBlock {
         bounds: crate::Bounds::default(),
         source_order,
+        raw: None,
         content: SlideBlockContent::Unsupported(crate::UnsupportedBlock {
             kind,
             fallback_text: None,
+            content_type: None,
         }),
     });
     page.elements.push(SlideElement::Unknown);
@@ -906,6 +931,11 @@ fn parse_image(element: &BytesStart<'_>) -> Option<ImageReference> {
     Some(ImageReference {
         id: target.clone(),
         target,
+        dimensions: None,
+        display_size: None,
+        link_target: None,
+        description: None,
+        title: None,
     })
 }
 
@@ -1036,13 +1066,14 @@ fn parse_list_item(
     style_name: Option<&str>,
     items: &mut Vec<ListItem>,
 ) -> Result<()> {
-    let mut runs = Vec::new();
+    let mut runs = Vec::<Run>::new();
     let mut nested = Vec::new();
     loop {
         match event(xml, "ODP list item")? {
             Event::Start(element) if element_is(xml, &element, TEXT_NS, b"p") => {
                 let mut paragraph = parse_paragraph(xml, &element, styles)?;
-                if let Some(last) = paragraph.last_mut() {
+                // Paragraph boundaries are kept as line breaks in the flat item runs.
+                if let Some(last) = runs.last_mut() {
                     last.text.push('\n');
                 }
                 runs.append(&mut paragraph);
@@ -1060,16 +1091,25 @@ fn parse_list_item(
             level,
             is_ordered: styles.is_ordered_list(style_name, level),
             runs,
+            properties: ParagraphProperties::default(),
         });
     }
     items.extend(nested);
     Ok(())
 }
 
-fn parse_table(xml: &mut XmlReader<'_>, styles: &StyleResolver) -> Result<TableElement> {
+fn parse_table(
+    xml: &mut XmlReader<'_>,
+    start: &BytesStart<'_>,
+    styles: &StyleResolver,
+) -> Result<TableElement> {
     let mut rows = Vec::new();
+    let mut has_header = attr(start, b"use-first-row-styles").as_deref() == Some("true");
     loop {
         match event(xml, "ODP table")? {
+            Event::Start(element) if element_is(xml, &element, TABLE_NS, b"table-header-rows") => {
+                has_header = true;
+            }
             Event::Start(element) if element_is(xml, &element, TABLE_NS, b"table-row") => {
                 let repeats = usize_attr(&element, b"number-rows-repeated").unwrap_or(1);
                 let row = parse_table_row(xml, styles)?;
@@ -1084,7 +1124,11 @@ fn parse_table(xml: &mut XmlReader<'_>, styles: &StyleResolver) -> Result<TableE
     for row in &mut rows {
         row.cells.resize_with(width, TableCell::default);
     }
-    Ok(TableElement { rows })
+    Ok(TableElement {
+        rows,
+        has_header,
+        column_widths: Vec::new(),
+    })
 }
 
 fn parse_table_row(xml: &mut XmlReader<'_>, styles: &StyleResolver) -> Result<TableRow> {
@@ -1144,16 +1188,11 @@ fn parse_table_cell(
     start: &BytesStart<'_>,
     styles: &StyleResolver,
 ) -> Result<TableCell> {
-    let mut runs = Vec::new();
     let mut paragraphs = Vec::new();
     loop {
         match event(xml, "ODP table cell")? {
             Event::Start(element) if element_is(xml, &element, TEXT_NS, b"p") => {
-                let mut paragraph = parse_paragraph(xml, &element, styles)?;
-                if let Some(last) = paragraph.last_mut() {
-                    last.text.push('\n');
-                }
-                runs.append(&mut paragraph.clone());
+                let paragraph = parse_paragraph(xml, &element, styles)?;
                 paragraphs.push(crate::Paragraph::plain(paragraph));
             }
             Event::End(element) if end_is(element.name().as_ref(), b"table-cell") => break,
@@ -1162,7 +1201,6 @@ fn parse_table_cell(
         }
     }
     Ok(TableCell {
-        runs,
         paragraphs,
         row_span: usize_attr(start, b"number-rows-spanned").unwrap_or(1),
         column_span: usize_attr(start, b"number-columns-spanned").unwrap_or(1),
@@ -1186,13 +1224,6 @@ fn node_position(element: &BytesStart<'_>) -> ElementPosition {
     ElementPosition {
         x: x + transform.x,
         y: y + transform.y,
-    }
-}
-
-fn node_bounds(element: &BytesStart<'_>, position: ElementPosition) -> crate::Bounds {
-    crate::Bounds {
-        x: position.x,
-        y: position.y,
         width: attr(element, b"width")
             .as_deref()
             .and_then(parse_length)
@@ -1219,6 +1250,7 @@ fn parse_translate(value: &str) -> ElementPosition {
     ElementPosition {
         x: values.next().and_then(parse_length).unwrap_or(0),
         y: values.next().and_then(parse_length).unwrap_or(0),
+        ..ElementPosition::default()
     }
 }
 
@@ -1248,6 +1280,7 @@ fn add_position(left: ElementPosition, right: ElementPosition) -> ElementPositio
     ElementPosition {
         x: left.x + right.x,
         y: left.y + right.y,
+        ..right
     }
 }
 
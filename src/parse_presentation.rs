@@ -0,0 +1,212 @@
+use crate::constants::{A_NAMESPACE, P_NAMESPACE, P14_NAMESPACE, RELATIONSHIPS_NAMESPACE};
+use crate::parse_rels::Relationship;
+use crate::xml::{XmlReader, attr, element_is, end_is, event, reader};
+use crate::{PptxContainer, PresentationSection, Result, Slide};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::ResolveResult;
+use std::collections::HashMap;
+
+const PRESENTATION_PATH: &str = "ppt/presentation.xml";
+
+/// Parses the `p14:sectionLst` extension of `ppt/presentation.xml`.
+///
+/// Sections reference slides by their `p:sldId` id, which is resolved through the
+/// presentation relationships to the slide number. Presentations without sections
+/// return an empty list.
+pub(crate) fn parse_pptx_sections(
+    presentation_xml: &[u8],
+    relationships: &[Relationship],
+) -> Result<Vec<PresentationSection>> {
+    let mut xml = reader(presentation_xml);
+    let mut slide_ids: HashMap<String, String> = HashMap::new();
+    let mut sections: Vec<(String, Vec<String>)> = Vec::new();
+    loop {
+        match event(&mut xml, "PPTX presentation")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"sldId") =>
+            {
+                if let (Some(id), Some(relationship_id)) =
+                    (unqualified_id(&element), relationship_id(&xml, &element))
+                {
+                    slide_ids.insert(id, relationship_id);
+                }
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P14_NAMESPACE, b"section") =>
+            {
+                sections.push((attr(&element, b"name").unwrap_or_default(), Vec::new()));
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P14_NAMESPACE, b"sldId") =>
+            {
+                if let (Some((_, ids)), Some(id)) = (sections.last_mut(), attr(&element, b"id")) {
+                    ids.push(id);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let slide_numbers: HashMap<&str, u32> = relationships
+        .iter()
+        .filter_map(|relationship| {
+            let path = PptxContainer::resolve_target_path(PRESENTATION_PATH, &relationship.target);
+            Slide::extract_slide_number(&path).map(|number| (relationship.id.as_str(), number))
+        })
+        .collect();
+
+    Ok(sections
+        .into_iter()
+        .map(|(name, ids)| PresentationSection {
+            name,
+            slide_numbers: ids
+                .iter()
+                .filter_map(|id| slide_ids.get(id))
+                .filter_map(|relationship_id| slide_numbers.get(relationship_id.as_str()))
+                .copied()
+                .collect(),
+        })
+        .collect())
+}
+
+/// Reads the slide width and height in EMU from the `p:sldSz` of `ppt/presentation.xml`.
+pub(crate) fn parse_slide_size(presentation_xml: &[u8]) -> Result<Option<(i64, i64)>> {
+    let mut xml = reader(presentation_xml);
+    loop {
+        match event(&mut xml, "PPTX presentation")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"sldSz") =>
+            {
+                let size = |name| attr(&element, name).and_then(|value| value.parse().ok());
+                return Ok(size(b"cx").zip(size(b"cy")));
+            }
+            Event::Eof => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the slide parts in the order of the `p:sldIdLst` of `ppt/presentation.xml`.
+///
+/// Slides keep their part name when they are moved in PowerPoint, so only this list
+/// reflects the order of the deck. Entries whose relationship is missing are skipped.
+pub(crate) fn parse_slide_order(
+    presentation_xml: &[u8],
+    relationships: &[Relationship],
+) -> Result<Vec<String>> {
+    let targets: HashMap<&str, &str> = relationships
+        .iter()
+        .map(|relationship| (relationship.id.as_str(), relationship.target.as_str()))
+        .collect();
+    let mut xml = reader(presentation_xml);
+    let mut slide_paths = Vec::new();
+    loop {
+        match event(&mut xml, "PPTX presentation")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"sldId") =>
+            {
+                if let Some(target) = relationship_id(&xml, &element)
+                    .and_then(|relationship_id| targets.get(relationship_id.as_str()).copied())
+                {
+                    slide_paths.push(PptxContainer::resolve_target_path(
+                        PRESENTATION_PATH,
+                        target,
+                    ));
+                }
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"sldIdLst") => break,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(slide_paths)
+}
+
+/// The table styles of `ppt/tableStyles.xml`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TableStyles {
+    default_style: Option<String>,
+    /// Whether each style, keyed by its id, formats the first row differently.
+    first_row_styles: HashMap<String, bool>,
+}
+
+impl TableStyles {
+    /// Returns whether a table with the `firstRow` flag shows a distinct header row.
+    ///
+    /// Tables without a style id use the default style. Styles that are not defined in the
+    /// package are assumed to format the first row.
+    pub(crate) fn formats_first_row(&self, style_id: Option<&str>) -> bool {
+        style_id
+            .or(self.default_style.as_deref())
+            .and_then(|id| self.first_row_styles.get(id))
+            .copied()
+            .unwrap_or(true)
+    }
+}
+
+/// Parses the table style list of `ppt/tableStyles.xml`.
+pub(crate) fn parse_table_styles(xml_data: &[u8]) -> Result<TableStyles> {
+    let mut xml = reader(xml_data);
+    let mut styles = TableStyles::default();
+    let mut current_style = None;
+    loop {
+        match event(&mut xml, "PPTX table styles")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, A_NAMESPACE, b"tblStyleLst") =>
+            {
+                styles.default_style = attr(&element, b"def");
+            }
+            Event::Start(element) if element_is(&xml, &element, A_NAMESPACE, b"tblStyle") => {
+                current_style = attr(&element, b"styleId");
+                if let Some(id) = current_style.clone() {
+                    styles.first_row_styles.insert(id, false);
+                }
+            }
+            Event::Empty(element) if element_is(&xml, &element, A_NAMESPACE, b"tblStyle") => {
+                if let Some(id) = attr(&element, b"styleId") {
+                    styles.first_row_styles.insert(id, false);
+                }
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, A_NAMESPACE, b"firstRow") =>
+            {
+                if let Some(id) = current_style.clone() {
+                    styles.first_row_styles.insert(id, true);
+                }
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"tblStyle") => {
+                current_style = None;
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(styles)
+}
+
+fn unqualified_id(element: &BytesStart<'_>) -> Option<String> {
+    element
+        .attributes()
+        .with_checks(false)
+        .flatten()
+        .find(|attribute| attribute.key.as_ref() == b"id")
+        .map(|attribute| String::from_utf8_lossy(attribute.value.as_ref()).into_owned())
+}
+
+fn relationship_id(xml: &XmlReader<'_>, element: &BytesStart<'_>) -> Option<String> {
+    element
+        .attributes()
+        .with_checks(false)
+        .flatten()
+        .find(|attribute| {
+            let (resolved, local_name) = xml.resolver().resolve_attribute(attribute.key);
+            local_name.as_ref() == b"id"
+                && matches!(resolved, ResolveResult::Bound(value) if value.as_ref() == RELATIONSHIPS_NAMESPACE.as_bytes())
+        })
+        .map(|attribute| String::from_utf8_lossy(attribute.value.as_ref()).into_owned())
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/parse_presentation.rs"]
+mod tests;
@@ -11,6 +11,9 @@ pub struct Relationship {
     pub id: String,
     pub rel_type: String,
     pub target: String,
+    /// Whether the relationship has `TargetMode="External"`, so its target is a URL or file
+    /// outside of the package instead of a part.
+    pub external: bool,
 }
 
 /// Parses package relationship (`.rels`) XML data and extracts all relationships.
@@ -54,6 +57,7 @@ fn push_relationship(element: &quick_xml::events::BytesStart<'_>, out: &mut Vec<
             id,
             rel_type,
             target,
+            external: attr(element, b"TargetMode").as_deref() == Some("External"),
         });
     }
 }
@@ -80,23 +84,52 @@ fn push_relationship(element: &quick_xml::events::BytesStart<'_>, out: &mut Vec<
 /// - Malformed or invalid XML structure is detected.
 /// ```
 pub fn parse_slide_rels(xml_data: &[u8]) -> Result<Vec<ImageReference>> {
-    Ok(parse_relationships(xml_data)?
-        .into_iter()
+    Ok(image_references(&parse_relationships(xml_data)?))
+}
+
+/// Returns the image references of already parsed relationships, see [`parse_slide_rels`].
+pub(crate) fn image_references(relationships: &[Relationship]) -> Vec<ImageReference> {
+    relationships
+        .iter()
         .filter(|rel| rel.rel_type == IMAGE_NAMESPACE)
         .map(|rel| ImageReference {
-            id: rel.id,
-            target: rel.target,
+            id: rel.id.clone(),
+            target: rel.target.clone(),
+            dimensions: None,
+            display_size: None,
+            link_target: None,
+            description: None,
+            title: None,
         })
-        .collect())
+        .collect()
 }
 
-/// Extracts external hyperlink targets keyed by their relationship ID.
+/// Extracts hyperlink targets keyed by their relationship ID.
+///
+/// External hyperlinks keep their target. Slide relationships, which are referenced by
+/// `ppaction://hlinksldjump` actions, are resolved to intra-document anchors such as `#slide-3`.
 pub fn parse_hyperlink_rels(xml_data: &[u8]) -> Result<std::collections::HashMap<String, String>> {
-    Ok(parse_relationships(xml_data)?
-        .into_iter()
-        .filter(|rel| rel.rel_type == crate::constants::HYPERLINK_NAMESPACE)
-        .map(|rel| (rel.id, rel.target))
-        .collect())
+    Ok(hyperlink_targets(&parse_relationships(xml_data)?))
+}
+
+/// Returns the hyperlink targets of already parsed relationships, see [`parse_hyperlink_rels`].
+pub(crate) fn hyperlink_targets(
+    relationships: &[Relationship],
+) -> std::collections::HashMap<String, String> {
+    relationships
+        .iter()
+        .filter_map(|rel| match rel.rel_type.as_str() {
+            crate::constants::HYPERLINK_NAMESPACE => Some((rel.id.clone(), rel.target.clone())),
+            crate::constants::SLIDE_NAMESPACE => crate::Slide::extract_slide_number(&rel.target)
+                .map(|number| {
+                    (
+                        rel.id.clone(),
+                        format!("#{}", crate::slide::slide_anchor(number)),
+                    )
+                }),
+            _ => None,
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -1,19 +1,29 @@
-use crate::constants::{A_NAMESPACE, P_NAMESPACE};
+use crate::constants::{A_NAMESPACE, C_NAMESPACE, P_NAMESPACE};
+use crate::parse_presentation::TableStyles;
 use crate::types::{SlideElement, TableCell, TableElement, TableRow, TextElement};
 use crate::xml::{
     XmlReader, attr, element_is, end_is, event, reader, reference, skip_element, text,
 };
 use crate::{
-    Bounds, DiagnosticSeverity, ElementPosition, Error, Formatting, ImageBlock, ImageReference,
-    ListElement, ListInfo, ListItem, ListKind, Paragraph, ParagraphAlignment, ParseDiagnostic,
-    Result, Run, SemanticTable, SemanticTableCell, SemanticTableRow, SlideBlock, SlideBlockContent,
-    TextBlock, TextRole, UnsupportedBlock,
+    Bounds, ChartElement, Color, ConnectorElement, DiagnosticSeverity, ElementId, ElementMeta,
+    ElementPosition, Error, Formatting, ImageBlock, ImageReference, ListElement, ListInfo,
+    ListItem, ListKind, MediaKind, MediaReference, Paragraph, ParagraphAlignment, ParseDiagnostic,
+    PartialFormatting, PlaceholderType, Result, Run, SemanticTable, SemanticTableCell,
+    SemanticTableRow, ShapeRef, SlideBlock, SlideBlockContent, TextBlock, TextRole,
+    UnsupportedBlock,
 };
 use quick_xml::events::{BytesStart, Event};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::Arc;
 
 type ParsedContent = TextBlock;
 
+/// Run formatting defaults keyed by the zero-based paragraph level (`a:lvl1pPr` is level 0).
+pub(crate) type LevelFormatting = HashMap<u32, PartialFormatting>;
+/// Bullet properties per paragraph level, where `None` records an explicit `a:buNone`.
+type LevelLists = HashMap<u32, Option<ListKind>>;
+
 #[derive(Debug, Clone, Copy)]
 struct CoordinateTransform {
     scale_x: f64,
@@ -36,20 +46,17 @@ impl CoordinateTransform {
         ElementPosition {
             x: (position.x as f64 * self.scale_x + self.translate_x).round() as i64,
             y: (position.y as f64 * self.scale_y + self.translate_y).round() as i64,
+            width: self.scale_width(position.width),
+            height: self.scale_height(position.height),
         }
     }
 
-    fn apply_bounds(self, bounds: Bounds) -> Bounds {
-        let position = self.apply(ElementPosition {
-            x: bounds.x,
-            y: bounds.y,
-        });
-        Bounds {
-            x: position.x,
-            y: position.y,
-            width: (bounds.width as f64 * self.scale_x.abs()).round() as i64,
-            height: (bounds.height as f64 * self.scale_y.abs()).round() as i64,
-        }
+    fn scale_width(self, width: i64) -> i64 {
+        (width as f64 * self.scale_x.abs()).round() as i64
+    }
+
+    fn scale_height(self, height: i64) -> i64 {
+        (height as f64 * self.scale_y.abs()).round() as i64
     }
 
     fn then(self, next: Self) -> Self {
@@ -62,19 +69,104 @@ impl CoordinateTransform {
     }
 }
 
+const TEMPLATE_PROMPTS: [&str; 2] = ["click to edit", "click to add"];
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct PlaceholderKey {
     kind: Option<String>,
     idx: Option<String>,
 }
 
+impl PlaceholderKey {
+    /// Reads the `type` and `idx` of a `p:ph` element.
+    pub(crate) fn from_element(element: &BytesStart<'_>) -> Self {
+        Self {
+            kind: attr(element, b"type"),
+            idx: attr(element, b"idx"),
+        }
+    }
+
+    /// Returns the placeholder type, e.g. `body`.
+    pub(crate) fn kind(&self) -> Option<&str> {
+        self.kind.as_deref()
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct InheritedPositions {
     positions: HashMap<PlaceholderKey, ElementPosition>,
-    list_styles: HashMap<PlaceholderKey, HashMap<u32, ListKind>>,
+    list_styles: HashMap<PlaceholderKey, LevelLists>,
+    template_text: HashSet<String>,
+    run_styles: HashMap<PlaceholderKey, LevelFormatting>,
+    master_styles: MasterTextStyles,
+    table_styles: Arc<TableStyles>,
+    /// See [`crate::ParserConfig::demote_single_item_lists`].
+    demote_single_item_lists: bool,
+    /// See [`crate::ParserConfig::preserve_groups`].
+    preserve_groups: bool,
+    /// Whether placeholder shapes are left out, see [`parse_static_content`].
+    skip_placeholders: bool,
+    /// The parsed chart parts of the slide, keyed by relationship id.
+    charts: HashMap<String, ChartElement>,
+    /// The video and audio clips linked from the slide, keyed by relationship id.
+    media: HashMap<String, MediaReference>,
+    /// Warnings about clips whose data could not be read, reported with the slide.
+    media_diagnostics: Vec<ParseDiagnostic>,
+}
+
+/// The `p:txStyles` of a slide master.
+#[derive(Debug, Clone, Default)]
+struct MasterTextStyles {
+    title: LevelFormatting,
+    body: LevelFormatting,
+    other: LevelFormatting,
+    body_lists: LevelLists,
 }
 
 impl InheritedPositions {
+    pub(crate) fn set_table_styles(&mut self, table_styles: Arc<TableStyles>) {
+        self.table_styles = table_styles;
+    }
+
+    pub(crate) fn set_demote_single_item_lists(&mut self, value: bool) {
+        self.demote_single_item_lists = value;
+    }
+
+    pub(crate) fn set_preserve_groups(&mut self, value: bool) {
+        self.preserve_groups = value;
+    }
+
+    pub(crate) fn set_charts(&mut self, charts: HashMap<String, ChartElement>) {
+        self.charts = charts;
+    }
+
+    pub(crate) fn set_media(
+        &mut self,
+        media: HashMap<String, MediaReference>,
+        diagnostics: Vec<ParseDiagnostic>,
+    ) {
+        self.media = media;
+        self.media_diagnostics = diagnostics;
+    }
+
+    /// Returns whether every paragraph of the text block repeats text of the slide layout or
+    /// master, or is a leftover placeholder prompt such as "Click to edit".
+    fn is_template_text(&self, content: &TextBlock) -> bool {
+        let mut paragraphs = content
+            .paragraphs
+            .iter()
+            .map(|paragraph| normalize_template_text(&paragraph.text()))
+            .filter(|text| !text.is_empty())
+            .peekable();
+        paragraphs.peek().is_some()
+            && paragraphs.all(|text| {
+                self.template_text.contains(&text)
+                    || TEMPLATE_PROMPTS
+                        .iter()
+                        .any(|prompt| text.to_lowercase().starts_with(prompt))
+            })
+    }
+
     fn resolve(&self, key: &PlaceholderKey) -> Option<ElementPosition> {
         self.positions.get(key).copied().or_else(|| {
             key.idx
@@ -95,7 +187,69 @@ impl InheritedPositions {
         })
     }
 
-    fn resolve_list_kind(&self, key: &PlaceholderKey, level: u32) -> Option<ListKind> {
+    /// Resolves the run formatting defaults of a shape from the master text styles and the
+    /// list styles of the matching layout and master placeholders.
+    pub(crate) fn resolve_run_styles(
+        &self,
+        placeholder: Option<&PlaceholderKey>,
+    ) -> LevelFormatting {
+        let mut styles = match placeholder.map(|key| key.kind.as_deref()) {
+            Some(Some("title" | "ctrTitle")) => self.master_styles.title.clone(),
+            Some(Some("dt" | "ftr" | "sldNum")) | None => self.master_styles.other.clone(),
+            Some(_) => self.master_styles.body.clone(),
+        };
+        if let Some(placeholder_styles) =
+            placeholder.and_then(|key| self.placeholder_run_styles(key))
+        {
+            merge_level_formatting(&mut styles, placeholder_styles);
+        }
+        styles
+    }
+
+    fn placeholder_run_styles(&self, key: &PlaceholderKey) -> Option<&LevelFormatting> {
+        self.run_styles
+            .get(key)
+            .or_else(|| {
+                key.idx.as_ref().and_then(|idx| {
+                    self.run_styles
+                        .iter()
+                        .find(|(candidate, _)| candidate.idx.as_ref() == Some(idx))
+                        .map(|(_, styles)| styles)
+                })
+            })
+            .or_else(|| {
+                key.kind.as_ref().and_then(|kind| {
+                    self.run_styles
+                        .iter()
+                        .find(|(candidate, _)| candidate.kind.as_ref() == Some(kind))
+                        .map(|(_, styles)| styles)
+                })
+            })
+    }
+
+    /// Resolves the bullet properties of a paragraph level from the master body style and the
+    /// list styles and sample paragraphs of the matching layout and master placeholders.
+    ///
+    /// Returns `Some(None)` if the level explicitly has no bullet.
+    pub(crate) fn resolve_list_kind(
+        &self,
+        key: &PlaceholderKey,
+        level: u32,
+    ) -> Option<&Option<ListKind>> {
+        let master = match key.kind.as_deref() {
+            Some("title" | "ctrTitle" | "dt" | "ftr" | "sldNum") => None,
+            _ => Some(&self.master_styles.body_lists),
+        };
+        let placeholder = self.placeholder_list_styles(key);
+        let lookup = |level| {
+            placeholder
+                .and_then(|styles| styles.get(&level))
+                .or_else(|| master.and_then(|styles| styles.get(&level)))
+        };
+        lookup(level).or_else(|| lookup(0))
+    }
+
+    fn placeholder_list_styles(&self, key: &PlaceholderKey) -> Option<&LevelLists> {
         self.list_styles
             .get(key)
             .or_else(|| {
@@ -114,8 +268,6 @@ impl InheritedPositions {
                         .map(|(_, styles)| styles)
                 })
             })
-            .and_then(|styles| styles.get(&level).or_else(|| styles.get(&0)))
-            .cloned()
     }
 }
 
@@ -127,9 +279,18 @@ struct PositionData {
     height: Option<i64>,
     placeholder: Option<PlaceholderKey>,
     fallback_text: String,
+    shape_id: Option<u32>,
+    /// The last segment of the `a:graphicData` uri of a graphic frame, e.g. `chart`.
+    graphic_type: Option<String>,
 }
 
 impl PositionData {
+    fn observe_shape_id(&mut self, element: &BytesStart<'_>) {
+        if self.shape_id.is_none() {
+            self.shape_id = attr(element, b"id").and_then(|value| value.parse().ok());
+        }
+    }
+
     fn observe_off(&mut self, element: &BytesStart<'_>) {
         if self.x.is_none() {
             self.x = attr(element, b"x").and_then(|value| value.parse().ok());
@@ -138,10 +299,7 @@ impl PositionData {
     }
 
     fn observe_placeholder(&mut self, element: &BytesStart<'_>) {
-        self.placeholder = Some(PlaceholderKey {
-            kind: attr(element, b"type"),
-            idx: attr(element, b"idx"),
-        });
+        self.placeholder = Some(PlaceholderKey::from_element(element));
     }
 
     fn observe_ext(&mut self, element: &BytesStart<'_>) {
@@ -155,6 +313,8 @@ impl PositionData {
         Some(ElementPosition {
             x: self.x?,
             y: self.y?,
+            width: self.width.unwrap_or(0),
+            height: self.height.unwrap_or(0),
         })
     }
 
@@ -163,14 +323,42 @@ impl PositionData {
         transform: CoordinateTransform,
         inherited: &InheritedPositions,
     ) -> ElementPosition {
-        self.raw()
-            .map(|position| transform.apply(position))
-            .or_else(|| {
-                self.placeholder
+        match self.raw() {
+            Some(position) => transform.apply(position),
+            None => {
+                let position = self
+                    .placeholder
                     .as_ref()
                     .and_then(|key| inherited.resolve(key))
-            })
-            .unwrap_or_default()
+                    .unwrap_or_default();
+                ElementPosition {
+                    width: self
+                        .width
+                        .map_or(position.width, |width| transform.scale_width(width)),
+                    height: self
+                        .height
+                        .map_or(position.height, |height| transform.scale_height(height)),
+                    ..position
+                }
+            }
+        }
+    }
+
+    /// Returns the id and placeholder type of the shape. The source order is set by the
+    /// semantic parser that pushes the element.
+    fn meta(&self) -> ElementMeta {
+        ElementMeta {
+            id: self.shape_id.map(|shape_id| ElementId {
+                slide_number: 0,
+                shape: ShapeRef::ShapeId(shape_id),
+                part: 0,
+            }),
+            placeholder: self
+                .placeholder
+                .as_ref()
+                .and_then(|key| PlaceholderType::from_attr(key.kind.as_deref())),
+            source_order: None,
+        }
     }
 
     fn effective_bounds(
@@ -178,49 +366,66 @@ impl PositionData {
         transform: CoordinateTransform,
         inherited: &InheritedPositions,
     ) -> Bounds {
-        if let Some(position) = self.raw() {
-            transform.apply_bounds(Bounds {
-                x: position.x,
-                y: position.y,
-                width: self.width.unwrap_or(0),
-                height: self.height.unwrap_or(0),
-            })
-        } else {
-            let position = self.effective(transform, inherited);
-            Bounds {
-                x: position.x,
-                y: position.y,
-                width: (self.width.unwrap_or(0) as f64 * transform.scale_x.abs()).round() as i64,
-                height: (self.height.unwrap_or(0) as f64 * transform.scale_y.abs()).round() as i64,
-            }
-        }
+        self.effective(transform, inherited).into()
     }
 }
 
 struct ShapeData {
     content: Option<ParsedContent>,
     position: PositionData,
+    /// The picture of a shape filled with an image through `p:spPr/a:blipFill`.
+    image: Option<ImageReference>,
+    alt_text: Option<String>,
 }
 
+#[derive(Default)]
 pub(crate) struct ParsedSlideDocument {
     pub elements: Vec<SlideElement>,
     pub blocks: Vec<SlideBlock>,
     pub diagnostics: Vec<ParseDiagnostic>,
+    /// The byte range of the source element of each block in the slide XML.
+    raw_ranges: Vec<Range<usize>>,
+}
+
+impl ParsedSlideDocument {
+    /// Assigns `range` to every block pushed since the last call.
+    fn record_raw_ranges(&mut self, range: Range<usize>) {
+        self.raw_ranges.resize(self.blocks.len(), range);
+    }
+
+    /// Stores the source XML of every block in [`SlideBlock::raw`].
+    pub(crate) fn attach_raw_xml(&mut self, xml_data: &[u8]) {
+        // The reader skips a byte order mark without counting it in its positions.
+        let xml_data = xml_data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(xml_data);
+        for (block, range) in self.blocks.iter_mut().zip(&self.raw_ranges) {
+            block.raw = xml_data
+                .get(range.clone())
+                .map(|raw| String::from_utf8_lossy(raw).trim().to_string());
+        }
+    }
 }
 
 pub fn parse_slide_xml(xml_data: &[u8]) -> Result<Vec<SlideElement>> {
     parse_slide_xml_with_hyperlinks(xml_data, &InheritedPositions::default(), &HashMap::new())
 }
 
+/// The text frames of the body placeholder and the pictures of a notes slide.
+#[derive(Debug, Default)]
+pub(crate) struct ParsedNotes {
+    pub(crate) text: Vec<TextElement>,
+    /// Pictures in the notes, referencing the relationships of the notes part.
+    pub(crate) images: Vec<ImageBlock>,
+}
+
 #[cfg(test)]
 pub(crate) fn parse_speaker_notes_xml(xml_data: &[u8]) -> Result<Vec<TextElement>> {
-    parse_speaker_notes_xml_with_hyperlinks(xml_data, &HashMap::new())
+    parse_speaker_notes_xml_with_hyperlinks(xml_data, &HashMap::new()).map(|notes| notes.text)
 }
 
 pub(crate) fn parse_speaker_notes_xml_with_hyperlinks(
     xml_data: &[u8],
     hyperlinks: &HashMap<String, String>,
-) -> Result<Vec<TextElement>> {
+) -> Result<ParsedNotes> {
     let mut xml = reader(xml_data);
     loop {
         match event(&mut xml, "PPTX notes")? {
@@ -236,26 +441,34 @@ pub(crate) fn parse_speaker_notes_xml_with_hyperlinks(
 fn parse_notes_tree(
     xml: &mut XmlReader<'_>,
     hyperlinks: &HashMap<String, String>,
-) -> Result<Vec<TextElement>> {
-    let mut notes = Vec::new();
+) -> Result<ParsedNotes> {
+    let mut notes = ParsedNotes::default();
     loop {
         match event(xml, "PPTX notes")? {
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"sp") => {
-                let shape = parse_shape(xml, hyperlinks)?;
+                let shape = parse_shape(xml, hyperlinks, &InheritedPositions::default())?;
                 if shape
                     .position
                     .placeholder
                     .as_ref()
-                    .and_then(|key| key.kind.as_deref())
+                    .and_then(PlaceholderKey::kind)
                     == Some("body")
                     && let Some(content) = shape.content
                 {
                     let text = content_to_text(content);
-                    if !text.runs.is_empty() {
-                        notes.push(text);
+                    if !text.is_empty() {
+                        notes.text.push(text);
                     }
                 }
             }
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"pic") => {
+                let picture = parse_picture(xml, hyperlinks)?;
+                notes.images.push(ImageBlock {
+                    reference: picture.image,
+                    alt_text: picture.alt_text,
+                    mime_type: None,
+                });
+            }
             Event::Start(element) => {
                 let end = element.name().as_ref().to_vec();
                 skip_element(xml, &end, "PPTX notes")?;
@@ -278,22 +491,22 @@ pub(crate) fn parse_comments_xml_with_hyperlinks(
     loop {
         match event(&mut xml, "PPTX comments")? {
             Event::Start(element) if crate::xml::local(element.name().as_ref()) == b"txBody" => {
-                let content = parse_text_body(&mut xml, true, hyperlinks)?;
+                let content = parse_text_body(&mut xml, hyperlinks)?;
                 let text = content_to_text(content);
-                if !text.runs.is_empty() {
+                if !text.is_empty() {
                     comments.push(text);
                 }
             }
             Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"text") => {
                 let value = read_simple_text(&mut xml, b"text", "PPTX comments")?;
                 if !value.is_empty() {
-                    legacy.push(TextElement {
-                        runs: vec![Run {
-                            text: format!("{value}\n"),
+                    legacy.push(TextElement::from_paragraphs(vec![Paragraph::plain(vec![
+                        Run {
+                            text: value,
                             formatting: Formatting::default(),
                             link_target: None,
-                        }],
-                    });
+                        },
+                    ])]));
                 }
             }
             Event::Eof => break,
@@ -367,23 +580,23 @@ pub(crate) fn parse_slide_document_with_hyperlinks(
             Event::Start(element)
                 if in_common_slide && element_is(&xml, &element, P_NAMESPACE, b"spTree") =>
             {
-                return parse_semantic_shape_tree(
+                let mut parsed = parse_semantic_shape_tree(
                     &mut xml,
                     CoordinateTransform::identity(),
                     inherited_positions,
                     hyperlinks,
                     b"spTree",
                     &mut source_order,
-                );
+                )?;
+                parsed
+                    .diagnostics
+                    .extend(inherited_positions.media_diagnostics.iter().cloned());
+                return Ok(parsed);
             }
             Event::Empty(element)
                 if in_common_slide && element_is(&xml, &element, P_NAMESPACE, b"spTree") =>
             {
-                return Ok(ParsedSlideDocument {
-                    elements: Vec::new(),
-                    blocks: Vec::new(),
-                    diagnostics: Vec::new(),
-                });
+                return Ok(ParsedSlideDocument::default());
             }
             Event::End(element) if end_is(element.name().as_ref(), b"cSld") => {
                 in_common_slide = false;
@@ -402,47 +615,65 @@ fn parse_semantic_shape_tree(
     end: &[u8],
     source_order: &mut usize,
 ) -> Result<ParsedSlideDocument> {
-    let mut parsed = ParsedSlideDocument {
-        elements: Vec::new(),
-        blocks: Vec::new(),
-        diagnostics: Vec::new(),
-    };
+    let mut parsed = ParsedSlideDocument::default();
     loop {
+        let start = xml.buffer_position() as usize;
         match event(xml, "PPTX slide")? {
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"sp") => {
-                let mut shape = parse_shape(xml, hyperlinks)?;
+                let mut shape = parse_shape(xml, hyperlinks, inherited)?;
+                if inherited.skip_placeholders && shape.position.placeholder.is_some() {
+                    continue;
+                }
                 let position = shape.position.effective(transform, inherited);
                 let bounds = shape.position.effective_bounds(transform, inherited);
+                let meta = shape.position.meta();
+                let has_image = push_shape_image(
+                    &mut parsed,
+                    source_order,
+                    &mut shape,
+                    position,
+                    meta,
+                    bounds,
+                );
                 if let Some(mut content) = shape.content.take() {
                     apply_inherited_list_styles(&mut content, &shape.position, inherited);
                     content.role = placeholder_role(shape.position.placeholder.as_ref());
+                    content.from_template = inherited.is_template_text(&content);
+                    let meta = ElementMeta {
+                        source_order: Some(*source_order),
+                        ..meta
+                    };
                     parsed
                         .elements
-                        .extend(content_to_elements(content.clone(), position));
+                        .extend(content_to_elements(content.clone(), position, meta));
                     push_semantic_block(
                         &mut parsed,
                         source_order,
                         bounds,
                         SlideBlockContent::Text(content),
                     );
-                } else {
-                    push_unsupported(&mut parsed, source_order, bounds, "shape", None);
+                } else if !has_image {
+                    push_unsupported(&mut parsed, source_order, bounds, "shape", None, None);
                 }
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"graphicFrame") => {
-                let (table, position) = parse_graphic_frame(xml, hyperlinks)?;
+                let (content, position) = parse_graphic_frame(
+                    xml,
+                    hyperlinks,
+                    &inherited.table_styles,
+                    &inherited.charts,
+                )?;
                 let bounds = position.effective_bounds(transform, inherited);
-                if let Some(table) = table {
-                    parsed.elements.push(SlideElement::Table(
-                        table.clone(),
+                if let Some(content) = content {
+                    let block = content.block_content();
+                    parsed.elements.push(content.into_element(
                         position.effective(transform, inherited),
+                        ElementMeta {
+                            source_order: Some(*source_order),
+                            ..position.meta()
+                        },
                     ));
-                    push_semantic_block(
-                        &mut parsed,
-                        source_order,
-                        bounds,
-                        SlideBlockContent::Table(legacy_table_to_semantic(&table)),
-                    );
+                    push_semantic_block(&mut parsed, source_order, bounds, block);
                 } else {
                     let fallback_text = (!position.fallback_text.trim().is_empty())
                         .then(|| position.fallback_text.trim().to_string());
@@ -452,25 +683,27 @@ fn parse_semantic_shape_tree(
                         bounds,
                         "graphicFrame",
                         fallback_text,
+                        position.graphic_type.clone(),
                     );
                 }
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"pic") => {
-                let (image, position, alt_text) = parse_picture(xml)?;
-                parsed.elements.push(SlideElement::Image(
-                    image.clone(),
-                    position.effective(transform, inherited),
-                ));
-                push_semantic_block(
-                    &mut parsed,
-                    source_order,
-                    position.effective_bounds(transform, inherited),
-                    SlideBlockContent::Image(ImageBlock {
-                        reference: image,
-                        alt_text,
-                        mime_type: None,
-                    }),
-                );
+                let picture = parse_picture(xml, hyperlinks)?;
+                let bounds = picture.position.effective_bounds(transform, inherited);
+                let position = picture.position.effective(transform, inherited);
+                let meta = ElementMeta {
+                    source_order: Some(*source_order),
+                    ..picture.position.meta()
+                };
+                let (element, content) = picture.into_content(&inherited.media, position, meta);
+                parsed.elements.push(element);
+                push_semantic_block(&mut parsed, source_order, bounds, content);
+            }
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"cxnSp") => {
+                let connector = parse_connector(xml, hyperlinks, inherited)?;
+                let position = connector.position.effective(transform, inherited);
+                let meta = connector.position.meta();
+                push_connector(&mut parsed, source_order, connector, position, meta);
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"grpSp") => {
                 merge_parsed(
@@ -493,6 +726,7 @@ fn parse_semantic_shape_tree(
                         Bounds::default(),
                         &String::from_utf8_lossy(&local_name),
                         None,
+                        None,
                     );
                 }
             }
@@ -500,6 +734,7 @@ fn parse_semantic_shape_tree(
             Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX shape tree")),
             _ => {}
         }
+        parsed.record_raw_ranges(start..xml.buffer_position() as usize);
     }
     Ok(parsed)
 }
@@ -511,53 +746,76 @@ fn parse_semantic_group(
     hyperlinks: &HashMap<String, String>,
     source_order: &mut usize,
 ) -> Result<ParsedSlideDocument> {
-    let mut parsed = ParsedSlideDocument {
-        elements: Vec::new(),
-        blocks: Vec::new(),
-        diagnostics: Vec::new(),
-    };
+    let mut parsed = ParsedSlideDocument::default();
     let mut transform = GroupTransformData::default();
+    let mut identity = GroupIdentity::default();
+    let first_source_order = *source_order;
     loop {
+        let start = xml.buffer_position() as usize;
         match event(xml, "PPTX group")? {
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"nvGrpSpPr") => {
+                identity = parse_group_identity(xml)?;
+            }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"grpSpPr") => {
                 parse_group_properties(xml, &mut transform)?;
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"sp") => {
                 let combined = parent.then(transform.finish());
-                let mut shape = parse_shape(xml, hyperlinks)?;
+                let mut shape = parse_shape(xml, hyperlinks, inherited)?;
+                if inherited.skip_placeholders && shape.position.placeholder.is_some() {
+                    continue;
+                }
                 let position = shape.position.effective(combined, inherited);
                 let bounds = shape.position.effective_bounds(combined, inherited);
+                let meta = shape.position.meta();
+                let has_image = push_shape_image(
+                    &mut parsed,
+                    source_order,
+                    &mut shape,
+                    position,
+                    meta,
+                    bounds,
+                );
                 if let Some(mut content) = shape.content.take() {
                     apply_inherited_list_styles(&mut content, &shape.position, inherited);
                     content.role = placeholder_role(shape.position.placeholder.as_ref());
+                    content.from_template = inherited.is_template_text(&content);
+                    let meta = ElementMeta {
+                        source_order: Some(*source_order),
+                        ..meta
+                    };
                     parsed
                         .elements
-                        .extend(content_to_elements(content.clone(), position));
+                        .extend(content_to_elements(content.clone(), position, meta));
                     push_semantic_block(
                         &mut parsed,
                         source_order,
                         bounds,
                         SlideBlockContent::Text(content),
                     );
-                } else {
-                    push_unsupported(&mut parsed, source_order, bounds, "shape", None);
+                } else if !has_image {
+                    push_unsupported(&mut parsed, source_order, bounds, "shape", None, None);
                 }
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"graphicFrame") => {
                 let combined = parent.then(transform.finish());
-                let (table, position) = parse_graphic_frame(xml, hyperlinks)?;
+                let (content, position) = parse_graphic_frame(
+                    xml,
+                    hyperlinks,
+                    &inherited.table_styles,
+                    &inherited.charts,
+                )?;
                 let bounds = position.effective_bounds(combined, inherited);
-                if let Some(table) = table {
-                    parsed.elements.push(SlideElement::Table(
-                        table.clone(),
+                if let Some(content) = content {
+                    let block = content.block_content();
+                    parsed.elements.push(content.into_element(
                         position.effective(combined, inherited),
+                        ElementMeta {
+                            source_order: Some(*source_order),
+                            ..position.meta()
+                        },
                     ));
-                    push_semantic_block(
-                        &mut parsed,
-                        source_order,
-                        bounds,
-                        SlideBlockContent::Table(legacy_table_to_semantic(&table)),
-                    );
+                    push_semantic_block(&mut parsed, source_order, bounds, block);
                 } else {
                     let fallback_text = (!position.fallback_text.trim().is_empty())
                         .then(|| position.fallback_text.trim().to_string());
@@ -567,26 +825,29 @@ fn parse_semantic_group(
                         bounds,
                         "graphicFrame",
                         fallback_text,
+                        position.graphic_type.clone(),
                     );
                 }
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"pic") => {
                 let combined = parent.then(transform.finish());
-                let (image, position, alt_text) = parse_picture(xml)?;
-                parsed.elements.push(SlideElement::Image(
-                    image.clone(),
-                    position.effective(combined, inherited),
-                ));
-                push_semantic_block(
-                    &mut parsed,
-                    source_order,
-                    position.effective_bounds(combined, inherited),
-                    SlideBlockContent::Image(ImageBlock {
-                        reference: image,
-                        alt_text,
-                        mime_type: None,
-                    }),
-                );
+                let picture = parse_picture(xml, hyperlinks)?;
+                let bounds = picture.position.effective_bounds(combined, inherited);
+                let position = picture.position.effective(combined, inherited);
+                let meta = ElementMeta {
+                    source_order: Some(*source_order),
+                    ..picture.position.meta()
+                };
+                let (element, content) = picture.into_content(&inherited.media, position, meta);
+                parsed.elements.push(element);
+                push_semantic_block(&mut parsed, source_order, bounds, content);
+            }
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"cxnSp") => {
+                let combined = parent.then(transform.finish());
+                let connector = parse_connector(xml, hyperlinks, inherited)?;
+                let position = connector.position.effective(combined, inherited);
+                let meta = connector.position.meta();
+                push_connector(&mut parsed, source_order, connector, position, meta);
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"grpSp") => {
                 merge_parsed(
@@ -604,20 +865,42 @@ fn parse_semantic_group(
                 let name = crate::xml::local(element.name().as_ref()).to_vec();
                 let end = element.name().as_ref().to_vec();
                 skip_element(xml, &end, "PPTX group")?;
-                if !matches!(name.as_slice(), b"nvGrpSpPr") {
-                    push_unsupported(
-                        &mut parsed,
-                        source_order,
-                        Bounds::default(),
-                        &String::from_utf8_lossy(&name),
-                        None,
-                    );
-                }
+                push_unsupported(
+                    &mut parsed,
+                    source_order,
+                    Bounds::default(),
+                    &String::from_utf8_lossy(&name),
+                    None,
+                    None,
+                );
             }
             Event::End(element) if end_is(element.name().as_ref(), b"grpSp") => break,
             Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX group")),
             _ => {}
         }
+        parsed.record_raw_ranges(start..xml.buffer_position() as usize);
+    }
+    if inherited.preserve_groups {
+        let position = parent.apply(ElementPosition {
+            x: transform.off_x,
+            y: transform.off_y,
+            width: transform.extent_x,
+            height: transform.extent_y,
+        });
+        parsed.elements = vec![SlideElement::Group {
+            children: std::mem::take(&mut parsed.elements),
+            position,
+            meta: ElementMeta {
+                id: identity.shape_id.map(|shape_id| ElementId {
+                    slide_number: 0,
+                    shape: ShapeRef::ShapeId(shape_id),
+                    part: 0,
+                }),
+                source_order: Some(first_source_order),
+                placeholder: None,
+            },
+            name: identity.name,
+        }];
     }
     Ok(parsed)
 }
@@ -626,6 +909,7 @@ fn merge_parsed(target: &mut ParsedSlideDocument, mut source: ParsedSlideDocumen
     target.elements.append(&mut source.elements);
     target.blocks.append(&mut source.blocks);
     target.diagnostics.append(&mut source.diagnostics);
+    target.raw_ranges.append(&mut source.raw_ranges);
 }
 
 fn push_semantic_block(
@@ -637,17 +921,79 @@ fn push_semantic_block(
     parsed.blocks.push(SlideBlock {
         bounds,
         source_order: *source_order,
+        raw: None,
         content,
     });
     *source_order += 1;
 }
 
+/// Pushes a connector with a text block for its text. Plain lines have no block, as Markdown
+/// cannot show them, but still take their place in the source order.
+fn push_connector(
+    parsed: &mut ParsedSlideDocument,
+    source_order: &mut usize,
+    connector: ConnectorData,
+    position: ElementPosition,
+    meta: ElementMeta,
+) {
+    let content = connector.content.clone();
+    parsed.elements.push(connector.into_element(
+        position,
+        ElementMeta {
+            source_order: Some(*source_order),
+            ..meta
+        },
+    ));
+    match content {
+        Some(content) => push_semantic_block(
+            parsed,
+            source_order,
+            position.into(),
+            SlideBlockContent::Text(content),
+        ),
+        None => *source_order += 1,
+    }
+}
+
+/// Pushes the picture fill of a shape as an image, returning whether the shape has one.
+fn push_shape_image(
+    parsed: &mut ParsedSlideDocument,
+    source_order: &mut usize,
+    shape: &mut ShapeData,
+    position: ElementPosition,
+    meta: ElementMeta,
+    bounds: Bounds,
+) -> bool {
+    let Some(image) = shape.image.take() else {
+        return false;
+    };
+    let meta = ElementMeta {
+        source_order: Some(*source_order),
+        ..meta
+    };
+    parsed
+        .elements
+        .push(SlideElement::Image(image.clone(), position, meta));
+    push_semantic_block(
+        parsed,
+        source_order,
+        bounds,
+        SlideBlockContent::Image(ImageBlock {
+            reference: image,
+            alt_text: shape.alt_text.take(),
+            mime_type: None,
+        }),
+    );
+    true
+}
+
 fn push_unsupported(
     parsed: &mut ParsedSlideDocument,
     source_order: &mut usize,
     bounds: Bounds,
     kind: &str,
     fallback_text: Option<String>,
+    content_type: Option<String>,
 ) {
     parsed.elements.push(SlideElement::Unknown);
     parsed.diagnostics.push(ParseDiagnostic {
@@ -662,11 +1008,12 @@ fn push_unsupported(
         SlideBlockContent::Unsupported(UnsupportedBlock {
             kind: kind.to_string(),
             fallback_text,
+            content_type,
         }),
     );
 }
 
-fn placeholder_role(placeholder: Option<&PlaceholderKey>) -> TextRole {
+pub(crate) fn placeholder_role(placeholder: Option<&PlaceholderKey>) -> TextRole {
     match placeholder.and_then(|placeholder| placeholder.kind.as_deref()) {
         Some("title") | Some("ctrTitle") => TextRole::Title,
         Some("subTitle") => TextRole::Subtitle,
@@ -691,13 +1038,39 @@ fn apply_inherited_list_styles(
         }
         let level = paragraph.list.as_ref().map(|list| list.level).unwrap_or(0);
         if let Some(kind) = inherited.resolve_list_kind(placeholder, level) {
-            paragraph.list = Some(ListInfo { level, kind });
+            paragraph.list = kind.clone().map(|kind| ListInfo { level, kind });
+        }
+    }
+    if inherited.demote_single_item_lists {
+        demote_single_item_list(content);
+    }
+}
+
+/// Turns a text block whose only non-empty paragraph is a level-0 list item with an inherited
+/// bullet into plain text, as for titles and captions placed in body placeholders.
+fn demote_single_item_list(content: &mut TextBlock) {
+    let mut items = content
+        .paragraphs
+        .iter()
+        .filter(|paragraph| !paragraph.text().trim().is_empty());
+    let single_item = match (items.next(), items.next()) {
+        (Some(paragraph), None) => paragraph
+            .list
+            .as_ref()
+            .is_some_and(|list| list.level == 0 && !paragraph.list_explicit),
+        _ => false,
+    };
+    if single_item {
+        for paragraph in &mut content.paragraphs {
+            paragraph.list = None;
         }
     }
 }
 
-fn legacy_table_to_semantic(table: &TableElement) -> SemanticTable {
+pub(crate) fn legacy_table_to_semantic(table: &TableElement) -> SemanticTable {
     SemanticTable {
+        has_header: table.has_header,
+        column_widths: table.column_widths.clone(),
         rows: table
             .rows
             .iter()
@@ -706,11 +1079,7 @@ fn legacy_table_to_semantic(table: &TableElement) -> SemanticTable {
                     .cells
                     .iter()
                     .map(|cell| SemanticTableCell {
-                        paragraphs: if cell.paragraphs.is_empty() {
-                            vec![Paragraph::plain(cell.runs.clone())]
-                        } else {
-                            cell.paragraphs.clone()
-                        },
+                        paragraphs: cell.paragraphs.clone(),
                         row_span: cell.row_span.max(1),
                         column_span: cell.column_span.max(1),
                         covered: cell.covered,
@@ -732,33 +1101,51 @@ fn parse_shape_tree(
     loop {
         match event(xml, "PPTX slide")? {
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"sp") => {
-                let mut shape = parse_shape(xml, hyperlinks)?;
+                let mut shape = parse_shape(xml, hyperlinks, inherited)?;
                 let position = shape.position.effective(transform, inherited);
-                if let Some(content) = shape.content.as_mut() {
-                    apply_inherited_list_styles(content, &shape.position, inherited);
+                let meta = shape.position.meta();
+                let image = shape.image.take();
+                match (shape.content.as_mut(), image.is_some()) {
+                    (Some(content), _) => {
+                        apply_inherited_list_styles(content, &shape.position, inherited)
+                    }
+                    (None, true) => {}
+                    (None, false) => return Err(Error::ParseError("PPTX shape has no text body")),
+                }
+                if let Some(image) = image {
+                    elements.push(SlideElement::Image(image, position, meta));
+                }
+                if let Some(content) = shape.content {
+                    elements.extend(content_to_elements(content, position, meta));
                 }
-                elements.extend(content_to_elements(
-                    shape
-                        .content
-                        .ok_or(Error::ParseError("PPTX shape has no text body"))?,
-                    position,
-                ));
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"graphicFrame") => {
-                let (table, position) = parse_graphic_frame(xml, hyperlinks)?;
-                if let Some(table) = table {
-                    elements.push(SlideElement::Table(
-                        table,
-                        position.effective(transform, inherited),
-                    ));
+                let (content, position) = parse_graphic_frame(
+                    xml,
+                    hyperlinks,
+                    &inherited.table_styles,
+                    &inherited.charts,
+                )?;
+                if let Some(content) = content {
+                    elements.push(
+                        content.into_element(
+                            position.effective(transform, inherited),
+                            position.meta(),
+                        ),
+                    );
                 }
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"pic") => {
-                let (image, position, _) = parse_picture(xml)?;
-                elements.push(SlideElement::Image(
-                    image,
-                    position.effective(transform, inherited),
-                ));
+                let picture = parse_picture(xml, hyperlinks)?;
+                let position = picture.position.effective(transform, inherited);
+                let meta = picture.position.meta();
+                elements.push(picture.into_content(&inherited.media, position, meta).0);
+            }
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"cxnSp") => {
+                let connector = parse_connector(xml, hyperlinks, inherited)?;
+                let position = connector.position.effective(transform, inherited);
+                let meta = connector.position.meta();
+                elements.push(connector.into_element(position, meta));
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"grpSp") => {
                 elements.extend(parse_group(xml, transform, inherited, hyperlinks)?);
@@ -805,35 +1192,52 @@ fn parse_group(
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"sp") => {
                 let combined = parent.then(transform.finish());
-                let mut shape = parse_shape(xml, hyperlinks)?;
+                let mut shape = parse_shape(xml, hyperlinks, inherited)?;
                 let position = shape.position.effective(combined, inherited);
-                if let Some(content) = shape.content.as_mut() {
-                    apply_inherited_list_styles(content, &shape.position, inherited);
+                let meta = shape.position.meta();
+                let image = shape.image.take();
+                match (shape.content.as_mut(), image.is_some()) {
+                    (Some(content), _) => {
+                        apply_inherited_list_styles(content, &shape.position, inherited)
+                    }
+                    (None, true) => {}
+                    (None, false) => return Err(Error::ParseError("PPTX shape has no text body")),
+                }
+                if let Some(image) = image {
+                    elements.push(SlideElement::Image(image, position, meta));
+                }
+                if let Some(content) = shape.content {
+                    elements.extend(content_to_elements(content, position, meta));
                 }
-                elements.extend(content_to_elements(
-                    shape
-                        .content
-                        .ok_or(Error::ParseError("PPTX shape has no text body"))?,
-                    position,
-                ));
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"graphicFrame") => {
                 let combined = parent.then(transform.finish());
-                let (table, position) = parse_graphic_frame(xml, hyperlinks)?;
-                if let Some(table) = table {
-                    elements.push(SlideElement::Table(
-                        table,
-                        position.effective(combined, inherited),
-                    ));
+                let (content, position) = parse_graphic_frame(
+                    xml,
+                    hyperlinks,
+                    &inherited.table_styles,
+                    &inherited.charts,
+                )?;
+                if let Some(content) = content {
+                    elements.push(
+                        content
+                            .into_element(position.effective(combined, inherited), position.meta()),
+                    );
                 }
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"pic") => {
                 let combined = parent.then(transform.finish());
-                let (image, position, _) = parse_picture(xml)?;
-                elements.push(SlideElement::Image(
-                    image,
-                    position.effective(combined, inherited),
-                ));
+                let picture = parse_picture(xml, hyperlinks)?;
+                let position = picture.position.effective(combined, inherited);
+                let meta = picture.position.meta();
+                elements.push(picture.into_content(&inherited.media, position, meta).0);
+            }
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"cxnSp") => {
+                let combined = parent.then(transform.finish());
+                let connector = parse_connector(xml, hyperlinks, inherited)?;
+                let position = connector.position.effective(combined, inherited);
+                let meta = connector.position.meta();
+                elements.push(connector.into_element(position, meta));
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"grpSp") => {
                 elements.extend(parse_group(
@@ -862,11 +1266,39 @@ fn parse_group(
     Ok(elements)
 }
 
-fn parse_shape(xml: &mut XmlReader<'_>, hyperlinks: &HashMap<String, String>) -> Result<ShapeData> {
+fn parse_shape(
+    xml: &mut XmlReader<'_>,
+    hyperlinks: &HashMap<String, String>,
+    inherited: &InheritedPositions,
+) -> Result<ShapeData> {
     let mut position = PositionData::default();
     let mut content = None;
+    let mut image_id = None;
+    let mut description = None;
+    let mut title = None;
+    let mut in_shape_properties = false;
     loop {
         match event(xml, "PPTX shape")? {
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"spPr") => {
+                in_shape_properties = true;
+            }
+            Event::End(element)
+                if in_shape_properties && end_is(element.name().as_ref(), b"spPr") =>
+            {
+                in_shape_properties = false;
+            }
+            Event::Start(element) | Event::Empty(element)
+                if in_shape_properties && element_is(xml, &element, A_NAMESPACE, b"blip") =>
+            {
+                image_id = attr(&element, b"embed");
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
+            {
+                position.observe_shape_id(&element);
+                description = attr(&element, b"descr");
+                title = attr(&element, b"title");
+            }
             Event::Start(element) | Event::Empty(element)
                 if element_is(xml, &element, A_NAMESPACE, b"off") =>
             {
@@ -883,42 +1315,85 @@ fn parse_shape(xml: &mut XmlReader<'_>, hyperlinks: &HashMap<String, String>) ->
                 position.observe_placeholder(&element);
             }
             Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"txBody") => {
-                content = Some(parse_text_body(xml, true, hyperlinks)?);
+                let styles = inherited.resolve_run_styles(position.placeholder.as_ref());
+                content = Some(parse_text_body_with_styles(xml, hyperlinks, styles)?);
             }
             Event::End(element) if end_is(element.name().as_ref(), b"sp") => break,
             Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX shape")),
             _ => {}
         }
     }
-    Ok(ShapeData { content, position })
+    Ok(ShapeData {
+        content,
+        position,
+        image: image_id.map(|id| ImageReference {
+            id,
+            target: String::new(),
+            dimensions: None,
+            display_size: None,
+            link_target: None,
+            description: description.clone(),
+            title: title.clone(),
+        }),
+        alt_text: description.or(title),
+    })
 }
 
 #[derive(Default)]
-struct ParagraphData {
+pub(crate) struct ParagraphData {
     runs: Vec<Run>,
-    level: u32,
-    list: Option<ListKind>,
-    list_explicit: bool,
+    pub(crate) level: u32,
+    /// Whether `level` comes from an `lvl` attribute rather than the default or inference.
+    level_explicit: bool,
+    /// The `marL` left margin in EMU.
+    margin_left: Option<i64>,
+    /// The `indent` first line indentation in EMU.
+    indent: Option<i64>,
+    pub(crate) list: Option<ListKind>,
+    pub(crate) list_explicit: bool,
     alignment: ParagraphAlignment,
-    default_formatting: Formatting,
+    pub(crate) default_formatting: Formatting,
+    run_defaults: PartialFormatting,
+}
+
+impl ParagraphData {
+    pub(crate) fn resolve_default_formatting(&mut self, styles: &LevelFormatting) {
+        self.default_formatting = Formatting::default();
+        if let Some(style) = styles.get(&self.level) {
+            style.merge_into(&mut self.default_formatting);
+        }
+        self.run_defaults.merge_into(&mut self.default_formatting);
+    }
 }
 
 fn parse_text_body(
     xml: &mut XmlReader<'_>,
-    add_newline: bool,
     hyperlinks: &HashMap<String, String>,
+) -> Result<ParsedContent> {
+    parse_text_body_with_styles(xml, hyperlinks, LevelFormatting::new())
+}
+
+fn parse_text_body_with_styles(
+    xml: &mut XmlReader<'_>,
+    hyperlinks: &HashMap<String, String>,
+    mut styles: LevelFormatting,
 ) -> Result<ParsedContent> {
     let mut paragraphs = Vec::new();
     loop {
         match event(xml, "DrawingML text body")? {
+            Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"lstStyle") => {
+                let list_styles = parse_level_formatting(xml, b"lstStyle")?;
+                merge_level_formatting(&mut styles, &list_styles);
+            }
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"p") => {
-                paragraphs.push(parse_paragraph_events(xml, add_newline, hyperlinks)?);
+                paragraphs.push(parse_paragraph_events(xml, hyperlinks, &styles)?);
             }
             Event::End(element) if end_is(element.name().as_ref(), b"txBody") => break,
             Event::Eof => return Err(Error::ParseError("Unexpected end of DrawingML text body")),
             _ => {}
         }
     }
+    infer_levels_from_margins(&mut paragraphs);
     Ok(TextBlock {
         role: TextRole::Other,
         paragraphs: paragraphs
@@ -931,30 +1406,30 @@ fn parse_text_body(
                     kind,
                 }),
                 list_explicit: paragraph.list_explicit,
+                margin_left: paragraph.margin_left,
+                indent: paragraph.indent,
             })
             .collect(),
+        from_template: false,
     })
 }
 
 fn parse_paragraph_events(
     xml: &mut XmlReader<'_>,
-    add_newline: bool,
     hyperlinks: &HashMap<String, String>,
+    styles: &LevelFormatting,
 ) -> Result<ParagraphData> {
     let mut paragraph = ParagraphData::default();
+    paragraph.resolve_default_formatting(styles);
     loop {
         match event(xml, "DrawingML paragraph")? {
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"pPr") => {
                 parse_paragraph_properties(xml, &element, &mut paragraph)?;
+                paragraph.resolve_default_formatting(styles);
             }
             Event::Empty(element) if element_is(xml, &element, A_NAMESPACE, b"pPr") => {
-                paragraph.level = attr(&element, b"lvl")
-                    .and_then(|value| value.parse().ok())
-                    .unwrap_or(0);
-                if attr(&element, b"lvl").is_some() {
-                    paragraph.list = Some(ListKind::Bullet { character: None });
-                }
-                paragraph.alignment = paragraph_alignment(attr(&element, b"algn").as_deref());
+                read_paragraph_attributes(&element, &mut paragraph);
+                paragraph.resolve_default_formatting(styles);
             }
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"r") => {
                 paragraph.runs.push(parse_run_events_with_base(
@@ -986,35 +1461,81 @@ fn parse_paragraph_events(
             _ => {}
         }
     }
-    if add_newline && let Some(last) = paragraph.runs.last_mut() {
-        last.text.push('\n');
-    }
     Ok(paragraph)
 }
 
-fn parse_paragraph_properties(
-    xml: &mut XmlReader<'_>,
-    start: &BytesStart<'_>,
-    paragraph: &mut ParagraphData,
-) -> Result<()> {
-    paragraph.level = attr(start, b"lvl")
+/// Reads the level, left margin and alignment attributes of an `a:pPr`.
+pub(crate) fn read_paragraph_attributes(start: &BytesStart<'_>, paragraph: &mut ParagraphData) {
+    let level = attr(start, b"lvl");
+    paragraph.level_explicit = level.is_some();
+    paragraph.level = level
+        .as_deref()
         .and_then(|value| value.parse().ok())
         .unwrap_or(0);
-    if attr(start, b"lvl").is_some() {
+    if paragraph.level_explicit {
         paragraph.list = Some(ListKind::Bullet { character: None });
     }
+    paragraph.margin_left = attr(start, b"marL").and_then(|value| value.parse().ok());
+    paragraph.indent = attr(start, b"indent").and_then(|value| value.parse().ok());
     paragraph.alignment = paragraph_alignment(attr(start, b"algn").as_deref());
+}
+
+/// Infers the levels of paragraphs without an `lvl` attribute from their `marL` left margins,
+/// as in decks from older templates that nest bullets only by indentation.
+///
+/// Only text bodies without any `lvl` attribute are inferred, and only paragraphs that are
+/// already list items; a margin alone does not make a paragraph a list item. The distinct
+/// margins of the list items are ranked and the smallest one becomes level 0, so an inferred
+/// level behaves like a written `lvl`. `indent` only moves the first line and is ignored.
+pub(crate) fn infer_levels_from_margins(paragraphs: &mut [ParagraphData]) {
+    if paragraphs.iter().any(|paragraph| paragraph.level_explicit) {
+        return;
+    }
+    let is_candidate =
+        |paragraph: &ParagraphData| paragraph.margin_left.is_some() && paragraph.list.is_some();
+    let mut margins: Vec<i64> = paragraphs
+        .iter()
+        .filter(|paragraph| is_candidate(paragraph))
+        .filter_map(|paragraph| paragraph.margin_left)
+        .collect();
+    margins.sort_unstable();
+    margins.dedup();
+    if margins.len() < 2 {
+        return;
+    }
+    for paragraph in paragraphs
+        .iter_mut()
+        .filter(|paragraph| is_candidate(paragraph))
+    {
+        let Some(margin) = paragraph.margin_left else {
+            continue;
+        };
+        let level = margins
+            .partition_point(|candidate| *candidate < margin)
+            .min(8) as u32;
+        paragraph.level = level;
+    }
+}
+
+fn auto_number_kind(element: &BytesStart<'_>) -> ListKind {
+    ListKind::Ordered {
+        style: attr(element, b"type"),
+        start: attr(element, b"startAt").and_then(|value| value.parse().ok()),
+    }
+}
+
+pub(crate) fn parse_paragraph_properties(
+    xml: &mut XmlReader<'_>,
+    start: &BytesStart<'_>,
+    paragraph: &mut ParagraphData,
+) -> Result<()> {
+    read_paragraph_attributes(start, paragraph);
     loop {
         match event(xml, "DrawingML paragraph properties")? {
             Event::Start(element) | Event::Empty(element)
                 if element_is(xml, &element, A_NAMESPACE, b"buAutoNum") =>
             {
-                paragraph.list = Some(ListKind::Ordered {
-                    style: attr(&element, b"type"),
-                    start: attr(&element, b"startAt")
-                        .and_then(|value| value.parse().ok())
-                        .unwrap_or(1),
-                });
+                paragraph.list = Some(auto_number_kind(&element));
                 paragraph.list_explicit = true;
             }
             Event::Start(element) | Event::Empty(element)
@@ -1034,7 +1555,7 @@ fn parse_paragraph_properties(
             Event::Start(element) | Event::Empty(element)
                 if element_is(xml, &element, A_NAMESPACE, b"defRPr") =>
             {
-                apply_run_attributes(&element, &mut paragraph.default_formatting);
+                paragraph.run_defaults.merge(&run_properties(&element));
             }
             Event::End(element) if end_is(element.name().as_ref(), b"pPr") => break,
             Event::Eof => {
@@ -1061,11 +1582,20 @@ fn parse_run_events_with_base(
 ) -> Result<Run> {
     let mut value = String::new();
     let mut link_id = None;
+    let mut typeface = None;
     loop {
         match event(xml, "DrawingML run")? {
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"rPr") => {
                 apply_run_attributes(&element, &mut formatting);
-                link_id = parse_run_properties(xml, link_id)?;
+                let properties = parse_run_properties(xml, link_id)?;
+                link_id = properties.link_id;
+                typeface = properties.typeface;
+                if typeface.is_some() {
+                    formatting.font.clone_from(&typeface);
+                }
+                if properties.color.is_some() {
+                    formatting.color = properties.color;
+                }
             }
             Event::Empty(element) if element_is(xml, &element, A_NAMESPACE, b"rPr") => {
                 apply_run_attributes(&element, &mut formatting);
@@ -1078,6 +1608,12 @@ fn parse_run_events_with_base(
             _ => {}
         }
     }
+    #[cfg(feature = "symbol-fonts")]
+    if let Some(typeface) = typeface.as_deref() {
+        value = crate::symbol_fonts::map_symbol_text(typeface, &value);
+    }
+    #[cfg(not(feature = "symbol-fonts"))]
+    let _ = typeface;
     Ok(Run {
         text: value,
         formatting,
@@ -1086,45 +1622,184 @@ fn parse_run_events_with_base(
 }
 
 fn apply_run_attributes(element: &BytesStart<'_>, formatting: &mut Formatting) {
-    if let Some(value) = attr(element, b"b") {
-        formatting.bold = value == "1" || value.eq_ignore_ascii_case("true");
-    }
-    if let Some(value) = attr(element, b"i") {
-        formatting.italic = value == "1" || value.eq_ignore_ascii_case("true");
-    }
-    if let Some(value) = attr(element, b"u") {
-        formatting.underlined = value != "none";
-    }
-    if let Some(value) = attr(element, b"strike") {
-        formatting.strikethrough = value != "noStrike" && value != "0" && value != "false";
-    }
-    if let Some(value) = attr(element, b"baseline").and_then(|value| value.parse::<i32>().ok()) {
-        formatting.baseline = if value > 0 {
-            crate::Baseline::Superscript
-        } else if value < 0 {
-            crate::Baseline::Subscript
-        } else {
-            crate::Baseline::Normal
-        };
+    run_properties(element).merge_into(formatting);
+}
+
+/// Reads the formatting attributes of an `a:rPr`, `a:defRPr` or `a:endParaRPr` element.
+pub(crate) fn run_properties(element: &BytesStart<'_>) -> PartialFormatting {
+    PartialFormatting {
+        bold: attr(element, b"b").map(|value| value == "1" || value.eq_ignore_ascii_case("true")),
+        italic: attr(element, b"i").map(|value| value == "1" || value.eq_ignore_ascii_case("true")),
+        underlined: attr(element, b"u").map(|value| value != "none"),
+        strikethrough: attr(element, b"strike")
+            .map(|value| value != "noStrike" && value != "0" && value != "false"),
+        baseline: attr(element, b"baseline")
+            .and_then(|value| value.parse::<i32>().ok())
+            .map(|value| {
+                if value > 0 {
+                    crate::Baseline::Superscript
+                } else if value < 0 {
+                    crate::Baseline::Subscript
+                } else {
+                    crate::Baseline::Normal
+                }
+            }),
+        font_size_points: attr(element, b"sz")
+            .and_then(|value| value.parse::<f32>().ok())
+            .map(|value| value / 100.0),
+        lang: attr(element, b"lang"),
+        font: None,
+        color: None,
     }
-    if let Some(value) = attr(element, b"sz").and_then(|value| value.parse::<f32>().ok()) {
-        formatting.font_size_points = Some(value / 100.0);
+}
+
+/// Parses the `a:defRPr` of every `a:lvlNpPr` in a list or text style.
+pub(crate) fn parse_level_formatting(
+    xml: &mut XmlReader<'_>,
+    end: &[u8],
+) -> Result<LevelFormatting> {
+    Ok(parse_level_styles(xml, end)?.0)
+}
+
+/// Reads the run formatting and bullet properties of the `lvl1pPr` … `lvl9pPr` children of a
+/// list style or master text style.
+fn parse_level_styles(
+    xml: &mut XmlReader<'_>,
+    end: &[u8],
+) -> Result<(LevelFormatting, LevelLists)> {
+    let mut styles = LevelFormatting::new();
+    let mut lists = LevelLists::new();
+    let mut level = None;
+    loop {
+        match event(xml, "DrawingML list style")? {
+            Event::Start(element) | Event::Empty(element)
+                if crate::xml::local(element.name().as_ref()).starts_with(b"lvl") =>
+            {
+                level = style_level(crate::xml::local(element.name().as_ref()));
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"defRPr") =>
+            {
+                if let Some(level) = level {
+                    styles
+                        .entry(level)
+                        .or_default()
+                        .merge(&run_properties(&element));
+                }
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"buAutoNum") =>
+            {
+                if let Some(level) = level {
+                    lists.insert(level, Some(auto_number_kind(&element)));
+                }
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"buChar") =>
+            {
+                if let Some(level) = level {
+                    lists.insert(
+                        level,
+                        Some(ListKind::Bullet {
+                            character: attr(&element, b"char"),
+                        }),
+                    );
+                }
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"buNone") =>
+            {
+                if let Some(level) = level {
+                    lists.insert(level, None);
+                }
+            }
+            Event::End(element) if end_is(element.name().as_ref(), end) => break,
+            Event::Eof => return Err(Error::ParseError("Unexpected end of DrawingML list style")),
+            _ => {}
+        }
     }
-    if let Some(value) = attr(element, b"lang") {
-        formatting.lang = value;
+    Ok((styles, lists))
+}
+
+/// Maps `lvl1pPr` … `lvl9pPr` to the zero-based paragraph level.
+fn style_level(name: &[u8]) -> Option<u32> {
+    std::str::from_utf8(name)
+        .ok()?
+        .strip_prefix("lvl")?
+        .strip_suffix("pPr")?
+        .parse::<u32>()
+        .ok()?
+        .checked_sub(1)
+}
+
+pub(crate) fn merge_level_formatting(styles: &mut LevelFormatting, overrides: &LevelFormatting) {
+    for (level, formatting) in overrides {
+        styles.entry(*level).or_default().merge(formatting);
     }
 }
 
-fn parse_run_properties(
+/// The children of an `a:rPr` whose colors do not apply to the text of the run.
+const NON_TEXT_FILLS: [&[u8]; 10] = [
+    b"ln",
+    b"gradFill",
+    b"blipFill",
+    b"pattFill",
+    b"effectLst",
+    b"effectDag",
+    b"highlight",
+    b"uLn",
+    b"uFill",
+    b"extLst",
+];
+
+/// The children of an `a:rPr` that are not attributes of the run.
+pub(crate) struct RunProperties {
+    pub(crate) link_id: Option<String>,
+    pub(crate) typeface: Option<String>,
+    color: Option<Color>,
+}
+
+/// Reads the children of an `a:rPr`, returning the hyperlink relationship id, the typeface
+/// and the solid fill color of the run. A symbol typeface (`a:sym`) takes precedence over
+/// the Latin one.
+pub(crate) fn parse_run_properties(
     xml: &mut XmlReader<'_>,
-    mut link_id: Option<String>,
-) -> Result<Option<String>> {
+    link_id: Option<String>,
+) -> Result<RunProperties> {
+    let mut properties = RunProperties {
+        link_id,
+        typeface: None,
+        color: None,
+    };
+    let mut latin = None;
+    let mut symbol = None;
     loop {
         match event(xml, "DrawingML run properties")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"latin") =>
+            {
+                latin = attr(&element, b"typeface");
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"sym") =>
+            {
+                symbol = attr(&element, b"typeface");
+            }
             Event::Start(element) | Event::Empty(element)
                 if element_is(xml, &element, A_NAMESPACE, b"hlinkClick") =>
             {
-                link_id = attr(&element, b"id");
+                properties.link_id = attr(&element, b"id");
+            }
+            Event::Start(element)
+                if NON_TEXT_FILLS
+                    .iter()
+                    .any(|name| element_is(xml, &element, A_NAMESPACE, name)) =>
+            {
+                let end = element.name().as_ref().to_vec();
+                skip_element(xml, &end, "DrawingML run properties")?;
+            }
+            Event::Start(element) | Event::Empty(element) if properties.color.is_none() => {
+                properties.color = crate::theme::color(xml, &element);
             }
             Event::End(element) if end_is(element.name().as_ref(), b"rPr") => break,
             Event::Eof => {
@@ -1135,18 +1810,52 @@ fn parse_run_properties(
             _ => {}
         }
     }
-    Ok(link_id)
+    properties.typeface = symbol.or(latin);
+    Ok(properties)
+}
+
+/// The content of a graphic frame the parser understands.
+enum FrameContent {
+    Table(TableElement),
+    Chart(ChartElement),
+}
+
+impl FrameContent {
+    fn block_content(&self) -> SlideBlockContent {
+        match self {
+            FrameContent::Table(table) => SlideBlockContent::Table(legacy_table_to_semantic(table)),
+            FrameContent::Chart(chart) => {
+                SlideBlockContent::Table(legacy_table_to_semantic(&chart.to_table()))
+            }
+        }
+    }
+
+    fn into_element(self, position: ElementPosition, meta: ElementMeta) -> SlideElement {
+        match self {
+            FrameContent::Table(table) => SlideElement::Table(table, position, meta),
+            FrameContent::Chart(chart) => SlideElement::Chart(chart, position, meta),
+        }
+    }
 }
 
+/// Parses a graphic frame holding a table, or a chart whose part is in `charts`.
 fn parse_graphic_frame(
     xml: &mut XmlReader<'_>,
     hyperlinks: &HashMap<String, String>,
-) -> Result<(Option<TableElement>, PositionData)> {
+    table_styles: &TableStyles,
+    charts: &HashMap<String, ChartElement>,
+) -> Result<(Option<FrameContent>, PositionData)> {
     let mut position = PositionData::default();
     let mut in_table_data = false;
     let mut table = None;
+    let mut chart_id = None;
     loop {
         match event(xml, "PPTX graphic frame")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
+            {
+                position.observe_shape_id(&element);
+            }
             Event::Start(element) | Event::Empty(element)
                 if element_is(xml, &element, A_NAMESPACE, b"off") =>
             {
@@ -1157,9 +1866,17 @@ fn parse_graphic_frame(
             {
                 position.observe_ext(&element);
             }
-            Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"graphicData") => {
-                in_table_data = attr(&element, b"uri").as_deref()
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"graphicData") =>
+            {
+                let uri = attr(&element, b"uri");
+                in_table_data = uri.as_deref()
                     == Some("http://schemas.openxmlformats.org/drawingml/2006/table");
+                position.graphic_type = uri
+                    .as_deref()
+                    .and_then(|uri| uri.rsplit('/').next())
+                    .filter(|kind| !kind.is_empty())
+                    .map(str::to_string);
             }
             Event::Start(element)
                 if !in_table_data && element_is(xml, &element, A_NAMESPACE, b"t") =>
@@ -1175,7 +1892,12 @@ fn parse_graphic_frame(
             Event::Start(element)
                 if in_table_data && element_is(xml, &element, A_NAMESPACE, b"tbl") =>
             {
-                table = Some(parse_table_events(xml, hyperlinks)?);
+                table = Some(parse_table_events(xml, hyperlinks, table_styles)?);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, C_NAMESPACE, b"chart") =>
+            {
+                chart_id = attr(&element, b"id");
             }
             Event::End(element) if end_is(element.name().as_ref(), b"graphicData") => {
                 in_table_data = false;
@@ -1185,16 +1907,42 @@ fn parse_graphic_frame(
             _ => {}
         }
     }
-    Ok((table, position))
+    let content = table.map(FrameContent::Table).or_else(|| {
+        chart_id
+            .and_then(|id| charts.get(&id))
+            .map(|chart| FrameContent::Chart(chart.clone()))
+    });
+    Ok((content, position))
 }
 
 fn parse_table_events(
     xml: &mut XmlReader<'_>,
     hyperlinks: &HashMap<String, String>,
+    table_styles: &TableStyles,
 ) -> Result<TableElement> {
     let mut rows = Vec::new();
+    let mut first_row = false;
+    let mut style_id = None;
+    let mut column_widths = Vec::new();
     loop {
         match event(xml, "DrawingML table")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"tblPr") =>
+            {
+                first_row = matches!(attr(&element, b"firstRow").as_deref(), Some("1" | "true"));
+            }
+            Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"tableStyleId") => {
+                style_id = Some(read_simple_text(xml, b"tableStyleId", "DrawingML table")?);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"gridCol") =>
+            {
+                column_widths.push(
+                    attr(&element, b"w")
+                        .and_then(|value| value.parse().ok())
+                        .unwrap_or(0),
+                );
+            }
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"tr") => {
                 rows.push(parse_table_row_events(xml, hyperlinks)?);
             }
@@ -1203,7 +1951,11 @@ fn parse_table_events(
             _ => {}
         }
     }
-    Ok(TableElement { rows })
+    Ok(TableElement {
+        rows,
+        has_header: first_row && table_styles.formats_first_row(style_id.as_deref()),
+        column_widths,
+    })
 }
 
 fn parse_table_row_events(
@@ -1229,14 +1981,11 @@ fn parse_table_cell_events(
     start: &BytesStart<'_>,
     hyperlinks: &HashMap<String, String>,
 ) -> Result<TableCell> {
-    let mut runs = Vec::new();
     let mut paragraphs = Vec::new();
     loop {
         match event(xml, "DrawingML table cell")? {
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"txBody") => {
-                let content = parse_text_body(xml, false, hyperlinks)?;
-                paragraphs = content.paragraphs.clone();
-                runs = content_to_text(content).runs;
+                paragraphs = parse_text_body(xml, hyperlinks)?.paragraphs;
             }
             Event::End(element) if end_is(element.name().as_ref(), b"tc") => break,
             Event::Eof => return Err(Error::ParseError("Unexpected end of DrawingML table cell")),
@@ -1244,7 +1993,6 @@ fn parse_table_cell_events(
         }
     }
     Ok(TableCell {
-        runs,
         paragraphs,
         row_span: attr(start, b"rowSpan")
             .and_then(|value| value.parse().ok())
@@ -1258,68 +2006,55 @@ fn parse_table_cell_events(
 }
 
 fn content_to_text(content: ParsedContent) -> TextElement {
-    TextElement {
-        runs: content
-            .paragraphs
-            .into_iter()
-            .flat_map(|paragraph| paragraph.runs)
-            .collect(),
-    }
+    TextElement::from_paragraphs(content.paragraphs)
 }
 
-fn content_to_elements(content: ParsedContent, position: ElementPosition) -> Vec<SlideElement> {
+fn content_to_elements(
+    content: ParsedContent,
+    position: ElementPosition,
+    meta: ElementMeta,
+) -> Vec<SlideElement> {
     let all_text = content
         .paragraphs
         .iter()
         .all(|paragraph| paragraph.list.is_none());
-    let all_list = content
-        .paragraphs
-        .iter()
-        .all(|paragraph| paragraph.list.is_some());
     if all_text {
-        return vec![SlideElement::Text(content_to_text(content), position)];
-    }
-    if all_list {
-        return vec![SlideElement::List(
-            ListElement {
-                items: content
-                    .paragraphs
-                    .into_iter()
-                    .map(paragraph_to_list_item)
-                    .collect(),
-            },
-            position,
-        )];
+        return vec![SlideElement::Text(content_to_text(content), position, meta)];
     }
-    content
-        .paragraphs
-        .into_iter()
-        .map(|paragraph| {
-            if paragraph.list.is_some() {
-                SlideElement::List(
-                    ListElement {
-                        items: vec![paragraph_to_list_item(paragraph)],
-                    },
-                    position,
-                )
-            } else {
-                SlideElement::Text(
-                    TextElement {
-                        runs: paragraph.runs,
-                    },
-                    position,
-                )
-            }
-        })
-        .collect()
+    // Consecutive paragraphs of the same kind are kept together, so an intro paragraph
+    // followed by bullets yields one text and one list element.
+    let mut elements = Vec::new();
+    for paragraph in content.paragraphs {
+        match (elements.last_mut(), paragraph.list.is_some()) {
+            (Some(SlideElement::List(list, _, _)), true) => {
+                list.items.push(paragraph_to_list_item(paragraph));
+            }
+            (Some(SlideElement::Text(text, _, _)), false) => text.paragraphs.push(paragraph),
+            (_, true) => elements.push(SlideElement::List(
+                ListElement {
+                    items: vec![paragraph_to_list_item(paragraph)],
+                },
+                position,
+                meta,
+            )),
+            (_, false) => elements.push(SlideElement::Text(
+                TextElement::from_paragraphs(vec![paragraph]),
+                position,
+                meta,
+            )),
+        }
+    }
+    elements
 }
 
 fn paragraph_to_list_item(paragraph: Paragraph) -> ListItem {
+    let properties = paragraph.properties();
     let list = paragraph.list.expect("list paragraph");
     ListItem {
         level: list.level,
         is_ordered: matches!(list.kind, ListKind::Ordered { .. }),
         runs: paragraph.runs,
+        properties,
     }
 }
 
@@ -1332,12 +2067,197 @@ fn paragraph_alignment(value: Option<&str>) -> ParagraphAlignment {
     }
 }
 
-fn parse_picture(
+/// A parsed `p:cxnSp`.
+#[derive(Default)]
+struct ConnectorData {
+    position: PositionData,
+    flip_horizontal: bool,
+    flip_vertical: bool,
+    /// The clockwise rotation from the `rot` of its `a:xfrm`, in 60000ths of a degree.
+    rotation: i64,
+    start_shape_id: Option<u32>,
+    end_shape_id: Option<u32>,
+    content: Option<ParsedContent>,
+}
+
+impl ConnectorData {
+    /// Returns the connector as a line between two corners of its effective bounding box,
+    /// from top left to bottom right unless the box is flipped. Like the rendered shape, the
+    /// line is flipped within the box and then rotated around the centre of the box.
+    fn into_element(self, position: ElementPosition, meta: ElementMeta) -> SlideElement {
+        let (left, right) = (position.x, position.x + position.width);
+        let (top, bottom) = (position.y, position.y + position.height);
+        let (start_x, end_x) = if self.flip_horizontal {
+            (right, left)
+        } else {
+            (left, right)
+        };
+        let (start_y, end_y) = if self.flip_vertical {
+            (bottom, top)
+        } else {
+            (top, bottom)
+        };
+        let center = (
+            position.x as f64 + position.width as f64 / 2.0,
+            position.y as f64 + position.height as f64 / 2.0,
+        );
+        let angle = (self.rotation as f64 / 60_000.0).to_radians();
+        let rotate = |(x, y): (i64, i64)| {
+            if self.rotation == 0 {
+                return (x, y);
+            }
+            let (dx, dy) = (x as f64 - center.0, y as f64 - center.1);
+            (
+                (center.0 + dx * angle.cos() - dy * angle.sin()).round() as i64,
+                (center.1 + dx * angle.sin() + dy * angle.cos()).round() as i64,
+            )
+        };
+        SlideElement::Connector(
+            ConnectorElement {
+                start: rotate((start_x, start_y)),
+                end: rotate((end_x, end_y)),
+                start_shape_id: self.start_shape_id,
+                end_shape_id: self.end_shape_id,
+                text: self.content.map(content_to_text),
+            },
+            position,
+            meta,
+        )
+    }
+}
+
+fn parse_connector(
     xml: &mut XmlReader<'_>,
-) -> Result<(ImageReference, PositionData, Option<String>)> {
+    hyperlinks: &HashMap<String, String>,
+    inherited: &InheritedPositions,
+) -> Result<ConnectorData> {
+    let mut connector = ConnectorData::default();
+    let is_set = |value: Option<String>| matches!(value.as_deref(), Some("1" | "true"));
+    let shape_id = |element: &BytesStart<'_>| attr(element, b"id").and_then(|id| id.parse().ok());
+    loop {
+        match event(xml, "PPTX connector")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
+            {
+                connector.position.observe_shape_id(&element);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"stCxn") =>
+            {
+                connector.start_shape_id = shape_id(&element);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"endCxn") =>
+            {
+                connector.end_shape_id = shape_id(&element);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"xfrm") =>
+            {
+                connector.flip_horizontal = is_set(attr(&element, b"flipH"));
+                connector.flip_vertical = is_set(attr(&element, b"flipV"));
+                connector.rotation = attr(&element, b"rot")
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or(0);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"off") =>
+            {
+                connector.position.observe_off(&element);
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"ext") =>
+            {
+                connector.position.observe_ext(&element);
+            }
+            Event::Start(element) if element_is(xml, &element, P_NAMESPACE, b"txBody") => {
+                let styles = inherited.resolve_run_styles(None);
+                connector.content = Some(parse_text_body_with_styles(xml, hyperlinks, styles)?);
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"cxnSp") => break,
+            Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX connector")),
+            _ => {}
+        }
+    }
+    Ok(connector)
+}
+
+/// A parsed `p:pic` with the video or audio clip it plays, if any.
+struct Picture {
+    image: ImageReference,
+    position: PositionData,
+    alt_text: Option<String>,
+    /// The kind and relationship id of an `a:videoFile` or `a:audioFile` link.
+    media_link: Option<(MediaKind, String)>,
+}
+
+impl Picture {
+    /// Returns a [`SlideElement::Media`] with its block if the picture links a clip resolved
+    /// in `media`, or the picture as an image otherwise.
+    fn into_content(
+        self,
+        media: &HashMap<String, MediaReference>,
+        position: ElementPosition,
+        meta: ElementMeta,
+    ) -> (SlideElement, SlideBlockContent) {
+        let clip = self
+            .media_link
+            .and_then(|(kind, id)| media.get(&id).filter(|clip| clip.kind == kind));
+        match clip {
+            Some(clip) => {
+                let clip = MediaReference {
+                    poster: Some(self.image),
+                    ..clip.clone()
+                };
+                let mut content = media_block_content(&clip);
+                if let SlideBlockContent::Image(image) = &mut content {
+                    image.alt_text = self.alt_text;
+                }
+                (SlideElement::Media(clip, position, meta), content)
+            }
+            None => (
+                SlideElement::Image(self.image.clone(), position, meta),
+                SlideBlockContent::Image(ImageBlock {
+                    reference: self.image,
+                    alt_text: self.alt_text,
+                    mime_type: None,
+                }),
+            ),
+        }
+    }
+}
+
+/// Returns the semantic content of a media clip: its poster frame as an image, or an
+/// unsupported block naming the kind of clip if it has none.
+pub(crate) fn media_block_content(media: &MediaReference) -> SlideBlockContent {
+    match &media.poster {
+        Some(poster) => SlideBlockContent::Image(ImageBlock {
+            reference: poster.clone(),
+            alt_text: poster.alt_text().map(str::to_string),
+            mime_type: None,
+        }),
+        None => SlideBlockContent::Unsupported(UnsupportedBlock {
+            kind: "pic".to_string(),
+            fallback_text: None,
+            content_type: Some(
+                match media.kind {
+                    MediaKind::Video => "video",
+                    MediaKind::Audio => "audio",
+                }
+                .to_string(),
+            ),
+        }),
+    }
+}
+
+fn parse_picture(xml: &mut XmlReader<'_>, hyperlinks: &HashMap<String, String>) -> Result<Picture> {
     let mut position = PositionData::default();
     let mut image_id = None;
-    let mut alt_text = None;
+    let mut description = None;
+    let mut title = None;
+    let mut name = None;
+    let mut link_id = None;
+    let mut media_link = None;
     loop {
         match event(xml, "PPTX picture")? {
             Event::Start(element) | Event::Empty(element)
@@ -1358,23 +2278,45 @@ fn parse_picture(
             Event::Start(element) | Event::Empty(element)
                 if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
             {
-                alt_text = attr(&element, b"descr")
-                    .or_else(|| attr(&element, b"title"))
-                    .or_else(|| attr(&element, b"name"));
+                position.observe_shape_id(&element);
+                description = attr(&element, b"descr");
+                title = attr(&element, b"title");
+                name = attr(&element, b"name");
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"hlinkClick") =>
+            {
+                link_id = attr(&element, b"id");
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"videoFile") =>
+            {
+                media_link = attr(&element, b"link").map(|id| (MediaKind::Video, id));
+            }
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, A_NAMESPACE, b"audioFile") =>
+            {
+                media_link = attr(&element, b"link").map(|id| (MediaKind::Audio, id));
             }
             Event::End(element) if end_is(element.name().as_ref(), b"pic") => break,
             Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX picture")),
             _ => {}
         }
     }
-    Ok((
-        ImageReference {
+    Ok(Picture {
+        image: ImageReference {
             id: image_id.ok_or(Error::ImageNotFound)?,
             target: String::new(),
+            dimensions: None,
+            display_size: None,
+            link_target: link_id.and_then(|id| hyperlinks.get(&id).cloned()),
+            description: description.clone(),
+            title: title.clone(),
         },
         position,
-        alt_text,
-    ))
+        alt_text: description.or(title).or(name),
+        media_link,
+    })
 }
 
 #[derive(Default)]
@@ -1410,6 +2352,31 @@ impl GroupTransformData {
     }
 }
 
+/// The `p:cNvPr` of a group shape, see [`SlideElement::Group`].
+#[derive(Default)]
+struct GroupIdentity {
+    shape_id: Option<u32>,
+    name: Option<String>,
+}
+
+fn parse_group_identity(xml: &mut XmlReader<'_>) -> Result<GroupIdentity> {
+    let mut identity = GroupIdentity::default();
+    loop {
+        match event(xml, "PPTX group properties")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(xml, &element, P_NAMESPACE, b"cNvPr") =>
+            {
+                identity.shape_id = attr(&element, b"id").and_then(|value| value.parse().ok());
+                identity.name = attr(&element, b"name").filter(|name| !name.is_empty());
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"nvGrpSpPr") => break,
+            Event::Eof => return Err(Error::ParseError("Unexpected end of PPTX group properties")),
+            _ => {}
+        }
+    }
+    Ok(identity)
+}
+
 fn parse_group_properties(xml: &mut XmlReader<'_>, data: &mut GroupTransformData) -> Result<()> {
     loop {
         match event(xml, "PPTX group properties")? {
@@ -1445,13 +2412,217 @@ fn parse_group_properties(xml: &mut XmlReader<'_>, data: &mut GroupTransformData
     Ok(())
 }
 
+/// Parses only the tables of a slide, skipping shapes, pictures and connectors.
+pub(crate) fn parse_slide_tables(
+    xml_data: &[u8],
+    hyperlinks: &HashMap<String, String>,
+    table_styles: &TableStyles,
+) -> Result<Vec<TableElement>> {
+    let mut xml = reader(xml_data);
+    let mut tables = Vec::new();
+    loop {
+        match event(&mut xml, "PPTX slide tables")? {
+            Event::Start(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"sp")
+                    || element_is(&xml, &element, P_NAMESPACE, b"pic")
+                    || element_is(&xml, &element, P_NAMESPACE, b"cxnSp") =>
+            {
+                let end = element.local_name().as_ref().to_vec();
+                skip_element(&mut xml, &end, "PPTX slide tables")?;
+            }
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"graphicFrame") => {
+                if let (Some(FrameContent::Table(table)), _) =
+                    parse_graphic_frame(&mut xml, hyperlinks, table_styles, &HashMap::new())?
+                {
+                    tables.push(table);
+                }
+            }
+            Event::Eof => return Ok(tables),
+            _ => {}
+        }
+    }
+}
+
+/// A `p:oleObj` in a graphic frame, see [`parse_ole_objects`].
+pub(crate) struct OleObject {
+    pub(crate) relationship_id: String,
+    pub(crate) prog_id: Option<String>,
+    pub(crate) name: Option<String>,
+}
+
+/// Reads the OLE objects (`p:oleObj`) embedded in the graphic frames of a slide. Objects
+/// repeated in the fallback of `mc:AlternateContent` are listed once.
+pub(crate) fn parse_ole_objects(xml_data: &[u8]) -> Result<Vec<OleObject>> {
+    let mut xml = reader(xml_data);
+    let mut objects: Vec<OleObject> = Vec::new();
+    let mut in_ole_data = false;
+    loop {
+        match event(&mut xml, "PPTX OLE objects")? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, A_NAMESPACE, b"graphicData") =>
+            {
+                in_ole_data = attr(&element, b"uri").as_deref()
+                    == Some("http://schemas.openxmlformats.org/presentationml/2006/ole");
+            }
+            Event::Start(element) | Event::Empty(element)
+                if in_ole_data && element_is(&xml, &element, P_NAMESPACE, b"oleObj") =>
+            {
+                if let Some(id) = attr(&element, b"id")
+                    && !objects.iter().any(|known| known.relationship_id == id)
+                {
+                    objects.push(OleObject {
+                        relationship_id: id,
+                        prog_id: attr(&element, b"progId"),
+                        name: attr(&element, b"name"),
+                    });
+                }
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"graphicData") => {
+                in_ole_data = false;
+            }
+            Event::Eof => return Ok(objects),
+            _ => {}
+        }
+    }
+}
+
+/// Reads the `name` of the `p:cSld` element of a slide, layout or master part.
+pub(crate) fn parse_part_name(xml_data: &[u8], part: &str) -> Result<Option<String>> {
+    let mut xml = reader(xml_data);
+    loop {
+        match event(&mut xml, part)? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"cSld") =>
+            {
+                return Ok(attr(&element, b"name").filter(|name| !name.is_empty()));
+            }
+            Event::Eof => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+/// Parses the shapes of a slide layout or master that appear on its slides, i.e. the text and
+/// tables outside of placeholders. Pictures reference the relationships of the layout or
+/// master and are left out together with unsupported shapes.
+pub(crate) fn parse_static_content(
+    xml_data: &[u8],
+    hyperlinks: &HashMap<String, String>,
+) -> Result<ParsedSlideDocument> {
+    let inherited = InheritedPositions {
+        skip_placeholders: true,
+        ..InheritedPositions::default()
+    };
+    let mut parsed = parse_slide_document_with_hyperlinks(xml_data, &inherited, hyperlinks)?;
+    parsed.blocks.retain(|block| match &block.content {
+        SlideBlockContent::Text(text) => text
+            .paragraphs
+            .iter()
+            .any(|paragraph| !paragraph.text().trim().is_empty()),
+        SlideBlockContent::Table(_) => true,
+        SlideBlockContent::Image(_) | SlideBlockContent::Unsupported(_) => false,
+    });
+    parsed.elements.retain(|element| match element {
+        SlideElement::Text(text, _, _) => !text.text().trim().is_empty(),
+        SlideElement::List(..) | SlideElement::Table(..) => true,
+        _ => false,
+    });
+    parsed.diagnostics.clear();
+    Ok(parsed)
+}
+
+/// Reads whether a slide or layout shows the shapes of its layout and master, which
+/// `showMasterSp="0"` on the root element turns off.
+pub(crate) fn parse_shows_master_shapes(xml_data: &[u8], part: &str) -> Result<bool> {
+    let mut xml = reader(xml_data);
+    loop {
+        match event(&mut xml, part)? {
+            Event::Start(element) | Event::Empty(element) => {
+                return Ok(!matches!(
+                    attr(&element, b"showMasterSp").as_deref(),
+                    Some("0" | "false")
+                ));
+            }
+            Event::Eof => return Ok(true),
+            _ => {}
+        }
+    }
+}
+
+/// Reads the `type` of a slide layout, e.g. `title` or `obj`.
+pub(crate) fn parse_layout_type(xml_data: &[u8], part: &str) -> Result<Option<String>> {
+    let mut xml = reader(xml_data);
+    loop {
+        match event(&mut xml, part)? {
+            Event::Start(element) | Event::Empty(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"sldLayout") =>
+            {
+                return Ok(attr(&element, b"type"));
+            }
+            Event::Eof => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+/// Reads only the text of the title placeholder (`title` or `ctrTitle`) of a slide.
+///
+/// Pictures and graphic frames are skipped without being parsed. Paragraphs are joined with a
+/// single space and surrounding whitespace is removed, as in [`crate::Slide::title`].
+pub(crate) fn parse_slide_title(xml_data: &[u8]) -> Result<Option<String>> {
+    let mut xml = reader(xml_data);
+    let mut in_shape = false;
+    let mut is_title = false;
+    let mut title = String::new();
+    loop {
+        match event(&mut xml, "PPTX slide title")? {
+            Event::Start(element)
+                if element_is(&xml, &element, P_NAMESPACE, b"pic")
+                    || element_is(&xml, &element, P_NAMESPACE, b"graphicFrame") =>
+            {
+                let end = element.local_name().as_ref().to_vec();
+                skip_element(&mut xml, &end, "PPTX slide title")?;
+            }
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"sp") => {
+                in_shape = true;
+                is_title = false;
+                title.clear();
+            }
+            Event::Start(element) | Event::Empty(element)
+                if in_shape && element_is(&xml, &element, P_NAMESPACE, b"ph") =>
+            {
+                is_title = matches!(
+                    attr(&element, b"type").as_deref(),
+                    Some("title" | "ctrTitle")
+                );
+            }
+            Event::Start(element) if is_title && element_is(&xml, &element, A_NAMESPACE, b"t") => {
+                title.push_str(&read_simple_text(&mut xml, b"t", "PPTX slide title")?);
+            }
+            Event::End(element) if is_title && end_is(element.name().as_ref(), b"p") => {
+                title.push(' ');
+            }
+            Event::End(element) if in_shape && end_is(element.name().as_ref(), b"sp") => {
+                let normalized = title.split_whitespace().collect::<Vec<_>>().join(" ");
+                if is_title && !normalized.is_empty() {
+                    return Ok(Some(normalized));
+                }
+                in_shape = false;
+                is_title = false;
+            }
+            Event::Eof => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
 fn integer_attr(element: &BytesStart<'_>, name: &[u8]) -> i64 {
     attr(element, name)
         .and_then(|value| value.parse().ok())
         .unwrap_or(0)
 }
 
-fn read_simple_text(xml: &mut XmlReader<'_>, end: &[u8], part: &str) -> Result<String> {
+pub(crate) fn read_simple_text(xml: &mut XmlReader<'_>, end: &[u8], part: &str) -> Result<String> {
     let mut value = String::new();
     loop {
         match event(xml, part)? {
@@ -1472,6 +2643,8 @@ pub fn extract_inherited_positions(
 ) -> Result<InheritedPositions> {
     let mut xml = reader(xml_data);
     let mut in_common_slide = false;
+    let mut found_shape_tree = false;
+    let mut result = inherited_positions.clone();
     loop {
         match event(&mut xml, "PPTX layout or master")? {
             Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"cSld") => {
@@ -1480,26 +2653,31 @@ pub fn extract_inherited_positions(
             Event::Start(element)
                 if in_common_slide && element_is(&xml, &element, P_NAMESPACE, b"spTree") =>
             {
-                let mut positions = inherited_positions.positions.clone();
-                let mut list_styles = inherited_positions.list_styles.clone();
+                found_shape_tree = true;
                 collect_placeholder_positions(
                     &mut xml,
                     b"spTree",
                     CoordinateTransform::identity(),
                     inherited_positions,
-                    &mut positions,
-                    &mut list_styles,
+                    &mut result,
                 )?;
-                return Ok(InheritedPositions {
-                    positions,
-                    list_styles,
-                });
             }
             Event::Empty(element)
                 if in_common_slide && element_is(&xml, &element, P_NAMESPACE, b"spTree") =>
             {
-                return Ok(inherited_positions.clone());
+                found_shape_tree = true;
+            }
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"titleStyle") => {
+                result.master_styles.title = parse_level_formatting(&mut xml, b"titleStyle")?;
+            }
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"bodyStyle") => {
+                (result.master_styles.body, result.master_styles.body_lists) =
+                    parse_level_styles(&mut xml, b"bodyStyle")?;
             }
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"otherStyle") => {
+                result.master_styles.other = parse_level_formatting(&mut xml, b"otherStyle")?;
+            }
+            Event::Eof if found_shape_tree => return Ok(result),
             Event::Eof => return Err(Error::ParseError("PPTX placeholder shape tree not found")),
             _ => {}
         }
@@ -1511,8 +2689,7 @@ fn collect_placeholder_positions(
     end: &[u8],
     transform: CoordinateTransform,
     inherited: &InheritedPositions,
-    positions: &mut HashMap<PlaceholderKey, ElementPosition>,
-    list_styles: &mut HashMap<PlaceholderKey, HashMap<u32, ListKind>>,
+    result: &mut InheritedPositions,
 ) -> Result<()> {
     let mut group_transform = GroupTransformData::default();
     loop {
@@ -1526,8 +2703,7 @@ fn collect_placeholder_positions(
                     b"grpSp",
                     transform.then(group_transform.finish()),
                     inherited,
-                    positions,
-                    list_styles,
+                    result,
                 )?;
             }
             Event::Start(element)
@@ -1536,19 +2712,35 @@ fn collect_placeholder_positions(
                     || element_is(xml, &element, P_NAMESPACE, b"graphicFrame") =>
             {
                 let shape_end = crate::xml::local(element.name().as_ref()).to_vec();
-                let (data, styles) = scan_position(xml, &shape_end)?;
+                let (data, styles, run_styles) =
+                    scan_position(xml, &shape_end, &mut result.template_text)?;
                 if let Some(key) = data.placeholder.as_ref()
                     && let Some(position) = data
                         .raw()
                         .map(|position| transform.apply(position))
                         .or_else(|| inherited.resolve(key))
                 {
-                    insert_placeholder_position(positions, key.clone(), position);
+                    insert_placeholder_position(&mut result.positions, key.clone(), position);
                 }
                 if let Some(key) = data.placeholder.as_ref()
                     && !styles.is_empty()
                 {
-                    insert_placeholder_list_styles(list_styles, key.clone(), styles);
+                    let mut merged = inherited
+                        .placeholder_list_styles(key)
+                        .cloned()
+                        .unwrap_or_default();
+                    merged.extend(styles);
+                    insert_placeholder_list_styles(&mut result.list_styles, key.clone(), merged);
+                }
+                if let Some(key) = data.placeholder.as_ref()
+                    && !run_styles.is_empty()
+                {
+                    let mut merged = inherited
+                        .placeholder_run_styles(key)
+                        .cloned()
+                        .unwrap_or_default();
+                    merge_level_formatting(&mut merged, &run_styles);
+                    insert_placeholder_run_styles(&mut result.run_styles, key.clone(), merged);
                 }
             }
             Event::End(element) if end_is(element.name().as_ref(), end) => break,
@@ -1562,9 +2754,12 @@ fn collect_placeholder_positions(
 fn scan_position(
     xml: &mut XmlReader<'_>,
     end: &[u8],
-) -> Result<(PositionData, HashMap<u32, ListKind>)> {
+    template_text: &mut HashSet<String>,
+) -> Result<(PositionData, LevelLists, LevelFormatting)> {
     let mut data = PositionData::default();
-    let mut list_styles = HashMap::new();
+    let mut list_styles = LevelLists::new();
+    let mut run_styles = LevelFormatting::new();
+    let mut paragraph_text = String::new();
     loop {
         match event(xml, "PPTX placeholder shape")? {
             Event::Start(element) | Event::Empty(element)
@@ -1580,15 +2775,34 @@ fn scan_position(
             Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"pPr") => {
                 let mut paragraph = ParagraphData::default();
                 parse_paragraph_properties(xml, &element, &mut paragraph)?;
-                if let Some(kind) = paragraph.list {
-                    list_styles.insert(paragraph.level, kind);
+                if paragraph.list_explicit {
+                    list_styles.insert(paragraph.level, paragraph.list);
+                } else if let Some(kind) = paragraph.list {
+                    list_styles.entry(paragraph.level).or_insert(Some(kind));
                 }
             }
             Event::Empty(element) if element_is(xml, &element, A_NAMESPACE, b"pPr") => {
                 if let Some(level) = attr(&element, b"lvl").and_then(|value| value.parse().ok()) {
-                    list_styles.insert(level, ListKind::Bullet { character: None });
+                    list_styles
+                        .entry(level)
+                        .or_insert(Some(ListKind::Bullet { character: None }));
                 }
             }
+            Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"lstStyle") => {
+                let lists;
+                (run_styles, lists) = parse_level_styles(xml, b"lstStyle")?;
+                list_styles.extend(lists);
+            }
+            Event::Start(element) if element_is(xml, &element, A_NAMESPACE, b"t") => {
+                paragraph_text.push_str(&read_simple_text(xml, b"t", "PPTX placeholder shape")?);
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"p") => {
+                let text = normalize_template_text(&paragraph_text);
+                if !text.is_empty() {
+                    template_text.insert(text);
+                }
+                paragraph_text.clear();
+            }
             Event::End(element) if end_is(element.name().as_ref(), end) => break,
             Event::Eof => {
                 return Err(Error::ParseError(
@@ -1598,7 +2812,11 @@ fn scan_position(
             _ => {}
         }
     }
-    Ok((data, list_styles))
+    Ok((data, list_styles, run_styles))
+}
+
+fn normalize_template_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
 fn insert_placeholder_position(
@@ -1612,10 +2830,21 @@ fn insert_placeholder_position(
     positions.insert(key, position);
 }
 
+fn insert_placeholder_run_styles(
+    run_styles: &mut HashMap<PlaceholderKey, LevelFormatting>,
+    key: PlaceholderKey,
+    styles: LevelFormatting,
+) {
+    if let Some(idx) = key.idx.as_deref() {
+        run_styles.retain(|candidate, _| candidate.idx.as_deref() != Some(idx));
+    }
+    run_styles.insert(key, styles);
+}
+
 fn insert_placeholder_list_styles(
-    list_styles: &mut HashMap<PlaceholderKey, HashMap<u32, ListKind>>,
+    list_styles: &mut HashMap<PlaceholderKey, LevelLists>,
     key: PlaceholderKey,
-    styles: HashMap<u32, ListKind>,
+    styles: LevelLists,
 ) {
     if let Some(idx) = key.idx.as_deref() {
         list_styles.retain(|candidate, _| candidate.idx.as_deref() != Some(idx));
@@ -1,14 +1,16 @@
-use std::path::PathBuf;
+use crate::{MarkdownOptions, OcrProvider};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 /// Determines how images are handled during content export.
 ///
 /// # Members
 ///
 /// | Member                | Description                                                                                                                       |
-/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|            
-/// | `InMarkdown`          | Images are embedded directly in the Markdown output using standard syntax as `base64` data (`![]()`)                              |            
-/// | `Manually`            | Image handling is delegated to the user, requiring manual copying or referencing (as `base64` encoded string)                     |            
-/// | `Save`                | Images are saved in a provided output directory and referenced using Markdown image syntax with a `file://` URL                  |
+/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|
+/// | `InMarkdown`          | Images are embedded directly in the Markdown output using standard syntax as `base64` data (`![]()`)                              |
+/// | `Manually`            | Image handling is delegated to the user, requiring manual copying or referencing (as `base64` encoded string)                     |
+/// | `Save`                | Images are saved in a provided output directory and referenced using Markdown image syntax with a `file://` URL                   |
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ImageHandlingMode {
     InMarkdown,
@@ -16,24 +18,265 @@ pub enum ImageHandlingMode {
     Save,
 }
 
+/// Determines the file names of images saved with [`ImageHandlingMode::Save`].
+///
+/// # Members
+///
+/// | Member                | Description                                                                                                                       |
+/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|
+/// | `SlideIndex`          | Files are named after the slide number, image index and relationship id (`slide2_image1_rId3.jpg`)                                |
+/// | `ContentHash`         | Files are named after a hash of the written image data (`3f2a9c0d1b4e8a76.jpg`), so identical images share one file               |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageNaming {
+    #[default]
+    SlideIndex,
+    ContentHash,
+}
+
+/// Determines what happens when an individual slide can not be parsed.
+///
+/// # Members
+///
+/// | Member                | Description                                                                                                                       |
+/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|
+/// | `Fail`                | The error of the slide is returned and the conversion stops                                                                       |
+/// | `Skip`                | The slide is left out of the parsed slides                                                                                        |
+/// | `Placeholder`         | An empty slide noting the error takes its place, with the error in its [`crate::Slide::diagnostics`]                              |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OnSlideError {
+    #[default]
+    Fail,
+    Skip,
+    Placeholder,
+}
+
+/// Determines where the `<a id="slide-n"></a>` anchor targeted by slide-jump links is emitted.
+///
+/// # Members
+///
+/// | Member                | Description                                                                                                                       |
+/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|
+/// | `Title`               | The anchor follows the title heading or [`RenderOptionsBuilder::slide_heading`], slides without either have no anchor             |
+/// | `TitleOrStandalone`   | Like `Title`, but slides without a title heading or slide heading start with a standalone anchor line                             |
+/// | `Standalone`          | Every slide starts with a standalone anchor line, so every slide can be linked to                                                 |
+/// | `Off`                 | No anchors are emitted, slide-jump links do not resolve within the document                                                       |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SlideAnchors {
+    #[default]
+    Title,
+    TitleOrStandalone,
+    Standalone,
+    Off,
+}
+
+/// Determines how consecutive paragraphs of a text block are separated in Markdown.
+///
+/// # Members
+///
+/// | Member                | Description                                                                                                                       |
+/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|
+/// | `BlankLine`           | Paragraphs are separated by a blank line and render as separate Markdown paragraphs                                               |
+/// | `Newline`             | Paragraphs are separated by a single line break, which most renderers join into one paragraph                                     |
+/// | `LineBreak`           | Paragraphs end with `<br>` and stay in one Markdown paragraph with visible line breaks; headings use a single line break          |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParagraphSpacing {
+    #[default]
+    BlankLine,
+    Newline,
+    LineBreak,
+}
+
+/// Determines how underlined runs are rendered in Markdown.
+///
+/// Markdown has no underline syntax, and some strict processors strip or escape HTML tags.
+///
+/// # Members
+///
+/// | Member                | Description                                                                                                                       |
+/// |-----------------------|-----------------------------------------------------------------------------------------------------------------------------------|
+/// | `U`                   | Underlined text is wrapped in `<u>…</u>`                                                                                          |
+/// | `Ins`                 | Underlined text is wrapped in `<ins>…</ins>`, which GitHub and other sanitizing renderers keep                                    |
+/// | `Emphasis`            | Underlined text is rendered like italic text with `_…_`                                                                           |
+/// | `Omit`                | Underlining is dropped and the text is rendered unformatted                                                                       |
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnderlineStyle {
+    #[default]
+    U,
+    Ins,
+    Emphasis,
+    Omit,
+}
+
+/// Limits that protect against crafted presentations, see
+/// [`ParseOptionsBuilder::resource_limits`].
+///
+/// Every limit is disabled with `None`. A presentation or slide exceeding a limit fails with
+/// [`crate::Error::LimitExceeded`] naming the offending part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResourceLimits {
+    /// Maximum nesting depth of group shapes (`p:grpSp`) on a slide.
+    pub max_group_depth: Option<usize>,
+    /// Maximum number of shapes, pictures, graphic frames, connectors and groups on a slide.
+    pub max_elements_per_slide: Option<usize>,
+    /// Maximum uncompressed size of a single package part in bytes.
+    pub max_part_size: Option<u64>,
+    /// Maximum uncompressed size of all package parts together in bytes.
+    pub max_total_uncompressed_bytes: Option<u64>,
+    /// Maximum size of the XML of a single slide in bytes.
+    pub max_slide_xml_bytes: Option<u64>,
+    /// Maximum number of XML elements in a single slide.
+    pub max_slide_xml_nodes: Option<usize>,
+}
+
+/// Options that control what a container reads from the presentation package.
+///
+/// Pass them to [`crate::PptxContainer::open_with_options`] or
+/// [`crate::PresentationContainer::open_with_options`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Whether image data is read from the package. Without it, images render as placeholders
+    /// and can not be extracted manually either.
+    pub extract_images: bool,
+    /// Whether the source XML of every PPTX slide block is kept in [`crate::SlideBlock::raw`].
+    pub preserve_raw_xml: bool,
+    /// Upper limit for the image data read from one presentation. Further images are skipped
+    /// with a warning diagnostic and render as placeholders.
+    pub max_total_media_bytes: Option<u64>,
+    /// Limits against deeply nested or enormously expanding packages.
+    pub resource_limits: ResourceLimits,
+    /// Whether invalid UTF-8 in slide XML is replaced with U+FFFD and reported as a warning
+    /// diagnostic instead of failing the slide.
+    pub lossy_utf8: bool,
+    /// What happens when an individual slide can not be parsed.
+    pub on_slide_error: OnSlideError,
+    /// Whether single inherited level-0 list items of placeholders are parsed as plain text.
+    pub demote_single_item_lists: bool,
+    /// Whether group shapes are kept as [`crate::SlideElement::Group`].
+    pub preserve_groups: bool,
+    /// Whether the text and tables placed directly on layouts and masters are added to slides.
+    pub include_layout_content: bool,
+    /// Whether the data of embedded video and audio clips is read from the package.
+    pub extract_media: bool,
+}
+
+impl ParseOptions {
+    pub fn builder() -> ParseOptionsBuilder {
+        ParseOptionsBuilder::default()
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            extract_images: true,
+            preserve_raw_xml: false,
+            max_total_media_bytes: None,
+            resource_limits: ResourceLimits::default(),
+            lossy_utf8: false,
+            on_slide_error: OnSlideError::Fail,
+            demote_single_item_lists: false,
+            preserve_groups: false,
+            include_layout_content: false,
+            extract_media: false,
+        }
+    }
+}
+
+/// Options that control how parsed slides are rendered to Markdown.
+///
+/// Pass them to `convert_to_md_with` of a slide or container to render the same parsed
+/// content in different ways.
+#[derive(Debug, Clone)]
+pub struct RenderOptions {
+    pub markdown: MarkdownOptions,
+    pub compress_images: bool,
+    pub quality: u8,
+    pub image_handling_mode: ImageHandlingMode,
+    pub image_output_path: Option<PathBuf>,
+    pub image_naming: ImageNaming,
+    pub include_presentation_metadata: bool,
+    /// Text recognition run on every rendered image with extracted data.
+    pub ocr_provider: Option<Arc<dyn OcrProvider>>,
+}
+
+impl RenderOptions {
+    pub fn builder() -> RenderOptionsBuilder {
+        RenderOptionsBuilder::default()
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        ParserConfig::default().render_options()
+    }
+}
+
+impl From<ParserConfig> for RenderOptions {
+    fn from(config: ParserConfig) -> Self {
+        config.render_options()
+    }
+}
+
+/// An invalid combination of options rejected by [`ParserConfigBuilder::build_checked`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ConfigError {
+    #[error("Image quality must be between 0 and 100, got {0}")]
+    InvalidQuality(u8),
+
+    #[error("ImageHandlingMode::Save requires an image output path")]
+    MissingImageOutputPath,
+
+    #[error("Image output path is not a writable directory: {}", .0.display())]
+    OutputPathNotWritable(PathBuf),
+
+    #[error("Image handling mode {0:?} has no effect when image extraction is disabled")]
+    ImagesNotExtracted(ImageHandlingMode),
+}
+
 /// Configuration options for the PPTX parser.
 ///
+/// `ParserConfig` combines the [`ParseOptions`] of a container with the [`RenderOptions`]
+/// that every parsed slide keeps in [`crate::Slide::render_options`]. It is kept for
+/// compatibility until the next release; new code should open containers with
+/// [`ParseOptions::builder()`] and render with [`RenderOptions::builder()`], to which
+/// [`ParserConfigBuilder`] forwards every option.
+///
 /// Use [`ParserConfig::builder()`] to create a configuration instance.
 /// This allows you to customize only the desired fields while falling back to sensible defaults for the rest.
 ///
 /// # Configuration Options
 ///
-/// | Parameter                 | Type                  | Default       | Description                                                                                               |
-/// |---------------------------|-----------------------|---------------|-----------------------------------------------------------------------------------------------------------|
-/// | `extract_images`          | `bool`                | `true`        | Whether images are extracted from slides or not. If false, images can not be extracted manually either    |
-/// | `compress_images`         | `bool`                | `true`        | Whether images are compressed before encoding or not. Effects manually extracted images too               |
-/// | `quality`                 | `u8`                  | `80`          | Compression level (0-100);<br/> higher values retain more detail but increase file size                   |
-/// | `image_handling_mode`     | `ImageHandlingMode`   | `InMarkdown`  | Determines how images are handled during content export                                                   |
-/// | `image_output_path`       | `Option<PathBuf>`     | `None`        | Output directory path for `ImageHandlingMode::Save` (mandatory for the saving mode)                       |
-/// | `include_slide_number_as_comment`   | `bool`                | `true`        | Whether the slide number comment is included (`<!-- Slide [n] -->`)                                       |
-/// | `include_speaker_notes`   | `bool`                | `false`       | Whether speaker notes are appended to Markdown as blockquotes                                               |
-/// | `include_comments`        | `bool`                | `false`       | Whether presentation comments are appended to Markdown as blockquotes                                       |
-/// | `include_presentation_metadata` | `bool`          | `true`        | Whether presentation-wide Markdown starts with a metadata comment                                            |
+/// | Parameter                         | Type                           | Default       | Description                                                                                                                                |
+/// |-----------------------------------|--------------------------------|---------------|--------------------------------------------------------------------------------------------------------------------------------------------|
+/// | `extract_images`                  | `bool`                         | `true`        | Whether images are extracted from slides or not. If false, images can not be extracted manually either                                     |
+/// | `compress_images`                 | `bool`                         | `true`        | Whether images are compressed before encoding or not. Effects manually extracted images too                                                |
+/// | `quality`                         | `u8`                           | `80`          | Compression level (0-100);<br/> higher values retain more detail but increase file size                                                    |
+/// | `image_handling_mode`             | `ImageHandlingMode`            | `InMarkdown`  | Determines how images are handled during content export                                                                                    |
+/// | `image_output_path`               | `Option<PathBuf>`              | `None`        | Output directory path for `ImageHandlingMode::Save` (mandatory for the saving mode)                                                        |
+/// | `include_slide_number_as_comment` | `bool`                         | `true`        | Whether the slide number comment is included (`<!-- Slide [n] -->`)                                                                        |
+/// | `include_speaker_notes`           | `bool`                         | `false`       | Whether speaker notes are appended to Markdown as blockquotes                                                                              |
+/// | `include_comments`                | `bool`                         | `false`       | Whether presentation comments are appended to Markdown as blockquotes                                                                      |
+/// | `include_presentation_metadata`   | `bool`                         | `true`        | Whether presentation-wide Markdown starts with a metadata comment                                                                          |
+/// | `exclude_template_text`           | `bool`                         | `false`       | Whether text repeating the slide layout or master (e.g. "Click to edit" prompts) is omitted from Markdown                                  |
+/// | `legacy_list_classification`      | `bool`                         | `false`       | Whether `a:buChar` bullets are rendered as ordered lists like before 1.0 (transitional, removed in the next release)                       |
+/// | `preserve_raw_xml`                | `bool`                         | `false`       | Whether the source XML of every PPTX slide block is kept in `SlideBlock::raw` for debugging                                                |
+/// | `ocr_provider`                    | `Option<Arc<dyn OcrProvider>>` | `None`        | Text recognition run on extracted images; the recognized text is rendered below the image                                                  |
+/// | `image_naming`                    | `ImageNaming`                  | `SlideIndex`  | File naming of images saved with `ImageHandlingMode::Save`; `ContentHash` deduplicates identical images and keeps names stable across runs |
+/// | `max_total_media_bytes`           | `Option<u64>`                  | `None`        | Upper limit for the image data read from one presentation; further images are skipped with a warning and render as placeholders            |
+/// | `resource_limits`                 | `ResourceLimits`               | no limits     | Group nesting, element count, slide XML size and node count, part size and total size limits against crafted files (`ResourceLimits`)      |
+/// | `lossy_utf8`                      | `bool`                         | `false`       | Replaces invalid UTF-8 in slide XML with U+FFFD and records a warning instead of failing the slide                                         |
+/// | `on_slide_error`                  | `OnSlideError`                 | `Fail`        | What happens when an individual slide can not be parsed: fail, skip it or render a placeholder (`OnSlideError`)                            |
+/// | `slide_heading`                   | `Option<String>`               | `None`        | Heading template emitted at the top of every slide, with `{n}` and `{title}` placeholders                                                  |
+/// | `slide_anchors`                   | `SlideAnchors`                 | `Title`       | Where the `<a id="slide-n"></a>` anchor of every slide is emitted (`SlideAnchors`)                                                         |
+/// | `paragraph_spacing`               | `ParagraphSpacing`             | `BlankLine`   | How consecutive paragraphs of a text block are separated (`ParagraphSpacing`)                                                              |
+/// | `continue_list_numbering`         | `bool`                         | `false`       | Whether numbering continues across consecutive ordered lists of different text blocks on a slide instead of restarting at 1                |
+/// | `demote_single_item_lists`        | `bool`                         | `false`       | Whether a placeholder whose only item is an inherited level-0 bullet is parsed as plain text instead of a one-item list                    |
+/// | `underline_style`                 | `UnderlineStyle`               | `U`           | How underlined runs are rendered: as `<u>`, as `<ins>`, as emphasis or not at all (`UnderlineStyle`)                                       |
+/// | `html_emphasis`                   | `bool`                         | `false`       | Whether bold and italic text is rendered as `<strong>` and `<em>` instead of `**` and `_`                                                  |
+/// | `preserve_groups`                 | `bool`                         | `false`       | Whether group shapes are kept as nested `SlideElement::Group` elements instead of being flattened                                          |
+/// | `include_layout_content`          | `bool`                         | `false`       | Whether text and tables placed directly on slide layouts and masters are added to every slide using them                                   |
+/// | `title_heading_level`             | `u8`                           | `2`           | Markdown heading level of title placeholders; other headings are one level deeper                                                          |
+/// | `extract_media`                   | `bool`                         | `false`       | Whether the data of embedded video and audio clips is read into `MediaReference::data`                                                     |
 ///
 /// # Example
 ///
@@ -60,6 +303,26 @@ pub struct ParserConfig {
     pub include_speaker_notes: bool,
     pub include_comments: bool,
     pub include_presentation_metadata: bool,
+    pub exclude_template_text: bool,
+    pub legacy_list_classification: bool,
+    pub preserve_raw_xml: bool,
+    pub ocr_provider: Option<Arc<dyn OcrProvider>>,
+    pub image_naming: ImageNaming,
+    pub max_total_media_bytes: Option<u64>,
+    pub resource_limits: ResourceLimits,
+    pub lossy_utf8: bool,
+    pub on_slide_error: OnSlideError,
+    pub slide_heading: Option<String>,
+    pub slide_anchors: SlideAnchors,
+    pub paragraph_spacing: ParagraphSpacing,
+    pub continue_list_numbering: bool,
+    pub demote_single_item_lists: bool,
+    pub underline_style: UnderlineStyle,
+    pub html_emphasis: bool,
+    pub preserve_groups: bool,
+    pub include_layout_content: bool,
+    pub title_heading_level: u8,
+    pub extract_media: bool,
 }
 
 impl Default for ParserConfig {
@@ -74,6 +337,26 @@ impl Default for ParserConfig {
             include_speaker_notes: false,
             include_comments: false,
             include_presentation_metadata: true,
+            exclude_template_text: false,
+            legacy_list_classification: false,
+            preserve_raw_xml: false,
+            ocr_provider: None,
+            image_naming: ImageNaming::SlideIndex,
+            max_total_media_bytes: None,
+            resource_limits: ResourceLimits::default(),
+            lossy_utf8: false,
+            on_slide_error: OnSlideError::Fail,
+            slide_heading: None,
+            slide_anchors: SlideAnchors::Title,
+            paragraph_spacing: ParagraphSpacing::BlankLine,
+            continue_list_numbering: false,
+            demote_single_item_lists: false,
+            underline_style: UnderlineStyle::U,
+            html_emphasis: false,
+            preserve_groups: false,
+            include_layout_content: false,
+            title_heading_level: 2,
+            extract_media: false,
         }
     }
 }
@@ -82,14 +365,256 @@ impl ParserConfig {
     pub fn builder() -> ParserConfigBuilder {
         ParserConfigBuilder::default()
     }
+
+    /// Combines separate parse and render options into one configuration.
+    ///
+    /// [`MarkdownOptions::reading_order`] and [`MarkdownOptions::render_unsupported_comments`]
+    /// have no equivalent in `ParserConfig` and are not retained.
+    pub fn from_options(parse: ParseOptions, render: RenderOptions) -> Self {
+        Self {
+            extract_images: parse.extract_images,
+            preserve_raw_xml: parse.preserve_raw_xml,
+            max_total_media_bytes: parse.max_total_media_bytes,
+            resource_limits: parse.resource_limits,
+            lossy_utf8: parse.lossy_utf8,
+            on_slide_error: parse.on_slide_error,
+            demote_single_item_lists: parse.demote_single_item_lists,
+            preserve_groups: parse.preserve_groups,
+            include_layout_content: parse.include_layout_content,
+            extract_media: parse.extract_media,
+            compress_images: render.compress_images,
+            quality: render.quality,
+            image_handling_mode: render.image_handling_mode,
+            image_output_path: render.image_output_path,
+            image_naming: render.image_naming,
+            include_slide_number_as_comment: render.markdown.include_slide_number_as_comment,
+            include_speaker_notes: render.markdown.include_speaker_notes,
+            include_comments: render.markdown.include_comments,
+            include_presentation_metadata: render.include_presentation_metadata,
+            ocr_provider: render.ocr_provider,
+            exclude_template_text: render.markdown.exclude_template_text,
+            legacy_list_classification: render.markdown.legacy_list_classification,
+            continue_list_numbering: render.markdown.continue_list_numbering,
+            underline_style: render.markdown.underline_style,
+            html_emphasis: render.markdown.html_emphasis,
+            slide_heading: render.markdown.slide_heading,
+            slide_anchors: render.markdown.slide_anchors,
+            paragraph_spacing: render.markdown.paragraph_spacing,
+            title_heading_level: render.markdown.title_heading_level,
+        }
+    }
+
+    /// Returns the parsing part of this configuration.
+    pub fn parse_options(&self) -> ParseOptions {
+        ParseOptions {
+            extract_images: self.extract_images,
+            preserve_raw_xml: self.preserve_raw_xml,
+            max_total_media_bytes: self.max_total_media_bytes,
+            resource_limits: self.resource_limits,
+            lossy_utf8: self.lossy_utf8,
+            on_slide_error: self.on_slide_error,
+            demote_single_item_lists: self.demote_single_item_lists,
+            preserve_groups: self.preserve_groups,
+            include_layout_content: self.include_layout_content,
+            extract_media: self.extract_media,
+        }
+    }
+
+    /// Returns the rendering part of this configuration.
+    pub fn render_options(&self) -> RenderOptions {
+        RenderOptions {
+            markdown: MarkdownOptions {
+                include_slide_number_as_comment: self.include_slide_number_as_comment,
+                include_speaker_notes: self.include_speaker_notes,
+                include_comments: self.include_comments,
+                exclude_template_text: self.exclude_template_text,
+                legacy_list_classification: self.legacy_list_classification,
+                continue_list_numbering: self.continue_list_numbering,
+                underline_style: self.underline_style,
+                html_emphasis: self.html_emphasis,
+                slide_heading: self.slide_heading.clone(),
+                slide_anchors: self.slide_anchors,
+                paragraph_spacing: self.paragraph_spacing,
+                title_heading_level: self.title_heading_level,
+                ..MarkdownOptions::default()
+            },
+            compress_images: self.compress_images,
+            quality: self.quality,
+            image_handling_mode: self.image_handling_mode.clone(),
+            image_output_path: self.image_output_path.clone(),
+            image_naming: self.image_naming,
+            include_presentation_metadata: self.include_presentation_metadata,
+            ocr_provider: self.ocr_provider.clone(),
+        }
+    }
+
+    /// A text-first configuration for feeding presentations to language models.
+    ///
+    /// Images are not extracted and render as short `[Image unavailable: …]` placeholders
+    /// carrying their alt text, layout and master prompt text is dropped, and speaker notes
+    /// are included because they often hold the actual narrative of a slide.
+    pub fn for_llm() -> Self {
+        Self {
+            extract_images: false,
+            include_speaker_notes: true,
+            exclude_template_text: true,
+            ..Self::default()
+        }
+    }
+
+    /// A configuration that keeps the original image data, embedding every image
+    /// uncompressed in its source format.
+    pub fn lossless() -> Self {
+        Self {
+            extract_images: true,
+            compress_images: false,
+            quality: 100,
+            image_handling_mode: ImageHandlingMode::InMarkdown,
+            ..Self::default()
+        }
+    }
+
+    /// The fastest configuration, which reads no image data and renders only the slide text
+    /// without the presentation metadata header.
+    pub fn fast_text_only() -> Self {
+        Self {
+            extract_images: false,
+            compress_images: false,
+            include_presentation_metadata: false,
+            ..Self::default()
+        }
+    }
 }
 
-/// Builder for [`ParserConfig`].
+/// Builder for [`ParseOptions`].
 ///
-/// Allows setting individual configuration fields while falling back to defaults for any unspecified values
+/// Allows setting individual parse options while falling back to the defaults of
+/// [`ParseOptions`] for any unspecified values.
 #[derive(Debug, Default)]
-pub struct ParserConfigBuilder {
+pub struct ParseOptionsBuilder {
     extract_images: Option<bool>,
+    preserve_raw_xml: Option<bool>,
+    max_total_media_bytes: Option<u64>,
+    resource_limits: Option<ResourceLimits>,
+    lossy_utf8: Option<bool>,
+    on_slide_error: Option<OnSlideError>,
+    demote_single_item_lists: Option<bool>,
+    preserve_groups: Option<bool>,
+    include_layout_content: Option<bool>,
+    extract_media: Option<bool>,
+}
+
+impl ParseOptionsBuilder {
+    /// Sets weather images should be extracted from the slides.
+    pub fn extract_images(mut self, value: bool) -> Self {
+        self.extract_images = Some(value);
+        self
+    }
+
+    /// Sets whether the source XML of every PPTX slide block is retained in
+    /// [`crate::SlideBlock::raw`]. This is intended for debugging and round-trip tooling and
+    /// increases memory usage.
+    pub fn preserve_raw_xml(mut self, value: bool) -> Self {
+        self.preserve_raw_xml = Some(value);
+        self
+    }
+
+    /// Limits the total size of image data read from one presentation.
+    ///
+    /// Once the limit would be exceeded, further images are not read. They render as placeholders
+    /// and a warning is added to the [`crate::Slide::diagnostics`] of their slide.
+    pub fn max_total_media_bytes(mut self, value: u64) -> Self {
+        self.max_total_media_bytes = Some(value);
+        self
+    }
+
+    /// Sets limits against crafted presentations, such as deeply nested groups or parts that
+    /// expand enormously when decompressed.
+    ///
+    /// The package limits are checked when a container is opened, the slide limits before a
+    /// slide is parsed.
+    pub fn resource_limits(mut self, value: ResourceLimits) -> Self {
+        self.resource_limits = Some(value);
+        self
+    }
+
+    /// Decodes slide XML with invalid UTF-8 bytes leniently.
+    ///
+    /// Invalid bytes are replaced with U+FFFD and a warning is added to the
+    /// [`crate::Slide::diagnostics`] of the slide instead of failing it.
+    pub fn lossy_utf8(mut self, value: bool) -> Self {
+        self.lossy_utf8 = Some(value);
+        self
+    }
+
+    /// Sets what happens when an individual slide can not be parsed.
+    ///
+    /// With [`OnSlideError::Skip`] or [`OnSlideError::Placeholder`] one broken slide no longer
+    /// fails the whole presentation.
+    pub fn on_slide_error(mut self, value: OnSlideError) -> Self {
+        self.on_slide_error = Some(value);
+        self
+    }
+
+    /// Sets whether a placeholder holding a single level-0 list item whose bullet is only
+    /// inherited from the layout or master, e.g. a caption in a body placeholder, is parsed as
+    /// plain text instead of a one-item list.
+    ///
+    /// Empty paragraphs are not counted, and paragraphs with an explicit bullet or numbering
+    /// always stay list items.
+    pub fn demote_single_item_lists(mut self, value: bool) -> Self {
+        self.demote_single_item_lists = Some(value);
+        self
+    }
+
+    /// Keeps group shapes (`p:grpSp`) as [`crate::SlideElement::Group`] in
+    /// [`crate::Slide::elements`] instead of flattening their children. The semantic blocks
+    /// and the Markdown output are flattened either way.
+    pub fn preserve_groups(mut self, value: bool) -> Self {
+        self.preserve_groups = Some(value);
+        self
+    }
+
+    /// Adds the text and tables placed directly on the slide layout and master, outside of
+    /// placeholders, to every slide using them, e.g. a standing agenda or legal text. Layouts and
+    /// slides with `showMasterSp="0"` hide the shapes of their master.
+    pub fn include_layout_content(mut self, value: bool) -> Self {
+        self.include_layout_content = Some(value);
+        self
+    }
+
+    /// Reads the data of embedded video and audio clips into [`crate::MediaReference::data`].
+    /// The data counts against [`ParseOptions::max_total_media_bytes`] like image data; clips
+    /// beyond it or failing to load keep no data and are reported as slide diagnostics.
+    pub fn extract_media(mut self, value: bool) -> Self {
+        self.extract_media = Some(value);
+        self
+    }
+
+    /// Builds the final [`ParseOptions`], applying default values for any fields that were
+    /// not set.
+    pub fn build(self) -> ParseOptions {
+        ParseOptions {
+            extract_images: self.extract_images.unwrap_or(true),
+            preserve_raw_xml: self.preserve_raw_xml.unwrap_or(false),
+            max_total_media_bytes: self.max_total_media_bytes,
+            resource_limits: self.resource_limits.unwrap_or_default(),
+            lossy_utf8: self.lossy_utf8.unwrap_or(false),
+            on_slide_error: self.on_slide_error.unwrap_or_default(),
+            demote_single_item_lists: self.demote_single_item_lists.unwrap_or(false),
+            preserve_groups: self.preserve_groups.unwrap_or(false),
+            include_layout_content: self.include_layout_content.unwrap_or(false),
+            extract_media: self.extract_media.unwrap_or(false),
+        }
+    }
+}
+
+/// Builder for [`RenderOptions`].
+///
+/// Allows setting individual render options while falling back to the defaults of
+/// [`RenderOptions`] for any unspecified values.
+#[derive(Debug, Default)]
+pub struct RenderOptionsBuilder {
     compress_images: Option<bool>,
     image_quality: Option<u8>,
     image_handling_mode: Option<ImageHandlingMode>,
@@ -98,15 +623,20 @@ pub struct ParserConfigBuilder {
     include_speaker_notes: Option<bool>,
     include_comments: Option<bool>,
     include_presentation_metadata: Option<bool>,
+    exclude_template_text: Option<bool>,
+    legacy_list_classification: Option<bool>,
+    ocr_provider: Option<Arc<dyn OcrProvider>>,
+    image_naming: Option<ImageNaming>,
+    slide_heading: Option<String>,
+    slide_anchors: Option<SlideAnchors>,
+    paragraph_spacing: Option<ParagraphSpacing>,
+    continue_list_numbering: Option<bool>,
+    underline_style: Option<UnderlineStyle>,
+    html_emphasis: Option<bool>,
+    title_heading_level: Option<u8>,
 }
 
-impl ParserConfigBuilder {
-    /// Sets weather images should be extracted from the slides.
-    pub fn extract_images(mut self, value: bool) -> Self {
-        self.extract_images = Some(value);
-        self
-    }
-
+impl RenderOptionsBuilder {
     /// Sets weather images should be compressed before encoded to base64 or not
     pub fn compress_images(mut self, value: bool) -> Self {
         self.compress_images = Some(value);
@@ -160,24 +690,376 @@ impl ParserConfigBuilder {
         self
     }
 
-    /// Builds the final [`ParserConfig`] instance, applying default values for any fields that were not set.
-    pub fn build(self) -> ParserConfig {
-        ParserConfig {
-            extract_images: self.extract_images.unwrap_or(true),
+    /// Sets whether text repeating the slide layout or master is omitted from Markdown.
+    /// Such text is still parsed and marked via [`crate::TextBlock::from_template`].
+    pub fn exclude_template_text(mut self, value: bool) -> Self {
+        self.exclude_template_text = Some(value);
+        self
+    }
+
+    /// Sets whether paragraphs with an explicit bullet character (`a:buChar`) are rendered as
+    /// ordered lists, restoring the classification of releases before 1.0.
+    ///
+    /// This option only exists to ease migration and will be removed in the next release.
+    pub fn legacy_list_classification(mut self, value: bool) -> Self {
+        self.legacy_list_classification = Some(value);
+        self
+    }
+
+    /// Sets an [`OcrProvider`] whose recognized text is rendered below every image with
+    /// extracted data.
+    pub fn ocr_provider<P: OcrProvider + 'static>(mut self, provider: P) -> Self {
+        self.ocr_provider = Some(Arc::new(provider));
+        self
+    }
+
+    /// Sets how images saved with [`ImageHandlingMode::Save`] are named.
+    ///
+    /// With [`ImageNaming::ContentHash`] identical images are written once and re-running a
+    /// conversion produces the same file names, regardless of slide order.
+    pub fn image_naming(mut self, value: ImageNaming) -> Self {
+        self.image_naming = Some(value);
+        self
+    }
+
+    /// Emits a heading at the top of every slide, rendered from a template such as
+    /// `"## Slide {n}: {title}"`. `{n}` is replaced by the slide number and `{title}` by
+    /// [`Slide::title`](crate::Slide::title). Slides without a title drop `{title}` together
+    /// with the separator before it, and the title block repeated by the heading is not
+    /// rendered again.
+    pub fn slide_heading(mut self, template: impl Into<String>) -> Self {
+        self.slide_heading = Some(template.into());
+        self
+    }
+
+    /// Determines where the anchor targeted by slide-jump links and
+    /// [`RenderOptionsBuilder::slide_heading`] headings is emitted.
+    pub fn slide_anchors(mut self, value: SlideAnchors) -> Self {
+        self.slide_anchors = Some(value);
+        self
+    }
+
+    /// Determines how consecutive paragraphs of a text block are separated: by a blank line,
+    /// a single line break or `<br>`.
+    pub fn paragraph_spacing(mut self, value: ParagraphSpacing) -> Self {
+        self.paragraph_spacing = Some(value);
+        self
+    }
+
+    /// Sets whether ordered lists split across consecutive text blocks of a slide, e.g. two
+    /// placeholders side by side, continue their numbering instead of restarting at 1.
+    ///
+    /// Blocks are consecutive in the reading order of the slide; any other content in between
+    /// restarts the numbering. A list with an explicit start number keeps it.
+    pub fn continue_list_numbering(mut self, value: bool) -> Self {
+        self.continue_list_numbering = Some(value);
+        self
+    }
+
+    /// Determines how underlined runs are rendered: as `<u>`, as `<ins>`, as emphasis for strict
+    /// Markdown processors that strip HTML, or not at all.
+    pub fn underline_style(mut self, value: UnderlineStyle) -> Self {
+        self.underline_style = Some(value);
+        self
+    }
+
+    /// Sets whether bold and italic text is rendered with the HTML tags `<strong>` and `<em>`
+    /// instead of `**` and `_`, which can not be broken by asterisks or underscores in the
+    /// slide text.
+    pub fn html_emphasis(mut self, value: bool) -> Self {
+        self.html_emphasis = Some(value);
+        self
+    }
+
+    /// Sets the Markdown heading level of title placeholders (`title` and `ctrTitle`), e.g. 1
+    /// for `#` headings. Headings below a title use the next level. Values are clamped to 1–5.
+    pub fn title_heading_level(mut self, value: u8) -> Self {
+        self.title_heading_level = Some(value);
+        self
+    }
+
+    /// Builds the final [`RenderOptions`] like [`RenderOptionsBuilder::build`], but first
+    /// rejects an invalid quality and a missing or unwritable image output directory of
+    /// [`ImageHandlingMode::Save`].
+    pub fn build_checked(self) -> Result<RenderOptions, ConfigError> {
+        self.check(None)?;
+        Ok(self.build())
+    }
+
+    /// Rejects the option combinations described at [`ParserConfigBuilder::build_checked`],
+    /// given the explicitly set [`ParseOptionsBuilder::extract_images`] option.
+    fn check(&self, extract_images: Option<bool>) -> Result<(), ConfigError> {
+        if let Some(quality) = self.image_quality.filter(|quality| *quality > 100) {
+            return Err(ConfigError::InvalidQuality(quality));
+        }
+        if extract_images == Some(false)
+            && let Some(mode) = &self.image_handling_mode
+        {
+            return Err(ConfigError::ImagesNotExtracted(mode.clone()));
+        }
+        if self.image_handling_mode == Some(ImageHandlingMode::Save)
+            && extract_images != Some(false)
+        {
+            let path = self
+                .image_output_path
+                .as_deref()
+                .ok_or(ConfigError::MissingImageOutputPath)?;
+            if !is_writable_directory(path) {
+                return Err(ConfigError::OutputPathNotWritable(path.to_path_buf()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds the final [`RenderOptions`], applying default values for any fields that were
+    /// not set.
+    pub fn build(self) -> RenderOptions {
+        RenderOptions {
+            markdown: MarkdownOptions {
+                include_slide_number_as_comment: self
+                    .include_slide_number_as_comment
+                    .unwrap_or(true),
+                include_speaker_notes: self.include_speaker_notes.unwrap_or(false),
+                include_comments: self.include_comments.unwrap_or(false),
+                exclude_template_text: self.exclude_template_text.unwrap_or(false),
+                legacy_list_classification: self.legacy_list_classification.unwrap_or(false),
+                continue_list_numbering: self.continue_list_numbering.unwrap_or(false),
+                underline_style: self.underline_style.unwrap_or(UnderlineStyle::U),
+                html_emphasis: self.html_emphasis.unwrap_or(false),
+                slide_heading: self.slide_heading,
+                slide_anchors: self.slide_anchors.unwrap_or(SlideAnchors::Title),
+                paragraph_spacing: self
+                    .paragraph_spacing
+                    .unwrap_or(ParagraphSpacing::BlankLine),
+                title_heading_level: self.title_heading_level.unwrap_or(2).clamp(1, 5),
+                ..MarkdownOptions::default()
+            },
             compress_images: self.compress_images.unwrap_or(true),
             quality: self.image_quality.unwrap_or(80),
             image_handling_mode: self
                 .image_handling_mode
                 .unwrap_or(ImageHandlingMode::InMarkdown),
             image_output_path: self.image_output_path,
-            include_slide_number_as_comment: self.include_slide_number_as_comment.unwrap_or(true),
-            include_speaker_notes: self.include_speaker_notes.unwrap_or(false),
-            include_comments: self.include_comments.unwrap_or(false),
+            image_naming: self.image_naming.unwrap_or(ImageNaming::SlideIndex),
             include_presentation_metadata: self.include_presentation_metadata.unwrap_or(true),
+            ocr_provider: self.ocr_provider,
         }
     }
 }
 
+/// Builder for [`ParserConfig`].
+///
+/// Forwards every option to a [`ParseOptionsBuilder`] or a [`RenderOptionsBuilder`] and
+/// combines the results with [`ParserConfig::from_options`].
+#[derive(Debug, Default)]
+pub struct ParserConfigBuilder {
+    parse: ParseOptionsBuilder,
+    render: RenderOptionsBuilder,
+}
+
+impl ParserConfigBuilder {
+    /// Same as [`ParseOptionsBuilder::extract_images`].
+    pub fn extract_images(mut self, value: bool) -> Self {
+        self.parse = self.parse.extract_images(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::compress_images`].
+    pub fn compress_images(mut self, value: bool) -> Self {
+        self.render = self.render.compress_images(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::quality`].
+    pub fn quality(mut self, value: u8) -> Self {
+        self.render = self.render.quality(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::image_handling_mode`].
+    pub fn image_handling_mode(mut self, value: ImageHandlingMode) -> Self {
+        self.render = self.render.image_handling_mode(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::image_output_path`].
+    pub fn image_output_path<P>(mut self, path: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.render = self.render.image_output_path(path);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::include_slide_number_as_comment`].
+    pub fn include_slide_number_as_comment(mut self, value: bool) -> Self {
+        self.render = self.render.include_slide_number_as_comment(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::include_speaker_notes`].
+    pub fn include_speaker_notes(mut self, value: bool) -> Self {
+        self.render = self.render.include_speaker_notes(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::include_comments`].
+    pub fn include_comments(mut self, value: bool) -> Self {
+        self.render = self.render.include_comments(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::include_presentation_metadata`].
+    pub fn include_presentation_metadata(mut self, value: bool) -> Self {
+        self.render = self.render.include_presentation_metadata(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::exclude_template_text`].
+    pub fn exclude_template_text(mut self, value: bool) -> Self {
+        self.render = self.render.exclude_template_text(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::legacy_list_classification`].
+    pub fn legacy_list_classification(mut self, value: bool) -> Self {
+        self.render = self.render.legacy_list_classification(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::preserve_raw_xml`].
+    pub fn preserve_raw_xml(mut self, value: bool) -> Self {
+        self.parse = self.parse.preserve_raw_xml(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::ocr_provider`].
+    pub fn ocr_provider<P: OcrProvider + 'static>(mut self, provider: P) -> Self {
+        self.render = self.render.ocr_provider(provider);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::image_naming`].
+    pub fn image_naming(mut self, value: ImageNaming) -> Self {
+        self.render = self.render.image_naming(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::max_total_media_bytes`].
+    pub fn max_total_media_bytes(mut self, value: u64) -> Self {
+        self.parse = self.parse.max_total_media_bytes(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::resource_limits`].
+    pub fn resource_limits(mut self, value: ResourceLimits) -> Self {
+        self.parse = self.parse.resource_limits(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::lossy_utf8`].
+    pub fn lossy_utf8(mut self, value: bool) -> Self {
+        self.parse = self.parse.lossy_utf8(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::on_slide_error`].
+    pub fn on_slide_error(mut self, value: OnSlideError) -> Self {
+        self.parse = self.parse.on_slide_error(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::slide_heading`].
+    pub fn slide_heading(mut self, template: impl Into<String>) -> Self {
+        self.render = self.render.slide_heading(template);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::slide_anchors`].
+    pub fn slide_anchors(mut self, value: SlideAnchors) -> Self {
+        self.render = self.render.slide_anchors(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::paragraph_spacing`].
+    pub fn paragraph_spacing(mut self, value: ParagraphSpacing) -> Self {
+        self.render = self.render.paragraph_spacing(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::continue_list_numbering`].
+    pub fn continue_list_numbering(mut self, value: bool) -> Self {
+        self.render = self.render.continue_list_numbering(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::demote_single_item_lists`].
+    pub fn demote_single_item_lists(mut self, value: bool) -> Self {
+        self.parse = self.parse.demote_single_item_lists(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::underline_style`].
+    pub fn underline_style(mut self, value: UnderlineStyle) -> Self {
+        self.render = self.render.underline_style(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::html_emphasis`].
+    pub fn html_emphasis(mut self, value: bool) -> Self {
+        self.render = self.render.html_emphasis(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::preserve_groups`].
+    pub fn preserve_groups(mut self, value: bool) -> Self {
+        self.parse = self.parse.preserve_groups(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::include_layout_content`].
+    pub fn include_layout_content(mut self, value: bool) -> Self {
+        self.parse = self.parse.include_layout_content(value);
+        self
+    }
+
+    /// Same as [`RenderOptionsBuilder::title_heading_level`].
+    pub fn title_heading_level(mut self, value: u8) -> Self {
+        self.render = self.render.title_heading_level(value);
+        self
+    }
+
+    /// Same as [`ParseOptionsBuilder::extract_media`].
+    pub fn extract_media(mut self, value: bool) -> Self {
+        self.parse = self.parse.extract_media(value);
+        self
+    }
+
+    /// Builds the final [`ParserConfig`] like [`ParserConfigBuilder::build`], but first rejects
+    /// option combinations that would otherwise only fail or be ignored while rendering.
+    ///
+    /// The image output directory does not need to exist yet, but its nearest existing
+    /// ancestor must be a writable directory.
+    pub fn build_checked(self) -> Result<ParserConfig, ConfigError> {
+        self.render.check(self.parse.extract_images)?;
+        Ok(self.build())
+    }
+
+    /// Builds the final [`ParserConfig`] instance, applying default values for any fields
+    /// that were not set.
+    pub fn build(self) -> ParserConfig {
+        ParserConfig::from_options(self.parse.build(), self.render.build())
+    }
+}
+
+fn is_writable_directory(path: &Path) -> bool {
+    path.ancestors()
+        .filter(|ancestor| !ancestor.as_os_str().is_empty())
+        .find_map(|ancestor| std::fs::metadata(ancestor).ok())
+        .or_else(|| std::fs::metadata(".").ok())
+        .is_some_and(|metadata| metadata.is_dir() && !metadata.permissions().readonly())
+}
+
 #[cfg(test)]
 #[path = "../tests/unit/parser_config.rs"]
 mod tests;
@@ -1,6 +1,11 @@
-use crate::container::SlideIterator;
+use crate::container::{OrderedSlideIterator, PrefetchingSlideIterator, SlideIterator};
+use crate::metadata::write_presentation_markdown;
 use crate::odp::{OdpContainer, OdpSlideIterator};
-use crate::{ParserConfig, PptxContainer, Presentation, PresentationMetadata, Result, Slide};
+use crate::{
+    ChartWorkbook, EmbeddedObject, MediaItem, OutlineEntry, ParseOptions, ParserConfig,
+    PptxContainer, Presentation, PresentationMetadata, PresentationSection, RenderOptions, Result,
+    Slide,
+};
 use std::io::Read;
 use std::path::Path;
 
@@ -36,6 +41,15 @@ impl PresentationContainer {
         Self::open_as(path, config, format)
     }
 
+    /// Opens a `.pptx` or `.odp` presentation with only parse options. Slides are rendered
+    /// with [`PresentationContainer::convert_to_md_with`] and explicit [`RenderOptions`].
+    pub fn open_with_options(path: &Path, options: ParseOptions) -> Result<Self> {
+        Self::open(
+            path,
+            ParserConfig::from_options(options, RenderOptions::default()),
+        )
+    }
+
     /// Opens a presentation as the explicitly provided format.
     ///
     /// Use this when the caller already knows the format and wants to skip
@@ -75,13 +89,103 @@ impl PresentationContainer {
             .iter()
             .flat_map(|slide| slide.diagnostics.iter().cloned())
             .collect();
+        let sections = self.sections().to_vec();
         Ok(Presentation {
             metadata,
             slides,
+            sections,
             diagnostics,
         })
     }
 
+    /// Returns the slide numbers, titles and section names of all slides.
+    ///
+    /// Images are not extracted, regardless of [`ParserConfig::extract_images`].
+    pub fn outline(&mut self) -> Result<Vec<OutlineEntry>> {
+        let extract_images = self.config_mut().extract_images;
+        self.config_mut().extract_images = false;
+        let presentation = self.parse_document();
+        self.config_mut().extract_images = extract_images;
+        Ok(presentation?.outline())
+    }
+
+    /// Returns the sections of a PPTX presentation with the numbers of their slides.
+    ///
+    /// See [`PptxContainer::sections`]. ODP presentations return an empty slice.
+    pub fn sections(&self) -> &[PresentationSection] {
+        match &self.inner {
+            ContainerInner::Pptx(container) => container.sections(),
+            ContainerInner::Odp(_) => &[],
+        }
+    }
+
+    /// Returns the width and height of PPTX slides in EMU.
+    ///
+    /// See [`PptxContainer::slide_size`]. ODP presentations return `None`.
+    pub fn slide_size(&self) -> Option<(i64, i64)> {
+        match &self.inner {
+            ContainerInner::Pptx(container) => container.slide_size(),
+            ContainerInner::Odp(_) => None,
+        }
+    }
+
+    /// Extracts the embedded workbooks holding the source data of PPTX charts.
+    ///
+    /// See [`PptxContainer::chart_workbooks`]. ODP presentations return an empty list.
+    pub fn chart_workbooks(&mut self) -> Result<Vec<ChartWorkbook>> {
+        match &mut self.inner {
+            ContainerInner::Pptx(container) => container.chart_workbooks(),
+            ContainerInner::Odp(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Extracts the OLE objects embedded in PPTX slides, such as Excel worksheets.
+    ///
+    /// See [`PptxContainer::embedded_objects`]. ODP presentations return an empty list.
+    pub fn embedded_objects(&mut self) -> Result<Vec<EmbeddedObject>> {
+        match &mut self.inner {
+            ContainerInner::Pptx(container) => container.embedded_objects(),
+            ContainerInner::Odp(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Lists the media parts of a PPTX package with their size, MIME type and slides.
+    ///
+    /// See [`PptxContainer::media_inventory`]. ODP presentations return an empty list.
+    pub fn media_inventory(&mut self) -> Result<Vec<MediaItem>> {
+        match &mut self.inner {
+            ContainerInner::Pptx(container) => container.media_inventory(),
+            ContainerInner::Odp(_) => Ok(Vec::new()),
+        }
+    }
+
+    /// Returns whether a PPTX package is a macro-enabled deck with a VBA project.
+    ///
+    /// See [`PptxContainer::has_vba_project`]. ODP presentations return `false`.
+    pub fn has_vba_project(&self) -> bool {
+        match &self.inner {
+            ContainerInner::Pptx(container) => container.has_vba_project(),
+            ContainerInner::Odp(_) => false,
+        }
+    }
+
+    /// Extracts the binary VBA project of a macro-enabled PPTX package.
+    ///
+    /// See [`PptxContainer::vba_project`]. ODP presentations return `None`.
+    pub fn vba_project(&mut self) -> Result<Option<Vec<u8>>> {
+        match &mut self.inner {
+            ContainerInner::Pptx(container) => container.vba_project(),
+            ContainerInner::Odp(_) => Ok(None),
+        }
+    }
+
+    fn config_mut(&mut self) -> &mut ParserConfig {
+        match &mut self.inner {
+            ContainerInner::Pptx(container) => &mut container.config,
+            ContainerInner::Odp(container) => &mut container.config,
+        }
+    }
+
     pub fn parse_all_multi_threaded(&mut self) -> Result<Vec<Slide>> {
         match &mut self.inner {
             ContainerInner::Pptx(container) => container.parse_all_multi_threaded(),
@@ -105,6 +209,87 @@ impl PresentationContainer {
         }
     }
 
+    /// Parses all slides in parallel and yields them in presentation order as they complete.
+    ///
+    /// See [`PptxContainer::iter_slides_multi_threaded`]. ODP presentations are parsed with
+    /// their normal document parser before the first slide is returned.
+    pub fn iter_slides_multi_threaded(&mut self) -> OrderedSlideIterator {
+        let slides = match &mut self.inner {
+            ContainerInner::Pptx(container) => return container.iter_slides_multi_threaded(),
+            ContainerInner::Odp(container) => container.parse_all(),
+        };
+        let slide_count = slides.as_ref().map_or(1, Vec::len);
+        OrderedSlideIterator::spawn(slide_count, move |sender, _| match slides {
+            Ok(slides) => {
+                for (index, slide) in slides.into_iter().enumerate() {
+                    let _ = sender.send((index, Ok(Some(slide))));
+                }
+            }
+            Err(error) => {
+                let _ = sender.send((0, Err(error)));
+            }
+        })
+    }
+
+    /// Parses and renders all slides in one parallel pipeline.
+    ///
+    /// See [`PptxContainer::convert_all_to_md`]. ODP presentations are converted with their
+    /// normal document parser.
+    pub fn convert_all_to_md(&mut self) -> Result<String> {
+        match &mut self.inner {
+            ContainerInner::Pptx(container) => container.convert_all_to_md(),
+            ContainerInner::Odp(container) => container.convert_to_md(),
+        }
+    }
+
+    /// Writes the Markdown of [`PresentationContainer::convert_to_md`] to `writer`, one
+    /// slide at a time.
+    pub fn render_md_to<W: std::io::Write + ?Sized>(&mut self, writer: &mut W) -> Result<()> {
+        let options = self.config_mut().render_options();
+        self.render_md_with(writer, &options)
+    }
+
+    /// Converts all slides to one Markdown document with explicit render options instead of
+    /// the rendering part of the [`ParserConfig`] the container was opened with.
+    pub fn convert_to_md_with(&mut self, options: &RenderOptions) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.render_md_with(&mut buffer, options)?;
+        String::from_utf8(buffer).map_err(|error| crate::Error::Utf8(error.utf8_error()))
+    }
+
+    /// Writes the Markdown of [`PresentationContainer::convert_to_md_with`] to `writer`, one
+    /// slide at a time.
+    pub fn render_md_with<W: std::io::Write + ?Sized>(
+        &mut self,
+        writer: &mut W,
+        options: &RenderOptions,
+    ) -> Result<()> {
+        let metadata = self.metadata().clone();
+        write_presentation_markdown(&metadata, options, self.iter_slides(), writer)
+    }
+
+    /// Streams every slide as one JSON object per line to `writer`, parsing slides one at a
+    /// time through [`PresentationContainer::iter_slides`].
+    ///
+    /// See [`crate::write_jsonl`] for the layout of the lines. Returns the number of slides
+    /// written.
+    pub fn write_jsonl<W: std::io::Write + ?Sized>(&mut self, writer: &mut W) -> Result<usize> {
+        crate::write_jsonl(self.iter_slides(), writer)
+    }
+
+    /// Moves the container to a background thread that parses slides ahead of the consumer.
+    ///
+    /// See [`PptxContainer::prefetch_slides`].
+    pub fn prefetch_slides(mut self, prefetch: usize) -> PrefetchingSlideIterator {
+        PrefetchingSlideIterator::spawn(prefetch, move |sender| {
+            for slide in self.iter_slides() {
+                if sender.send(slide).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+
     pub fn iter_slides(&mut self) -> PresentationSlideIterator<'_> {
         let inner = match &mut self.inner {
             ContainerInner::Pptx(container) => {
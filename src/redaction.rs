@@ -0,0 +1,287 @@
+use crate::Slide;
+use crate::{
+    Formatting, ImageBlock, ImageReference, Paragraph, Presentation, Run, SlideBlockContent,
+    SlideElement, TextElement,
+};
+use regex::Regex;
+use std::ops::Range;
+
+/// Text that replaces redacted matches unless another placeholder is given.
+pub const REDACTION_PLACEHOLDER: &str = "[REDACTED]";
+
+/// A match removed by [`Slide::redact`] or [`Presentation::redact`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactionHit {
+    pub slide_number: u32,
+    pub location: RedactionLocation,
+    /// Index of the matching pattern in the pattern list.
+    pub pattern: usize,
+    /// The redacted text.
+    pub text: String,
+}
+
+/// Where on a slide a redacted match was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RedactionLocation {
+    /// A paragraph of the text block at `block` in [`Slide::blocks`].
+    Text { block: usize, paragraph: usize },
+    /// A paragraph of a table cell of the table block at `block` in [`Slide::blocks`].
+    TableCell {
+        block: usize,
+        row: usize,
+        column: usize,
+        paragraph: usize,
+    },
+    /// The alternative text of the image block at `block` in [`Slide::blocks`].
+    ImageAltText { block: usize },
+    /// The speaker notes paragraph at the given index, counted across all notes frames.
+    SpeakerNotes { paragraph: usize },
+    /// The alternative text of the picture at `index` in [`Slide::notes_images`].
+    NotesImageAltText { index: usize },
+    /// The comment at the given index.
+    Comment { index: usize },
+}
+
+impl Slide {
+    /// Replaces every match of `patterns` in the slide text, table cells, image alternative
+    /// texts, speaker notes and comments with [`REDACTION_PLACEHOLDER`] and reports each match.
+    ///
+    /// See [`Slide::redact_with`].
+    pub fn redact(&mut self, patterns: &[Regex]) -> Vec<RedactionHit> {
+        self.redact_with(patterns, REDACTION_PLACEHOLDER)
+    }
+
+    /// Replaces every match of `patterns` with `placeholder` and reports each match.
+    ///
+    /// Matches may span several runs of a paragraph; the placeholder takes the formatting of
+    /// the run the match starts in. Patterns are applied in order, so later patterns see the
+    /// text redacted by earlier ones. The legacy [`Slide::elements`] and the descriptions and
+    /// titles of image references are redacted as well, but only matches in [`Slide::blocks`]
+    /// and the alternative texts of image blocks are reported, so no text is counted twice.
+    pub fn redact_with(&mut self, patterns: &[Regex], placeholder: &str) -> Vec<RedactionHit> {
+        let slide_number = self.slide_number;
+        let mut hits = Vec::new();
+        let mut report = |location, matches: Vec<(usize, String)>| {
+            hits.extend(matches.into_iter().map(|(pattern, text)| RedactionHit {
+                slide_number,
+                location,
+                pattern,
+                text,
+            }));
+        };
+
+        for (block_index, block) in self.blocks.iter_mut().enumerate() {
+            match &mut block.content {
+                SlideBlockContent::Text(text) => {
+                    for (paragraph, runs) in paragraph_runs(&mut text.paragraphs) {
+                        let location = RedactionLocation::Text {
+                            block: block_index,
+                            paragraph,
+                        };
+                        report(location, redact_runs(runs, patterns, placeholder));
+                    }
+                }
+                SlideBlockContent::Table(table) => {
+                    for (row_index, row) in table.rows.iter_mut().enumerate() {
+                        for (column_index, cell) in row.cells.iter_mut().enumerate() {
+                            for (paragraph, runs) in paragraph_runs(&mut cell.paragraphs) {
+                                let location = RedactionLocation::TableCell {
+                                    block: block_index,
+                                    row: row_index,
+                                    column: column_index,
+                                    paragraph,
+                                };
+                                report(location, redact_runs(runs, patterns, placeholder));
+                            }
+                        }
+                    }
+                }
+                SlideBlockContent::Image(image) => {
+                    let location = RedactionLocation::ImageAltText { block: block_index };
+                    report(location, redact_image(image, patterns, placeholder));
+                }
+                SlideBlockContent::Unsupported(_) => {}
+            }
+        }
+        let mut offset = 0;
+        for notes in &mut self.speaker_notes {
+            for (index, runs) in paragraph_runs(&mut notes.paragraphs) {
+                let location = RedactionLocation::SpeakerNotes {
+                    paragraph: offset + index,
+                };
+                report(location, redact_runs(runs, patterns, placeholder));
+            }
+            offset += notes.paragraphs.len();
+        }
+        for (index, image) in self.notes_images.iter_mut().enumerate() {
+            let location = RedactionLocation::NotesImageAltText { index };
+            report(location, redact_image(image, patterns, placeholder));
+        }
+        for (index, comment) in self.comments.iter_mut().enumerate() {
+            let location = RedactionLocation::Comment { index };
+            for runs in paragraph_runs(&mut comment.paragraphs).map(|(_, runs)| runs) {
+                report(location, redact_runs(runs, patterns, placeholder));
+            }
+        }
+
+        for runs in self.elements.iter_mut().flat_map(element_runs) {
+            redact_runs(runs, patterns, placeholder);
+        }
+        for element in self.elements.iter_mut().flat_map(SlideElement::leaves_mut) {
+            let reference = match element {
+                SlideElement::Image(reference, _, _) => Some(reference),
+                SlideElement::Media(media, _, _) => media.poster.as_mut(),
+                _ => None,
+            };
+            if let Some(reference) = reference {
+                redact_reference(reference, patterns, placeholder);
+            }
+        }
+        hits
+    }
+}
+
+impl Presentation {
+    /// Redacts every slide with [`REDACTION_PLACEHOLDER`], see [`Slide::redact_with`].
+    pub fn redact(&mut self, patterns: &[Regex]) -> Vec<RedactionHit> {
+        self.redact_with(patterns, REDACTION_PLACEHOLDER)
+    }
+
+    /// Redacts every slide with `placeholder`, see [`Slide::redact_with`].
+    pub fn redact_with(&mut self, patterns: &[Regex], placeholder: &str) -> Vec<RedactionHit> {
+        self.slides
+            .iter_mut()
+            .flat_map(|slide| slide.redact_with(patterns, placeholder))
+            .collect()
+    }
+}
+
+fn paragraph_runs(paragraphs: &mut [Paragraph]) -> impl Iterator<Item = (usize, &mut [Run])> {
+    paragraphs
+        .iter_mut()
+        .enumerate()
+        .map(|(index, paragraph)| (index, paragraph.runs.as_mut_slice()))
+}
+
+fn element_runs(element: &mut SlideElement) -> Vec<&mut [Run]> {
+    match element {
+        SlideElement::Text(TextElement { paragraphs }, _, _) => {
+            paragraph_runs(paragraphs).map(|(_, runs)| runs).collect()
+        }
+        SlideElement::Table(table, _, _) => table
+            .rows
+            .iter_mut()
+            .flat_map(|row| &mut row.cells)
+            .flat_map(|cell| paragraph_runs(&mut cell.paragraphs).map(|(_, runs)| runs))
+            .collect(),
+        SlideElement::List(list, _, _) => list
+            .items
+            .iter_mut()
+            .map(|item| item.runs.as_mut_slice())
+            .collect(),
+        SlideElement::Connector(connector, _, _) => connector
+            .text
+            .iter_mut()
+            .flat_map(|text| paragraph_runs(&mut text.paragraphs).map(|(_, runs)| runs))
+            .collect(),
+        SlideElement::Group { children, .. } => {
+            children.iter_mut().flat_map(element_runs).collect()
+        }
+        SlideElement::Image(..)
+        | SlideElement::Chart(..)
+        | SlideElement::Media(..)
+        | SlideElement::Unknown => Vec::new(),
+    }
+}
+
+/// Redacts the alternative text of an image block and returns its matches. The description and
+/// title of its reference are redacted without being reported, as the alternative text is
+/// taken from them.
+fn redact_image(
+    image: &mut ImageBlock,
+    patterns: &[Regex],
+    placeholder: &str,
+) -> Vec<(usize, String)> {
+    redact_reference(&mut image.reference, patterns, placeholder);
+    redact_text(&mut image.alt_text, patterns, placeholder)
+}
+
+fn redact_reference(reference: &mut ImageReference, patterns: &[Regex], placeholder: &str) {
+    redact_text(&mut reference.description, patterns, placeholder);
+    redact_text(&mut reference.title, patterns, placeholder);
+}
+
+/// Redacts `text` like a paragraph with a single run.
+fn redact_text(
+    text: &mut Option<String>,
+    patterns: &[Regex],
+    placeholder: &str,
+) -> Vec<(usize, String)> {
+    let Some(text) = text else {
+        return Vec::new();
+    };
+    let mut runs = [Run {
+        text: std::mem::take(text),
+        formatting: Formatting::default(),
+        link_target: None,
+    }];
+    let matches = redact_runs(&mut runs, patterns, placeholder);
+    *text = std::mem::take(&mut runs[0].text);
+    matches
+}
+
+/// Redacts the matches of `patterns` in the concatenated text of `runs`.
+///
+/// Returns the pattern index and text of every match.
+pub(crate) fn redact_runs(
+    runs: &mut [Run],
+    patterns: &[Regex],
+    placeholder: &str,
+) -> Vec<(usize, String)> {
+    let mut matches = Vec::new();
+    for (pattern_index, pattern) in patterns.iter().enumerate() {
+        let text: String = runs.iter().map(|run| run.text.as_str()).collect();
+        let ranges: Vec<Range<usize>> = pattern
+            .find_iter(&text)
+            .filter(|found| !found.is_empty())
+            .map(|found| found.range())
+            .collect();
+        for range in ranges.iter().rev() {
+            replace_range(runs, range.clone(), placeholder);
+        }
+        matches.extend(
+            ranges
+                .into_iter()
+                .map(|range| (pattern_index, text[range].to_string())),
+        );
+    }
+    matches
+}
+
+/// Replaces `range` of the concatenated run text with `placeholder`, which is inserted into
+/// the run containing the start of the range.
+fn replace_range(runs: &mut [Run], range: Range<usize>, placeholder: &str) {
+    let mut offset = 0;
+    for run in runs {
+        let run_range = offset..offset + run.text.len();
+        offset = run_range.end;
+        if run_range.end <= range.start {
+            continue;
+        }
+        if run_range.start >= range.end {
+            break;
+        }
+        let start = range.start.saturating_sub(run_range.start);
+        let end = range.end.min(run_range.end) - run_range.start;
+        let replacement = if run_range.contains(&range.start) {
+            placeholder
+        } else {
+            ""
+        };
+        run.text.replace_range(start..end, replacement);
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/redaction.rs"]
+mod tests;
@@ -1,15 +1,23 @@
-use crate::markdown::{MarkdownContext, render_runs};
-use crate::parser_config::ImageHandlingMode;
+use crate::markdown::{
+    MarkdownContext, MarkdownWriter, RunStyle, markdown_link_destination, render_runs_with,
+};
+use crate::parser_config::{ImageHandlingMode, ImageNaming, ParagraphSpacing, SlideAnchors};
+use crate::types::assign_element_ids;
 use crate::{
-    Bounds, ImageBlock, ImageReference, ListInfo, ListKind, MarkdownOptions, Paragraph,
-    ParseDiagnostic, ParserConfig, ReadingOrder, Result, SemanticTable, SemanticTableCell,
-    SemanticTableRow, SlideBlock, SlideBlockContent, SlideElement, TextBlock, TextRole,
-    UnsupportedBlock,
+    Background, Bounds, ContentDominance, ImageBlock, ImageDimensions, ImageReference,
+    LanguageSpan, ListInfo, ListKind, MarkdownOptions, NotesElement, Paragraph, ParseDiagnostic,
+    ParseOptions, ParserConfig, ReadingOrder, RenderOptions, Result, Run, SemanticTable,
+    SlideBlock, SlideBlockContent, SlideElement, SlideParseReport, SlideStats, SlideVisitor,
+    TextBlock, TextRole, ThemeSummary, UnsupportedBlock,
 };
 use base64::{Engine as _, engine::general_purpose};
 use image::codecs::jpeg::JpegEncoder;
-use std::collections::HashMap;
+use rayon::prelude::*;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io::Write;
+use std::ops::Range;
 use std::path::{Path, PathBuf};
 
 /// Encapsulates images for manual extraction of images from slides
@@ -17,12 +25,17 @@ use std::path::{Path, PathBuf};
 pub struct ManualImage {
     pub base64_content: String,
     pub img_ref: ImageReference,
+    /// The token of the image, see [`Slide::image_token`]. The Markdown of the
+    /// [`ImageHandlingMode::Manually`] mode contains it as `<!-- {token} -->` at the position of
+    /// the image.
+    pub token: String,
 }
 impl ManualImage {
-    pub fn new(base64_content: String, img_ref: ImageReference) -> ManualImage {
+    pub fn new(base64_content: String, img_ref: ImageReference, token: String) -> ManualImage {
         Self {
             base64_content,
             img_ref,
+            token,
         }
     }
 }
@@ -41,12 +54,34 @@ pub struct Slide {
     pub slide_number: u32,
     pub elements: Vec<SlideElement>,
     pub speaker_notes: Vec<crate::TextElement>,
+    /// Pictures pasted into the speaker notes of a PPTX slide. Their data is extracted into
+    /// [`Slide::image_data`] like slide images, keyed by the relationship id of the notes part
+    /// prefixed with `notes-`, and the references are part of [`Slide::images`].
+    pub notes_images: Vec<ImageBlock>,
     pub comments: Vec<crate::TextElement>,
     pub images: Vec<ImageReference>,
     pub image_data: HashMap<String, Vec<u8>>,
-    pub config: ParserConfig,
+    /// The options [`Slide::convert_to_md`] renders with, taken from the [`ParserConfig`] of
+    /// the container. Parse options are not kept on the slide.
+    pub render_options: RenderOptions,
     pub blocks: Vec<SlideBlock>,
     pub diagnostics: Vec<ParseDiagnostic>,
+    /// The background fill of the slide, inherited from its layout or master if the slide
+    /// has none. Only set for PPTX slides.
+    pub background: Option<Background>,
+    /// The theme of the slide master. Only set for PPTX slides.
+    pub theme: Option<ThemeSummary>,
+    /// The name of the slide layout, e.g. `Title and Content`. Only set for PPTX slides.
+    pub layout_name: Option<String>,
+    /// The type of the slide layout, e.g. `title` for title slides or `obj` for title and
+    /// content. Only set for PPTX slides whose layout declares a type.
+    pub layout_type: Option<String>,
+    /// The name of the slide master, or the name of its theme if the master has none. Only set
+    /// for PPTX slides.
+    pub master_name: Option<String>,
+    /// The width and height of the slide in EMU, see [`crate::PptxContainer::slide_size`]. Only set
+    /// for PPTX slides.
+    pub slide_size: Option<(i64, i64)>,
 }
 
 impl Slide {
@@ -59,21 +94,32 @@ impl Slide {
         comments: Vec<crate::TextElement>,
         images: Vec<ImageReference>,
         image_data: HashMap<String, Vec<u8>>,
-        config: ParserConfig,
+        render_options: impl Into<RenderOptions>,
     ) -> Self {
+        let mut elements = elements;
+        assign_element_ids(&mut elements, slide_number);
         let blocks = legacy_blocks(&elements);
-        Self {
+        let mut slide = Self {
             rel_path,
             slide_number,
             elements,
             speaker_notes,
+            notes_images: Vec::new(),
             comments,
             images,
             image_data,
-            config,
+            render_options: render_options.into(),
             blocks,
             diagnostics: Vec::new(),
-        }
+            background: None,
+            theme: None,
+            layout_name: None,
+            layout_type: None,
+            master_name: None,
+            slide_size: None,
+        };
+        slide.resolve_image_metadata();
+        slide
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -86,21 +132,78 @@ impl Slide {
         comments: Vec<crate::TextElement>,
         images: Vec<ImageReference>,
         image_data: HashMap<String, Vec<u8>>,
-        config: ParserConfig,
+        render_options: impl Into<RenderOptions>,
         diagnostics: Vec<ParseDiagnostic>,
     ) -> Self {
-        Self {
+        let mut elements = elements;
+        assign_element_ids(&mut elements, slide_number);
+        let mut slide = Self {
             rel_path,
             slide_number,
             elements,
             speaker_notes,
+            notes_images: Vec::new(),
             comments,
             images,
             image_data,
-            config,
+            render_options: render_options.into(),
             blocks,
             diagnostics,
-        }
+            background: None,
+            theme: None,
+            layout_name: None,
+            layout_type: None,
+            master_name: None,
+            slide_size: None,
+        };
+        slide.resolve_image_metadata();
+        slide
+    }
+
+    /// Creates the slide that replaces a slide which could not be parsed with
+    /// [`crate::OnSlideError::Placeholder`].
+    pub(crate) fn placeholder(
+        rel_path: String,
+        slide_number: u32,
+        error: &crate::Error,
+        render_options: RenderOptions,
+    ) -> Self {
+        let message = format!("Slide could not be parsed: {error}");
+        let note = Run {
+            text: message.clone(),
+            formatting: crate::Formatting {
+                italic: true,
+                ..Default::default()
+            },
+            link_target: None,
+        };
+        let block = SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 0,
+            raw: None,
+            content: SlideBlockContent::Text(TextBlock {
+                role: TextRole::Body,
+                paragraphs: vec![Paragraph::plain(vec![note])],
+                from_template: false,
+            }),
+        };
+        let diagnostic = ParseDiagnostic {
+            severity: crate::DiagnosticSeverity::Error,
+            message,
+            source: Some(rel_path.clone()),
+        };
+        Self::new_semantic(
+            rel_path,
+            slide_number,
+            Vec::new(),
+            vec![block],
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            HashMap::new(),
+            render_options,
+            vec![diagnostic],
+        )
     }
 
     /// Converts slide contents into a Markdown formatted string.
@@ -114,155 +217,675 @@ impl Slide {
     /// - `Some(String)`: Markdown representation of slide if conversion succeeds.
     /// - `None`: If a conversion error occurs during image encoding.
     pub fn convert_to_md(&self) -> Result<String> {
-        let options = MarkdownOptions {
-            include_slide_number_as_comment: self.config.include_slide_number_as_comment,
-            include_speaker_notes: self.config.include_speaker_notes,
-            include_comments: self.config.include_comments,
-            ..MarkdownOptions::default()
-        };
-        self.to_markdown(&options)
+        self.to_markdown(&self.markdown_options())
+    }
+
+    /// Writes the Markdown of [`Slide::convert_to_md`] to `writer` as it is rendered.
+    ///
+    /// Inline images are base64-encoded directly into the writer, so the complete slide
+    /// Markdown is never held in memory.
+    pub fn render_md_to<W: Write + ?Sized>(&self, writer: &mut W) -> Result<()> {
+        self.write_markdown(writer, &self.markdown_options())
+    }
+
+    /// Converts the slide to Markdown with explicit render options instead of the options
+    /// the slide was parsed with.
+    pub fn convert_to_md_with(&self, options: &RenderOptions) -> Result<String> {
+        let mut buffer = Vec::new();
+        self.render_md_with(&mut buffer, options)?;
+        String::from_utf8(buffer).map_err(|error| crate::Error::Utf8(error.utf8_error()))
+    }
+
+    fn markdown_options(&self) -> MarkdownOptions {
+        self.render_options.markdown.clone()
+    }
+
+    /// Returns the render options of the slide combined with default parse options.
+    #[deprecated(
+        since = "1.1.0",
+        note = "slides only keep their render options; use `Slide::render_options` instead"
+    )]
+    pub fn config(&self) -> ParserConfig {
+        ParserConfig::from_options(ParseOptions::default(), self.render_options.clone())
     }
 
     pub fn to_markdown(&self, options: &MarkdownOptions) -> Result<String> {
-        let mut slide_txt = String::new();
+        let mut buffer = Vec::new();
+        self.write_markdown(&mut buffer, options)?;
+        String::from_utf8(buffer).map_err(|error| crate::Error::Utf8(error.utf8_error()))
+    }
+
+    /// Writes the Markdown of [`Slide::to_markdown`] to `writer` as it is rendered.
+    pub fn write_markdown<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        options: &MarkdownOptions,
+    ) -> Result<()> {
+        let render_options = RenderOptions {
+            markdown: options.clone(),
+            ..self.render_options.clone()
+        };
+        self.render_md_with(writer, &render_options)
+    }
+
+    /// Writes the Markdown of [`Slide::convert_to_md_with`] to `writer` as it is rendered.
+    pub fn render_md_with<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        render_options: &RenderOptions,
+    ) -> Result<()> {
+        self.render_md_shared(writer, render_options, None)
+    }
+
+    /// Renders the slide like [`Slide::render_md_with`], reusing the images of `shared` that
+    /// were already written or embedded by earlier slides.
+    pub(crate) fn render_md_shared<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+        render_options: &RenderOptions,
+        mut shared: Option<&mut SharedImages>,
+    ) -> Result<()> {
+        let options = &render_options.markdown;
+        let mut slide_txt = MarkdownWriter::new(writer);
         if options.include_slide_number_as_comment {
-            slide_txt.push_str(format!("<!-- Slide {} -->\n\n", self.slide_number).as_str());
+            slide_txt.push_str(&format!("<!-- Slide {} -->\n\n", self.slide_number))?;
+        }
+        let anchor = slide_anchor(self.slide_number);
+        let blocks = self.semantic_blocks();
+        let mut anchored = options.slide_anchors == SlideAnchors::Off;
+        let renders_title = blocks.iter().any(|block| {
+            matches!(
+                &block.content,
+                SlideBlockContent::Text(text) if text.role == TextRole::Title
+                    && !(options.exclude_template_text && text.from_template)
+            )
+        });
+        if options.slide_anchors == SlideAnchors::Standalone
+            || (options.slide_anchors == SlideAnchors::TitleOrStandalone
+                && options.slide_heading.is_none()
+                && !renders_title)
+        {
+            slide_txt.push_str(&format!("<a id=\"{anchor}\"></a>\n\n"))?;
+            anchored = true;
+        }
+        let mut title_in_heading = None;
+        if let Some(template) = &options.slide_heading {
+            let title = self.title();
+            let heading = render_slide_heading(template, self.slide_number, title.as_deref());
+            slide_txt.push_str(&heading)?;
+            if !anchored {
+                slide_txt.push_str(&format!(" <a id=\"{anchor}\"></a>"))?;
+                anchored = true;
+            }
+            slide_txt.push_str("\n\n")?;
+            if template.contains("{title}") {
+                title_in_heading = title;
+            }
         }
         let mut image_count = 0;
-        let fallback_blocks;
-        let blocks = if self.blocks.is_empty() {
-            fallback_blocks = legacy_blocks(&self.elements);
-            &fallback_blocks
-        } else {
-            &self.blocks
-        };
+        let compressed = self.compress_block_images(&blocks, render_options);
+        let mut list_counters = HashMap::new();
 
-        for block in ordered_blocks(blocks, options.reading_order) {
+        for block in ordered_blocks(&blocks, options.reading_order) {
+            if !matches!(block.content, SlideBlockContent::Text(_)) {
+                list_counters.clear();
+            }
             match &block.content {
                 SlideBlockContent::Text(text) => {
-                    render_text_block(&mut slide_txt, text);
+                    if options.exclude_template_text && text.from_template {
+                        continue;
+                    }
+                    if matches!(text.role, TextRole::Title | TextRole::Heading)
+                        && title_in_heading.as_deref() == Some(collapsed_text(text).as_str())
+                    {
+                        title_in_heading = None;
+                        continue;
+                    }
+                    let title_anchor =
+                        (!anchored && text.role == TextRole::Title).then_some(anchor.as_str());
+                    anchored |= title_anchor.is_some();
+                    let mut rendered = String::new();
+                    render_text_block(
+                        &mut rendered,
+                        text,
+                        title_anchor,
+                        options,
+                        &mut list_counters,
+                    );
+                    slide_txt.push_str(&rendered)?;
                     if !slide_txt.ends_with("\n\n") {
-                        slide_txt.push('\n');
+                        slide_txt.push('\n')?;
                     }
                 }
-                SlideBlockContent::Table(table) => render_table(&mut slide_txt, table),
+                SlideBlockContent::Table(table) => {
+                    let mut rendered = String::new();
+                    render_table(&mut rendered, table, RunStyle::from_options(options));
+                    slide_txt.push_str(&rendered)?;
+                }
                 SlideBlockContent::Image(image) => {
-                    let image_ref = &image.reference;
-                    match self.config.image_handling_mode {
-                        ImageHandlingMode::InMarkdown => {
-                            if let Some(image_data) = self.image_data.get(&image_ref.id) {
-                                let image_data = if self.config.compress_images {
-                                    self.compress_image(image_data)
-                                } else {
-                                    Some(image_data.clone())
-                                };
-
-                                let Some(image_data) = image_data else {
-                                    slide_txt.push_str(&missing_image_markdown(image));
-                                    continue;
-                                };
-                                let base64_string = general_purpose::STANDARD.encode(image_data);
-                                let image_name =
-                                    image_ref.target.split('/').next_back().unwrap_or("image");
-                                let file_ext = image
-                                    .mime_type
-                                    .as_deref()
-                                    .and_then(|mime| mime.split('/').next_back())
-                                    .or_else(|| image_name.rsplit('.').next())
-                                    .unwrap_or("bin");
-                                let alt = image.alt_text.as_deref().unwrap_or(image_name);
-
-                                slide_txt.push_str(
-                                    format!(
-                                        "![{}](data:image/{};base64,{})",
-                                        alt, file_ext, base64_string
-                                    )
-                                    .as_str(),
-                                );
-                            } else {
-                                slide_txt.push_str(&missing_image_markdown(image));
-                            }
-                        }
-                        ImageHandlingMode::Save => {
-                            if let Some(image_data) = self.image_data.get(&image_ref.id) {
-                                let image_data = if self.config.compress_images {
-                                    self.compress_image(image_data)
-                                } else {
-                                    Some(image_data.clone())
-                                };
-
-                                let ext = if self.config.compress_images {
-                                    "jpg".to_string()
-                                } else {
-                                    self.get_image_extension(&image_ref.target)
-                                };
-
-                                let output_dir = self
-                                    .config
-                                    .image_output_path
-                                    .clone()
-                                    .unwrap_or_else(|| PathBuf::from("."));
-
-                                fs::create_dir_all(&output_dir)?;
-
-                                let mut image_path = output_dir.clone();
-                                let file_name = format!(
-                                    "slide{}_image{}_{}.{}",
-                                    self.slide_number,
-                                    image_count + 1,
-                                    &image_ref.id,
-                                    ext
-                                );
-                                image_path.push(&file_name);
-
-                                let Some(image_data) = image_data else {
-                                    slide_txt.push_str(&missing_image_markdown(image));
-                                    continue;
-                                };
-                                fs::write(&image_path, image_data)?;
-
-                                let abs_file_url = self.path_to_file_url(&image_path);
-                                let Some(abs_file_url) = abs_file_url else {
-                                    slide_txt.push_str(&missing_image_markdown(image));
-                                    continue;
-                                };
-                                let alt = image.alt_text.as_deref().unwrap_or(&file_name);
-                                let html_link = format!("![{alt}]({abs_file_url})");
-                                image_count += 1;
-                                slide_txt.push_str(&html_link);
-                                slide_txt.push('\n');
-                            } else {
-                                slide_txt.push_str(&missing_image_markdown(image));
-                            }
-                        }
-                        ImageHandlingMode::Manually => {
-                            slide_txt.push('\n');
-                            continue;
-                        }
-                    }
-                    slide_txt.push('\n');
+                    let index = blocks
+                        .iter()
+                        .position(|candidate| std::ptr::eq(candidate, block))
+                        .unwrap_or_default();
+                    self.render_image(
+                        &mut slide_txt,
+                        index,
+                        image,
+                        &compressed,
+                        render_options,
+                        &mut shared,
+                        &mut image_count,
+                    )?;
                 }
                 SlideBlockContent::Unsupported(unsupported) => {
                     if let Some(text) = &unsupported.fallback_text {
-                        slide_txt.push_str(text);
-                        slide_txt.push_str("\n\n");
+                        slide_txt.push_str(text)?;
+                        slide_txt.push_str("\n\n")?;
                     }
                     if options.render_unsupported_comments {
                         slide_txt.push_str(&format!(
                             "<!-- Unsupported slide element: {} -->\n\n",
                             unsupported.kind.replace("--", "—")
-                        ));
+                        ))?;
                     }
                 }
             }
         }
-        if options.include_speaker_notes && !self.speaker_notes.is_empty() {
-            append_quoted_section(&mut slide_txt, "Speaker Notes", &self.speaker_notes);
+        if options.include_speaker_notes
+            && (!self.speaker_notes.is_empty() || !self.notes_images.is_empty())
+        {
+            append_quoted_section(
+                &mut slide_txt,
+                "Speaker Notes",
+                &self.speaker_notes,
+                RunStyle::from_options(options),
+            )?;
+            for (index, image) in self.notes_images.iter().enumerate() {
+                let mut rendered = Vec::new();
+                self.render_image(
+                    &mut MarkdownWriter::new(&mut rendered),
+                    blocks.len() + index,
+                    image,
+                    &compressed,
+                    render_options,
+                    &mut shared,
+                    &mut image_count,
+                )?;
+                if index > 0 || !self.speaker_notes.is_empty() {
+                    slide_txt.push_str(">\n")?;
+                }
+                for line in String::from_utf8_lossy(&rendered).lines() {
+                    if !line.is_empty() {
+                        slide_txt.push_str("> ")?;
+                        slide_txt.push_str(line)?;
+                        slide_txt.push('\n')?;
+                    }
+                }
+            }
         }
         if options.include_comments && !self.comments.is_empty() {
-            append_quoted_section(&mut slide_txt, "Comments", &self.comments);
+            append_quoted_section(
+                &mut slide_txt,
+                "Comments",
+                &self.comments,
+                RunStyle::from_options(options),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes an image block in the [`ImageHandlingMode`] of `render_options`.
+    #[allow(clippy::too_many_arguments)]
+    fn render_image<W: Write + ?Sized>(
+        &self,
+        slide_txt: &mut MarkdownWriter<'_, W>,
+        index: usize,
+        image: &ImageBlock,
+        compressed: &HashMap<&str, Option<Vec<u8>>>,
+        render_options: &RenderOptions,
+        shared: &mut Option<&mut SharedImages>,
+        image_count: &mut usize,
+    ) -> Result<()> {
+        let image_ref = &image.reference;
+        let image_text = self.recognize_image_text(image, render_options);
+        match render_options.image_handling_mode {
+            ImageHandlingMode::InMarkdown => {
+                if let Some(image_data) = self.image_data.get(&image_ref.id) {
+                    let image_data = if render_options.compress_images {
+                        compressed.get(image_ref.id.as_str()).cloned().flatten()
+                    } else {
+                        Some(image_data.clone())
+                    };
+
+                    let Some(image_data) = image_data else {
+                        slide_txt.push_str(&self.missing_image_markdown(image))?;
+                        return Ok(());
+                    };
+                    let image_name = image_ref.target.split('/').next_back().unwrap_or("image");
+                    let file_ext = image
+                        .mime_type
+                        .as_deref()
+                        .and_then(|mime| mime.split('/').next_back())
+                        .or_else(|| image_name.rsplit('.').next())
+                        .unwrap_or("bin");
+                    let alt = image.alt_text.as_deref().unwrap_or(image_name);
+
+                    if let Some(shared) = shared.as_deref_mut() {
+                        let label = shared.inline_label(&image_data, || {
+                            format!(
+                                "data:image/{file_ext};base64,{}",
+                                general_purpose::STANDARD.encode(&image_data)
+                            )
+                        });
+                        push_image_link_start(slide_txt, image_ref)?;
+                        slide_txt.push_str(&format!("![{alt}][{label}]"))?;
+                        push_image_link_end(slide_txt, image_ref)?;
+                        push_image_text(slide_txt, image_text.as_deref())?;
+                        slide_txt.push('\n')?;
+                        return Ok(());
+                    }
+                    push_image_link_start(slide_txt, image_ref)?;
+                    slide_txt.push_str(&format!("![{alt}](data:image/{file_ext};base64,"))?;
+                    write_base64(slide_txt, &image_data)?;
+                    slide_txt.push(')')?;
+                    push_image_link_end(slide_txt, image_ref)?;
+                    push_image_text(slide_txt, image_text.as_deref())?;
+                } else {
+                    slide_txt.push_str(&self.missing_image_markdown(image))?;
+                }
+            }
+            ImageHandlingMode::Save => {
+                if let Some(image_data) = self.image_data.get(&image_ref.id) {
+                    let image_data = if render_options.compress_images {
+                        compressed.get(image_ref.id.as_str()).cloned().flatten()
+                    } else {
+                        Some(image_data.clone())
+                    };
+
+                    let ext = if render_options.compress_images {
+                        "jpg".to_string()
+                    } else {
+                        self.get_image_extension(&image_ref.target)
+                    };
+
+                    let output_dir = render_options
+                        .image_output_path
+                        .clone()
+                        .unwrap_or_else(|| PathBuf::from("."));
+
+                    fs::create_dir_all(&output_dir)?;
+
+                    let Some(image_data) = image_data else {
+                        slide_txt.push_str(&self.missing_image_markdown(image))?;
+                        return Ok(());
+                    };
+                    let hash = content_hash(&image_data);
+                    let file_name = match render_options.image_naming {
+                        ImageNaming::SlideIndex => format!(
+                            "slide{}_image{}_{}.{}",
+                            self.slide_number,
+                            *image_count + 1,
+                            &image_ref.id,
+                            ext
+                        ),
+                        ImageNaming::ContentHash => format!("{hash:016x}.{ext}"),
+                    };
+                    let image_path = output_dir.join(&file_name);
+                    if let Some(url) = shared.as_deref().and_then(|shared| shared.saved.get(&hash))
+                    {
+                        let alt = image.alt_text.as_deref().unwrap_or(&file_name);
+                        push_image_link_start(slide_txt, image_ref)?;
+                        slide_txt.push_str(&format!("![{alt}]({url})"))?;
+                        push_image_link_end(slide_txt, image_ref)?;
+                        push_image_text(slide_txt, image_text.as_deref())?;
+                        slide_txt.push_str("\n\n")?;
+                        return Ok(());
+                    }
+                    // Content-hash names already hold the same data if the file exists.
+                    if render_options.image_naming == ImageNaming::SlideIndex
+                        || !image_path.exists()
+                    {
+                        fs::write(&image_path, image_data)?;
+                    }
+
+                    let abs_file_url = self.path_to_file_url(&image_path);
+                    let Some(abs_file_url) = abs_file_url else {
+                        slide_txt.push_str(&self.missing_image_markdown(image))?;
+                        return Ok(());
+                    };
+                    if let Some(shared) = shared.as_deref_mut() {
+                        shared.saved.insert(hash, abs_file_url.clone());
+                    }
+                    let alt = image.alt_text.as_deref().unwrap_or(&file_name);
+                    let html_link = format!("![{alt}]({abs_file_url})");
+                    *image_count += 1;
+                    push_image_link_start(slide_txt, image_ref)?;
+                    slide_txt.push_str(&html_link)?;
+                    push_image_link_end(slide_txt, image_ref)?;
+                    push_image_text(slide_txt, image_text.as_deref())?;
+                    slide_txt.push('\n')?;
+                } else {
+                    slide_txt.push_str(&self.missing_image_markdown(image))?;
+                }
+            }
+            ImageHandlingMode::Manually => {
+                slide_txt.push_str(&format!("<!-- {} -->", self.image_token(index, image_ref)))?;
+            }
+        }
+        slide_txt.push('\n')?;
+        Ok(())
+    }
+
+    /// Returns a stable fingerprint of the slide content for caching and change detection.
+    ///
+    /// The hash covers the blocks in source order with their text, emphasis, links, list
+    /// levels, table cells, image data (or image target if the data was not extracted) and
+    /// unsupported element kinds, followed by the speaker notes and comments. Positions,
+    /// relationship ids, fonts, languages and the raw XML are ignored, so the hash does not
+    /// depend on layout tweaks or on the Markdown rendering options. The hash is the 64-bit
+    /// FNV-1a hash of this content and stays the same across platforms.
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = ContentHasher::default();
+        let blocks = self.semantic_blocks();
+        let mut ordered: Vec<&SlideBlock> = blocks.iter().collect();
+        ordered.sort_by_key(|block| block.source_order);
+        for block in ordered {
+            match &block.content {
+                SlideBlockContent::Text(text) => {
+                    hasher.write_tag(b'T');
+                    hasher.write_u64(text.role as u64);
+                    hasher.write_paragraphs(&text.paragraphs);
+                }
+                SlideBlockContent::Table(table) => {
+                    hasher.write_tag(b'G');
+                    hasher.write_u64(u64::from(table.has_header));
+                    for row in &table.rows {
+                        hasher.write_tag(b'R');
+                        for cell in &row.cells {
+                            hasher.write_tag(b'C');
+                            hasher.write_u64(cell.row_span as u64);
+                            hasher.write_u64(cell.column_span as u64);
+                            hasher.write_u64(u64::from(cell.covered));
+                            hasher.write_paragraphs(&cell.paragraphs);
+                        }
+                    }
+                }
+                SlideBlockContent::Image(image) => {
+                    hasher.write_tag(b'I');
+                    match self.image_data.get(&image.reference.id) {
+                        Some(data) => hasher.write_u64(content_hash(data)),
+                        None => hasher.write_str(&image.reference.target),
+                    }
+                    hasher.write_str(image.alt_text.as_deref().unwrap_or_default());
+                }
+                SlideBlockContent::Unsupported(unsupported) => {
+                    hasher.write_tag(b'U');
+                    hasher.write_str(&unsupported.kind);
+                    hasher.write_str(unsupported.fallback_text.as_deref().unwrap_or_default());
+                }
+            }
+        }
+        for (tag, section) in [(b'N', &self.speaker_notes), (b'K', &self.comments)] {
+            for element in section {
+                hasher.write_tag(tag);
+                hasher.write_paragraphs(&element.paragraphs);
+            }
+        }
+        hasher.0
+    }
+
+    /// Collects word, character, run and element counts for this slide.
+    ///
+    /// The dominant content is derived from the covered area of text and image blocks.
+    /// If the slide has no block dimensions, a slide is treated as image-dominant when
+    /// it contains fewer than 20 words per image.
+    pub fn stats(&self) -> SlideStats {
+        let mut stats = SlideStats::default();
+        let mut text_area = 0i128;
+        let mut image_area = 0i128;
+        for block in self.semantic_blocks().iter() {
+            let area = i128::from(block.bounds.width) * i128::from(block.bounds.height);
+            match &block.content {
+                SlideBlockContent::Text(text) => {
+                    let mut in_list = false;
+                    for paragraph in &text.paragraphs {
+                        if paragraph.list.is_some() && !in_list {
+                            stats.list_count += 1;
+                        }
+                        in_list = paragraph.list.is_some();
+                        count_paragraph(&mut stats, paragraph);
+                    }
+                    if !block_is_semantically_empty(block) {
+                        text_area += area;
+                    }
+                }
+                SlideBlockContent::Table(table) => {
+                    stats.table_count += 1;
+                    for paragraph in table
+                        .rows
+                        .iter()
+                        .flat_map(|row| &row.cells)
+                        .flat_map(|cell| &cell.paragraphs)
+                    {
+                        count_paragraph(&mut stats, paragraph);
+                    }
+                    text_area += area;
+                }
+                SlideBlockContent::Image(_) => {
+                    stats.image_count += 1;
+                    image_area += area;
+                }
+                SlideBlockContent::Unsupported(_) => {}
+            }
+        }
+        stats.dominant_content = if stats.word_count == 0 && stats.image_count == 0 {
+            ContentDominance::Empty
+        } else if text_area > 0 || image_area > 0 {
+            if image_area > text_area {
+                ContentDominance::Image
+            } else {
+                ContentDominance::Text
+            }
+        } else if stats.image_count > 0 && stats.word_count < stats.image_count * 20 {
+            ContentDominance::Image
+        } else {
+            ContentDominance::Text
+        };
+        stats
+    }
+
+    /// Returns the text of the title placeholder, falling back to the first heading.
+    ///
+    /// Paragraphs are joined with a single space and surrounding whitespace is removed.
+    pub fn title(&self) -> Option<String> {
+        let blocks = self.semantic_blocks();
+        let text_with_role = |role: TextRole| {
+            blocks.iter().find_map(|block| match &block.content {
+                SlideBlockContent::Text(text) if text.role == role => {
+                    let title = collapsed_text(text);
+                    (!title.is_empty()).then_some(title)
+                }
+                _ => None,
+            })
+        };
+        text_with_role(TextRole::Title).or_else(|| text_with_role(TextRole::Heading))
+    }
+
+    /// Returns the elements of the slide that were encountered but not parsed.
+    pub fn parse_report(&self) -> SlideParseReport {
+        let mut skipped = BTreeMap::new();
+        for block in &self.blocks {
+            if let SlideBlockContent::Unsupported(unsupported) = &block.content {
+                *skipped.entry(unsupported.report_key()).or_insert(0) += 1;
+            }
+        }
+        SlideParseReport {
+            slide_number: self.slide_number,
+            skipped,
+        }
+    }
+
+    /// Returns the plain text of the slide in the reading order used for Markdown.
+    ///
+    /// Paragraphs are separated by line breaks and blocks by blank lines. Table cells are
+    /// separated by tabs, images contribute no text. Text repeating the layout or master is
+    /// omitted if [`ParserConfig::exclude_template_text`] is set.
+    pub fn text(&self) -> String {
+        self.block_texts().join("\n\n")
+    }
+
+    /// Returns the ranges of [`Slide::text`] whose runs declare a language with a `lang`
+    /// attribute, e.g. to tag segments of multilingual decks for speech synthesis or
+    /// translation.
+    ///
+    /// Consecutive runs of the same language form one span, even across paragraph and block
+    /// separators. Runs without a language tag and whitespace-only runs are not covered.
+    pub fn language_spans(&self) -> Vec<LanguageSpan> {
+        let mut tagged = TaggedText::default();
+        for (index, block) in self.tagged_block_texts().into_iter().enumerate() {
+            if index > 0 {
+                tagged.text.push_str("\n\n");
+            }
+            let offset = tagged.text.len();
+            tagged.text.push_str(&block.text);
+            for span in block.languages {
+                tagged.push_span(
+                    span.lang,
+                    span.range.start + offset..span.range.end + offset,
+                );
+            }
+        }
+        tagged.languages
+    }
+
+    /// Returns the non-empty text of every block in the order of [`Slide::text`].
+    pub(crate) fn block_texts(&self) -> Vec<String> {
+        self.tagged_block_texts()
+            .into_iter()
+            .map(|block| block.text)
+            .collect()
+    }
+
+    fn tagged_block_texts(&self) -> Vec<TaggedText> {
+        let options = self.markdown_options();
+        let blocks = self.semantic_blocks();
+        let mut parts = Vec::new();
+        for block in ordered_blocks(&blocks, options.reading_order) {
+            let mut tagged = TaggedText::default();
+            match &block.content {
+                SlideBlockContent::Text(text)
+                    if !(options.exclude_template_text && text.from_template) =>
+                {
+                    tagged.push_paragraphs(&text.paragraphs);
+                }
+                SlideBlockContent::Table(table) => {
+                    for (row_index, row) in table.rows.iter().enumerate() {
+                        if row_index > 0 {
+                            tagged.text.push('\n');
+                        }
+                        let cells = row.cells.iter().filter(|cell| !cell.covered);
+                        for (cell_index, cell) in cells.enumerate() {
+                            if cell_index > 0 {
+                                tagged.text.push('\t');
+                            }
+                            tagged.push_paragraphs(&cell.paragraphs);
+                        }
+                    }
+                }
+                SlideBlockContent::Unsupported(unsupported) => {
+                    tagged
+                        .text
+                        .push_str(unsupported.fallback_text.as_deref().unwrap_or_default());
+                }
+                _ => {}
+            }
+            if !tagged.text.trim().is_empty() {
+                parts.push(tagged);
+            }
+        }
+        parts
+    }
+
+    /// Returns the speaker notes of the slide with their runs, formatting and pictures, or
+    /// `None` if the slide has no notes.
+    ///
+    /// The view borrows [`Slide::speaker_notes`] and [`Slide::notes_images`].
+    pub fn notes(&self) -> Option<NotesElement<'_>> {
+        let has_text = self.speaker_notes.iter().any(|element| !element.is_empty());
+        (has_text || !self.notes_images.is_empty()).then(|| NotesElement {
+            text_frames: &self.speaker_notes,
+            images: &self.notes_images,
+        })
+    }
+
+    /// Returns every run of the slide in element order, including the runs of table cells
+    /// and list items.
+    pub fn runs(&self) -> impl Iterator<Item = &Run> {
+        self.elements
+            .iter()
+            .flat_map(SlideElement::leaves)
+            .flat_map(|element| -> Box<dyn Iterator<Item = &Run> + '_> {
+                match element {
+                    SlideElement::Text(text, _, _) => Box::new(text.iter_runs()),
+                    SlideElement::Table(table, _, _) => Box::new(
+                        table
+                            .rows
+                            .iter()
+                            .flat_map(|row| &row.cells)
+                            .flat_map(|cell| cell.iter_runs()),
+                    ),
+                    SlideElement::List(list, _, _) => {
+                        Box::new(list.items.iter().flat_map(|item| &item.runs))
+                    }
+                    SlideElement::Connector(connector, _, _) => Box::new(
+                        connector
+                            .text
+                            .iter()
+                            .flat_map(crate::TextElement::iter_runs),
+                    ),
+                    SlideElement::Image(..)
+                    | SlideElement::Chart(..)
+                    | SlideElement::Media(..)
+                    | SlideElement::Group { .. }
+                    | SlideElement::Unknown => Box::new(std::iter::empty()),
+                }
+            })
+    }
+
+    /// Walks the elements of the slide in order and calls the matching [`SlideVisitor`]
+    /// callbacks, descending into groups, table cells and list items.
+    pub fn accept<V: SlideVisitor + ?Sized>(&self, visitor: &mut V) {
+        accept_elements(&self.elements, visitor);
+    }
+
+    /// Calls `f` with every run of the text and table blocks in source order.
+    pub(crate) fn for_each_run(&self, mut f: impl FnMut(&Run)) {
+        for block in self.semantic_blocks().iter() {
+            match &block.content {
+                SlideBlockContent::Text(text) => text
+                    .paragraphs
+                    .iter()
+                    .flat_map(|paragraph| &paragraph.runs)
+                    .for_each(&mut f),
+                SlideBlockContent::Table(table) => table
+                    .rows
+                    .iter()
+                    .flat_map(|row| &row.cells)
+                    .flat_map(|cell| &cell.paragraphs)
+                    .flat_map(|paragraph| &paragraph.runs)
+                    .for_each(&mut f),
+                SlideBlockContent::Image(_) | SlideBlockContent::Unsupported(_) => {}
+            }
+        }
+    }
+
+    /// Returns the semantic blocks, deriving them from the legacy elements if necessary.
+    pub(crate) fn semantic_blocks(&self) -> Cow<'_, [SlideBlock]> {
+        if self.blocks.is_empty() {
+            Cow::Owned(legacy_blocks(&self.elements))
+        } else {
+            Cow::Borrowed(&self.blocks)
         }
-        Ok(slide_txt)
     }
 
     /// Extracts the numeric slide identifier from a slide path.
@@ -302,8 +925,13 @@ impl Slide {
             .map(|img_ref| (img_ref.id.clone(), img_ref.target.clone()))
             .collect();
 
-        for element in &mut self.elements {
-            if let SlideElement::Image(img_ref, _pos) = element
+        for element in self.elements.iter_mut().flat_map(SlideElement::leaves_mut) {
+            let reference = match element {
+                SlideElement::Image(img_ref, _pos, _) => Some(img_ref),
+                SlideElement::Media(media, _pos, _) => media.poster.as_mut(),
+                _ => None,
+            };
+            if let Some(img_ref) = reference
                 && let Some(target) = id_to_target.get(&img_ref.id)
             {
                 img_ref.target = target.clone();
@@ -319,6 +947,51 @@ impl Slide {
                 image.mime_type = mime_type_from_path(&image.reference.target).map(str::to_string);
             }
         }
+        for image in &mut self.notes_images {
+            image.mime_type = mime_type_from_path(&image.reference.target).map(str::to_string);
+        }
+    }
+
+    /// Fills [`ImageReference::dimensions`] from the image data and
+    /// [`ImageReference::display_size`] from the bounds of each placement: the bounds of the
+    /// image block for blocks and image elements of the same source order, and the element
+    /// position for other image elements.
+    fn resolve_image_metadata(&mut self) {
+        let Self {
+            elements,
+            blocks,
+            images,
+            image_data,
+            ..
+        } = self;
+        let dimensions = |reference: &ImageReference| {
+            image_data
+                .get(&reference.id)
+                .and_then(|data| read_image_dimensions(data))
+        };
+        let display_size =
+            |width: i64, height: i64| (width > 0 && height > 0).then_some((width, height));
+        let mut display_sizes = HashMap::new();
+        for block in blocks.iter_mut() {
+            if let SlideBlockContent::Image(image) = &mut block.content {
+                image.reference.dimensions = dimensions(&image.reference);
+                image.reference.display_size =
+                    display_size(block.bounds.width, block.bounds.height);
+                display_sizes.insert(block.source_order, image.reference.display_size);
+            }
+        }
+        for element in elements.iter_mut().flat_map(SlideElement::leaves_mut) {
+            if let SlideElement::Image(reference, position, meta) = element {
+                reference.dimensions = dimensions(reference);
+                reference.display_size = meta
+                    .source_order
+                    .and_then(|source_order| display_sizes.get(&source_order).copied())
+                    .unwrap_or_else(|| display_size(position.width, position.height));
+            }
+        }
+        for reference in images.iter_mut() {
+            reference.dimensions = dimensions(reference);
+        }
     }
 
     /// Extracts the file extension from image paths
@@ -344,39 +1017,64 @@ impl Slide {
     ///
     /// All images will be converted to `jpg`
     pub fn compress_image(&self, image_data: &[u8]) -> Option<Vec<u8>> {
-        let img = match image::load_from_memory(image_data) {
-            Ok(image) => image,
-            Err(_) => return None,
-        };
-
-        let mut output = Vec::new();
-        let quality = self.config.quality;
+        compress_image_with_quality(image_data, self.render_options.quality)
+    }
 
-        if JpegEncoder::new_with_quality(&mut output, quality)
-            .encode_image(&img)
-            .is_ok()
+    /// Compresses the data of all images in `blocks` in parallel, keyed by image id.
+    ///
+    /// Re-encoding dominates the rendering time of slides with many images, so it runs ahead
+    /// of the sequential Markdown output. Nothing is compressed if `render_options` does not
+    /// embed or save compressed images.
+    fn compress_block_images<'a>(
+        &'a self,
+        blocks: &'a [SlideBlock],
+        render_options: &RenderOptions,
+    ) -> HashMap<&'a str, Option<Vec<u8>>> {
+        if !render_options.compress_images
+            || render_options.image_handling_mode == ImageHandlingMode::Manually
         {
-            Some(output)
-        } else {
-            None
+            return HashMap::new();
         }
+        let mut ids: Vec<&str> = blocks
+            .iter()
+            .filter_map(|block| match &block.content {
+                SlideBlockContent::Image(image) => Some(image.reference.id.as_str()),
+                _ => None,
+            })
+            .chain(
+                self.notes_images
+                    .iter()
+                    .map(|image| image.reference.id.as_str()),
+            )
+            .filter(|id| self.image_data.contains_key(*id))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.into_par_iter()
+            .map(|id| {
+                let compressed =
+                    compress_image_with_quality(&self.image_data[id], render_options.quality);
+                (id, compressed)
+            })
+            .collect()
     }
 
     pub fn load_images_manually(&self) -> Option<Vec<ManualImage>> {
         let mut images: Vec<ManualImage> = Vec::new();
 
-        let image_refs: Vec<&ImageReference> = self
-            .elements
-            .iter()
-            .filter_map(|element| match element {
-                SlideElement::Image(img, _pos) => Some(img),
-                _ => None,
-            })
-            .collect();
+        let blocks = self.semantic_blocks();
+        let image_refs =
+            blocks
+                .iter()
+                .enumerate()
+                .filter_map(|(index, block)| match &block.content {
+                    SlideBlockContent::Image(image) => Some((index, &image.reference)),
+                    _ => None,
+                });
 
-        for image_ref in image_refs {
+        for (index, image_ref) in image_refs {
             if let Some(image_data) = self.image_data.get(&image_ref.id) {
-                let image_data = if self.config.compress_images {
+                let image_data = if self.render_options.compress_images {
                     self.compress_image(image_data)
                 } else {
                     Some(image_data.clone())
@@ -384,14 +1082,56 @@ impl Slide {
 
                 let base64_str = general_purpose::STANDARD.encode(image_data?);
 
-                let image = ManualImage::new(base64_str, image_ref.clone());
-                images.push(image);
+                images.push(ManualImage::new(
+                    base64_str,
+                    image_ref.clone(),
+                    self.image_token(index, image_ref),
+                ));
             }
         }
 
         Some(images)
     }
 
+    /// Returns the placeholder of an image that could not be embedded.
+    fn missing_image_markdown(&self, image: &ImageBlock) -> String {
+        let label = image
+            .alt_text
+            .as_deref()
+            .or_else(|| image.reference.target.split('/').next_back())
+            .unwrap_or("image");
+        format!("[Image unavailable: {label}]")
+    }
+
+    /// Returns the deterministic token of an image, `img:{slide}:{block}:{hash}`.
+    ///
+    /// `block` is the index of the image block in [`Slide::blocks`], or among the blocks
+    /// built from [`Slide::elements`] for slides without blocks, so placements of the same
+    /// image get different tokens. Pictures of the speaker notes continue after the last
+    /// block. The hash is taken from the original image data, or from the image target if the
+    /// data was not extracted, so the token does not change between runs.
+    pub fn image_token(&self, block: usize, image: &ImageReference) -> String {
+        let hash = match self.image_data.get(&image.id) {
+            Some(data) => content_hash(data),
+            None => content_hash(image.target.as_bytes()),
+        };
+        format!("img:{}:{block}:{hash:016x}", self.slide_number)
+    }
+
+    /// Runs the OCR provider of `options` on the data of `image`.
+    fn recognize_image_text(&self, image: &ImageBlock, options: &RenderOptions) -> Option<String> {
+        let provider = options.ocr_provider.as_deref()?;
+        let data = self.image_data.get(&image.reference.id)?;
+        let mime = image
+            .mime_type
+            .as_deref()
+            .or_else(|| mime_type_from_path(&image.reference.target))
+            .unwrap_or("application/octet-stream");
+        provider
+            .ocr(data, mime)
+            .filter(|text| !text.trim().is_empty())
+    }
+
     fn path_to_file_url(&self, path: &Path) -> Option<String> {
         let abs_path = path.canonicalize().ok()?;
         let mut path_str = abs_path.to_string_lossy().replace('\\', "/");
@@ -408,24 +1148,66 @@ impl Slide {
     }
 }
 
+fn accept_elements<V: SlideVisitor + ?Sized>(elements: &[SlideElement], visitor: &mut V) {
+    for element in elements {
+        match element {
+            SlideElement::Text(text, position, _) => visitor.visit_text(text, *position),
+            SlideElement::Table(table, position, _) => {
+                visitor.visit_table(table, *position);
+                for (row_index, row) in table.rows.iter().enumerate() {
+                    for (column_index, cell) in row.cells.iter().enumerate() {
+                        visitor.visit_table_cell(cell, row_index, column_index);
+                    }
+                }
+            }
+            SlideElement::List(list, position, _) => {
+                visitor.visit_list(list, *position);
+                for item in &list.items {
+                    visitor.visit_list_item(item);
+                }
+            }
+            SlideElement::Image(image, position, _) => visitor.visit_image(image, *position),
+            SlideElement::Chart(chart, position, _) => visitor.visit_chart(chart, *position),
+            SlideElement::Media(media, position, _) => visitor.visit_media(media, *position),
+            SlideElement::Connector(connector, position, _) => {
+                visitor.visit_connector(connector, *position)
+            }
+            SlideElement::Group {
+                children,
+                position,
+                name,
+                ..
+            } => {
+                visitor.visit_group(name.as_deref(), *position);
+                accept_elements(children, visitor);
+            }
+            SlideElement::Unknown => visitor.visit_unknown(),
+        }
+    }
+}
+
 pub(crate) fn legacy_blocks(elements: &[SlideElement]) -> Vec<SlideBlock> {
     elements
         .iter()
+        .flat_map(SlideElement::leaves)
         .enumerate()
-        .map(|(source_order, element)| legacy_block(element, source_order))
+        .filter_map(|(source_order, element)| legacy_block(element, source_order))
         .collect()
 }
 
-pub(crate) fn legacy_block(element: &SlideElement, source_order: usize) -> SlideBlock {
+/// Returns the block of a legacy element, or `None` for a connector without text, which has
+/// no block, like in the semantic parser.
+pub(crate) fn legacy_block(element: &SlideElement, source_order: usize) -> Option<SlideBlock> {
     let (bounds, content) = match element {
-        SlideElement::Text(text, position) => (
+        SlideElement::Text(text, position, _) => (
             (*position).into(),
             SlideBlockContent::Text(TextBlock {
                 role: TextRole::Other,
-                paragraphs: vec![Paragraph::plain(text.runs.clone())],
+                paragraphs: text.paragraphs.clone(),
+                from_template: false,
             }),
         ),
-        SlideElement::List(list, position) => (
+        SlideElement::List(list, position, _) => (
             (*position).into(),
             SlideBlockContent::Text(TextBlock {
                 role: TextRole::Body,
@@ -434,13 +1216,15 @@ pub(crate) fn legacy_block(element: &SlideElement, source_order: usize) -> Slide
                     .iter()
                     .map(|item| Paragraph {
                         runs: item.runs.clone(),
-                        alignment: Default::default(),
+                        alignment: item.properties.alignment,
+                        margin_left: item.properties.margin_left,
+                        indent: item.properties.indent,
                         list: Some(ListInfo {
                             level: item.level,
                             kind: if item.is_ordered {
                                 ListKind::Ordered {
                                     style: None,
-                                    start: 1,
+                                    start: None,
                                 }
                             } else {
                                 ListKind::Bullet { character: None }
@@ -449,49 +1233,91 @@ pub(crate) fn legacy_block(element: &SlideElement, source_order: usize) -> Slide
                         list_explicit: true,
                     })
                     .collect(),
+                from_template: false,
             }),
         ),
-        SlideElement::Table(table, position) => (
+        SlideElement::Table(table, position, _) => (
             (*position).into(),
-            SlideBlockContent::Table(SemanticTable {
-                rows: table
-                    .rows
-                    .iter()
-                    .map(|row| SemanticTableRow {
-                        cells: row
-                            .cells
-                            .iter()
-                            .map(|cell| SemanticTableCell {
-                                paragraphs: vec![Paragraph::plain(cell.runs.clone())],
-                                row_span: 1,
-                                column_span: 1,
-                                covered: false,
-                            })
-                            .collect(),
-                    })
-                    .collect(),
-            }),
+            SlideBlockContent::Table(crate::parse_xml::legacy_table_to_semantic(table)),
+        ),
+        SlideElement::Chart(chart, position, _) => (
+            (*position).into(),
+            SlideBlockContent::Table(crate::parse_xml::legacy_table_to_semantic(
+                &chart.to_table(),
+            )),
         ),
-        SlideElement::Image(image, position) => (
+        SlideElement::Image(image, position, _) => (
             (*position).into(),
             SlideBlockContent::Image(ImageBlock {
                 reference: image.clone(),
-                alt_text: None,
+                alt_text: image.alt_text().map(str::to_string),
                 mime_type: None,
             }),
         ),
-        SlideElement::Unknown => (
+        SlideElement::Media(media, position, _) => (
+            (*position).into(),
+            crate::parse_xml::media_block_content(media),
+        ),
+        SlideElement::Connector(connector, position, _) => (
+            (*position).into(),
+            SlideBlockContent::Text(TextBlock {
+                role: TextRole::Other,
+                paragraphs: connector.text.as_ref()?.paragraphs.clone(),
+                from_template: false,
+            }),
+        ),
+        SlideElement::Group { .. } | SlideElement::Unknown => (
             Bounds::default(),
             SlideBlockContent::Unsupported(UnsupportedBlock {
                 kind: "unknown".to_string(),
                 fallback_text: None,
+                content_type: None,
             }),
         ),
     };
-    SlideBlock {
+    Some(SlideBlock {
         bounds,
         source_order,
+        raw: None,
         content,
+    })
+}
+
+/// Plain text with the language spans of its runs, see [`Slide::language_spans`].
+#[derive(Default)]
+struct TaggedText {
+    text: String,
+    languages: Vec<LanguageSpan>,
+}
+
+impl TaggedText {
+    fn push_paragraphs(&mut self, paragraphs: &[Paragraph]) {
+        for (index, paragraph) in paragraphs.iter().enumerate() {
+            if index > 0 {
+                self.text.push('\n');
+            }
+            for run in &paragraph.runs {
+                let start = self.text.len();
+                self.text.push_str(&run.text);
+                if !run.formatting.lang.is_empty() && !run.text.trim().is_empty() {
+                    self.push_span(run.formatting.lang.clone(), start..self.text.len());
+                }
+            }
+        }
+    }
+
+    /// Records a span of `text`, extending the previous span if it has the same language and
+    /// only whitespace lies between them.
+    fn push_span(&mut self, lang: String, range: Range<usize>) {
+        match self.languages.last_mut() {
+            Some(previous)
+                if previous.lang == lang
+                    && self.text[previous.range.end..range.start].trim().is_empty() =>
+            {
+                previous.range.end = range.end;
+            }
+            _ => self.languages.push(LanguageSpan { lang, range }),
+        }
     }
 }
 
@@ -614,19 +1440,84 @@ fn block_is_semantically_empty(block: &SlideBlock) -> bool {
     )
 }
 
+fn count_paragraph(stats: &mut SlideStats, paragraph: &Paragraph) {
+    stats.run_count += paragraph.runs.len();
+    let text = paragraph.text();
+    stats.word_count += text.split_whitespace().count();
+    stats.character_count += text
+        .chars()
+        .filter(|character| !matches!(character, '\n' | '\r'))
+        .count();
+}
+
 fn sort_spatial_band(blocks: &mut Vec<&SlideBlock>) {
     blocks.sort_by_key(|block| (block.bounds.x, block.bounds.y, block.source_order));
 }
 
-fn render_text_block(output: &mut String, text: &TextBlock) {
-    let mut counters: HashMap<u32, u32> = HashMap::new();
+/// Joins the paragraphs of a text block with single spaces, collapsing whitespace.
+fn collapsed_text(text: &TextBlock) -> String {
+    text.paragraphs
+        .iter()
+        .map(Paragraph::text)
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Fills the `{n}` and `{title}` placeholders of a slide heading template. Without a title,
+/// `{title}` is removed together with the separator in front of it.
+fn render_slide_heading(template: &str, slide_number: u32, title: Option<&str>) -> String {
+    let heading = template.replace("{n}", &slide_number.to_string());
+    match title {
+        Some(title) => heading.replace("{title}", title),
+        None => match heading.split_once("{title}") {
+            Some((before, after)) => {
+                let before = before.trim_end_matches([' ', ':', '-', '–', '—', '|']);
+                format!("{before}{}", after.replace("{title}", ""))
+            }
+            None => heading,
+        },
+    }
+}
+
+/// Returns the anchor ID emitted for a slide and targeted by slide-jump hyperlinks.
+pub(crate) fn slide_anchor(slide_number: u32) -> String {
+    format!("slide-{slide_number}")
+}
+
+fn render_text_block(
+    output: &mut String,
+    text: &TextBlock,
+    anchor: Option<&str>,
+    options: &MarkdownOptions,
+    counters: &mut HashMap<u32, u32>,
+) {
+    // The counters of the previous block only continue into a block starting with an ordered
+    // list item that has no explicit start number.
+    match text
+        .paragraphs
+        .first()
+        .and_then(|paragraph| paragraph.list.as_ref())
+    {
+        Some(list) if options.continue_list_numbering => match ordered_start(list, options) {
+            Some(None) => {}
+            Some(Some(_)) => {
+                counters.remove(&list.level);
+            }
+            None => counters.clear(),
+        },
+        _ => counters.clear(),
+    }
     for (index, paragraph) in text.paragraphs.iter().enumerate() {
         let context = if paragraph.list.is_some() {
             MarkdownContext::ListItem
         } else {
             MarkdownContext::Flow
         };
-        let mut rendered = render_runs(&paragraph.runs, context);
+        let mut rendered =
+            render_runs_with(&paragraph.runs, context, RunStyle::from_options(options));
         if context == MarkdownContext::Flow || context == MarkdownContext::Quote {
             if rendered.ends_with('\n') {
                 rendered.pop();
@@ -637,14 +1528,14 @@ fn render_text_block(output: &mut String, text: &TextBlock) {
         if let Some(list) = &paragraph.list {
             counters.retain(|level, _| *level <= list.level);
             let indent = "\t".repeat(list.level as usize);
-            let marker = match &list.kind {
-                ListKind::Bullet { .. } => "- ".to_string(),
-                ListKind::Ordered { start, .. } => {
-                    let counter = counters.entry(list.level).or_insert(*start);
+            let marker = match ordered_start(list, options) {
+                Some(start) => {
+                    let counter = counters.entry(list.level).or_insert(start.unwrap_or(1));
                     let marker = format!("{}. ", *counter);
                     *counter += 1;
                     marker
                 }
+                None => "- ".to_string(),
             };
             output.push_str(&indent);
             output.push_str(&marker);
@@ -654,31 +1545,54 @@ fn render_text_block(output: &mut String, text: &TextBlock) {
         }
 
         counters.clear();
+        let title_level = usize::from(options.title_heading_level.clamp(1, 5));
         let prefix = match text.role {
-            TextRole::Title => "## ",
-            TextRole::Heading => "### ",
-            _ => "",
+            TextRole::Title => format!("{} ", "#".repeat(title_level)),
+            TextRole::Heading => format!("{} ", "#".repeat(title_level + 1)),
+            _ => String::new(),
         };
         if text.role == TextRole::Subtitle && !rendered.is_empty() {
             output.push('_');
             output.push_str(&rendered);
             output.push('_');
         } else {
-            output.push_str(prefix);
+            output.push_str(&prefix);
             output.push_str(&rendered);
+            if let Some(anchor) = anchor.filter(|_| index == 0 && text.role == TextRole::Title) {
+                output.push_str(&format!(" <a id=\"{anchor}\"></a>"));
+            }
         }
-        if index + 1 < text.paragraphs.len() {
-            output.push_str("\n\n");
-        } else {
-            output.push('\n');
+        match text.paragraphs.get(index + 1) {
+            Some(next) if next.list.is_none() => {
+                output.push_str(match options.paragraph_spacing {
+                    ParagraphSpacing::BlankLine => "\n\n",
+                    ParagraphSpacing::Newline => "\n",
+                    ParagraphSpacing::LineBreak if prefix.is_empty() => "<br>\n",
+                    ParagraphSpacing::LineBreak => "\n",
+                });
+            }
+            Some(_) => output.push_str("\n\n"),
+            None => output.push('\n'),
         }
     }
 }
 
-fn render_table(output: &mut String, table: &SemanticTable) {
-    let complex = table.rows.iter().flat_map(|row| &row.cells).any(|cell| {
-        cell.row_span > 1 || cell.column_span > 1 || cell.covered || cell.paragraphs.len() > 1
-    });
+/// Returns the explicit start number of an ordered list item, `Some(None)` for an ordered item
+/// without one, or `None` for a bullet.
+fn ordered_start(list: &ListInfo, options: &MarkdownOptions) -> Option<Option<u32>> {
+    match &list.kind {
+        ListKind::Ordered { start, .. } => Some(*start),
+        ListKind::Bullet { character: Some(_) } if options.legacy_list_classification => Some(None),
+        ListKind::Bullet { .. } => None,
+    }
+}
+
+fn render_table(output: &mut String, table: &SemanticTable, style: RunStyle) {
+    let complex = table
+        .rows
+        .iter()
+        .flat_map(|row| &row.cells)
+        .any(|cell| cell.row_span > 1 || cell.column_span > 1 || cell.covered);
     if complex {
         output.push_str("<table>\n");
         for row in &table.rows {
@@ -697,7 +1611,9 @@ fn render_table(output: &mut String, table: &SemanticTable) {
                 let value = cell
                     .paragraphs
                     .iter()
-                    .map(|paragraph| render_runs(&paragraph.runs, MarkdownContext::TableCell))
+                    .map(|paragraph| {
+                        render_runs_with(&paragraph.runs, MarkdownContext::TableCell, style)
+                    })
                     .collect::<Vec<_>>()
                     .join("<br>");
                 output.push_str(&format!("<td{attributes}>{value}</td>"));
@@ -708,6 +1624,17 @@ fn render_table(output: &mut String, table: &SemanticTable) {
         return;
     }
 
+    if !table.has_header {
+        // Markdown tables require a header row, so tables without one get an empty header.
+        let columns = table
+            .rows
+            .iter()
+            .map(|row| row.cells.len())
+            .max()
+            .unwrap_or(0);
+        output.push_str(&format!("|{}|\n", vec!["   "; columns].join("|")));
+        output.push_str(&format!("|{}|\n", vec![" --- "; columns].join("|")));
+    }
     for (row_index, row) in table.rows.iter().enumerate() {
         let cells = row
             .cells
@@ -715,26 +1642,196 @@ fn render_table(output: &mut String, table: &SemanticTable) {
             .map(|cell| {
                 cell.paragraphs
                     .iter()
-                    .map(|paragraph| render_runs(&paragraph.runs, MarkdownContext::TableCell))
+                    .map(|paragraph| {
+                        render_runs_with(&paragraph.runs, MarkdownContext::TableCell, style)
+                    })
                     .collect::<Vec<_>>()
                     .join("<br>")
             })
             .collect::<Vec<_>>();
         output.push_str(&format!("| {} |\n", cells.join(" | ")));
-        if row_index == 0 {
+        if row_index == 0 && table.has_header {
             output.push_str(&format!("|{}|\n", vec![" --- "; cells.len()].join("|")));
         }
     }
     output.push('\n');
 }
 
-fn missing_image_markdown(image: &ImageBlock) -> String {
-    let label = image
-        .alt_text
-        .as_deref()
-        .or_else(|| image.reference.target.split('/').next_back())
-        .unwrap_or("image");
-    format!("[Image unavailable: {label}]")
+/// Opens a Markdown link around an image whose picture has a hyperlink.
+fn push_image_link_start<W: Write + ?Sized>(
+    output: &mut MarkdownWriter<'_, W>,
+    image: &ImageReference,
+) -> std::io::Result<()> {
+    if image.link_target.is_some() {
+        output.push('[')?;
+    }
+    Ok(())
+}
+
+/// Closes the link opened by [`push_image_link_start`] with the hyperlink target.
+fn push_image_link_end<W: Write + ?Sized>(
+    output: &mut MarkdownWriter<'_, W>,
+    image: &ImageReference,
+) -> std::io::Result<()> {
+    if let Some(target) = &image.link_target {
+        output.push_str(&format!("]({})", markdown_link_destination(target)))?;
+    }
+    Ok(())
+}
+
+/// Images shared by the slides of several presentations rendered into one document.
+#[derive(Debug, Default)]
+pub(crate) struct SharedImages {
+    /// File URLs of saved images, keyed by the hash of the written data.
+    saved: HashMap<u64, String>,
+    /// Reference labels of inline images, keyed by the hash of the embedded data.
+    inline_labels: HashMap<u64, String>,
+    /// Reference definitions of inline images in order of their first use.
+    pub(crate) definitions: Vec<(String, String)>,
+}
+
+impl SharedImages {
+    /// Returns the reference label of `data`, adding a definition built by `url` on first use.
+    fn inline_label(&mut self, data: &[u8], url: impl FnOnce() -> String) -> String {
+        let next = self.definitions.len() + 1;
+        let label = self
+            .inline_labels
+            .entry(content_hash(data))
+            .or_insert_with(|| format!("image-{next}"))
+            .clone();
+        if self.definitions.len() < self.inline_labels.len() {
+            self.definitions.push((label.clone(), url()));
+        }
+        label
+    }
+}
+
+/// Reads the size and format of an image from its header.
+pub(crate) fn read_image_dimensions(data: &[u8]) -> Option<ImageDimensions> {
+    let reader = image::ImageReader::new(std::io::Cursor::new(data))
+        .with_guessed_format()
+        .ok()?;
+    let format = reader.format()?;
+    let (width, height) = reader.into_dimensions().ok()?;
+    Some(ImageDimensions {
+        width,
+        height,
+        format: format.extensions_str().first()?.to_string(),
+    })
+}
+
+/// Writes `data` base64 encoded to `writer`.
+///
+/// The data is encoded in large chunks into one reused buffer, so multi-megabyte images are
+/// neither encoded into one string first nor passed on in many small writes.
+pub(crate) fn write_base64<W: Write + ?Sized>(writer: &mut W, data: &[u8]) -> std::io::Result<()> {
+    const CHUNK_LENGTH: usize = 3 * 16 * 1024;
+    let mut encoded = vec![0; data.len().min(CHUNK_LENGTH).div_ceil(3) * 4];
+    for chunk in data.chunks(CHUNK_LENGTH) {
+        let length = general_purpose::STANDARD
+            .encode_slice(chunk, &mut encoded)
+            .map_err(std::io::Error::other)?;
+        writer.write_all(&encoded[..length])?;
+    }
+    Ok(())
+}
+
+/// 64-bit FNV-1a hash of `data`, stable across platforms and releases.
+pub(crate) fn content_hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Incremental FNV-1a hash behind [`Slide::content_hash`].
+///
+/// Strings are prefixed with their length and every element with a tag byte, so adjacent
+/// values cannot shift into each other.
+struct ContentHasher(u64);
+
+impl Default for ContentHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl ContentHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_tag(&mut self, tag: u8) {
+        self.write(&[tag]);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    fn write_str(&mut self, value: &str) {
+        self.write_u64(value.len() as u64);
+        self.write(value.as_bytes());
+    }
+
+    fn write_run(&mut self, run: &Run) {
+        let formatting = &run.formatting;
+        self.write_tag(b'r');
+        self.write_str(&run.text);
+        self.write(&[
+            u8::from(formatting.bold),
+            u8::from(formatting.italic),
+            u8::from(formatting.underlined),
+            u8::from(formatting.strikethrough),
+            formatting.baseline as u8,
+        ]);
+        self.write_str(run.link_target.as_deref().unwrap_or_default());
+    }
+
+    fn write_paragraphs(&mut self, paragraphs: &[Paragraph]) {
+        for paragraph in paragraphs {
+            self.write_tag(b'p');
+            match &paragraph.list {
+                Some(ListInfo {
+                    level,
+                    kind: ListKind::Bullet { .. },
+                }) => {
+                    self.write_tag(b'b');
+                    self.write_u64(u64::from(*level));
+                }
+                Some(ListInfo {
+                    level,
+                    kind: ListKind::Ordered { start, .. },
+                }) => {
+                    self.write_tag(b'o');
+                    self.write_u64(u64::from(*level));
+                    self.write_u64(u64::from(start.unwrap_or(1)));
+                }
+                None => {}
+            }
+            for run in &paragraph.runs {
+                self.write_run(run);
+            }
+        }
+    }
+}
+
+fn compress_image_with_quality(image_data: &[u8], quality: u8) -> Option<Vec<u8>> {
+    let img = match image::load_from_memory(image_data) {
+        Ok(image) => image,
+        Err(_) => return None,
+    };
+
+    let mut output = Vec::new();
+    if JpegEncoder::new_with_quality(&mut output, quality)
+        .encode_image(&img)
+        .is_ok()
+    {
+        Some(output)
+    } else {
+        None
+    }
 }
 
 fn mime_type_from_path(path: &str) -> Option<&'static str> {
@@ -755,22 +1852,100 @@ fn mime_type_from_path(path: &str) -> Option<&'static str> {
     }
 }
 
-fn append_quoted_section(output: &mut String, title: &str, elements: &[crate::TextElement]) {
+/// Detects the MIME type of a media part from its leading bytes, falling back to the
+/// extension of `path`.
+pub(crate) fn detect_mime_type(header: &[u8], path: &str) -> Option<&'static str> {
+    const SIGNATURES: [(&[u8], &str); 10] = [
+        (b"\x89PNG\r\n\x1a\n", "image/png"),
+        (b"\xff\xd8\xff", "image/jpeg"),
+        (b"GIF8", "image/gif"),
+        (b"BM", "image/bmp"),
+        (b"II*\0", "image/tiff"),
+        (b"MM\0*", "image/tiff"),
+        (b"\xd7\xcd\xc6\x9a", "image/x-wmf"),
+        (b"ID3", "audio/mpeg"),
+        (b"fLaC", "audio/flac"),
+        (b"OggS", "audio/ogg"),
+    ];
+    if let Some((_, mime)) = SIGNATURES
+        .iter()
+        .find(|(signature, _)| header.starts_with(signature))
+    {
+        return Some(mime);
+    }
+    match (header.get(..4), header.get(8..12)) {
+        (Some(b"RIFF"), Some(b"WEBP")) => return Some("image/webp"),
+        (Some(b"RIFF"), Some(b"WAVE")) => return Some("audio/wav"),
+        (Some(b"RIFF"), Some(b"AVI ")) => return Some("video/x-msvideo"),
+        _ => {}
+    }
+    if header.get(40..44) == Some(b" EMF") {
+        return Some("image/emf");
+    }
+    if header.get(4..8) == Some(b"ftyp") {
+        return Some(match header.get(8..11) {
+            Some(b"M4A") => "audio/mp4",
+            Some(b"qt ") => "video/quicktime",
+            _ => "video/mp4",
+        });
+    }
+    mime_type_from_path(path).or_else(|| {
+        match Path::new(path)
+            .extension()
+            .and_then(|extension| extension.to_str())?
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "emf" => Some("image/emf"),
+            "wmf" => Some("image/x-wmf"),
+            "mp4" | "m4v" => Some("video/mp4"),
+            "mov" => Some("video/quicktime"),
+            "mp3" => Some("audio/mpeg"),
+            "m4a" => Some("audio/mp4"),
+            "wav" => Some("audio/wav"),
+            _ => None,
+        }
+    })
+}
+
+/// Writes the recognized text of an image as a blockquote below it.
+fn push_image_text<W: Write + ?Sized>(
+    output: &mut MarkdownWriter<'_, W>,
+    text: Option<&str>,
+) -> std::io::Result<()> {
+    let Some(text) = text else {
+        return Ok(());
+    };
+    output.push('\n')?;
+    for line in text.trim().lines() {
+        output.push_str("\n> ")?;
+        output.push_str(line.trim_end())?;
+    }
+    Ok(())
+}
+
+fn append_quoted_section<W: Write + ?Sized>(
+    output: &mut MarkdownWriter<'_, W>,
+    title: &str,
+    elements: &[crate::TextElement],
+    style: RunStyle,
+) -> std::io::Result<()> {
     if !output.is_empty() && !output.ends_with("\n\n") {
-        output.push('\n');
+        output.push('\n')?;
     }
-    output.push_str(&format!("> **{}**\n>\n", title));
+    output.push_str(&format!("> **{}**\n>\n", title))?;
     for (index, element) in elements.iter().enumerate() {
-        let content = render_runs(&element.runs, MarkdownContext::Quote);
+        let content = render_runs_with(&element.runs(), MarkdownContext::Quote, style);
         for line in content.lines() {
-            output.push_str("> ");
-            output.push_str(line);
-            output.push('\n');
+            output.push_str("> ")?;
+            output.push_str(line)?;
+            output.push('\n')?;
         }
         if index + 1 < elements.len() {
-            output.push_str(">\n");
+            output.push_str(">\n")?;
         }
     }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -0,0 +1,109 @@
+/// Converts text of a run set in a symbol font to the equivalent Unicode characters.
+///
+/// Symbol fonts place their glyphs on ASCII code points or, when inserted through the symbol
+/// dialog, on the private use range `U+F020`–`U+F0FF`. Characters without a known equivalent
+/// and text in other typefaces are returned unchanged.
+pub(crate) fn map_symbol_text(typeface: &str, text: &str) -> String {
+    let Some(table) = symbol_table(typeface) else {
+        return text.to_string();
+    };
+    text.chars()
+        .map(|character| {
+            let code = match u32::from(character) {
+                code @ 0xF020..=0xF0FF => code - 0xF000,
+                code => code,
+            };
+            u8::try_from(code).ok().and_then(table).unwrap_or(character)
+        })
+        .collect()
+}
+
+fn symbol_table(typeface: &str) -> Option<fn(u8) -> Option<char>> {
+    match typeface.trim().to_ascii_lowercase().as_str() {
+        "wingdings" => Some(wingdings),
+        "webdings" => Some(webdings),
+        "symbol" => Some(symbol),
+        _ => None,
+    }
+}
+
+fn wingdings(code: u8) -> Option<char> {
+    Some(match code {
+        0x21 => '✏',
+        0x22 => '✂',
+        0x43 => '👍',
+        0x44 => '👎',
+        0x46 => '☞',
+        0x4A => '☺',
+        0x4B => '😐',
+        0x4C => '☹',
+        0x6C => '●',
+        0x6E => '■',
+        0x6F => '□',
+        0x71 => '❑',
+        0x75 => '◆',
+        0x76 => '❖',
+        0xA7 => '▪',
+        0xA8 => '◻',
+        0xD8 => '➢',
+        0xE8 => '➔',
+        0xEF => '⇦',
+        0xF0 => '⇨',
+        0xF1 => '⇧',
+        0xF2 => '⇩',
+        0xFB => '✗',
+        0xFC => '✓',
+        0xFD => '☒',
+        0xFE => '☑',
+        _ => return None,
+    })
+}
+
+fn webdings(code: u8) -> Option<char> {
+    Some(match code {
+        0x33 => '◀',
+        0x34 => '▶',
+        0x35 => '▲',
+        0x36 => '▼',
+        0x61 => '✓',
+        0x6E => '●',
+        0x72 => '✕',
+        _ => return None,
+    })
+}
+
+fn symbol(code: u8) -> Option<char> {
+    const UPPERCASE: &str = "ΑΒΧΔΕΦΓΗΙϑΚΛΜΝΟΠΘΡΣΤΥςΩΞΨΖ";
+    const LOWERCASE: &str = "αβχδεφγηιϕκλμνοπθρστυϖωξψζ";
+    Some(match code {
+        b'A'..=b'Z' => UPPERCASE.chars().nth(usize::from(code - b'A'))?,
+        b'a'..=b'z' => LOWERCASE.chars().nth(usize::from(code - b'a'))?,
+        0xA3 => '≤',
+        0xA5 => '∞',
+        0xAB => '↔',
+        0xAC => '←',
+        0xAD => '↑',
+        0xAE => '→',
+        0xAF => '↓',
+        0xB0 => '°',
+        0xB1 => '±',
+        0xB3 => '≥',
+        0xB4 => '×',
+        0xB6 => '∂',
+        0xB7 => '•',
+        0xB8 => '÷',
+        0xB9 => '≠',
+        0xBB => '≈',
+        0xD6 => '√',
+        0xD7 => '⋅',
+        0xDB => '⇔',
+        0xDE => '⇒',
+        0xE5 => '∑',
+        0xF2 => '∫',
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/symbol_fonts.rs"]
+mod tests;
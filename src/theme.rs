@@ -0,0 +1,191 @@
+use crate::constants::{A_NAMESPACE, P_NAMESPACE};
+use crate::container::PptxContainer;
+use crate::parse_rels::Relationship;
+use crate::xml::{XmlReader, attr, element_is, end_is, event, local, reader};
+use crate::{Background, Color, GradientStop, Result, ThemeSummary};
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashMap;
+
+const ACCENTS: [&str; 6] = [
+    "accent1", "accent2", "accent3", "accent4", "accent5", "accent6",
+];
+
+/// The theme of a slide master with its color scheme, used to resolve scheme colors.
+#[derive(Debug, Default)]
+pub(crate) struct Theme {
+    pub(crate) summary: ThemeSummary,
+    colors: HashMap<String, Color>,
+}
+
+impl Theme {
+    /// Replaces a scheme color with the color of the theme, using the default mapping of
+    /// `bg1`/`tx1`/`bg2`/`tx2` to `lt1`/`dk1`/`lt2`/`dk2`.
+    pub(crate) fn resolve(&self, color: Color) -> Color {
+        let Color::Scheme(name) = &color else {
+            return color;
+        };
+        let slot = match name.as_str() {
+            "bg1" => "lt1",
+            "tx1" => "dk1",
+            "bg2" => "lt2",
+            "tx2" => "dk2",
+            other => other,
+        };
+        self.colors.get(slot).cloned().unwrap_or(color)
+    }
+}
+
+/// Reads the theme name and color scheme of a theme part.
+pub(crate) fn parse_theme(xml_data: &[u8], part: &str) -> Result<Theme> {
+    let mut xml = reader(xml_data);
+    let mut theme = Theme::default();
+    let mut in_scheme = false;
+    let mut slot = None;
+    loop {
+        match event(&mut xml, part)? {
+            Event::Start(element) if element_is(&xml, &element, A_NAMESPACE, b"theme") => {
+                theme.summary.name = attr(&element, b"name");
+            }
+            Event::Start(element) if element_is(&xml, &element, A_NAMESPACE, b"clrScheme") => {
+                theme.summary.color_scheme = attr(&element, b"name");
+                in_scheme = true;
+            }
+            Event::Start(element) | Event::Empty(element) if in_scheme => {
+                match color(&xml, &element) {
+                    Some(color) => {
+                        if let Some(slot) = slot.take() {
+                            theme.colors.insert(slot, color);
+                        }
+                    }
+                    None => {
+                        slot = Some(
+                            String::from_utf8_lossy(local(element.name().as_ref())).into_owned(),
+                        );
+                    }
+                }
+            }
+            Event::End(element) if end_is(element.name().as_ref(), b"clrScheme") => break,
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    theme.summary.accents = ACCENTS
+        .iter()
+        .filter_map(|name| theme.colors.get(*name).cloned())
+        .collect();
+    Ok(theme)
+}
+
+/// Reads the background fill of a slide, layout or master part.
+///
+/// Image fills hold the relationship id of the image until [`resolve_background`] replaces it
+/// with the package path. Malformed XML ends the scan without an error, it is reported when
+/// the part itself is parsed.
+pub(crate) fn parse_background(xml_data: &[u8]) -> Option<Background> {
+    let mut xml = reader(xml_data);
+    let mut in_background = false;
+    let mut fill = None;
+    let mut stop_position = None;
+    while let Ok(event) = xml.read_event() {
+        match event {
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"bg") => {
+                in_background = true;
+            }
+            Event::Start(element) if element_is(&xml, &element, P_NAMESPACE, b"spTree") => break,
+            Event::End(element) if in_background && end_is(element.name().as_ref(), b"bg") => {
+                break;
+            }
+            Event::Start(element) | Event::Empty(element) if in_background => {
+                if let Some(color) = color(&xml, &element) {
+                    match &mut fill {
+                        Some(Fill::Solid(solid @ None)) => *solid = Some(color),
+                        Some(Fill::Gradient(stops)) => {
+                            if let Some(position) = stop_position.take() {
+                                stops.push(GradientStop { position, color });
+                            }
+                        }
+                        _ => {}
+                    }
+                } else if element_is(&xml, &element, A_NAMESPACE, b"solidFill")
+                    || element_is(&xml, &element, P_NAMESPACE, b"bgRef")
+                {
+                    fill = Some(Fill::Solid(None));
+                } else if element_is(&xml, &element, A_NAMESPACE, b"gradFill") {
+                    fill = Some(Fill::Gradient(Vec::new()));
+                } else if element_is(&xml, &element, A_NAMESPACE, b"gs") {
+                    stop_position = attr(&element, b"pos").and_then(|value| value.parse().ok());
+                } else if element_is(&xml, &element, A_NAMESPACE, b"blipFill") {
+                    fill = Some(Fill::Image(None));
+                } else if element_is(&xml, &element, A_NAMESPACE, b"blip")
+                    && let Some(Fill::Image(image)) = &mut fill
+                {
+                    *image = attr(&element, b"embed");
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    match fill {
+        Some(Fill::Solid(Some(color))) => Some(Background::Solid(color)),
+        Some(Fill::Gradient(stops)) if !stops.is_empty() => Some(Background::Gradient(stops)),
+        Some(Fill::Image(Some(id))) => Some(Background::Image(id)),
+        _ => None,
+    }
+}
+
+/// Resolves the scheme colors of a parsed background against `theme` and the relationship
+/// id of an image fill against the relationships of `part_path`.
+pub(crate) fn resolve_background(
+    background: Background,
+    part_path: &str,
+    relationships: &[Relationship],
+    theme: Option<&Theme>,
+) -> Option<Background> {
+    let resolve = |color| match theme {
+        Some(theme) => theme.resolve(color),
+        None => color,
+    };
+    Some(match background {
+        Background::Solid(color) => Background::Solid(resolve(color)),
+        Background::Gradient(stops) => Background::Gradient(
+            stops
+                .into_iter()
+                .map(|stop| GradientStop {
+                    position: stop.position,
+                    color: resolve(stop.color),
+                })
+                .collect(),
+        ),
+        Background::Image(id) => {
+            let target = relationships.iter().find(|rel| rel.id == id)?;
+            Background::Image(PptxContainer::resolve_target_path(
+                part_path,
+                &target.target,
+            ))
+        }
+    })
+}
+
+enum Fill {
+    Solid(Option<Color>),
+    Gradient(Vec<GradientStop>),
+    Image(Option<String>),
+}
+
+/// Reads an `a:srgbClr`, `a:sysClr` or `a:schemeClr` element.
+pub(crate) fn color(xml: &XmlReader<'_>, element: &BytesStart<'_>) -> Option<Color> {
+    if element_is(xml, element, A_NAMESPACE, b"srgbClr") {
+        attr(element, b"val").map(Color::Rgb)
+    } else if element_is(xml, element, A_NAMESPACE, b"sysClr") {
+        attr(element, b"lastClr").map(Color::Rgb)
+    } else if element_is(xml, element, A_NAMESPACE, b"schemeClr") {
+        attr(element, b"val").map(Color::Scheme)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/theme.rs"]
+mod tests;
@@ -1,40 +1,828 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
 #[derive(Debug)]
 pub struct Presentation {
     pub metadata: crate::PresentationMetadata,
     pub slides: Vec<crate::Slide>,
+    pub sections: Vec<PresentationSection>,
     pub diagnostics: Vec<ParseDiagnostic>,
 }
 
+impl Presentation {
+    /// Returns the elements that were encountered but not parsed, per slide.
+    pub fn parse_report(&self) -> ParseReport {
+        ParseReport {
+            slides: self.slides.iter().map(crate::Slide::parse_report).collect(),
+        }
+    }
+
+    /// Aggregates the content statistics of all slides.
+    ///
+    /// Media is counted once per part, even if several slides show it. Its byte count is only
+    /// known if image data was extracted.
+    pub fn stats(&self) -> PresentationStats {
+        let mut stats = PresentationStats {
+            slide_count: self.slides.len(),
+            unsupported_elements: self.parse_report().skipped_totals(),
+            ..PresentationStats::default()
+        };
+        let mut seen_media = BTreeSet::new();
+        for slide in &self.slides {
+            stats.word_count += slide.stats().word_count;
+            slide.for_each_run(|run| {
+                if let Some(font) = &run.formatting.font {
+                    stats.fonts.insert(font.clone());
+                }
+                if !run.formatting.lang.is_empty() {
+                    stats.languages.insert(run.formatting.lang.clone());
+                }
+            });
+            for image in &slide.images {
+                let part =
+                    crate::PptxContainer::resolve_target_path(&slide.rel_path, &image.target);
+                if !seen_media.insert(part) {
+                    continue;
+                }
+                let extension = image
+                    .target
+                    .rsplit_once('.')
+                    .map(|(_, extension)| extension.to_ascii_lowercase())
+                    .unwrap_or_default();
+                let media = stats.media.entry(extension).or_default();
+                media.count += 1;
+                media.bytes += slide.image_data.get(&image.id).map_or(0, Vec::len) as u64;
+            }
+        }
+        stats
+    }
+
+    /// Writes every slide as its own Markdown file into `output_dir`.
+    ///
+    /// The file name is built from `pattern`, in which `{num}` is replaced by the slide
+    /// number, `{title}` by the slide title and `{section}` by the name of the slide's section.
+    /// Titles and section names are reduced to lowercase words joined by `-`; slides without
+    /// either use `untitled`. An `.md` extension is added if the pattern has none, and names
+    /// used by an earlier slide get a `-2`, `-3`, … suffix.
+    ///
+    /// Images are saved into `output_dir` as with [`crate::ImageHandlingMode::Save`],
+    /// independent of the configured image handling. Returns the paths of the written files.
+    ///
+    /// ```no_run
+    /// # use pptx_to_md::{ParserConfig, PresentationContainer};
+    /// # use std::path::Path;
+    /// # fn main() -> pptx_to_md::Result<()> {
+    /// let presentation = PresentationContainer::open(Path::new("deck.pptx"), ParserConfig::default())?
+    ///     .parse_document()?;
+    /// presentation.write_markdown_files(Path::new("slides"), "{num}-{title}.md")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_markdown_files(
+        &self,
+        output_dir: &Path,
+        pattern: &str,
+    ) -> crate::Result<Vec<PathBuf>> {
+        fs::create_dir_all(output_dir)?;
+        let mut used = BTreeSet::new();
+        let mut paths = Vec::new();
+        for slide in &self.slides {
+            let section = self
+                .sections
+                .iter()
+                .find(|section| section.slide_numbers.contains(&slide.slide_number))
+                .map(|section| section.name.as_str());
+            let mut name = pattern
+                .replace("{num}", &slide.slide_number.to_string())
+                .replace("{title}", &file_name_part(slide.title().as_deref()))
+                .replace("{section}", &file_name_part(section));
+            if Path::new(&name).extension().is_none() {
+                name.push_str(".md");
+            }
+            let (stem, extension) = name.rsplit_once('.').unwrap_or((&name, "md"));
+            let mut file_name = name.clone();
+            let mut counter = 1;
+            while !used.insert(file_name.clone()) {
+                counter += 1;
+                file_name = format!("{stem}-{counter}.{extension}");
+            }
+
+            let options = crate::RenderOptions {
+                image_handling_mode: crate::ImageHandlingMode::Save,
+                image_output_path: Some(output_dir.to_path_buf()),
+                ..slide.render_options.clone()
+            };
+            let path = output_dir.join(file_name);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&path, slide.convert_to_md_with(&options)?)?;
+            paths.push(path);
+        }
+        Ok(paths)
+    }
+
+    /// Writes every table of the presentation as a CSV file into `output_dir`.
+    ///
+    /// The files are named `slide{number}_table{index}.csv` with a one-based table index per
+    /// slide, and the directory is created if needed. Returns the paths of the written files.
+    pub fn write_tables_csv(&self, output_dir: &Path) -> crate::Result<Vec<PathBuf>> {
+        fs::create_dir_all(output_dir)?;
+        let mut paths = Vec::new();
+        for slide in &self.slides {
+            let leaves = slide.elements.iter().flat_map(SlideElement::leaves);
+            let tables = leaves.filter_map(|element| match element {
+                SlideElement::Table(table, _, _) => Some(table),
+                _ => None,
+            });
+            for (index, table) in tables.enumerate() {
+                let path = output_dir.join(format!(
+                    "slide{}_table{}.csv",
+                    slide.slide_number,
+                    index + 1
+                ));
+                fs::write(&path, table.to_csv())?;
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Returns every hyperlink of all slides in order, independent of Markdown rendering.
+    ///
+    /// Adjacent runs with the same target form one hyperlink whose text is their joined text.
+    /// Links to other slides of the presentation are returned as [`HyperlinkTarget::Slide`].
+    pub fn hyperlinks(&self) -> Vec<Hyperlink> {
+        let mut hyperlinks: Vec<Hyperlink> = Vec::new();
+        for slide in &self.slides {
+            let mut current: Option<(String, Hyperlink)> = None;
+            slide.for_each_run(|run| {
+                let target = run.link_target.as_deref();
+                if current.as_ref().map(|(current, _)| current.as_str()) != target {
+                    hyperlinks.extend(current.take().map(|(_, hyperlink)| hyperlink));
+                }
+                if let Some(target) = target {
+                    current
+                        .get_or_insert_with(|| {
+                            let hyperlink = Hyperlink {
+                                slide_number: slide.slide_number,
+                                text: String::new(),
+                                target: HyperlinkTarget::parse(target),
+                            };
+                            (target.to_string(), hyperlink)
+                        })
+                        .1
+                        .text
+                        .push_str(&run.text);
+                }
+            });
+            hyperlinks.extend(current.map(|(_, hyperlink)| hyperlink));
+        }
+        for hyperlink in &mut hyperlinks {
+            hyperlink.text = hyperlink.text.trim().to_string();
+        }
+        hyperlinks
+    }
+
+    /// Returns the slide numbers, titles and section names of all slides in order.
+    ///
+    /// To build an outline without extracting images, use
+    /// [`crate::PresentationContainer::outline`].
+    pub fn outline(&self) -> Vec<OutlineEntry> {
+        self.slides
+            .iter()
+            .map(|slide| OutlineEntry {
+                slide_number: slide.slide_number,
+                title: slide.title(),
+                section: self
+                    .sections
+                    .iter()
+                    .find(|section| section.slide_numbers.contains(&slide.slide_number))
+                    .map(|section| section.name.clone()),
+            })
+            .collect()
+    }
+
+    /// Returns the title slide of the presentation.
+    ///
+    /// This is the first slide using a layout of type `title`. Without such a layout, the
+    /// first slide counts as title slide if it has a title and a subtitle, as ODP title
+    /// pages and PPTX slides based on custom layouts do.
+    pub fn title_slide(&self) -> Option<&crate::Slide> {
+        title_slide(&self.slides)
+    }
+
+    /// Returns the title of the document, as used for the headings of
+    /// [`crate::merge_to_md`].
+    ///
+    /// The title of the [title slide](Presentation::title_slide) is preferred, as the title
+    /// in the document properties is often missing or left at a template default. It falls
+    /// back to [`crate::PresentationMetadata::title`] and then to the title of the first
+    /// slide.
+    pub fn document_title(&self) -> Option<String> {
+        document_title(&self.metadata, &self.slides)
+    }
+}
+
+fn title_slide(slides: &[crate::Slide]) -> Option<&crate::Slide> {
+    slides
+        .iter()
+        .find(|slide| slide.layout_type.as_deref() == Some("title"))
+        .or_else(|| {
+            slides.first().filter(|slide| {
+                slide.title().is_some()
+                    && slide.blocks.iter().any(|block| {
+                        matches!(
+                            &block.content,
+                            SlideBlockContent::Text(text) if text.role == TextRole::Subtitle
+                        )
+                    })
+            })
+        })
+}
+
+/// Returns the [`Presentation::document_title`] of a deck with `metadata` and `slides`.
+pub(crate) fn document_title(
+    metadata: &crate::PresentationMetadata,
+    slides: &[crate::Slide],
+) -> Option<String> {
+    title_slide(slides)
+        .and_then(crate::Slide::title)
+        .or_else(|| metadata.title.clone())
+        .or_else(|| slides.first().and_then(crate::Slide::title))
+}
+
+/// A range of [`crate::Slide::text`] written in one language, as returned by
+/// [`crate::Slide::language_spans`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageSpan {
+    /// The `lang` tag of the runs, e.g. `de-DE`.
+    pub lang: String,
+    /// The byte range within the slide text.
+    pub range: std::ops::Range<usize>,
+}
+
+/// A hyperlink on a slide as returned by [`Presentation::hyperlinks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hyperlink {
+    pub slide_number: u32,
+    /// The anchor text of the link.
+    pub text: String,
+    pub target: HyperlinkTarget,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HyperlinkTarget {
+    /// A URL or file outside the presentation.
+    External(String),
+    /// Another slide of the presentation, by its slide number.
+    Slide(u32),
+}
+
+impl HyperlinkTarget {
+    fn parse(target: &str) -> Self {
+        target
+            .strip_prefix("#slide-")
+            .and_then(|number| number.parse().ok())
+            .map_or_else(|| Self::External(target.to_string()), Self::Slide)
+    }
+}
+
+/// Reduces a title or section name to lowercase words joined by `-` for use in a file name.
+fn file_name_part(value: Option<&str>) -> String {
+    let words: Vec<String> = value
+        .unwrap_or_default()
+        .split(|character: char| !character.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.is_empty() {
+        "untitled".to_string()
+    } else {
+        words.join("-")
+    }
+}
+
+/// Content statistics of a whole presentation as returned by [`Presentation::stats`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PresentationStats {
+    pub slide_count: usize,
+    pub word_count: usize,
+    /// The typefaces set on runs, including theme font references like `+mn-lt`.
+    pub fonts: BTreeSet<String>,
+    /// The language tags set on runs, e.g. `en-US`.
+    pub languages: BTreeSet<String>,
+    /// Media count and size per lowercase file extension, e.g. `png`.
+    pub media: BTreeMap<String, MediaStats>,
+    /// The number of skipped elements per tag, as in [`ParseReport::skipped_totals`].
+    pub unsupported_elements: BTreeMap<String, usize>,
+}
+
+/// The number and total size of the media parts of one type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MediaStats {
+    pub count: usize,
+    pub bytes: u64,
+}
+
+/// The elements that were skipped while parsing a presentation, see
+/// [`Presentation::parse_report`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ParseReport {
+    pub slides: Vec<SlideParseReport>,
+}
+
+impl ParseReport {
+    /// Returns whether every element of the presentation was parsed.
+    pub fn is_fully_supported(&self) -> bool {
+        self.slides.iter().all(|slide| slide.skipped.is_empty())
+    }
+
+    /// Returns the number of skipped elements per tag across all slides.
+    pub fn skipped_totals(&self) -> BTreeMap<String, usize> {
+        let mut totals = BTreeMap::new();
+        for (tag, count) in self.slides.iter().flat_map(|slide| &slide.skipped) {
+            *totals.entry(tag.clone()).or_insert(0) += count;
+        }
+        totals
+    }
+}
+
+/// The elements skipped on one slide, counted per tag.
+///
+/// Graphic frames are counted together with their content type, e.g. `graphicFrame/chart`
+/// or `graphicFrame/diagram` for SmartArt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SlideParseReport {
+    pub slide_number: u32,
+    pub skipped: BTreeMap<String, usize>,
+}
+
+/// A named section of a presentation and the numbers of the slides it contains.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PresentationSection {
+    pub name: String,
+    pub slide_numbers: Vec<u32>,
+}
+
+impl PresentationSection {
+    /// Returns the range from the lowest to the highest slide number of the section, or `None`
+    /// for an empty section.
+    pub fn slide_range(&self) -> Option<std::ops::RangeInclusive<u32>> {
+        let first = *self.slide_numbers.iter().min()?;
+        let last = *self.slide_numbers.iter().max()?;
+        Some(first..=last)
+    }
+}
+
+/// A color of a slide background or theme.
+///
+/// Color transformations such as `lumMod` or `alpha` are not applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Color {
+    /// A hex `RRGGBB` value.
+    Rgb(String),
+    /// A theme color such as `accent1` that was not resolved against the theme.
+    Scheme(String),
+}
+
+/// The fill of a slide background, see [`crate::Slide::background`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Background {
+    Solid(Color),
+    Gradient(Vec<GradientStop>),
+    /// An image fill, holding the package path of the image, e.g. `ppt/media/image3.png`.
+    Image(String),
+}
+
+/// A color stop of a [`Background::Gradient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GradientStop {
+    /// The position of the stop in thousandths of a percent, from `0` to `100000`.
+    pub position: u32,
+    pub color: Color,
+}
+
+/// The name and colors of the theme a slide is based on, see [`crate::Slide::theme`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ThemeSummary {
+    pub name: Option<String>,
+    pub color_scheme: Option<String>,
+    /// The theme colors `accent1` to `accent6` in this order.
+    pub accents: Vec<Color>,
+}
+
+/// A single entry of [`Presentation::outline`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    pub slide_number: u32,
+    pub title: Option<String>,
+    pub section: Option<String>,
+}
+
+/// An embedded workbook holding the source data of a chart, as returned by
+/// [`crate::PptxContainer::chart_workbooks`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChartWorkbook {
+    pub slide_number: u32,
+    /// Relationship id of the chart on the slide, as referenced by its graphic frame.
+    pub chart_relationship_id: String,
+    /// Archive path of the chart part, e.g. `ppt/charts/chart1.xml`.
+    pub chart_path: String,
+    /// Archive path of the workbook, e.g. `ppt/embeddings/Microsoft_Excel_Worksheet.xlsx`.
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+/// An OLE object embedded in a graphic frame of a slide, e.g. an Excel worksheet or a Word
+/// document, as returned by [`crate::PptxContainer::embedded_objects`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedObject {
+    pub slide_number: u32,
+    /// Relationship id of the object on the slide, as referenced by its `p:oleObj`.
+    pub relationship_id: String,
+    /// The ProgID of the application that created the object, e.g. `Excel.Sheet.12`.
+    pub prog_id: Option<String>,
+    /// The display name of the object, e.g. `Worksheet`.
+    pub name: Option<String>,
+    /// Archive path of the embedded file, e.g. `ppt/embeddings/Microsoft_Excel_Worksheet.xlsx`
+    /// or `ppt/embeddings/oleObject1.bin` for objects stored as OLE compound files.
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+/// A media part of the package, as returned by [`crate::PptxContainer::media_inventory`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediaItem {
+    /// Archive path of the part, e.g. `ppt/media/image1.png`.
+    pub path: String,
+    /// Uncompressed size in bytes.
+    pub size: u64,
+    /// MIME type detected from the leading bytes of the part, falling back to its extension.
+    pub mime_type: Option<String>,
+    /// Numbers of the slides referencing the part, in ascending order.
+    pub slides: Vec<u32>,
+}
+
+/// A connector line between two points of a slide, see [`SlideElement::Connector`].
+#[derive(Debug, Clone, Default)]
+pub struct ConnectorElement {
+    /// The start point in EMU. The flips of the connector's `a:xfrm` decide which corner of
+    /// its bounding box the line starts at.
+    pub start: (i64, i64),
+    /// The end point in EMU, where PowerPoint draws the arrowhead of a default arrow.
+    pub end: (i64, i64),
+    /// The shape id of the shape the start is attached to, from `a:stCxn`.
+    pub start_shape_id: Option<u32>,
+    /// The shape id of the shape the end is attached to, from `a:endCxn`.
+    pub end_shape_id: Option<u32>,
+    /// The text body of the connector. PowerPoint places connector labels in separate text
+    /// boxes, so this is only set for decks written by other applications.
+    pub text: Option<TextElement>,
+}
+
+/// Whether a [`MediaReference`] is a video or an audio clip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaKind {
+    Video,
+    Audio,
+}
+
+/// A video or audio clip on a slide, see [`SlideElement::Media`].
+#[derive(Debug, Clone)]
+pub struct MediaReference {
+    pub kind: MediaKind,
+    /// The relationship id of the `a:videoFile` or `a:audioFile` link.
+    pub id: String,
+    /// The package path of embedded media, e.g. `ppt/media/media1.mp4`, or the URL of
+    /// linked media.
+    pub target: String,
+    /// Whether the media is linked from outside the package.
+    pub external: bool,
+    /// The MIME type detected from the media data or the extension of the target.
+    pub mime_type: Option<String>,
+    /// The poster frame shown on the slide in place of the clip.
+    pub poster: Option<ImageReference>,
+    /// The embedded media data, only read with [`crate::ParserConfig::extract_media`].
+    pub data: Option<Vec<u8>>,
+}
+
 #[derive(Debug, Clone)]
 pub enum SlideElement {
-    Text(TextElement, ElementPosition),
-    Table(TableElement, ElementPosition),
-    Image(ImageReference, ElementPosition),
-    List(ListElement, ElementPosition),
+    Text(TextElement, ElementPosition, ElementMeta),
+    Table(TableElement, ElementPosition, ElementMeta),
+    Image(ImageReference, ElementPosition, ElementMeta),
+    List(ListElement, ElementPosition, ElementMeta),
+    /// A chart in a graphic frame, with the data cached in its chart part.
+    Chart(ChartElement, ElementPosition, ElementMeta),
+    /// A video or audio clip inserted as a PPTX picture with an `a:videoFile` or
+    /// `a:audioFile` link.
+    Media(MediaReference, ElementPosition, ElementMeta),
+    /// A connector line (`p:cxnSp`), such as an arrow of a flow diagram.
+    Connector(ConnectorElement, ElementPosition, ElementMeta),
+    /// A group shape (`p:grpSp`) with its child elements in document order. Only produced
+    /// with [`crate::ParserConfig::preserve_groups`]; otherwise the children are flattened
+    /// into the surrounding element list.
+    Group {
+        children: Vec<SlideElement>,
+        /// The position of the group's top left corner.
+        position: ElementPosition,
+        meta: ElementMeta,
+        /// The name of the group shape from its `p:cNvPr`, such as `Group 5`.
+        name: Option<String>,
+    },
     Unknown,
 }
 
 impl SlideElement {
     pub fn position(&self) -> ElementPosition {
         match self {
-            SlideElement::Text(_, pos)
-            | SlideElement::Image(_, pos)
-            | SlideElement::List(_, pos)
-            | SlideElement::Table(_, pos) => *pos,
+            SlideElement::Text(_, pos, _)
+            | SlideElement::Image(_, pos, _)
+            | SlideElement::List(_, pos, _)
+            | SlideElement::Table(_, pos, _)
+            | SlideElement::Chart(_, pos, _)
+            | SlideElement::Media(_, pos, _)
+            | SlideElement::Connector(_, pos, _)
+            | SlideElement::Group { position: pos, .. } => *pos,
             SlideElement::Unknown => ElementPosition::default(),
         }
     }
+
+    /// Returns the identity and source metadata of the element.
+    pub fn meta(&self) -> ElementMeta {
+        match self {
+            SlideElement::Text(_, _, meta)
+            | SlideElement::Image(_, _, meta)
+            | SlideElement::List(_, _, meta)
+            | SlideElement::Table(_, _, meta)
+            | SlideElement::Chart(_, _, meta)
+            | SlideElement::Media(_, _, meta)
+            | SlideElement::Connector(_, _, meta)
+            | SlideElement::Group { meta, .. } => *meta,
+            SlideElement::Unknown => ElementMeta::default(),
+        }
+    }
+
+    /// Returns the child elements of a [`SlideElement::Group`], or an empty slice for every
+    /// other element.
+    pub fn children(&self) -> &[SlideElement] {
+        match self {
+            SlideElement::Group { children, .. } => children,
+            _ => &[],
+        }
+    }
+
+    /// Returns the element itself, or the non-group elements nested in a
+    /// [`SlideElement::Group`] in document order.
+    pub fn leaves(&self) -> Vec<&SlideElement> {
+        match self {
+            SlideElement::Group { children, .. } => {
+                children.iter().flat_map(SlideElement::leaves).collect()
+            }
+            element => vec![element],
+        }
+    }
+
+    pub(crate) fn leaves_mut(&mut self) -> Vec<&mut SlideElement> {
+        match self {
+            SlideElement::Group { children, .. } => children
+                .iter_mut()
+                .flat_map(SlideElement::leaves_mut)
+                .collect(),
+            element => vec![element],
+        }
+    }
+
+    /// Returns the stable identifier of the element, which is `None` only for
+    /// [`SlideElement::Unknown`].
+    pub fn id(&self) -> Option<ElementId> {
+        self.meta().id
+    }
+
+    pub(crate) fn meta_mut(&mut self) -> Option<&mut ElementMeta> {
+        match self {
+            SlideElement::Text(_, _, meta)
+            | SlideElement::Image(_, _, meta)
+            | SlideElement::List(_, _, meta)
+            | SlideElement::Table(_, _, meta)
+            | SlideElement::Chart(_, _, meta)
+            | SlideElement::Media(_, _, meta)
+            | SlideElement::Connector(_, _, meta)
+            | SlideElement::Group { meta, .. } => Some(meta),
+            SlideElement::Unknown => None,
+        }
+    }
+}
+
+/// The source shape of an element, see [`ElementId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum ShapeRef {
+    /// The `id` of the shape's `p:cNvPr`, which is unique within a PPTX slide.
+    ShapeId(u32),
+    /// The index of the element on its slide, for shapes without an id such as ODP frames.
+    Index(u32),
+}
+
+/// A stable identifier of a slide element, composed of the slide number and its source shape.
+///
+/// Parsing the same file twice yields the same ids, so Markdown fragments can be correlated
+/// with their source shapes. When a shape produces several elements, e.g. an introduction
+/// paragraph followed by a list, `part` counts them in order. The id formats as
+/// `slide3/shape5`, `slide3/shape5.1` or `slide3/index2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ElementId {
+    pub slide_number: u32,
+    pub shape: ShapeRef,
+    pub part: u32,
+}
+
+impl std::fmt::Display for ElementId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.shape {
+            ShapeRef::ShapeId(id) => write!(f, "slide{}/shape{id}", self.slide_number)?,
+            ShapeRef::Index(index) => write!(f, "slide{}/index{index}", self.slide_number)?,
+        }
+        if self.part > 0 {
+            write!(f, ".{}", self.part)?;
+        }
+        Ok(())
+    }
+}
+
+/// Assigns the slide number and part of every element id, and index-based ids to elements
+/// whose shape has no id.
+pub(crate) fn assign_element_ids(elements: &mut [SlideElement], slide_number: u32) {
+    let mut parts: std::collections::HashMap<ShapeRef, u32> = std::collections::HashMap::new();
+    let mut index = 0;
+    assign_ids(elements, slide_number, &mut parts, &mut index);
+}
+
+/// Assigns the ids of [`assign_element_ids`], numbering groups before their children.
+fn assign_ids(
+    elements: &mut [SlideElement],
+    slide_number: u32,
+    parts: &mut std::collections::HashMap<ShapeRef, u32>,
+    index: &mut u32,
+) {
+    for element in elements {
+        if let Some(meta) = element.meta_mut() {
+            let shape = meta
+                .id
+                .map(|id| id.shape)
+                .unwrap_or(ShapeRef::Index(*index));
+            let part = parts.entry(shape).or_insert(0);
+            meta.id = Some(ElementId {
+                slide_number,
+                shape,
+                part: *part,
+            });
+            *part += 1;
+        }
+        *index += 1;
+        if let SlideElement::Group { children, .. } = element {
+            assign_ids(children, slide_number, parts, index);
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ImageReference {
     pub id: String,
     pub target: String,
+    /// The pixel size and format read from the image header, if the image data was extracted
+    /// and its format is supported.
+    pub dimensions: Option<ImageDimensions>,
+    /// The width and height of the picture on the slide in EMU, taken from its `a:xfrm` or
+    /// the frame of an ODP image.
+    pub display_size: Option<(i64, i64)>,
+    /// The resolved target of a hyperlink on the picture, such as a clickable logo, in the
+    /// form of [`Run::link_target`].
+    pub link_target: Option<String>,
+    /// The alternative text of the picture, from the `descr` attribute of its `p:cNvPr`.
+    pub description: Option<String>,
+    /// The title of the picture, from the `title` attribute of its `p:cNvPr`.
+    pub title: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+impl ImageReference {
+    /// Returns the text describing the picture for readers who cannot see it: its
+    /// [`ImageReference::description`], falling back to its [`ImageReference::title`].
+    pub fn alt_text(&self) -> Option<&str> {
+        self.description.as_deref().or(self.title.as_deref())
+    }
+}
+
+/// The size and format of an image, read from its header without decoding the image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageDimensions {
+    pub width: u32,
+    pub height: u32,
+    /// The preferred file extension of the detected format, e.g. `png` or `jpg`.
+    pub format: String,
+}
+
+/// The paragraphs of a text shape, speaker notes frame or comment.
+#[derive(Debug, Clone, Default)]
 pub struct TextElement {
-    pub runs: Vec<Run>,
+    pub paragraphs: Vec<Paragraph>,
+}
+
+impl TextElement {
+    /// Creates a text element from paragraphs, keeping their alignment and list properties.
+    pub fn from_paragraphs(paragraphs: Vec<Paragraph>) -> Self {
+        Self { paragraphs }
+    }
+
+    /// Creates a text element from flat runs in which a line break at the end of a run ends
+    /// a paragraph, the inverse of [`TextElement::runs`].
+    pub fn from_runs(runs: Vec<Run>) -> Self {
+        let mut paragraphs = Vec::new();
+        let mut current = Vec::new();
+        for mut run in runs {
+            let ends_paragraph = run.text.ends_with('\n');
+            if ends_paragraph {
+                run.text.pop();
+            }
+            current.push(run);
+            if ends_paragraph {
+                paragraphs.push(Paragraph::plain(std::mem::take(&mut current)));
+            }
+        }
+        if !current.is_empty() {
+            paragraphs.push(Paragraph::plain(current));
+        }
+        Self { paragraphs }
+    }
+
+    /// Flattens the paragraphs into one sequence of runs, ending every paragraph with a line
+    /// break in the text of its last run.
+    ///
+    /// This is the shape of the `runs` field of `TextElement` before it held paragraphs.
+    pub fn runs(&self) -> Vec<Run> {
+        let mut runs = Vec::new();
+        for paragraph in &self.paragraphs {
+            runs.extend(paragraph.runs.iter().cloned());
+            if !paragraph.runs.is_empty()
+                && let Some(last) = runs.last_mut()
+            {
+                last.text.push('\n');
+            }
+        }
+        runs
+    }
+
+    /// Iterates over the runs of all paragraphs without adding line breaks.
+    pub fn iter_runs(&self) -> impl Iterator<Item = &Run> {
+        self.paragraphs
+            .iter()
+            .flat_map(|paragraph| paragraph.runs.iter())
+    }
+
+    /// Returns whether no paragraph has any runs.
+    pub fn is_empty(&self) -> bool {
+        self.paragraphs
+            .iter()
+            .all(|paragraph| paragraph.runs.is_empty())
+    }
+
+    /// Returns the text of the paragraphs, separated by line breaks.
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .filter(|paragraph| !paragraph.runs.is_empty())
+            .map(Paragraph::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// The speaker notes of a slide, as returned by [`crate::Slide::notes`].
+#[derive(Debug, Clone, Copy)]
+pub struct NotesElement<'a> {
+    /// The text frames of the notes page, with their paragraphs, runs and formatting.
+    pub text_frames: &'a [TextElement],
+    /// The pictures pasted into the notes page.
+    pub images: &'a [ImageBlock],
+}
+
+impl<'a> NotesElement<'a> {
+    /// Iterates over the runs of all text frames without adding line breaks.
+    pub fn iter_runs(&self) -> impl Iterator<Item = &'a Run> {
+        self.text_frames.iter().flat_map(TextElement::iter_runs)
+    }
+
+    /// Returns the text of the notes, with paragraphs and text frames separated by line
+    /// breaks.
+    pub fn text(&self) -> String {
+        self.text_frames
+            .iter()
+            .filter(|element| !element.is_empty())
+            .map(TextElement::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
 
 #[derive(Debug, Default, Clone)]
@@ -46,6 +834,75 @@ pub struct Formatting {
     pub baseline: Baseline,
     pub font_size_points: Option<f32>,
     pub lang: String,
+    /// The typeface of the run, e.g. `Calibri` or a theme font reference like `+mn-lt`.
+    pub font: Option<String>,
+    /// The solid fill color of the text. Theme colors are kept as [`Color::Scheme`].
+    pub color: Option<Color>,
+}
+
+/// Run formatting in which unset properties are inherited from an enclosing style.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PartialFormatting {
+    pub(crate) bold: Option<bool>,
+    pub(crate) italic: Option<bool>,
+    pub(crate) underlined: Option<bool>,
+    pub(crate) strikethrough: Option<bool>,
+    pub(crate) baseline: Option<Baseline>,
+    pub(crate) font_size_points: Option<f32>,
+    pub(crate) lang: Option<String>,
+    pub(crate) font: Option<String>,
+    pub(crate) color: Option<Color>,
+}
+
+impl PartialFormatting {
+    pub(crate) fn merge_into(&self, formatting: &mut Formatting) {
+        if let Some(value) = self.bold {
+            formatting.bold = value;
+        }
+        if let Some(value) = self.italic {
+            formatting.italic = value;
+        }
+        if let Some(value) = self.underlined {
+            formatting.underlined = value;
+        }
+        if let Some(value) = self.strikethrough {
+            formatting.strikethrough = value;
+        }
+        if let Some(value) = self.baseline {
+            formatting.baseline = value;
+        }
+        if let Some(value) = self.font_size_points {
+            formatting.font_size_points = Some(value);
+        }
+        if let Some(value) = &self.lang {
+            formatting.lang = value.clone();
+        }
+        if let Some(value) = &self.font {
+            formatting.font = Some(value.clone());
+        }
+        if let Some(value) = &self.color {
+            formatting.color = Some(value.clone());
+        }
+    }
+
+    /// Overrides the properties of `self` with every property set in `other`.
+    pub(crate) fn merge(&mut self, other: &PartialFormatting) {
+        self.bold = other.bold.or(self.bold);
+        self.italic = other.italic.or(self.italic);
+        self.underlined = other.underlined.or(self.underlined);
+        self.strikethrough = other.strikethrough.or(self.strikethrough);
+        self.baseline = other.baseline.or(self.baseline);
+        self.font_size_points = other.font_size_points.or(self.font_size_points);
+        if other.lang.is_some() {
+            self.lang.clone_from(&other.lang);
+        }
+        if other.font.is_some() {
+            self.font.clone_from(&other.font);
+        }
+        if other.color.is_some() {
+            self.color.clone_from(&other.color);
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -76,6 +933,140 @@ impl Run {
 #[derive(Debug, Clone)]
 pub struct TableElement {
     pub rows: Vec<TableRow>,
+    /// Whether the first row is formatted as a header row.
+    pub has_header: bool,
+    /// The widths of the table columns in EMU, from the `a:gridCol` elements of a PPTX
+    /// table. Empty for ODP tables.
+    pub column_widths: Vec<i64>,
+}
+
+impl TableElement {
+    /// Returns the table as comma-separated values, one line per row.
+    ///
+    /// Fields containing the delimiter, a quote or a line break are quoted, with quotes
+    /// doubled. Cells covered by a merged cell are empty.
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
+
+    /// Returns the table as tab-separated values, quoted like [`TableElement::to_csv`].
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
+
+    fn to_delimited(&self, delimiter: char) -> String {
+        let mut output = String::new();
+        for row in &self.rows {
+            for (index, cell) in row.cells.iter().enumerate() {
+                if index > 0 {
+                    output.push(delimiter);
+                }
+                let text = if cell.covered {
+                    String::new()
+                } else {
+                    cell.text()
+                };
+                let text = text.trim_end_matches(['\n', '\r']);
+                if text.contains([delimiter, '"', '\n', '\r']) {
+                    output.push('"');
+                    output.push_str(&text.replace('"', "\"\""));
+                    output.push('"');
+                } else {
+                    output.push_str(text);
+                }
+            }
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// The data of a chart, read from the cached values of its chart part
+/// (`ppt/charts/chartN.xml`).
+///
+/// Charts keep a copy of their source data next to the embedded workbook, so the data is
+/// available without opening the workbook, see [`crate::PptxContainer::chart_workbooks`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChartElement {
+    /// The relationship id of the chart part in the slide, matching
+    /// [`crate::ChartWorkbook::chart_relationship_id`].
+    pub relationship_id: String,
+    /// The local name of the first plot of the chart, e.g. `barChart` or `pieChart`.
+    pub chart_type: Option<String>,
+    /// The chart title, if it has one.
+    pub title: Option<String>,
+    /// The category labels, or for scatter charts the x values, of the first series.
+    pub categories: Vec<String>,
+    /// The series of all plots in document order.
+    pub series: Vec<ChartSeries>,
+}
+
+/// A data series of a [`ChartElement`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChartSeries {
+    pub name: Option<String>,
+    /// The values per category. Missing data points are `None`.
+    pub values: Vec<Option<f64>>,
+}
+
+impl ChartElement {
+    /// Returns the chart data as a table with one row per category and one column per
+    /// series.
+    ///
+    /// The header row holds the series names, falling back to `Series N`. Categories
+    /// without a label are numbered from 1.
+    pub fn to_table(&self) -> TableElement {
+        let cell = |text: String| {
+            TableCell::from_runs(vec![Run {
+                text,
+                formatting: Formatting::default(),
+                link_target: None,
+            }])
+        };
+        let mut header = vec![cell(String::new())];
+        header.extend(self.series.iter().enumerate().map(|(index, series)| {
+            cell(
+                series
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| format!("Series {}", index + 1)),
+            )
+        }));
+        let count = self
+            .series
+            .iter()
+            .map(|series| series.values.len())
+            .chain([self.categories.len()])
+            .max()
+            .unwrap_or(0);
+        let mut rows = vec![TableRow { cells: header }];
+        for index in 0..count {
+            let category = self
+                .categories
+                .get(index)
+                .filter(|category| !category.is_empty())
+                .cloned()
+                .unwrap_or_else(|| (index + 1).to_string());
+            let mut cells = vec![cell(category)];
+            cells.extend(self.series.iter().map(|series| {
+                cell(
+                    series
+                        .values
+                        .get(index)
+                        .copied()
+                        .flatten()
+                        .map(|value| value.to_string())
+                        .unwrap_or_default(),
+                )
+            }));
+            rows.push(TableRow { cells });
+        }
+        TableElement {
+            rows,
+            has_header: true,
+            column_widths: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -83,15 +1074,67 @@ pub struct TableRow {
     pub cells: Vec<TableCell>,
 }
 
+/// A cell of a [`TableElement`].
+///
+/// Like [`TextElement`], a cell holds its paragraphs with their alignment and list
+/// properties, so renderers can join them with `<br>` or render lists inside cells.
 #[derive(Debug, Clone, Default)]
 pub struct TableCell {
-    pub runs: Vec<Run>,
     pub paragraphs: Vec<Paragraph>,
     pub row_span: usize,
     pub column_span: usize,
     pub covered: bool,
 }
 
+impl TableCell {
+    /// Creates a cell spanning one row and column from flat runs in which a line break at
+    /// the end of a run ends a paragraph, the inverse of [`TableCell::runs`].
+    pub fn from_runs(runs: Vec<Run>) -> Self {
+        Self {
+            paragraphs: TextElement::from_runs(runs).paragraphs,
+            row_span: 1,
+            column_span: 1,
+            covered: false,
+        }
+    }
+
+    /// Flattens the paragraphs into one sequence of runs, separating paragraphs with a line
+    /// break at the end of the last run of every paragraph but the last one.
+    ///
+    /// This is the shape of the `runs` field of `TableCell` before it held only paragraphs.
+    pub fn runs(&self) -> Vec<Run> {
+        let mut runs: Vec<Run> = Vec::new();
+        for paragraph in self
+            .paragraphs
+            .iter()
+            .filter(|paragraph| !paragraph.runs.is_empty())
+        {
+            if let Some(last) = runs.last_mut() {
+                last.text.push('\n');
+            }
+            runs.extend(paragraph.runs.iter().cloned());
+        }
+        runs
+    }
+
+    /// Iterates over the runs of all paragraphs without adding line breaks.
+    pub fn iter_runs(&self) -> impl Iterator<Item = &Run> {
+        self.paragraphs
+            .iter()
+            .flat_map(|paragraph| paragraph.runs.iter())
+    }
+
+    /// Returns the text of the paragraphs, separated by line breaks.
+    pub fn text(&self) -> String {
+        self.paragraphs
+            .iter()
+            .filter(|paragraph| !paragraph.runs.is_empty())
+            .map(Paragraph::text)
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ListElement {
     pub items: Vec<ListItem>,
@@ -102,12 +1145,138 @@ pub struct ListItem {
     pub level: u32,
     pub is_ordered: bool,
     pub runs: Vec<Run>,
+    /// The alignment and indentation of the paragraph the item was read from.
+    pub properties: ParagraphProperties,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub struct ElementPosition {
     pub x: i64,
     pub y: i64,
+    /// The width of the element in EMU, from the `cx` of its `a:ext`. `0` if unknown.
+    pub width: i64,
+    /// The height of the element in EMU, from the `cy` of its `a:ext`. `0` if unknown.
+    pub height: i64,
+}
+
+/// The identity and source metadata of a slide element, kept apart from its
+/// [`ElementPosition`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ElementMeta {
+    /// The stable identifier of the element, see [`SlideElement::id`].
+    pub id: Option<ElementId>,
+    /// The index of the source shape in the document order of the slide's shape tree, equal
+    /// to the [`SlideBlock::source_order`] of its block, if it has one. Groups are counted
+    /// through even when they are flattened, so sorting by it restores the authoring order
+    /// after the reading order was applied. `None` for elements built by hand or read with
+    /// the standalone [`crate::parse_xml::parse_slide_xml`] functions.
+    pub source_order: Option<usize>,
+    /// The placeholder type of the source shape, for shapes with a `p:ph`.
+    pub placeholder: Option<PlaceholderType>,
+}
+
+/// The type of a placeholder shape, from the `type` attribute of its `p:ph`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaceholderType {
+    /// `title`
+    Title,
+    /// `ctrTitle`, the title of a title slide.
+    CenteredTitle,
+    /// `subTitle`
+    Subtitle,
+    /// `body`
+    Body,
+    /// `obj`, a content placeholder. Placeholders without a type are content placeholders.
+    Object,
+    /// `chart`
+    Chart,
+    /// `tbl`
+    Table,
+    /// `clipArt`
+    ClipArt,
+    /// `dgm`, a SmartArt diagram.
+    Diagram,
+    /// `media`
+    Media,
+    /// `pic`
+    Picture,
+    /// `sldImg`, the slide image on a notes page.
+    SlideImage,
+    /// `dt`
+    Date,
+    /// `ftr`
+    Footer,
+    /// `hdr`
+    Header,
+    /// `sldNum`
+    SlideNumber,
+}
+
+impl PlaceholderType {
+    /// Parses the `type` attribute of a `p:ph`, where a missing attribute means
+    /// [`PlaceholderType::Object`]. Returns `None` for unknown types.
+    pub fn from_attr(value: Option<&str>) -> Option<Self> {
+        Some(match value {
+            Some("title") => Self::Title,
+            Some("ctrTitle") => Self::CenteredTitle,
+            Some("subTitle") => Self::Subtitle,
+            Some("body") => Self::Body,
+            Some("obj") | None => Self::Object,
+            Some("chart") => Self::Chart,
+            Some("tbl") => Self::Table,
+            Some("clipArt") => Self::ClipArt,
+            Some("dgm") => Self::Diagram,
+            Some("media") => Self::Media,
+            Some("pic") => Self::Picture,
+            Some("sldImg") => Self::SlideImage,
+            Some("dt") => Self::Date,
+            Some("ftr") => Self::Footer,
+            Some("hdr") => Self::Header,
+            Some("sldNum") => Self::SlideNumber,
+            Some(_) => return None,
+        })
+    }
+
+    /// Returns whether the placeholder holds a slide title.
+    pub fn is_title(self) -> bool {
+        matches!(self, Self::Title | Self::CenteredTitle)
+    }
+}
+
+/// English Metric Units per inch, the unit of all positions and sizes in PPTX.
+pub const EMU_PER_INCH: i64 = 914_400;
+/// English Metric Units per typographic point.
+pub const EMU_PER_POINT: i64 = 12_700;
+
+impl ElementPosition {
+    /// Returns `(x, y)` in typographic points.
+    pub fn to_points(&self) -> (f64, f64) {
+        (
+            self.x as f64 / EMU_PER_POINT as f64,
+            self.y as f64 / EMU_PER_POINT as f64,
+        )
+    }
+
+    /// Returns `(x, y)` in pixels at the given resolution in dots per inch.
+    pub fn to_pixels(&self, dpi: f64) -> (f64, f64) {
+        (
+            self.x as f64 * dpi / EMU_PER_INCH as f64,
+            self.y as f64 * dpi / EMU_PER_INCH as f64,
+        )
+    }
+
+    /// Returns `(x, y)` as percentages of the slide width and height, both given in EMU.
+    ///
+    /// Returns `None` if a dimension of the slide is not positive.
+    pub fn to_percent(&self, slide_size: (i64, i64)) -> Option<(f64, f64)> {
+        let (width, height) = slide_size;
+        (width > 0 && height > 0).then(|| {
+            (
+                self.x as f64 * 100.0 / width as f64,
+                self.y as f64 * 100.0 / height as f64,
+            )
+        })
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -123,8 +1292,8 @@ impl From<ElementPosition> for Bounds {
         Self {
             x: position.x,
             y: position.y,
-            width: 0,
-            height: 0,
+            width: position.width,
+            height: position.height,
         }
     }
 }
@@ -149,10 +1318,28 @@ pub enum ParagraphAlignment {
     Justify,
 }
 
+/// The layout properties of a paragraph, see [`Paragraph::properties`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParagraphProperties {
+    pub alignment: ParagraphAlignment,
+    /// The left margin of the paragraph in EMU, from the `marL` attribute of a PPTX `a:pPr`.
+    pub margin_left: Option<i64>,
+    /// The indentation of the first line in EMU relative to `margin_left`, from the `indent`
+    /// attribute. Negative values produce a hanging indent.
+    pub indent: Option<i64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ListKind {
-    Bullet { character: Option<String> },
-    Ordered { style: Option<String>, start: u32 },
+    Bullet {
+        character: Option<String>,
+    },
+    /// `start` is the `startAt` number of the list, or `None` if the list does not set one
+    /// and starts at 1.
+    Ordered {
+        style: Option<String>,
+        start: Option<u32>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -167,6 +1354,10 @@ pub struct Paragraph {
     pub alignment: ParagraphAlignment,
     pub list: Option<ListInfo>,
     pub list_explicit: bool,
+    /// The left margin in EMU, see [`ParagraphProperties::margin_left`].
+    pub margin_left: Option<i64>,
+    /// The first line indentation in EMU, see [`ParagraphProperties::indent`].
+    pub indent: Option<i64>,
 }
 
 impl Paragraph {
@@ -180,12 +1371,24 @@ impl Paragraph {
     pub fn text(&self) -> String {
         self.runs.iter().map(|run| run.text.as_str()).collect()
     }
+
+    /// Returns the alignment and indentation of the paragraph.
+    pub fn properties(&self) -> ParagraphProperties {
+        ParagraphProperties {
+            alignment: self.alignment,
+            margin_left: self.margin_left,
+            indent: self.indent,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct TextBlock {
     pub role: TextRole,
     pub paragraphs: Vec<Paragraph>,
+    /// Whether the text repeats content of the slide layout or master, such as prompt text
+    /// or recurring decorative text.
+    pub from_template: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -204,6 +1407,10 @@ pub struct SemanticTableRow {
 #[derive(Debug, Clone, Default)]
 pub struct SemanticTable {
     pub rows: Vec<SemanticTableRow>,
+    /// Whether the first row is formatted as a header row.
+    pub has_header: bool,
+    /// The widths of the table columns in EMU, see [`TableElement::column_widths`].
+    pub column_widths: Vec<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -217,6 +1424,19 @@ pub struct ImageBlock {
 pub struct UnsupportedBlock {
     pub kind: String,
     pub fallback_text: Option<String>,
+    /// The type of the embedded content, e.g. `chart`, `diagram` (SmartArt) or `ole` for a
+    /// PPTX graphic frame.
+    pub content_type: Option<String>,
+}
+
+impl UnsupportedBlock {
+    /// The name under which the element is counted in a [`SlideParseReport`].
+    pub fn report_key(&self) -> String {
+        match &self.content_type {
+            Some(content_type) => format!("{}/{content_type}", self.kind),
+            None => self.kind.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -231,6 +1451,9 @@ pub enum SlideBlockContent {
 pub struct SlideBlock {
     pub bounds: Bounds,
     pub source_order: usize,
+    /// The XML of the source element, retained if [`crate::ParserConfig::preserve_raw_xml`]
+    /// is set. Only PPTX slides keep their raw XML.
+    pub raw: Option<String>,
     pub content: SlideBlockContent,
 }
 
@@ -248,6 +1471,25 @@ pub struct MarkdownOptions {
     pub include_speaker_notes: bool,
     pub include_comments: bool,
     pub render_unsupported_comments: bool,
+    pub exclude_template_text: bool,
+    pub legacy_list_classification: bool,
+    /// Whether consecutive ordered lists of different text blocks continue their numbering,
+    /// see [`crate::RenderOptionsBuilder::continue_list_numbering`].
+    pub continue_list_numbering: bool,
+    pub underline_style: crate::UnderlineStyle,
+    /// Whether bold and italic text is rendered as `<strong>` and `<em>`.
+    pub html_emphasis: bool,
+    /// Heading template emitted at the top of every slide, see
+    /// [`crate::RenderOptionsBuilder::slide_heading`].
+    pub slide_heading: Option<String>,
+    /// Where the `<a id="slide-n"></a>` anchor of every slide is emitted. With
+    /// [`crate::SlideAnchors::TitleOrStandalone`], slides without a title or slide heading
+    /// start with a standalone anchor, so every slide-jump link resolves.
+    pub slide_anchors: crate::SlideAnchors,
+    pub paragraph_spacing: crate::ParagraphSpacing,
+    /// The heading level of title placeholders, see
+    /// [`crate::RenderOptionsBuilder::title_heading_level`].
+    pub title_heading_level: u8,
 }
 
 impl Default for MarkdownOptions {
@@ -258,10 +1500,50 @@ impl Default for MarkdownOptions {
             include_speaker_notes: false,
             include_comments: false,
             render_unsupported_comments: true,
+            exclude_template_text: false,
+            legacy_list_classification: false,
+            continue_list_numbering: false,
+            underline_style: crate::UnderlineStyle::U,
+            html_emphasis: false,
+            slide_heading: None,
+            slide_anchors: crate::SlideAnchors::Title,
+            paragraph_spacing: crate::ParagraphSpacing::BlankLine,
+            title_heading_level: 2,
         }
     }
 }
 
+/// The kind of content that dominates a slide, see [`SlideStats::dominant_content`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ContentDominance {
+    #[default]
+    Empty,
+    Text,
+    Image,
+}
+
+/// Content statistics of a single slide as returned by [`crate::Slide::stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SlideStats {
+    pub word_count: usize,
+    pub character_count: usize,
+    pub run_count: usize,
+    pub list_count: usize,
+    pub table_count: usize,
+    pub image_count: usize,
+    pub dominant_content: ContentDominance,
+}
+
+impl SlideStats {
+    pub fn is_text_dominant(&self) -> bool {
+        self.dominant_content == ContentDominance::Text
+    }
+
+    pub fn is_image_dominant(&self) -> bool {
+        self.dominant_content == ContentDominance::Image
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiagnosticSeverity {
     Warning,
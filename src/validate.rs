@@ -0,0 +1,162 @@
+use crate::constants::IMAGE_NAMESPACE;
+use crate::parse_rels::parse_relationships;
+use crate::xml::{event, reader};
+use crate::{PptxContainer, Result};
+use quick_xml::events::Event;
+
+/// The parts every presentation package needs.
+const REQUIRED_PARTS: [&str; 3] = ["[Content_Types].xml", "_rels/.rels", "ppt/presentation.xml"];
+
+/// The outcome of [`PptxContainer::validate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Returns whether no issues were found.
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(&mut self, kind: ValidationIssueKind, part: &str, message: String) {
+        self.issues.push(ValidationIssue {
+            kind,
+            part: part.to_string(),
+            message,
+        });
+    }
+}
+
+/// A problem of the package structure found by [`PptxContainer::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub kind: ValidationIssueKind,
+    /// The part the issue was found in, or the missing part for
+    /// [`ValidationIssueKind::MissingPart`].
+    pub part: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationIssueKind {
+    /// A part required by every presentation is missing.
+    MissingPart,
+    /// A relationship points to a part that does not exist.
+    UnresolvedRelationship,
+    /// An image relationship points to a media part that does not exist.
+    MissingMedia,
+    /// A slide or relationship part is not well-formed XML.
+    MalformedXml,
+}
+
+impl PptxContainer {
+    /// Checks the structure of the package without converting it.
+    ///
+    /// The check covers the required parts, the targets of all internal relationships, the
+    /// referenced media and the well-formedness of the slide and relationship XML. It is much
+    /// cheaper than parsing the slides and suited as a pre-flight check.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if a part can not be read from the archive. Structural problems
+    /// are recorded in the returned [`ValidationReport`].
+    pub fn validate(&mut self) -> Result<ValidationReport> {
+        let mut report = ValidationReport::default();
+        for part in REQUIRED_PARTS {
+            if !self.has_part(part) {
+                report.push(
+                    ValidationIssueKind::MissingPart,
+                    part,
+                    "Required part is missing".to_string(),
+                );
+            }
+        }
+
+        let rels_paths: Vec<String> = self
+            .part_names()
+            .into_iter()
+            .filter(|name| name.ends_with(".rels"))
+            .map(str::to_string)
+            .collect();
+        for rels_path in rels_paths {
+            let data = self.read_file_from_archive(&rels_path)?;
+            let relationships = match parse_relationships(&data) {
+                Ok(relationships) => relationships,
+                Err(error) => {
+                    report.push(
+                        ValidationIssueKind::MalformedXml,
+                        &rels_path,
+                        error.to_string(),
+                    );
+                    continue;
+                }
+            };
+            let source = source_part(&rels_path);
+            for relationship in relationships
+                .iter()
+                .filter(|relationship| !relationship.external)
+            {
+                let target = Self::resolve_target_path(&source, &relationship.target);
+                if self.has_part(&target) {
+                    continue;
+                }
+                let kind = if relationship.rel_type == IMAGE_NAMESPACE {
+                    ValidationIssueKind::MissingMedia
+                } else {
+                    ValidationIssueKind::UnresolvedRelationship
+                };
+                report.push(
+                    kind,
+                    &rels_path,
+                    format!(
+                        "Relationship {} targets missing part {target}",
+                        relationship.id
+                    ),
+                );
+            }
+        }
+
+        for slide_path in self.slide_paths.clone() {
+            let data = self.read_file_from_archive(&slide_path)?;
+            if let Err(error) = check_well_formed(&data, &slide_path) {
+                report.push(
+                    ValidationIssueKind::MalformedXml,
+                    &slide_path,
+                    error.to_string(),
+                );
+            }
+        }
+        Ok(report)
+    }
+}
+
+/// Returns the part a relationship part belongs to, e.g. `ppt/slides/slide1.xml` for
+/// `ppt/slides/_rels/slide1.xml.rels` and an empty path for the package relationships.
+fn source_part(rels_path: &str) -> String {
+    let rels_path = rels_path.strip_suffix(".rels").unwrap_or(rels_path);
+    match rels_path.rsplit_once("_rels/") {
+        Some((directory, name)) => format!("{directory}{name}"),
+        None => rels_path.to_string(),
+    }
+}
+
+fn check_well_formed(xml_data: &[u8], part: &str) -> Result<()> {
+    let mut xml = reader(xml_data);
+    let mut depth = 0usize;
+    loop {
+        match event(&mut xml, part)? {
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth = depth.saturating_sub(1),
+            Event::Eof if depth > 0 => {
+                return Err(crate::Error::ParseError("Unexpected end of XML document"));
+            }
+            Event::Eof => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/validate.rs"]
+mod tests;
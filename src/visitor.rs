@@ -0,0 +1,43 @@
+use crate::{
+    ChartElement, ConnectorElement, ElementPosition, ImageReference, ListElement, ListItem,
+    MediaReference, TableCell, TableElement, TextElement,
+};
+
+/// Callbacks for the elements of a slide, invoked by [`crate::Slide::accept`].
+///
+/// Every method has an empty default implementation, so a visitor only implements the
+/// callbacks it needs. Elements are visited in slide order; tables and lists are announced
+/// before their cells and items.
+pub trait SlideVisitor {
+    fn visit_text(&mut self, _text: &TextElement, _position: ElementPosition) {}
+
+    fn visit_table(&mut self, _table: &TableElement, _position: ElementPosition) {}
+
+    /// Called for each cell of a table after [`SlideVisitor::visit_table`], including cells
+    /// covered by a merged cell.
+    fn visit_table_cell(&mut self, _cell: &TableCell, _row: usize, _column: usize) {}
+
+    fn visit_list(&mut self, _list: &ListElement, _position: ElementPosition) {}
+
+    /// Called for each item of a list after [`SlideVisitor::visit_list`].
+    fn visit_list_item(&mut self, _item: &ListItem) {}
+
+    fn visit_image(&mut self, _image: &ImageReference, _position: ElementPosition) {}
+
+    fn visit_chart(&mut self, _chart: &ChartElement, _position: ElementPosition) {}
+
+    fn visit_media(&mut self, _media: &MediaReference, _position: ElementPosition) {}
+
+    fn visit_connector(&mut self, _connector: &ConnectorElement, _position: ElementPosition) {}
+
+    /// Called for a group shape before its children are visited. Groups are only kept with
+    /// [`crate::ParserConfig::preserve_groups`].
+    fn visit_group(&mut self, _name: Option<&str>, _position: ElementPosition) {}
+
+    /// Called for elements the parser could not classify.
+    fn visit_unknown(&mut self) {}
+}
+
+#[cfg(test)]
+#[path = "../tests/unit/visitor.rs"]
+mod tests;
@@ -1,4 +1,4 @@
-use crate::{Error, Result};
+use crate::{DiagnosticSeverity, Error, ParseDiagnostic, Result};
 use quick_xml::events::{BytesRef, BytesStart, BytesText, Event};
 use quick_xml::name::ResolveResult;
 use quick_xml::reader::NsReader;
@@ -54,13 +54,26 @@ pub(crate) fn attr(element: &BytesStart<'_>, wanted: &[u8]) -> Option<String> {
 }
 
 pub(crate) fn text(event: &BytesText<'_>, part: &str) -> Result<String> {
+    let mut value = String::new();
+    push_text(event, part, &mut value)?;
+    Ok(value)
+}
+
+/// Appends the unescaped content of a text event to `out`, without allocating an
+/// intermediate string unless the content has to be unescaped.
+pub(crate) fn push_text<W: std::fmt::Write>(
+    event: &BytesText<'_>,
+    part: &str,
+    out: &mut W,
+) -> Result<()> {
     let decoded = event.decode().map_err(|source| Error::Xml {
         part: part.to_string(),
         source: source.into(),
     })?;
-    Ok(quick_xml::escape::unescape(&decoded)
-        .map(Cow::into_owned)
-        .unwrap_or_else(|_| decoded.into_owned()))
+    let unescaped = quick_xml::escape::unescape(&decoded).unwrap_or(Cow::Borrowed(&decoded));
+    // Writing to a string can not fail.
+    let _ = out.write_str(&unescaped);
+    Ok(())
 }
 
 pub(crate) fn reference(event: &BytesRef<'_>, part: &str) -> Result<String> {
@@ -74,6 +87,22 @@ pub(crate) fn reference(event: &BytesRef<'_>, part: &str) -> Result<String> {
         .unwrap_or(escaped))
 }
 
+/// Replaces invalid UTF-8 sequences in `data` with U+FFFD, returning a warning if any were
+/// found.
+pub(crate) fn repair_utf8(data: &mut Vec<u8>, part: &str) -> Option<ParseDiagnostic> {
+    let invalid = std::str::from_utf8(data).err()?;
+    let repaired = String::from_utf8_lossy(data).into_owned();
+    *data = repaired.into_bytes();
+    Some(ParseDiagnostic {
+        severity: DiagnosticSeverity::Warning,
+        message: format!(
+            "Invalid UTF-8 replaced with U+FFFD, first at byte {}",
+            invalid.valid_up_to()
+        ),
+        source: Some(part.to_string()),
+    })
+}
+
 pub(crate) fn skip_element(reader: &mut XmlReader<'_>, end: &[u8], part: &str) -> Result<()> {
     let mut depth = 1usize;
     while depth > 0 {
@@ -84,14 +84,13 @@ fn parses_odp_tables_lists_and_text_formatting() {
         .elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::Text(text, position) => Some((text, position)),
+            SlideElement::Text(text, position, _) => Some((text, position)),
             _ => None,
         })
         .unwrap();
     assert!(
         text.0
-            .runs
-            .iter()
+            .iter_runs()
             .any(|run| run.text.contains("Bold text") && run.formatting.bold)
     );
     assert_eq!(text.1.x, 360_000);
@@ -101,7 +100,7 @@ fn parses_odp_tables_lists_and_text_formatting() {
         .elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::List(list, _) => Some(list),
+            SlideElement::List(list, _, _) => Some(list),
             _ => None,
         })
         .unwrap();
@@ -114,7 +113,7 @@ fn parses_odp_tables_lists_and_text_formatting() {
         .elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::Table(table, _) => Some(table),
+            SlideElement::Table(table, _, _) => Some(table),
             _ => None,
         })
         .unwrap();
@@ -175,12 +174,12 @@ fn extracts_and_embeds_the_image_on_slide_seven() {
         .expect("image slide");
 
     assert!(slide.elements.iter().any(|element| {
-        matches!(element, SlideElement::Text(text, _) if text.runs.iter().any(|run| run.text.contains("Image")))
+        matches!(element, SlideElement::Text(text, _, _) if text.iter_runs().any(|run| run.text.contains("Image")))
     }));
     assert_eq!(slide.images.len(), 1);
     let image = slide.images.first().expect("image reference");
     assert!(slide.elements.iter().any(|element| {
-        matches!(element, SlideElement::Image(reference, _) if reference.id == image.id)
+        matches!(element, SlideElement::Image(reference, _, _) if reference.id == image.id)
     }));
 
     let expected_bytes = image_fixture_bytes();
@@ -1,9 +1,11 @@
 use base64::Engine as _;
 use pptx_to_md::{
-    ImageHandlingMode, ListKind, ParserConfig, PptxContainer, PresentationContainer,
-    PresentationFormat, Slide, SlideBlockContent, SlideElement,
+    Background, Color, Error, ImageHandlingMode, ListKind, ParseOptions, ParserConfig,
+    PptxContainer, PptxEditor, PresentationContainer, PresentationFormat, RenderOptions,
+    ResourceLimits, ShapeRef, Slide, SlideBlockContent, SlideElement,
 };
 use std::fs;
+use std::io::Read;
 use std::path::PathBuf;
 
 fn pptx_fixture_path() -> PathBuf {
@@ -65,6 +67,217 @@ fn pull_parser_matches_parallel_and_streaming_container_paths() {
     assert_eq!(streamed_count, slides.len());
 }
 
+#[test]
+fn parallel_parse_resolves_image_data_per_slide() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let config = ParserConfig::builder().extract_images(true).build();
+    let parallel = PptxContainer::open(&path, config.clone())
+        .expect("open PPTX fixture")
+        .parse_all_multi_threaded()
+        .expect("parse PPTX fixture in parallel");
+    let sequential = PptxContainer::open(&path, config)
+        .expect("open PPTX fixture")
+        .parse_all()
+        .expect("parse PPTX fixture");
+
+    let mut archive =
+        zip::ZipArchive::new(fs::File::open(&path).expect("open fixture")).expect("read zip");
+    for (parallel, sequential) in parallel.iter().zip(&sequential) {
+        assert_eq!(parallel.image_data, sequential.image_data);
+        for reference in &parallel.images {
+            let part = PptxContainer::resolve_target_path(&parallel.rel_path, &reference.target);
+            let mut expected = Vec::new();
+            archive
+                .by_name(&part)
+                .expect("image part")
+                .read_to_end(&mut expected)
+                .expect("read image part");
+            assert_eq!(parallel.image_data.get(&reference.id), Some(&expected));
+        }
+    }
+}
+
+#[test]
+fn stops_reading_images_beyond_the_media_limit() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let image_count = |config: ParserConfig| {
+        PptxContainer::open(&path, config)
+            .expect("open PPTX fixture")
+            .parse_all()
+            .expect("parse PPTX fixture")
+            .iter()
+            .map(|slide| slide.image_data.len())
+            .sum::<usize>()
+    };
+    assert!(image_count(ParserConfig::default()) > 0);
+
+    let config = ParserConfig::builder().max_total_media_bytes(1).build();
+    assert_eq!(image_count(config.clone()), 0);
+    let slides = PptxContainer::open(&path, config)
+        .expect("open PPTX fixture")
+        .parse_all_multi_threaded()
+        .expect("parse PPTX fixture in parallel");
+    let slide = slides
+        .iter()
+        .find(|slide| !slide.images.is_empty())
+        .expect("slide with image");
+    assert!(slide.image_data.is_empty());
+    assert!(
+        slide
+            .diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.message.contains("media limit of 1 bytes"))
+    );
+    assert!(
+        slide
+            .convert_to_md()
+            .expect("render slide")
+            .contains("Image unavailable")
+    );
+}
+
+#[test]
+fn aggregates_presentation_stats() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let presentation = PresentationContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .parse_document()
+        .expect("parse PPTX fixture");
+    let stats = presentation.stats();
+    assert_eq!(stats.slide_count, presentation.slides.len());
+    assert_eq!(
+        stats.word_count,
+        presentation
+            .slides
+            .iter()
+            .map(|slide| slide.stats().word_count)
+            .sum::<usize>()
+    );
+    assert!(stats.languages.contains("en-US"));
+    assert_eq!(stats.media["jpeg"].count, 1);
+    assert!(stats.media["jpeg"].bytes > 0);
+    assert_eq!(
+        stats.unsupported_elements,
+        presentation.parse_report().skipped_totals()
+    );
+}
+
+#[test]
+fn extracts_the_same_notes_as_a_full_parse() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open PPTX fixture");
+    let notes = container.extract_notes().expect("extract notes");
+    let expected: Vec<(u32, String)> = container
+        .parse_all()
+        .expect("parse PPTX fixture")
+        .iter()
+        .map(|slide| (slide.slide_number, speaker_note_text(slide)))
+        .filter(|(_, text)| !text.trim().is_empty())
+        .map(|(number, text)| (number, text.trim_end().to_string()))
+        .collect();
+    assert!(!notes.is_empty());
+    assert_eq!(notes, expected);
+}
+
+#[test]
+fn validates_the_structure_of_a_well_formed_presentation() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let report = PptxContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .validate()
+        .expect("validate PPTX fixture");
+    assert!(report.is_valid(), "{:?}", report.issues);
+}
+
+#[test]
+fn rejects_presentations_beyond_the_resource_limits() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let open = |limits: ResourceLimits| {
+        PptxContainer::open(
+            &path,
+            ParserConfig::builder().resource_limits(limits).build(),
+        )
+    };
+    let total = open(ResourceLimits {
+        max_total_uncompressed_bytes: Some(1024),
+        ..ResourceLimits::default()
+    });
+    assert!(matches!(
+        total,
+        Err(Error::LimitExceeded {
+            limit: "max_total_uncompressed_bytes",
+            ..
+        })
+    ));
+
+    let mut container = open(ResourceLimits {
+        max_elements_per_slide: Some(1),
+        ..ResourceLimits::default()
+    })
+    .expect("open PPTX fixture");
+    let error = container
+        .parse_all_multi_threaded()
+        .expect_err("slides exceed the element limit");
+    assert!(matches!(
+        error,
+        Error::LimitExceeded { ref part, limit: "max_elements_per_slide" }
+            if part.starts_with("ppt/slides/slide")
+    ));
+}
+
+#[cfg(feature = "arena")]
+#[test]
+fn arena_slides_hold_the_same_words_as_owned_slides() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open PPTX fixture");
+    let slides = container.parse_all().expect("parse PPTX fixture");
+    let mut arena = pptx_to_md::Bump::new();
+    for (slide, slide_path) in slides.iter().zip(container.slide_paths.clone()) {
+        let arena_slide = container
+            .load_slide_in(&slide_path, &arena)
+            .expect("load arena slide")
+            .expect("slide is not skipped");
+        let words = |text: &str| {
+            let mut words: Vec<String> = text.split_whitespace().map(str::to_string).collect();
+            words.sort();
+            words
+        };
+        assert_eq!(arena_slide.slide_number, slide.slide_number);
+        assert_eq!(words(&arena_slide.text()), words(&slide.text()));
+        arena.reset();
+    }
+}
+
 #[test]
 fn exposes_and_renders_pptx_metadata_once() {
     let path = pptx_fixture_path();
@@ -83,17 +296,447 @@ fn exposes_and_renders_pptx_metadata_once() {
     assert_eq!(markdown.matches("Presentation Metadata").count(), 1);
 }
 
+#[test]
+fn streams_the_same_markdown_as_convert_to_md() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let config = ParserConfig::builder()
+        .extract_images(true)
+        .compress_images(false)
+        .image_handling_mode(ImageHandlingMode::InMarkdown)
+        .build();
+    let expected = PptxContainer::open(&path, config.clone())
+        .expect("open PPTX fixture")
+        .convert_to_md()
+        .expect("convert presentation");
+
+    let mut streamed = Vec::new();
+    PptxContainer::open(&path, config.clone())
+        .expect("open PPTX fixture")
+        .render_md_to(&mut streamed)
+        .expect("stream presentation");
+    assert_eq!(
+        String::from_utf8(streamed).expect("UTF-8 Markdown"),
+        expected
+    );
+
+    let mut streamed = Vec::new();
+    PresentationContainer::open(&path, config)
+        .expect("open PPTX fixture")
+        .render_md_to(&mut streamed)
+        .expect("stream presentation");
+    assert_eq!(
+        String::from_utf8(streamed).expect("UTF-8 Markdown"),
+        expected
+    );
+}
+
+#[test]
+fn converts_in_one_parallel_pipeline_like_the_serial_conversion() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let open = || PresentationContainer::open(&path, ParserConfig::default()).expect("open PPTX");
+
+    let pipelined = open().convert_all_to_md().expect("convert in one pipeline");
+
+    assert_eq!(pipelined, open().convert_to_md().expect("convert serially"));
+}
+
+#[test]
+fn prefetches_the_same_slides_as_the_streaming_iterator() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let open = || PresentationContainer::open(&path, ParserConfig::default()).expect("open PPTX");
+    let markdown = |slide: Result<Slide, Error>| slide.expect("parse slide").convert_to_md();
+
+    let streamed: Vec<_> = open().iter_slides().map(markdown).collect();
+    let prefetched: Vec<_> = open().prefetch_slides(2).map(markdown).collect();
+
+    assert_eq!(prefetched.len(), streamed.len());
+    for (prefetched, streamed) in prefetched.into_iter().zip(streamed) {
+        assert_eq!(prefetched.expect("render"), streamed.expect("render"));
+    }
+    let mut early = open().prefetch_slides(0);
+    assert_eq!(
+        early
+            .next()
+            .expect("first slide")
+            .expect("parse")
+            .slide_number,
+        1
+    );
+    drop(early);
+}
+
+#[test]
+fn streams_parallel_slides_in_presentation_order() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let expected: Vec<u32> = parse_pptx_fixture()
+        .expect("parse PPTX fixture")
+        .iter()
+        .map(|slide| slide.slide_number)
+        .collect();
+
+    let mut container =
+        PresentationContainer::open(&path, ParserConfig::default()).expect("open PPTX");
+    let numbers: Vec<u32> = container
+        .iter_slides_multi_threaded()
+        .map(|slide| slide.expect("parse slide").slide_number)
+        .collect();
+
+    assert_eq!(numbers, expected);
+}
+
+#[test]
+fn streams_one_json_line_per_slide() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let slide_count = parse_pptx_fixture().expect("parse PPTX fixture").len();
+    let mut output = Vec::new();
+    let written = PresentationContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .write_jsonl(&mut output)
+        .expect("write JSONL");
+    let output = String::from_utf8(output).expect("UTF-8 JSONL");
+
+    assert_eq!(written, slide_count);
+    assert_eq!(output.lines().count(), slide_count);
+    for (index, line) in output.lines().enumerate() {
+        assert!(line.starts_with(&format!("{{\"slide_number\":{},", index + 1)));
+        assert!(line.ends_with("]}"));
+    }
+}
+
+#[test]
+fn resolves_slide_backgrounds_and_the_master_theme() {
+    let Some(slides) = parse_pptx_fixture() else {
+        return;
+    };
+    let white = Some(Background::Solid(Color::Rgb("FFFFFF".to_string())));
+
+    assert!(slides.iter().all(|slide| slide.background == white));
+    let theme = slides[0].theme.as_ref().expect("theme of the master");
+    assert_eq!(theme.name.as_deref(), Some("Office"));
+    assert_eq!(theme.accents.len(), 6);
+    assert_eq!(theme.accents[0], Color::Rgb("156082".to_string()));
+}
+
+#[test]
+fn names_the_layout_and_master_of_every_slide() {
+    let Some(slides) = parse_pptx_fixture() else {
+        return;
+    };
+    let layouts: Vec<_> = slides
+        .iter()
+        .map(|slide| slide.layout_name.as_deref())
+        .collect();
+
+    assert_eq!(layouts[0], Some("Titel und Inhalt"));
+    assert_eq!(slides[0].layout_type.as_deref(), Some("obj"));
+    assert_eq!(layouts[3], Some("Nur Titel"));
+    assert_eq!(layouts[5], Some("Leer"));
+    assert!(
+        slides
+            .iter()
+            .all(|slide| slide.master_name.as_deref() == Some("Office"))
+    );
+}
+
+#[test]
+fn renders_one_container_with_separate_render_options() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let mut container = PresentationContainer::open_with_options(
+        &path,
+        ParseOptions {
+            extract_images: false,
+            ..ParseOptions::default()
+        },
+    )
+    .expect("open PPTX fixture");
+    let default_markdown = container
+        .convert_to_md_with(&RenderOptions::default())
+        .expect("convert presentation");
+    assert!(default_markdown.starts_with("<!-- Presentation Metadata\n"));
+    assert!(default_markdown.contains("<!-- Slide 1 -->"));
+
+    let mut options = RenderOptions {
+        include_presentation_metadata: false,
+        ..RenderOptions::default()
+    };
+    options.markdown.include_slide_number_as_comment = false;
+    let plain_markdown = container
+        .convert_to_md_with(&options)
+        .expect("convert presentation");
+    assert!(!plain_markdown.contains("<!-- Presentation Metadata"));
+    assert!(!plain_markdown.contains("<!-- Slide "), "{plain_markdown}");
+}
+
+#[test]
+fn assigns_stable_unique_element_ids() {
+    let Some(first) = parse_pptx_fixture() else {
+        return;
+    };
+    let second = parse_pptx_fixture().expect("parse PPTX fixture again");
+    for (first, second) in first.iter().zip(&second) {
+        let ids: Vec<_> = first.elements.iter().filter_map(SlideElement::id).collect();
+        let again: Vec<_> = second
+            .elements
+            .iter()
+            .filter_map(SlideElement::id)
+            .collect();
+        assert_eq!(ids, again);
+        assert!(ids.iter().all(|id| id.slide_number == first.slide_number));
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+    }
+    assert!(
+        first[0]
+            .elements
+            .iter()
+            .filter_map(SlideElement::id)
+            .any(|id| matches!(id.shape, ShapeRef::ShapeId(_)))
+    );
+}
+
+#[test]
+fn reports_skipped_elements_for_every_slide() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let presentation =
+        PresentationContainer::open(&path, ParserConfig::builder().extract_images(false).build())
+            .expect("open PPTX fixture")
+            .parse_document()
+            .expect("parse PPTX fixture");
+
+    let report = presentation.parse_report();
+    assert_eq!(report.slides.len(), presentation.slides.len());
+    assert_eq!(
+        report.is_fully_supported(),
+        report.skipped_totals().is_empty()
+    );
+}
+
+#[test]
+fn preserves_raw_slide_xml_only_when_enabled() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let config = ParserConfig::builder()
+        .extract_images(false)
+        .preserve_raw_xml(true)
+        .build();
+    let slides = PptxContainer::open(&path, config)
+        .expect("open PPTX fixture")
+        .parse_all()
+        .expect("parse PPTX fixture");
+    let raw = slides[0].blocks[0].raw.as_deref().expect("raw block XML");
+    assert!(raw.starts_with('<') && raw.ends_with('>'), "{raw}");
+    assert!(
+        slides
+            .iter()
+            .flat_map(|slide| &slide.blocks)
+            .all(|block| block.raw.is_some())
+    );
+
+    let slides = parse_pptx_fixture().expect("parse PPTX fixture");
+    assert!(
+        slides
+            .iter()
+            .flat_map(|slide| &slide.blocks)
+            .all(|block| block.raw.is_none())
+    );
+}
+
+#[test]
+fn edits_run_text_and_copies_untouched_parts() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let output =
+        std::env::temp_dir().join(format!("pptx-to-md-edited-{}.pptx", std::process::id()));
+    let mut editor = PptxEditor::open(&path).expect("open PPTX fixture");
+    assert!(
+        editor
+            .replace_text("PPTX Parser", "Edited & Parsed")
+            .expect("edit runs")
+            > 0
+    );
+    editor.save(&output).expect("save edited PPTX");
+
+    let config = ParserConfig::builder().extract_images(false).build();
+    let slides = PptxContainer::open(&output, config)
+        .expect("open edited PPTX")
+        .parse_all()
+        .expect("parse edited PPTX");
+    let text = slide_text(&slides[0]);
+    assert!(text.contains("Edited & Parsed Fixtures"), "{text}");
+
+    let mut original = zip::ZipArchive::new(fs::File::open(&path).unwrap()).unwrap();
+    let mut edited = zip::ZipArchive::new(fs::File::open(&output).unwrap()).unwrap();
+    assert_eq!(original.len(), edited.len());
+    for index in 0..original.len() {
+        let mut original_part = original.by_index_raw(index).unwrap();
+        let mut edited_part = edited.by_index_raw(index).unwrap();
+        assert_eq!(original_part.name(), edited_part.name());
+        if original_part.name() == "ppt/slides/slide1.xml" {
+            continue;
+        }
+        let (mut original_bytes, mut edited_bytes) = (Vec::new(), Vec::new());
+        original_part.read_to_end(&mut original_bytes).unwrap();
+        edited_part.read_to_end(&mut edited_bytes).unwrap();
+        assert_eq!(original_bytes, edited_bytes, "{}", original_part.name());
+    }
+    fs::remove_file(output).ok();
+}
+
+#[test]
+fn merges_presentations_with_continuous_slide_numbers() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let config = ParserConfig::builder()
+        .extract_images(false)
+        .include_slide_number_as_comment(true)
+        .build();
+    let slide_count = PptxContainer::open(&path, config.clone())
+        .expect("open PPTX fixture")
+        .slide_count;
+
+    let document_title = PresentationContainer::open(&path, config.clone())
+        .expect("open PPTX fixture")
+        .parse_document()
+        .expect("parse presentation")
+        .document_title()
+        .unwrap_or_else(|| "basic.pptx".to_string());
+
+    let markdown = pptx_to_md::merge_to_md(&[&path, &path], config).expect("merge PPTX fixtures");
+
+    assert!(markdown.starts_with(&format!("# {document_title}\n")));
+    assert_eq!(markdown.matches("<!-- Source: basic.pptx").count(), 2);
+    let second_deck = format!("slides {}-{} -->", slide_count + 1, slide_count * 2);
+    assert!(markdown.contains(&second_deck), "{markdown}");
+    assert!(markdown.contains(&format!("<!-- Slide {} -->", slide_count * 2)));
+}
+
+#[test]
+fn converts_directories_recursively_and_reports_failures() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let root = std::env::temp_dir().join(format!("pptx-to-md-batch-{}", std::process::id()));
+    let (input, output) = (root.join("input"), root.join("output"));
+    fs::create_dir_all(input.join("nested")).unwrap();
+    fs::copy(&path, input.join("nested").join("deck.pptx")).unwrap();
+    fs::write(input.join("broken.pptx"), b"not a zip").unwrap();
+    fs::write(input.join("notes.txt"), b"ignored").unwrap();
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&input, input.join("nested").join("loop")).unwrap();
+
+    let config = ParserConfig::builder().extract_images(false).build();
+    let report = pptx_to_md::convert_directory(&input, &output, config).expect("convert directory");
+
+    assert_eq!(report.converted.len(), 1);
+    assert_eq!(
+        report.converted[0].output,
+        output.join("nested").join("deck.md")
+    );
+    assert!(
+        fs::read_to_string(&report.converted[0].output)
+            .unwrap()
+            .contains("PPTX Parser")
+    );
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].input, input.join("broken.pptx"));
+    assert!(!report.is_success());
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn saves_the_images_of_each_converted_file_to_its_own_directory() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let root = std::env::temp_dir().join(format!("pptx-to-md-batch-images-{}", std::process::id()));
+    let (input, output, images) = (root.join("input"), root.join("output"), root.join("images"));
+    fs::create_dir_all(input.join("nested")).unwrap();
+    fs::copy(&path, input.join("first.pptx")).unwrap();
+    fs::copy(&path, input.join("nested").join("second.pptx")).unwrap();
+
+    let config = ParserConfig::builder()
+        .extract_images(true)
+        .image_handling_mode(ImageHandlingMode::Save)
+        .image_output_path(&images)
+        .build();
+    let report = pptx_to_md::convert_directory(&input, &output, config).expect("convert directory");
+
+    assert!(report.is_success());
+    for directory in [images.join("first"), images.join("nested").join("second")] {
+        assert!(
+            fs::read_dir(&directory)
+                .expect("image directory")
+                .next()
+                .is_some()
+        );
+    }
+    let markdown = fs::read_to_string(output.join("nested").join("second.md")).unwrap();
+    assert!(markdown.contains(&images.join("nested").join("second").display().to_string()));
+    fs::remove_dir_all(root).ok();
+}
+
+#[test]
+fn reads_image_dimensions_and_display_size() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let config = ParserConfig::builder().extract_images(true).build();
+    let slides = PptxContainer::open(&path, config)
+        .expect("open PPTX fixture")
+        .parse_all_multi_threaded()
+        .expect("parse PPTX fixture");
+    let reference = slides
+        .iter()
+        .flat_map(|slide| &slide.blocks)
+        .find_map(|block| match &block.content {
+            SlideBlockContent::Image(image) => Some(&image.reference),
+            _ => None,
+        })
+        .expect("image block");
+
+    let dimensions = reference.dimensions.as_ref().expect("image dimensions");
+    assert_eq!(dimensions.format, "jpg");
+    assert!(dimensions.width > 0 && dimensions.height > 0);
+    let (width, height) = reference.display_size.expect("display size");
+    assert!(width > 0 && height > 0);
+}
+
 fn slide_text(slide: &Slide) -> String {
     slide
         .elements
         .iter()
         .filter_map(|element| match element {
-            SlideElement::Text(text, _) => Some(
-                text.runs
-                    .iter()
-                    .map(|run| run.text.as_str())
-                    .collect::<String>(),
-            ),
+            SlideElement::Text(text, _, _) => Some(text.text()),
             _ => None,
         })
         .collect()
@@ -103,8 +746,8 @@ fn speaker_note_text(slide: &Slide) -> String {
     slide
         .speaker_notes
         .iter()
-        .flat_map(|note| note.runs.iter())
-        .map(|run| run.text.as_str())
+        .flat_map(|note| note.runs())
+        .map(|run| run.text)
         .collect()
 }
 
@@ -112,8 +755,8 @@ fn comment_text(slide: &Slide) -> String {
     slide
         .comments
         .iter()
-        .flat_map(|comment| comment.runs.iter())
-        .map(|run| run.text.as_str())
+        .flat_map(|comment| comment.runs())
+        .map(|run| run.text)
         .collect()
 }
 
@@ -144,7 +787,7 @@ fn parses_title_and_run_formatting_from_real_pptx() {
         .elements
         .iter()
         .filter_map(|element| match element {
-            SlideElement::Text(text, _) => Some(text.runs.iter()),
+            SlideElement::Text(text, _, _) => Some(text.iter_runs()),
             _ => None,
         })
         .flatten()
@@ -235,18 +878,18 @@ fn parses_formatted_table_from_real_pptx() {
         .elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::Table(table, _) => Some(table),
+            SlideElement::Table(table, _, _) => Some(table),
             _ => None,
         })
         .expect("table on third slide");
 
     assert_eq!(table.rows.len(), 3);
     assert_eq!(table.rows[0].cells.len(), 3);
-    assert_eq!(table.rows[0].cells[0].runs[0].text, "Heading A");
-    assert_eq!(table.rows[1].cells[1].runs[0].text, "B1");
-    assert_eq!(table.rows[2].cells[2].runs[0].text, "C2");
+    assert_eq!(table.rows[0].cells[0].runs()[0].text, "Heading A");
+    assert_eq!(table.rows[1].cells[1].runs()[0].text, "B1");
+    assert_eq!(table.rows[2].cells[2].runs()[0].text, "C2");
     assert_eq!(
-        table.rows[2].cells[2].runs[0].link_target.as_deref(),
+        table.rows[2].cells[2].runs()[0].link_target.as_deref(),
         Some("https://github.com/nilskruthoff/pptx-parser")
     );
 
@@ -254,6 +897,97 @@ fn parses_formatted_table_from_real_pptx() {
     assert!(markdown.contains("[C2](https://github.com/nilskruthoff/pptx-parser)"));
 }
 
+#[test]
+fn extracts_tables_without_parsing_other_elements() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let tables = PptxContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .extract_tables()
+        .expect("extract tables");
+    let expected = parse_pptx_fixture()
+        .expect("parse PPTX fixture")
+        .iter()
+        .map(|slide| {
+            slide
+                .elements
+                .iter()
+                .filter(|element| matches!(element, SlideElement::Table(..)))
+                .count()
+        })
+        .sum::<usize>();
+
+    assert_eq!(tables.len(), expected);
+    let (slide_number, table) = &tables[0];
+    assert_eq!(*slide_number, 3);
+    assert_eq!(table.rows[0].cells[0].runs()[0].text, "Heading A");
+    assert_eq!(
+        table.rows[2].cells[2].runs()[0].link_target.as_deref(),
+        Some("https://github.com/nilskruthoff/pptx-parser")
+    );
+}
+
+#[test]
+fn writes_one_markdown_file_per_slide_with_saved_images() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let presentation = PresentationContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .parse_document()
+        .expect("parse PPTX fixture");
+    let output = std::env::temp_dir().join(format!("pptx-to-md-files-{}", std::process::id()));
+
+    let paths = presentation
+        .write_markdown_files(&output, "{num}-{title}")
+        .expect("write slide files");
+    assert_eq!(paths.len(), presentation.slides.len());
+    let first = paths[0].file_name().unwrap().to_string_lossy().into_owned();
+    assert!(first.starts_with("1-") && first.ends_with(".md"), "{first}");
+    let with_image = fs::read_to_string(&paths[6]).expect("read slide with image");
+    assert!(with_image.contains("file://"), "{with_image}");
+    assert!(fs::read_dir(&output).unwrap().count() > paths.len());
+
+    let collisions = output.join("collisions");
+    let paths = presentation
+        .write_markdown_files(&collisions, "deck.md")
+        .expect("write colliding slide files");
+    assert_eq!(paths[0], collisions.join("deck.md"));
+    assert_eq!(paths[1], collisions.join("deck-2.md"));
+    fs::remove_dir_all(output).expect("remove slide files");
+}
+
+#[test]
+fn writes_one_csv_file_per_table() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let presentation = PresentationContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .parse_document()
+        .expect("parse PPTX fixture");
+    let output = std::env::temp_dir().join(format!("pptx-to-md-csv-{}", std::process::id()));
+
+    let paths = presentation
+        .write_tables_csv(&output)
+        .expect("write table CSV files");
+    assert_eq!(
+        paths,
+        vec![
+            output.join("slide3_table1.csv"),
+            output.join("slide5_table1.csv")
+        ]
+    );
+    let csv = fs::read_to_string(&paths[0]).expect("read table CSV");
+    assert!(csv.starts_with("Heading A,"), "{csv}");
+    assert_eq!(csv.lines().count(), 3);
+    fs::remove_dir_all(output).expect("remove CSV directory");
+}
+
 #[test]
 fn parses_grouped_text_sorting_and_empty_cells_from_real_pptx() {
     let Some(slides) = parse_pptx_fixture() else {
@@ -274,13 +1008,13 @@ fn parses_grouped_text_sorting_and_empty_cells_from_real_pptx() {
         .elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::Table(table, _) => Some(table),
+            SlideElement::Table(table, _, _) => Some(table),
             _ => None,
         })
         .expect("table on fifth slide");
-    assert!(table.rows[0].cells[1].runs.is_empty());
-    assert!(table.rows[1].cells[0].runs.is_empty());
-    assert!(table.rows[1].cells[2].runs.is_empty());
+    assert!(table.rows[0].cells[1].runs().is_empty());
+    assert!(table.rows[1].cells[0].runs().is_empty());
+    assert!(table.rows[1].cells[2].runs().is_empty());
 }
 
 #[test]
@@ -305,7 +1039,7 @@ fn extracts_and_embeds_the_image_on_slide_seven() {
     assert_eq!(slide.images.len(), 1);
     let image = slide.images.first().expect("image reference");
     assert!(slide.elements.iter().any(|element| {
-        matches!(element, SlideElement::Image(reference, _) if reference.id == image.id)
+        matches!(element, SlideElement::Image(reference, _, _) if reference.id == image.id)
     }));
 
     let expected_bytes = image_fixture_bytes();
@@ -351,3 +1085,58 @@ fn presentation_container_exercises_parallel_and_streaming_pptx_wrappers() {
     assert_eq!(streamed_count, slides.len());
     assert!(iterator.next().is_none());
 }
+
+#[test]
+fn outlines_pptx_slides_without_extracting_images() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let mut container =
+        PresentationContainer::open_as(&path, ParserConfig::default(), PresentationFormat::Pptx)
+            .expect("open PPTX fixture");
+
+    let outline = container.outline().expect("outline PPTX fixture");
+
+    assert_eq!(outline.len(), 7);
+    assert_eq!(
+        outline
+            .iter()
+            .map(|entry| entry.slide_number)
+            .collect::<Vec<_>>(),
+        [1, 2, 3, 4, 5, 6, 7]
+    );
+    assert_eq!(outline[0].title.as_deref(), Some("PPTX Parser Fixtures"));
+    assert_eq!(outline[1].title.as_deref(), Some("Lists"));
+    assert_eq!(outline[5].title, None);
+    assert!(outline.iter().all(|entry| entry.section.is_none()));
+
+    let slides = container.parse_all().expect("parse PPTX fixture");
+    assert!(!slides[6].image_data.is_empty());
+    assert!(container.slide_size().is_some());
+    assert_eq!(slides[0].slide_size, container.slide_size());
+}
+
+#[test]
+fn scans_pptx_titles_without_parsing_slides() {
+    let path = pptx_fixture_path();
+    if !path.is_file() {
+        return;
+    }
+    let mut container = PptxContainer::open(&path, ParserConfig::default()).expect("open fixture");
+    let titles = container.titles().expect("scan PPTX titles");
+    let outline = PresentationContainer::open(&path, ParserConfig::default())
+        .expect("open PPTX fixture")
+        .outline()
+        .expect("outline PPTX fixture");
+
+    assert_eq!(titles.len(), outline.len());
+    assert_eq!(titles[0], (1, Some("PPTX Parser Fixtures".to_string())));
+    assert_eq!(titles[1], (2, Some("Lists".to_string())));
+    for ((slide_number, title), entry) in titles.iter().zip(&outline) {
+        assert_eq!(*slide_number, entry.slide_number);
+        if title.is_some() {
+            assert_eq!(title, &entry.title);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use super::*;
+use crate::test_support::write_package;
+use crate::{OnSlideError, ParserConfig};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Counts the heap allocations made by the current thread, so tests running in parallel do
+/// not disturb each other.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn count_allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    (value, ALLOCATIONS.with(Cell::get) - before)
+}
+
+const SLIDE: &[u8] = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld><p:spTree>
+<p:sp><p:nvSpPr><p:cNvPr id="2" name="Title"/><p:cNvSpPr/><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Quarterly &amp; yearly</a:t></a:r></a:p></p:txBody></p:sp>
+<p:grpSp><p:sp><p:nvSpPr><p:cNvPr id="3" name="Body"/><p:cNvSpPr/><p:nvPr/></p:nvSpPr><p:txBody><a:p><a:pPr lvl="1"/><a:r><a:rPr b="1"/><a:t>Bold</a:t></a:r><a:br/><a:r><a:rPr u="sng"><a:hlinkClick r:id="rId2"/></a:rPr><a:t>link</a:t></a:r></a:p><a:p/></p:txBody></p:sp></p:grpSp>
+<p:graphicFrame><p:nvGraphicFramePr><p:cNvPr id="4" name="Table"/><p:cNvGraphicFramePr/><p:nvPr/></p:nvGraphicFramePr><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/table"><a:tbl><a:tr><a:tc gridSpan="2"><a:txBody><a:p><a:r><a:t>Wide</a:t></a:r></a:p></a:txBody></a:tc><a:tc hMerge="1"><a:txBody><a:p/></a:txBody></a:tc></a:tr><a:tr><a:tc><a:txBody><a:p><a:r><a:t>A</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>B</a:t></a:r></a:p></a:txBody></a:tc></a:tr></a:tbl></a:graphicData></a:graphic></p:graphicFrame>
+<p:pic><p:nvPicPr><p:cNvPr id="5" name="Picture" descr="Chart"/><p:cNvPicPr/><p:nvPr/></p:nvPicPr><p:blipFill><a:blip r:embed="rId3"/></p:blipFill></p:pic>
+</p:spTree></p:cSld></p:sld>"#;
+
+fn relationships() -> Vec<Relationship> {
+    vec![
+        Relationship {
+            id: "rId2".to_string(),
+            rel_type: crate::constants::HYPERLINK_NAMESPACE.to_string(),
+            target: "https://example.com".to_string(),
+            external: true,
+        },
+        Relationship {
+            id: "rId3".to_string(),
+            rel_type: crate::constants::IMAGE_NAMESPACE.to_string(),
+            target: "../media/image1.png".to_string(),
+            external: false,
+        },
+    ]
+}
+
+fn parse<'a>(xml: &[u8], arena: &'a Bump) -> &'a [ArenaBlock<'a>] {
+    let relationships = relationships();
+    ArenaParser {
+        arena,
+        inherited: &InheritedPositions::default(),
+        hyperlinks: &hyperlink_targets(&relationships),
+        relationships: &relationships,
+    }
+    .parse_slide(xml)
+    .expect("parse slide")
+}
+
+#[test]
+fn parses_shapes_tables_and_pictures_into_the_arena() {
+    let arena = Bump::new();
+    let blocks = parse(SLIDE, &arena);
+    assert_eq!(blocks.len(), 4);
+
+    let ArenaBlock::Text(title) = blocks[0] else {
+        panic!("expected title block");
+    };
+    assert!(title.is_title);
+    assert_eq!(title.paragraphs[0].text(), "Quarterly & yearly");
+
+    let ArenaBlock::Text(body) = blocks[1] else {
+        panic!("expected body block");
+    };
+    assert!(!body.is_title);
+    assert_eq!(body.paragraphs.len(), 1);
+    let runs = body.paragraphs[0].runs;
+    assert_eq!(body.paragraphs[0].level, 1);
+    assert_eq!(body.paragraphs[0].text(), "Bold\nlink");
+    assert!(runs[0].bold && !runs[0].underlined);
+    assert!(runs[2].underlined);
+    assert_eq!(runs[2].link_target, Some("https://example.com"));
+
+    let ArenaBlock::Table(rows) = blocks[2] else {
+        panic!("expected table block");
+    };
+    assert_eq!(rows[0].cells.len(), 1);
+    assert_eq!(rows[1].cells.len(), 2);
+
+    let ArenaBlock::Image(image) = blocks[3] else {
+        panic!("expected image block");
+    };
+    assert_eq!(image.id, "rId3");
+    assert_eq!(image.target, Some("../media/image1.png"));
+    assert_eq!(image.alt_text, Some("Chart"));
+}
+
+#[test]
+fn joins_paragraphs_blocks_and_cells_of_the_slide_text() {
+    let arena = Bump::new();
+    let slide = ArenaSlide {
+        slide_number: 1,
+        blocks: parse(SLIDE, &arena),
+        notes: &[],
+        comments: &[],
+    };
+    assert_eq!(
+        slide.text(),
+        "Quarterly & yearly\n\nBold\nlink\n\nWide\nA\tB"
+    );
+}
+
+#[test]
+fn inherits_list_styles_and_skips_alternate_content_like_owned_slides() {
+    let xml = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><p:cSld><p:spTree>
+<p:sp><p:nvSpPr><p:cNvPr id="2" name="Body"/><p:cNvSpPr/><p:nvPr/></p:nvSpPr><p:txBody><a:lstStyle><a:lvl1pPr><a:defRPr b="1"/></a:lvl1pPr></a:lstStyle><a:p><a:r><a:t>Inherited</a:t></a:r></a:p></p:txBody></p:sp>
+<mc:AlternateContent><mc:Choice Requires="p14"><p:sp><p:nvSpPr><p:cNvPr id="3" name="Choice"/><p:cNvSpPr/><p:nvPr/></p:nvSpPr><p:txBody><a:p><a:r><a:t>Choice</a:t></a:r></a:p></p:txBody></p:sp></mc:Choice><mc:Fallback><p:sp><p:nvSpPr><p:cNvPr id="3" name="Fallback"/><p:cNvSpPr/><p:nvPr/></p:nvSpPr><p:txBody><a:p><a:r><a:t>Fallback</a:t></a:r></a:p></p:txBody></p:sp></mc:Fallback></mc:AlternateContent>
+</p:spTree></p:cSld></p:sld>"#;
+    let arena = Bump::new();
+    let blocks = parse(xml, &arena);
+    assert_eq!(blocks.len(), 1, "{blocks:?}");
+
+    let ArenaBlock::Text(body) = blocks[0] else {
+        panic!("expected body block");
+    };
+    assert_eq!(body.paragraphs[0].text(), "Inherited");
+    assert!(body.paragraphs[0].runs[0].bold);
+}
+
+const RELATIONSHIPS: &[u8] = br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesSlide" Target="../notesSlides/notesSlide1.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments/comment1.xml"/></Relationships>"#;
+
+const NOTES: &[u8] = br#"<p:notes xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+<p:sp><p:nvSpPr><p:cNvPr id="2" name="Slide Image"/><p:cNvSpPr/><p:nvPr><p:ph type="sldImg"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Image</a:t></a:r></a:p></p:txBody></p:sp>
+<p:sp><p:nvSpPr><p:cNvPr id="3" name="Notes"/><p:cNvSpPr/><p:nvPr><p:ph type="body" idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Speak slowly</a:t></a:r></a:p></p:txBody></p:sp>
+</p:spTree></p:cSld></p:notes>"#;
+
+const COMMENTS: &[u8] = br#"<p:cmLst xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cm authorId="0" idx="1"><p:pos x="10" y="10"/><p:text>Check the numbers</p:text></p:cm></p:cmLst>"#;
+
+#[test]
+fn loads_speaker_notes_and_comments_of_the_slide() {
+    let path = write_package(&[
+        ("ppt/slides/slide1.xml", SLIDE),
+        ("ppt/slides/_rels/slide1.xml.rels", RELATIONSHIPS),
+        ("ppt/notesSlides/notesSlide1.xml", NOTES),
+        ("ppt/comments/comment1.xml", COMMENTS),
+    ]);
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let arena = Bump::new();
+    let slide = container
+        .load_slide_in("ppt/slides/slide1.xml", &arena)
+        .expect("load arena slide")
+        .expect("slide is not skipped");
+
+    assert_eq!(slide.slide_number, 1);
+    assert_eq!(slide.blocks.len(), 4);
+    assert_eq!(slide.notes.len(), 1);
+    assert_eq!(slide.notes[0][0].text(), "Speak slowly");
+    assert_eq!(slide.comments.len(), 1);
+    assert_eq!(slide.comments[0][0].text(), "Check the numbers");
+}
+
+#[test]
+fn applies_the_slide_error_policy_to_broken_slides() {
+    let path = write_package(&[("ppt/slides/slide1.xml", b"<p:sld><p:cSld></p:sld>")]);
+    let load = |policy: OnSlideError| {
+        let config = ParserConfig::builder().on_slide_error(policy).build();
+        let mut container = PptxContainer::open(&path, config).expect("open temporary PPTX");
+        let arena = Bump::new();
+        container
+            .load_slide_in("ppt/slides/slide1.xml", &arena)
+            .map(|slide| slide.map(|slide| (slide.slide_number, slide.text())))
+    };
+
+    assert!(load(OnSlideError::Fail).is_err());
+    assert_eq!(load(OnSlideError::Skip).expect("skip broken slide"), None);
+    let (slide_number, text) = load(OnSlideError::Placeholder)
+        .expect("replace broken slide")
+        .expect("placeholder slide");
+    assert_eq!(slide_number, 1);
+    assert!(
+        text.starts_with("Slide could not be parsed: XML parse error in PPTX slide"),
+        "{text}"
+    );
+}
+
+#[test]
+fn allocates_less_on_the_heap_than_owned_slides() {
+    let runs: String = (0..200)
+        .map(|index| format!("<a:r><a:rPr b=\"1\"/><a:t>Run {index}</a:t></a:r>"))
+        .collect();
+    let slide = format!(
+        r#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:cNvPr id="2" name="Body"/><p:cNvSpPr/><p:nvPr/></p:nvSpPr><p:txBody><a:p>{runs}</a:p><a:p>{runs}</a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#
+    );
+    let path = write_package(&[("ppt/slides/slide1.xml", slide.as_bytes())]);
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let mut arena = Bump::with_capacity(64 * 1024);
+    let slide_path = "ppt/slides/slide1.xml";
+
+    // Warm up the slide buffer and the cached parts, so both paths start from the same state.
+    container.load_slide(slide_path).expect("load slide");
+    container
+        .load_slide_in(slide_path, &arena)
+        .expect("load arena slide");
+    arena.reset();
+
+    let (owned, owned_allocations) =
+        count_allocations(|| container.load_slide(slide_path).expect("load slide"));
+    let (slide, arena_allocations) = count_allocations(|| {
+        container
+            .load_slide_in(slide_path, &arena)
+            .expect("load arena slide")
+    });
+    let slide = slide.expect("slide is not skipped");
+
+    assert_eq!(slide.blocks.len(), 1);
+    assert_eq!(slide.text(), owned.expect("slide is not skipped").text());
+    assert!(
+        arena_allocations * 2 < owned_allocations,
+        "arena path made {arena_allocations} allocations, owned path {owned_allocations}"
+    );
+}
@@ -0,0 +1,68 @@
+use super::*;
+
+const BAR_CHART: &str = r#"<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">
+  <c:chart>
+    <c:title><c:tx><c:rich><a:bodyPr/><a:p><a:r><a:t>Revenue</a:t></a:r></a:p><a:p><a:r><a:t>by quarter</a:t></a:r></a:p></c:rich></c:tx></c:title>
+    <c:plotArea>
+      <c:barChart>
+        <c:barDir val="col"/>
+        <c:ser>
+          <c:idx val="0"/>
+          <c:tx><c:strRef><c:f>Sheet1!$B$1</c:f><c:strCache><c:ptCount val="1"/><c:pt idx="0"><c:v>2024</c:v></c:pt></c:strCache></c:strRef></c:tx>
+          <c:dLbls><c:dLbl><c:idx val="0"/><c:tx><c:rich><a:p><a:r><a:t>Label</a:t></a:r></a:p></c:rich></c:tx></c:dLbl></c:dLbls>
+          <c:cat><c:strRef><c:f>Sheet1!$A$2:$A$4</c:f><c:strCache><c:ptCount val="3"/><c:pt idx="0"><c:v>Q1</c:v></c:pt><c:pt idx="1"><c:v>Q2</c:v></c:pt><c:pt idx="2"><c:v>Q3</c:v></c:pt></c:strCache></c:strRef></c:cat>
+          <c:val><c:numRef><c:f>Sheet1!$B$2:$B$4</c:f><c:numCache><c:formatCode>General</c:formatCode><c:ptCount val="3"/><c:pt idx="0"><c:v>4.5</c:v></c:pt><c:pt idx="2"><c:v>10</c:v></c:pt></c:numCache></c:numRef></c:val>
+        </c:ser>
+        <c:ser>
+          <c:idx val="1"/>
+          <c:val><c:numLit><c:ptCount val="3"/><c:pt idx="0"><c:v>1</c:v></c:pt><c:pt idx="1"><c:v>2</c:v></c:pt><c:pt idx="2"><c:v>3</c:v></c:pt></c:numLit></c:val>
+        </c:ser>
+      </c:barChart>
+      <c:catAx><c:title><c:tx><c:rich><a:p><a:r><a:t>Quarter</a:t></a:r></a:p></c:rich></c:tx></c:title></c:catAx>
+    </c:plotArea>
+  </c:chart>
+</c:chartSpace>"#;
+
+#[test]
+fn parses_title_categories_and_series_from_the_chart_cache() {
+    let chart = parse_chart(BAR_CHART.as_bytes()).expect("parse chart");
+
+    assert_eq!(chart.chart_type.as_deref(), Some("barChart"));
+    assert_eq!(chart.title.as_deref(), Some("Revenue by quarter"));
+    assert_eq!(chart.categories, vec!["Q1", "Q2", "Q3"]);
+    assert_eq!(
+        chart.series,
+        vec![
+            ChartSeries {
+                name: Some("2024".to_string()),
+                values: vec![Some(4.5), None, Some(10.0)],
+            },
+            ChartSeries {
+                name: None,
+                values: vec![Some(1.0), Some(2.0), Some(3.0)],
+            },
+        ]
+    );
+}
+
+#[test]
+fn converts_chart_data_into_a_table_with_a_header_row() {
+    let chart = parse_chart(BAR_CHART.as_bytes()).expect("parse chart");
+
+    assert_eq!(
+        chart.to_table().to_csv(),
+        ",2024,Series 2\nQ1,4.5,1\nQ2,,2\nQ3,10,3\n"
+    );
+}
+
+#[test]
+fn reads_scatter_values_and_literal_series_names() {
+    let xml = r#"<c:chartSpace xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart"><c:chart><c:plotArea><c:scatterChart><c:ser><c:tx><c:v>Samples</c:v></c:tx><c:xVal><c:numLit><c:ptCount val="2"/><c:pt idx="0"><c:v>0.5</c:v></c:pt><c:pt idx="1"><c:v>1.5</c:v></c:pt></c:numLit></c:xVal><c:yVal><c:numLit><c:ptCount val="2"/><c:pt idx="0"><c:v>3</c:v></c:pt><c:pt idx="1"><c:v>n/a</c:v></c:pt></c:numLit></c:yVal></c:ser></c:scatterChart></c:plotArea></c:chart></c:chartSpace>"#;
+    let chart = parse_chart(xml.as_bytes()).expect("parse chart");
+
+    assert_eq!(chart.chart_type.as_deref(), Some("scatterChart"));
+    assert_eq!(chart.title, None);
+    assert_eq!(chart.categories, vec!["0.5", "1.5"]);
+    assert_eq!(chart.series[0].name.as_deref(), Some("Samples"));
+    assert_eq!(chart.series[0].values, vec![Some(3.0), None]);
+}
@@ -1,7 +1,9 @@
 use super::*;
-use std::fs;
-use std::io::Write;
-use zip::write::SimpleFileOptions;
+use crate::test_support::write_package;
+use crate::{DiagnosticSeverity, SlideElement};
+use tempfile::TempPath;
+
+const SLIDE: &[u8] = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:spTree/></p:cSld></p:sld>"#;
 
 #[test]
 fn sorts_slide_paths_numerically() {
@@ -21,6 +23,32 @@ fn sorts_slide_paths_numerically() {
     );
 }
 
+#[test]
+fn orders_slide_paths_like_the_presentation_and_appends_unlisted_slides() {
+    let mut slide_paths = vec![
+        "ppt/slides/slide1.xml".to_string(),
+        "ppt/slides/slide10.xml".to_string(),
+        "ppt/slides/slide2.xml".to_string(),
+        "ppt/slides/slide3.xml".to_string(),
+    ];
+    let slide_order = vec![
+        "ppt/slides/slide3.xml".to_string(),
+        "ppt/slides/slide1.xml".to_string(),
+        "ppt/slides/slide99.xml".to_string(),
+        "ppt/slides/slide2.xml".to_string(),
+    ];
+    order_slide_paths(&mut slide_paths, &slide_order);
+    assert_eq!(
+        slide_paths,
+        vec![
+            "ppt/slides/slide3.xml".to_string(),
+            "ppt/slides/slide1.xml".to_string(),
+            "ppt/slides/slide2.xml".to_string(),
+            "ppt/slides/slide10.xml".to_string()
+        ]
+    );
+}
+
 #[test]
 fn resolves_target_path_with_parent_segments() {
     assert_eq!(
@@ -43,36 +71,721 @@ fn resolves_root_relative_target_path() {
     );
 }
 
+fn write_speaker_notes_package() -> TempPath {
+    write_package(&[
+        (
+            "ppt/slides/slide1.xml",
+            br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:spTree/></p:cSld></p:sld>"#,
+        ),
+        (
+            "ppt/slides/_rels/slide1.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesSlide" Target="../notesSlides/notesSlide1.xml"/></Relationships>"#,
+        ),
+        (
+            "ppt/notesSlides/notesSlide1.xml",
+            br#"<p:notes xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="body"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Presenter detail</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:notes>"#,
+        ),
+    ])
+}
+
 #[test]
 fn loads_speaker_notes_from_a_slide_relationship() {
-    let path = std::env::temp_dir().join(format!(
-        "pptx-to-md-speaker-notes-{}.pptx",
-        std::process::id()
-    ));
-    let file = fs::File::create(&path).expect("create temporary PPTX");
-    let mut archive = zip::ZipWriter::new(file);
-    let options = SimpleFileOptions::default();
-    archive
-        .start_file("ppt/slides/slide1.xml", options)
-        .expect("start slide entry");
-    archive.write_all(br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:spTree/></p:cSld></p:sld>"#).expect("write slide entry");
-    archive
-        .start_file("ppt/slides/_rels/slide1.xml.rels", options)
-        .expect("start relationship entry");
-    archive.write_all(br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesSlide" Target="../notesSlides/notesSlide1.xml"/></Relationships>"#).expect("write relationship entry");
-    archive
-        .start_file("ppt/notesSlides/notesSlide1.xml", options)
-        .expect("start notes entry");
-    archive.write_all(br#"<p:notes xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="body"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Presenter detail</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:notes>"#).expect("write notes entry");
-    archive.finish().expect("finish temporary PPTX");
+    let path = write_speaker_notes_package();
     let mut container =
         PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
     let slides = container.parse_all().expect("parse temporary PPTX");
     assert_eq!(slides.len(), 1);
     assert_eq!(
-        slides[0].speaker_notes[0].runs[0].text,
+        slides[0].speaker_notes[0].runs()[0].text,
         "Presenter detail\n"
     );
-    drop(container);
-    fs::remove_file(path).expect("remove temporary PPTX");
+}
+
+#[test]
+fn extracts_notes_without_parsing_slides() {
+    let path = write_speaker_notes_package();
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let notes = container.extract_notes().expect("extract notes");
+    assert_eq!(notes, vec![(1, "Presenter detail".to_string())]);
+    assert!(
+        !container
+            .relationships
+            .contains_key("ppt/slideLayouts/slideLayout1.xml")
+    );
+}
+
+#[test]
+fn parses_relationships_once_per_part() {
+    let path = write_speaker_notes_package();
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let first = container.parse_all().expect("parse temporary PPTX");
+
+    let mut cached: Vec<&str> = container.relationships.keys().map(String::as_str).collect();
+    cached.sort_unstable();
+    assert_eq!(
+        cached,
+        ["ppt/notesSlides/notesSlide1.xml", "ppt/slides/slide1.xml"]
+    );
+    let slide_relationships = Arc::clone(&container.relationships["ppt/slides/slide1.xml"]);
+    assert_eq!(slide_relationships.len(), 1);
+
+    let second = container
+        .parse_all_multi_threaded()
+        .expect("parse temporary PPTX again");
+    assert!(Arc::ptr_eq(
+        &slide_relationships,
+        &container.relationships["ppt/slides/slide1.xml"]
+    ));
+    assert_eq!(
+        first[0].speaker_notes[0].runs()[0].text,
+        second[0].speaker_notes[0].runs()[0].text
+    );
+}
+
+#[test]
+fn extracts_embedded_chart_workbooks_with_their_originating_slide() {
+    let path = write_package(&[
+        ("ppt/slides/slide1.xml", SLIDE),
+        ("ppt/slides/slide2.xml", SLIDE),
+        (
+            "ppt/slides/_rels/slide2.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart" Target="../charts/chart7.xml"/></Relationships>"#,
+        ),
+        (
+            "ppt/charts/_rels/chart7.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/package" Target="../embeddings/Microsoft_Excel_Worksheet.xlsx"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/package" Target="file:///C:/linked.xlsx" TargetMode="External"/></Relationships>"#,
+        ),
+        ("ppt/embeddings/Microsoft_Excel_Worksheet.xlsx", b"workbook"),
+    ]);
+
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let workbooks = container
+        .chart_workbooks()
+        .expect("extract chart workbooks");
+
+    assert_eq!(
+        workbooks,
+        vec![ChartWorkbook {
+            slide_number: 2,
+            chart_relationship_id: "rId3".to_string(),
+            chart_path: "ppt/charts/chart7.xml".to_string(),
+            path: "ppt/embeddings/Microsoft_Excel_Worksheet.xlsx".to_string(),
+            data: b"workbook".to_vec(),
+        }]
+    );
+}
+
+#[test]
+fn extracts_embedded_ole_objects_with_their_prog_id() {
+    let path = write_package(&[
+        (
+            "ppt/slides/slide1.xml",
+            br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:mc="http://schemas.openxmlformats.org/markup-compatibility/2006"><p:cSld><p:spTree><p:graphicFrame><p:nvGraphicFramePr><p:cNvPr id="4" name="Object 3"/></p:nvGraphicFramePr><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/presentationml/2006/ole"><mc:AlternateContent><mc:Choice Requires="v"><p:oleObj spid="_x0000_s1026" name="Worksheet" r:id="rId2" progId="Excel.Sheet.12"><p:embed/></p:oleObj></mc:Choice><mc:Fallback><p:oleObj name="Worksheet" r:id="rId2" progId="Excel.Sheet.12"><p:embed/><p:pic/></p:oleObj></mc:Fallback></mc:AlternateContent></a:graphicData></a:graphic></p:graphicFrame><p:graphicFrame><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/presentationml/2006/ole"><p:oleObj name="Document" r:id="rId3" progId="Word.Document.12"><p:link/></p:oleObj></a:graphicData></a:graphic></p:graphicFrame></p:spTree></p:cSld></p:sld>"#,
+        ),
+        (
+            "ppt/slides/_rels/slide1.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/package" Target="../embeddings/Microsoft_Excel_Worksheet.xlsx"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/oleObject" Target="file:///C:/report.docx" TargetMode="External"/></Relationships>"#,
+        ),
+        ("ppt/embeddings/Microsoft_Excel_Worksheet.xlsx", b"workbook"),
+    ]);
+
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let objects = container
+        .embedded_objects()
+        .expect("extract embedded objects");
+
+    assert_eq!(
+        objects,
+        vec![EmbeddedObject {
+            slide_number: 1,
+            relationship_id: "rId2".to_string(),
+            prog_id: Some("Excel.Sheet.12".to_string()),
+            name: Some("Worksheet".to_string()),
+            path: "ppt/embeddings/Microsoft_Excel_Worksheet.xlsx".to_string(),
+            data: b"workbook".to_vec(),
+        }]
+    );
+}
+
+#[test]
+fn lists_media_parts_with_their_referencing_slides() {
+    let relationships = br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image1.bin"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image1.bin"/></Relationships>"#;
+    let path = write_package(&[
+        ("ppt/slides/slide1.xml", SLIDE),
+        ("ppt/slides/slide2.xml", SLIDE),
+        ("ppt/slides/slide3.xml", SLIDE),
+        ("ppt/slides/_rels/slide1.xml.rels", relationships),
+        ("ppt/slides/_rels/slide3.xml.rels", relationships),
+        ("ppt/media/image1.bin", b"\x89PNG\r\n\x1a\n0000"),
+        ("ppt/media/media2.mp4", b"video"),
+    ]);
+
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let inventory = container.media_inventory().expect("list media parts");
+
+    assert_eq!(
+        inventory,
+        vec![
+            MediaItem {
+                path: "ppt/media/image1.bin".to_string(),
+                size: 12,
+                mime_type: Some("image/png".to_string()),
+                slides: vec![1, 3],
+            },
+            MediaItem {
+                path: "ppt/media/media2.mp4".to_string(),
+                size: 5,
+                mime_type: Some("video/mp4".to_string()),
+                slides: Vec::new(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn resolves_embedded_and_linked_clips_and_reads_their_data_on_request() {
+    let path = write_package(&[
+        (
+            "ppt/slides/slide1.xml",
+            br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld><p:spTree><p:pic><p:nvPicPr><p:cNvPr id="2" name="Video"/><p:cNvPicPr/><p:nvPr><a:videoFile r:link="rId2"/></p:nvPr></p:nvPicPr><p:blipFill><a:blip r:embed="rId3"/></p:blipFill></p:pic><p:pic><p:nvPicPr><p:cNvPr id="3" name="Audio"/><p:cNvPicPr/><p:nvPr><a:audioFile r:link="rId4"/></p:nvPr></p:nvPicPr><p:blipFill><a:blip r:embed="rId3"/></p:blipFill></p:pic></p:spTree></p:cSld></p:sld>"#,
+        ),
+        (
+            "ppt/slides/_rels/slide1.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/video" Target="../media/media1.mp4"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image1.png"/><Relationship Id="rId4" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/audio" Target="https://example.com/theme.mp3" TargetMode="External"/></Relationships>"#,
+        ),
+        ("ppt/media/media1.mp4", b"video"),
+        ("ppt/media/image1.png", b"\x89PNG\r\n\x1a\n0000"),
+    ]);
+
+    let parse = |config| {
+        let mut container = PptxContainer::open(&path, config).expect("open temporary PPTX");
+        container
+            .parse_all()
+            .expect("parse temporary PPTX")
+            .remove(0)
+    };
+    let clips = |config| {
+        parse(config)
+            .elements
+            .into_iter()
+            .filter_map(|element| match element {
+                SlideElement::Media(media, _, _) => Some(media),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let skipped = clips(ParserConfig::default());
+    assert_eq!(skipped.len(), 2);
+    assert_eq!(skipped[0].target, "ppt/media/media1.mp4");
+    assert_eq!(skipped[0].mime_type.as_deref(), Some("video/mp4"));
+    assert!(skipped[0].data.is_none());
+    assert_eq!(
+        skipped[0]
+            .poster
+            .as_ref()
+            .map(|poster| poster.target.as_str()),
+        Some("../media/image1.png")
+    );
+    assert_eq!(skipped[1].kind, MediaKind::Audio);
+    assert!(skipped[1].external);
+    assert_eq!(skipped[1].target, "https://example.com/theme.mp3");
+
+    let extracted = clips(ParserConfig::builder().extract_media(true).build());
+    assert_eq!(extracted[0].data.as_deref(), Some(&b"video"[..]));
+    assert!(extracted[1].data.is_none());
+
+    let limited = parse(
+        ParserConfig::builder()
+            .extract_media(true)
+            .extract_images(false)
+            .max_total_media_bytes(1)
+            .build(),
+    );
+    assert!(limited.elements.iter().any(|element| matches!(
+        element,
+        SlideElement::Media(media, _, _) if media.data.is_none()
+    )));
+    assert!(limited.diagnostics.iter().any(|diagnostic| {
+        diagnostic.message.starts_with("Media clip skipped")
+            && diagnostic.source.as_deref() == Some("ppt/media/media1.mp4")
+    }));
+}
+
+fn write_revision_package(slides: &[&str], notes: &str) -> TempPath {
+    let notes = format!(
+        r#"<p:notes xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="body"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>{notes}</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:notes>"#
+    );
+    let slide_names: Vec<String> = slides
+        .iter()
+        .map(|slide| format!("ppt/slides/{slide}.xml"))
+        .collect();
+    let mut parts: Vec<(&str, &[u8])> = slide_names
+        .iter()
+        .map(|name| (name.as_str(), SLIDE))
+        .collect();
+    parts.push((
+        "ppt/slides/_rels/slide2.xml.rels",
+        br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/notesSlide" Target="../notesSlides/notesSlide1.xml"/></Relationships>"#,
+    ));
+    parts.push(("ppt/notesSlides/notesSlide1.xml", notes.as_bytes()));
+    write_package(&parts)
+}
+
+#[test]
+fn parses_only_slides_with_modified_dependencies() {
+    let path = write_revision_package(&["slide1", "slide2", "slide3"], "First draft");
+    let previous = PptxContainer::open(&path, ParserConfig::default())
+        .expect("open first revision")
+        .manifest()
+        .expect("read manifest");
+    let previous: PartManifest = previous.to_string().parse().expect("parse stored manifest");
+
+    let path = write_revision_package(&["slide1", "slide2", "slide4"], "Second draft");
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open second revision");
+    let changed = container
+        .parse_changed(&previous)
+        .expect("parse changed slides");
+
+    let parsed: Vec<u32> = changed
+        .parsed
+        .iter()
+        .map(|slide| slide.slide_number)
+        .collect();
+    assert_eq!(parsed, vec![2, 4]);
+    assert_eq!(changed.unchanged, vec![1]);
+    assert_eq!(changed.removed, vec![3]);
+    assert_eq!(
+        changed.manifest,
+        container.manifest().expect("read manifest")
+    );
+
+    let unchanged = container
+        .parse_changed(&changed.manifest)
+        .expect("parse unchanged deck");
+    assert!(unchanged.parsed.is_empty());
+    assert_eq!(unchanged.unchanged, vec![1, 2, 4]);
+}
+
+#[test]
+fn chooses_the_parse_strategy_from_slide_count_media_size_and_cores() {
+    let slides: Vec<(String, String)> = (1..=3)
+        .map(|slide| {
+            (
+                format!("ppt/slides/slide{slide}.xml"),
+                format!(
+                    r#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>Slide {slide}</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#
+                ),
+            )
+        })
+        .collect();
+    let mut parts: Vec<(&str, &[u8])> = slides
+        .iter()
+        .map(|(name, xml)| (name.as_str(), xml.as_bytes()))
+        .collect();
+    parts.push(("ppt/media/image1.png", &[0; 64]));
+    let path = write_package(&parts);
+
+    let mut container = PptxContainer::open(&path, ParserConfig::default()).expect("open deck");
+    let thresholds = AutoParseThresholds {
+        max_sequential_slides: 0,
+        max_parallel_media_bytes: 64,
+        slides_per_core: 1,
+        cores: Some(2),
+    };
+    let mut strategy = |thresholds: AutoParseThresholds| {
+        container
+            .parse_strategy(&thresholds)
+            .expect("choose strategy")
+    };
+    assert_eq!(
+        strategy(AutoParseThresholds {
+            cores: Some(4),
+            ..AutoParseThresholds::default()
+        }),
+        ParseStrategy::Sequential
+    );
+    assert_eq!(
+        strategy(AutoParseThresholds {
+            cores: Some(1),
+            ..thresholds
+        }),
+        ParseStrategy::Sequential
+    );
+    assert_eq!(strategy(thresholds), ParseStrategy::Parallel);
+    assert_eq!(
+        strategy(AutoParseThresholds {
+            max_parallel_media_bytes: 63,
+            ..thresholds
+        }),
+        ParseStrategy::Chunked { chunk_size: 2 }
+    );
+
+    let expected = container.parse_all().expect("parse sequentially");
+    let chunked = container
+        .parse_auto_with(&AutoParseThresholds {
+            max_parallel_media_bytes: 63,
+            ..thresholds
+        })
+        .expect("parse in chunks");
+    let texts = |slides: &[Slide]| -> Vec<String> { slides.iter().map(Slide::text).collect() };
+    assert_eq!(texts(&chunked), texts(&expected));
+    assert_eq!(texts(&chunked), vec!["Slide 1", "Slide 2", "Slide 3"]);
+}
+
+#[test]
+fn detects_and_extracts_the_vba_project_of_macro_enabled_decks() {
+    let path = write_package(&[
+        ("ppt/slides/slide1.xml", SLIDE),
+        (
+            "ppt/_rels/presentation.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId9" Type="http://schemas.microsoft.com/office/2006/relationships/vbaProject" Target="macros/project.bin"/></Relationships>"#,
+        ),
+        ("ppt/macros/project.bin", b"VBA"),
+    ]);
+
+    let mut container = PptxContainer::open(&path, ParserConfig::default()).expect("open PPTM");
+    assert!(container.has_vba_project());
+    assert_eq!(container.vba_project_path(), Some("ppt/macros/project.bin"));
+    assert_eq!(
+        container.vba_project().expect("extract VBA project"),
+        Some(b"VBA".to_vec())
+    );
+    assert_eq!(container.parse_all().expect("parse PPTM").len(), 1);
+
+    let path = write_revision_package(&["slide1"], "No macros");
+    let mut container = PptxContainer::open(&path, ParserConfig::default()).expect("open PPTX");
+    assert!(!container.has_vba_project());
+    assert_eq!(container.vba_project().expect("extract VBA project"), None);
+}
+
+#[test]
+fn looks_up_parts_by_the_name_index() {
+    let path = write_speaker_notes_package();
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+
+    assert_eq!(container.part_names().len(), 3);
+    let index = container
+        .part_index("ppt/notesSlides/notesSlide1.xml")
+        .expect("indexed part");
+    assert_eq!(index, 2);
+    assert!(
+        container
+            .read_file_from_archive("ppt/notesSlides/notesSlide1.xml")
+            .expect("read indexed part")
+            .starts_with(b"<p:notes")
+    );
+    assert!(matches!(
+        container.read_file_from_archive("ppt/missing.xml"),
+        Err(crate::Error::Zip(zip::result::ZipError::FileNotFound))
+    ));
+}
+
+#[test]
+fn reads_parts_into_a_reused_buffer() {
+    let path = write_speaker_notes_package();
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+
+    let mut buffer = Vec::new();
+    container
+        .read_part_into("ppt/notesSlides/notesSlide1.xml", &mut buffer)
+        .expect("read notes part");
+    let capacity = buffer.capacity();
+    container
+        .read_part_into("ppt/slides/slide1.xml", &mut buffer)
+        .expect("read slide part");
+    assert!(buffer.starts_with(b"<p:sld"));
+    assert_eq!(buffer.capacity(), capacity);
+    assert_eq!(
+        buffer,
+        container
+            .read_file_from_archive("ppt/slides/slide1.xml")
+            .expect("read slide part")
+    );
+}
+
+#[test]
+fn charges_the_media_budget_with_the_bytes_actually_read() {
+    let path = write_package(&[
+        ("ppt/media/a.bin", &[b'x'; 100]),
+        ("ppt/media/b.bin", &[b'x'; 100]),
+    ]);
+    let config = ParserConfig::builder().max_total_media_bytes(150).build();
+    let mut container = PptxContainer::open(&path, config).expect("open temporary PPTX");
+
+    assert_eq!(
+        container
+            .read_media("ppt/media/a.bin")
+            .unwrap()
+            .map(|data| data.len()),
+        Some(100)
+    );
+    assert!(container.read_media("ppt/media/a.bin").unwrap().is_some());
+    assert!(container.read_media("ppt/media/b.bin").unwrap().is_none());
+
+    container.config.resource_limits.max_part_size = Some(99);
+    assert!(matches!(
+        container.read_media("ppt/media/a.bin"),
+        Err(crate::Error::LimitExceeded {
+            limit: "max_part_size",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn stops_reading_slides_beyond_the_slide_size_limit() {
+    let path = write_speaker_notes_package();
+    let mut container =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    let mut buffer = Vec::new();
+    container
+        .read_slide_into("ppt/slides/slide1.xml", &mut buffer)
+        .expect("read slide within limit");
+
+    container.config.resource_limits.max_slide_xml_bytes = Some(10);
+    let error = container.read_slide_into("ppt/slides/slide1.xml", &mut buffer);
+    assert!(matches!(
+        error,
+        Err(crate::Error::LimitExceeded {
+            limit: "max_slide_xml_bytes",
+            ..
+        })
+    ));
+    assert_eq!(buffer.len(), 11);
+}
+
+#[test]
+fn adds_static_layout_and_master_content_when_enabled() {
+    let namespaces = r#"xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main""#;
+    let shapes = |placeholder: &str, text: &str| {
+        format!(
+            r#"<p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>{placeholder}</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:txBody><a:p><a:r><a:t>{text}</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld>"#
+        )
+    };
+    let parts = [
+        (
+            "ppt/slides/slide1.xml",
+            format!("<p:sld {namespaces}>{}</p:sld>", shapes("Slide title", "Slide body")),
+        ),
+        (
+            "ppt/slides/slide2.xml",
+            format!(
+                r#"<p:sld {namespaces} showMasterSp="0">{}</p:sld>"#,
+                shapes("Plain", "Only slide")
+            ),
+        ),
+        (
+            "ppt/slides/_rels/slide1.xml.rels",
+            r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout" Target="../slideLayouts/slideLayout1.xml"/></Relationships>"#.to_string(),
+        ),
+        (
+            "ppt/slides/_rels/slide2.xml.rels",
+            r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout" Target="../slideLayouts/slideLayout1.xml"/></Relationships>"#.to_string(),
+        ),
+        (
+            "ppt/slideLayouts/slideLayout1.xml",
+            format!(
+                "<p:sldLayout {namespaces}>{}</p:sldLayout>",
+                shapes("Click to edit", "Standing agenda")
+            ),
+        ),
+        (
+            "ppt/slideLayouts/_rels/slideLayout1.xml.rels",
+            r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideMaster" Target="../slideMasters/slideMaster1.xml"/></Relationships>"#.to_string(),
+        ),
+        (
+            "ppt/slideMasters/slideMaster1.xml",
+            format!(
+                "<p:sldMaster {namespaces}>{}</p:sldMaster>",
+                shapes("Master title", "Legal notice")
+            ),
+        ),
+    ];
+    let path = write_package(
+        &parts
+            .iter()
+            .map(|(part, content)| (*part, content.as_bytes()))
+            .collect::<Vec<_>>(),
+    );
+
+    let mut plain =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    assert_eq!(plain.parse_all().unwrap()[0].blocks.len(), 2);
+
+    let config = ParserConfig::builder().include_layout_content(true).build();
+    for slides in [
+        PptxContainer::open(&path, config.clone())
+            .and_then(|mut container| container.parse_all())
+            .expect("parse slides"),
+        PptxContainer::open(&path, config)
+            .and_then(|mut container| container.parse_all_multi_threaded())
+            .expect("parse slides in parallel"),
+    ] {
+        let text = slides[0].text();
+        assert!(text.contains("Slide body"));
+        assert!(text.contains("Legal notice"));
+        assert!(text.contains("Standing agenda"));
+        assert!(!text.contains("Click to edit") && !text.contains("Master title"));
+        let orders: Vec<_> = slides[0]
+            .blocks
+            .iter()
+            .map(|block| block.source_order)
+            .collect();
+        assert_eq!(orders, [0, 1, 2, 3]);
+        assert_eq!(slides[1].text().trim_end(), "Plain\n\nOnly slide");
+    }
+}
+
+#[test]
+fn replaces_invalid_utf8_in_slide_xml_when_lossy() {
+    let path = write_package(&[(
+        "ppt/slides/slide1.xml",
+        b"<p:sld xmlns:p=\"http://schemas.openxmlformats.org/presentationml/2006/main\" xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>Caf\xe9</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>",
+    )]);
+
+    let mut strict =
+        PptxContainer::open(&path, ParserConfig::default()).expect("open temporary PPTX");
+    assert!(strict.parse_all().is_err());
+
+    let config = ParserConfig::builder().lossy_utf8(true).build();
+    for slides in [
+        PptxContainer::open(&path, config.clone())
+            .and_then(|mut container| container.parse_all())
+            .expect("parse lossy slides"),
+        PptxContainer::open(&path, config)
+            .and_then(|mut container| container.parse_all_multi_threaded())
+            .expect("parse lossy slides in parallel"),
+    ] {
+        assert_eq!(slides[0].text().trim_end(), "Caf\u{fffd}");
+        assert_eq!(slides[0].diagnostics.len(), 1);
+        assert_eq!(
+            slides[0].diagnostics[0].source.as_deref(),
+            Some("ppt/slides/slide1.xml")
+        );
+    }
+}
+
+#[test]
+fn applies_the_slide_error_policy_to_broken_slides() {
+    let path = write_package(&[
+        ("ppt/slides/slide1.xml", SLIDE),
+        ("ppt/slides/slide2.xml", b"<p:sld><p:cSld></p:sld>"),
+    ]);
+
+    let parse = |policy: OnSlideError, parallel: bool| {
+        let config = ParserConfig::builder().on_slide_error(policy).build();
+        let mut container = PptxContainer::open(&path, config).expect("open temporary PPTX");
+        if parallel {
+            container.parse_all_multi_threaded()
+        } else {
+            container.parse_all()
+        }
+    };
+    for parallel in [false, true] {
+        assert!(parse(OnSlideError::Fail, parallel).is_err());
+
+        let skipped = parse(OnSlideError::Skip, parallel).expect("skip broken slide");
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].slide_number, 1);
+
+        let placeholders = parse(OnSlideError::Placeholder, parallel).expect("replace slide");
+        assert_eq!(placeholders.len(), 2);
+        let placeholder = &placeholders[1];
+        assert_eq!(placeholder.slide_number, 2);
+        assert_eq!(
+            placeholder.diagnostics[0].severity,
+            DiagnosticSeverity::Error
+        );
+        assert!(
+            placeholder
+                .convert_to_md()
+                .expect("render placeholder")
+                .contains("_Slide could not be parsed: XML parse error in PPTX slide")
+        );
+    }
+}
+
+#[test]
+fn yields_slides_parsed_out_of_order_in_presentation_order() {
+    let slide = |number| {
+        Ok(Some(Slide::new(
+            format!("ppt/slides/slide{number}.xml"),
+            number,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            HashMap::new(),
+            ParserConfig::default(),
+        )))
+    };
+    let iterator = OrderedSlideIterator::spawn(4, move |sender, _| {
+        for (index, slide) in [(3, slide(4)), (1, Ok(None)), (2, slide(3)), (0, slide(1))] {
+            sender.send((index, slide)).expect("send slide");
+        }
+    });
+
+    let numbers: Vec<u32> = iterator
+        .map(|slide| slide.expect("parsed slide").slide_number)
+        .collect();
+
+    assert_eq!(numbers, vec![1, 3, 4]);
+
+    let iterator = OrderedSlideIterator::spawn(4, move |sender, _| {
+        for (index, slide) in [(2, slide(3)), (0, slide(1))] {
+            sender.send((index, slide)).expect("send slide");
+        }
+    });
+    let slides: Vec<_> = iterator
+        .map(|slide| slide.map(|slide| slide.slide_number).ok())
+        .collect();
+    assert_eq!(slides, vec![Some(1), None, Some(3), None]);
+}
+
+#[test]
+fn resumes_a_panic_of_the_ordered_slide_producer() {
+    let mut iterator = OrderedSlideIterator::spawn(1, |_, _| panic!("worker failed"));
+    let panic = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| iterator.next()))
+        .expect_err("resumed panic");
+    assert_eq!(panic.downcast_ref::<&str>(), Some(&"worker failed"));
+}
+
+#[test]
+fn continues_the_source_order_after_slide_elements_without_blocks() {
+    let element = |source_order| {
+        SlideElement::Text(
+            Default::default(),
+            Default::default(),
+            crate::ElementMeta {
+                source_order: Some(source_order),
+                ..Default::default()
+            },
+        )
+    };
+    let block = |source_order| crate::SlideBlock {
+        bounds: Default::default(),
+        source_order,
+        raw: None,
+        content: crate::SlideBlockContent::Unsupported(crate::UnsupportedBlock {
+            kind: "shape".to_string(),
+            fallback_text: None,
+            content_type: None,
+        }),
+    };
+    // The element at 2 is a plain connector line, which has no block.
+    let (mut elements, mut blocks) = (vec![element(0), element(2)], vec![block(0)]);
+
+    append_static_content(&mut elements, &mut blocks, &[element(0)], &[block(0)]);
+
+    assert_eq!(blocks[1].source_order, 3);
+    assert_eq!(elements[2].meta().source_order, Some(3));
 }
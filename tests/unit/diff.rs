@@ -0,0 +1,115 @@
+use super::*;
+use crate::{Paragraph, ParserConfig, PresentationMetadata, Run, SlideBlock, TextBlock, TextRole};
+use std::collections::HashMap;
+
+fn slide(slide_number: u32, title: &str, body: &[&str]) -> Slide {
+    let block = |source_order: usize, role: TextRole, text: &str| SlideBlock {
+        bounds: Default::default(),
+        source_order,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role,
+            paragraphs: vec![Paragraph::plain(vec![Run {
+                text: text.to_string(),
+                formatting: Default::default(),
+                link_target: None,
+            }])],
+            from_template: false,
+        }),
+    };
+    let mut blocks = vec![block(0, TextRole::Title, title)];
+    blocks.extend(
+        body.iter()
+            .enumerate()
+            .map(|(index, text)| block(index + 1, TextRole::Body, text)),
+    );
+    Slide::new_semantic(
+        format!("ppt/slides/slide{slide_number}.xml"),
+        slide_number,
+        Vec::new(),
+        blocks,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        ParserConfig::default(),
+        Vec::new(),
+    )
+}
+
+fn presentation(slides: Vec<Slide>) -> Presentation {
+    Presentation {
+        metadata: PresentationMetadata::default(),
+        slides,
+        sections: Vec::new(),
+        diagnostics: Vec::new(),
+    }
+}
+
+#[test]
+fn reports_added_removed_moved_and_changed_slides() {
+    let old = presentation(vec![
+        slide(1, "Intro", &["Welcome"]),
+        slide(2, "Agenda", &["One", "Two"]),
+        slide(3, "Legacy", &["Old stuff"]),
+        slide(4, "Summary", &["Thanks"]),
+    ]);
+    let new = presentation(vec![
+        slide(1, "Summary", &["Thanks"]),
+        slide(2, "Intro", &["Welcome"]),
+        slide(3, "Agenda", &["One", "Three", "Four"]),
+        slide(4, "Outlook", &["Next year"]),
+    ]);
+
+    let diff = diff(&old, &new);
+
+    assert_eq!(
+        diff.slides,
+        [
+            SlideDiff::Changed {
+                old_slide_number: 4,
+                new_slide_number: 1,
+                title: Some("Summary".to_string()),
+                moved: true,
+                changes: Vec::new(),
+            },
+            SlideDiff::Changed {
+                old_slide_number: 2,
+                new_slide_number: 3,
+                title: Some("Agenda".to_string()),
+                moved: false,
+                changes: vec![
+                    ContentChange::TextChanged {
+                        old: "Two".to_string(),
+                        new: "Three".to_string(),
+                    },
+                    ContentChange::TextAdded("Four".to_string()),
+                ],
+            },
+            SlideDiff::Added {
+                slide_number: 4,
+                title: Some("Outlook".to_string()),
+            },
+            SlideDiff::Removed {
+                slide_number: 3,
+                title: Some("Legacy".to_string()),
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_markdown(),
+        "# Presentation diff\n\n\
+         ## Slide 1: Summary (moved from slide 4)\n\n\
+         ## Slide 3: Agenda (slide 2 before)\n\n```diff\n- Two\n+ Three\n+ Four\n```\n\n\
+         ## Slide 4: Outlook (added)\n\n\
+         ## Slide 3: Legacy (removed)\n"
+    );
+}
+
+#[test]
+fn identical_presentations_have_no_diff() {
+    let slides = || presentation(vec![slide(1, "Intro", &["Welcome"])]);
+    let diff = diff(&slides(), &slides());
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_markdown(), "# Presentation diff\n\nNo changes.\n");
+}
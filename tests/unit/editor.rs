@@ -0,0 +1,27 @@
+use super::*;
+
+const SLIDE: &[u8] = b"\xEF\xBB\xBF<p:sld xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\" xmlns:p=\"http://schemas.openxmlformats.org/presentationml/2006/main\"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:rPr lang=\"en-US\" b=\"1\"/><a:t>Hello {{name}}</a:t></a:r><a:r><a:t>Fish &amp; Chips</a:t></a:r><a:r><a:t/></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>";
+
+#[test]
+fn replaces_run_text_and_keeps_surrounding_bytes() {
+    let (edited, count) =
+        replace_run_texts(SLIDE, |text| Some(text.replace("{{name}}", "<World>"))).unwrap();
+
+    assert_eq!(count, 1);
+    let expected = String::from_utf8_lossy(SLIDE).replace("{{name}}", "&lt;World&gt;");
+    assert_eq!(String::from_utf8_lossy(&edited), expected);
+}
+
+#[test]
+fn passes_unescaped_text_and_leaves_unchanged_runs_alone() {
+    let mut seen = Vec::new();
+    let (edited, count) = replace_run_texts(SLIDE, |text| {
+        seen.push(text.to_string());
+        Some(text.to_string())
+    })
+    .unwrap();
+
+    assert_eq!(seen, ["Hello {{name}}", "Fish & Chips"]);
+    assert_eq!(count, 0);
+    assert_eq!(edited, SLIDE);
+}
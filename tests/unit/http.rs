@@ -0,0 +1,73 @@
+use super::*;
+use crate::Error;
+use std::io::{BufRead, BufReader, Cursor};
+use std::net::TcpListener;
+use zip::write::SimpleFileOptions;
+
+fn package() -> Vec<u8> {
+    let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    archive
+        .start_file("ppt/slides/slide1.xml", SimpleFileOptions::default())
+        .expect("start slide entry");
+    archive.write_all(br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>Downloaded</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#).expect("write slide entry");
+    archive
+        .finish()
+        .expect("finish in-memory PPTX")
+        .into_inner()
+}
+
+/// Serves `body` to a single request and returns the URL of the server.
+fn serve_once(body: Vec<u8>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("bind local server");
+    let url = format!("http://{}/deck.pptx", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        let (stream, _) = listener.accept().expect("accept request");
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        while reader.read_line(&mut line).is_ok_and(|read| read > 2) {
+            line.clear();
+        }
+        let mut stream = reader.into_inner();
+        let _ = write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        );
+        let _ = stream.write_all(&body);
+    });
+    url
+}
+
+#[test]
+fn opens_a_presentation_downloaded_over_http() {
+    let url = serve_once(package());
+
+    let mut container = PptxContainer::open_url(&url, ParserConfig::default()).expect("open URL");
+
+    assert_eq!(container.slide_count, 1);
+    assert!(
+        container
+            .convert_to_md()
+            .expect("convert download")
+            .contains("Downloaded")
+    );
+}
+
+#[test]
+fn rejects_downloads_beyond_the_size_limit() {
+    let url = serve_once(package());
+    let limits = DownloadLimits {
+        max_bytes: Some(16),
+        ..DownloadLimits::default()
+    };
+
+    let result = PptxContainer::open_url_with_limits(&url, ParserConfig::default(), &limits);
+
+    assert!(matches!(
+        result,
+        Err(Error::LimitExceeded {
+            limit: "max_bytes",
+            ..
+        })
+    ));
+}
@@ -0,0 +1,63 @@
+use super::*;
+use crate::{ElementMeta, ParserConfig, SlideElement, TextElement};
+use std::collections::HashMap;
+
+#[test]
+fn escapes_quotes_backslashes_and_control_characters() {
+    let mut json = String::new();
+    push_json_string(&mut json, "say \"hi\"\\\n\tend\u{1}ä");
+    assert_eq!(json, r#""say \"hi\"\\\n\tend\u0001ä""#);
+}
+
+fn slide_with_image() -> Slide {
+    let run = crate::Run {
+        text: "Hello\nworld".to_string(),
+        formatting: Default::default(),
+        link_target: None,
+    };
+    let image = crate::ImageReference {
+        id: "rId2".to_string(),
+        target: "../media/image1.png".to_string(),
+        dimensions: None,
+        display_size: None,
+        link_target: None,
+        description: None,
+        title: None,
+    };
+    Slide::new(
+        "ppt/slides/slide1.xml".to_string(),
+        1,
+        vec![SlideElement::Text(
+            TextElement::from_runs(vec![run]),
+            Default::default(),
+            ElementMeta::default(),
+        )],
+        Vec::new(),
+        Vec::new(),
+        vec![image],
+        HashMap::from([("rId2".to_string(), b"png".to_vec())]),
+        ParserConfig::default(),
+    )
+}
+
+#[test]
+fn writes_one_line_per_slide_with_image_hashes() {
+    let mut output = Vec::new();
+    let count = write_jsonl(
+        [Ok(slide_with_image()), Ok(slide_with_image())],
+        &mut output,
+    )
+    .unwrap();
+    let output = String::from_utf8(output).unwrap();
+    let lines: Vec<_> = output.lines().collect();
+
+    assert_eq!(count, 2);
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with(
+        r#"{"slide_number":1,"source":"ppt/slides/slide1.xml","title":null,"text":"Hello\nworld","#
+    ));
+    assert!(lines[0].ends_with(&format!(
+        r#""word_count":2,"images":[{{"id":"rId2","target":"../media/image1.png","hash":"{:016x}"}}]}}"#,
+        content_hash(b"png")
+    )));
+}
@@ -0,0 +1,66 @@
+use super::*;
+use crate::{Formatting, Paragraph, ParserConfig, SlideBlock, TextBlock};
+
+fn slide_with_text(text: &str, lang: &str) -> Slide {
+    let run = Run {
+        text: text.to_string(),
+        formatting: Formatting {
+            lang: lang.to_string(),
+            ..Formatting::default()
+        },
+        link_target: None,
+    };
+    Slide::new_semantic(
+        "ppt/slides/slide1.xml".to_string(),
+        1,
+        Vec::new(),
+        vec![SlideBlock {
+            bounds: Default::default(),
+            source_order: 0,
+            raw: None,
+            content: SlideBlockContent::Text(TextBlock {
+                role: Default::default(),
+                paragraphs: vec![Paragraph::plain(vec![run])],
+                from_template: false,
+            }),
+        }],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        ParserConfig::default(),
+        Vec::new(),
+    )
+}
+
+#[test]
+fn detects_content_language_independent_of_declared_run_language() {
+    let slide = slide_with_text(
+        "Die Ergebnisse des letzten Quartals zeigen ein deutliches Wachstum in allen Regionen.",
+        "en-US",
+    );
+
+    let detection = slide.detect_language();
+
+    assert_eq!(detection.declared.as_deref(), Some("en-US"));
+    assert_eq!(detection.detected.as_deref(), Some("deu"));
+    assert_eq!(detection.detected_name.as_deref(), Some("German"));
+    assert!(detection.confidence > 0.0);
+}
+
+#[test]
+fn reports_no_language_for_empty_presentations() {
+    let presentation = Presentation {
+        metadata: Default::default(),
+        slides: vec![slide_with_text("", "")],
+        sections: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+
+    let detection = presentation.detect_language();
+
+    assert_eq!(detection.declared, None);
+    assert_eq!(detection.detected, None);
+    assert_eq!(detection.confidence, 0.0);
+    assert!(!detection.is_reliable);
+}
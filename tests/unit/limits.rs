@@ -0,0 +1,124 @@
+use super::*;
+use std::io::{Cursor, Write};
+
+const SLIDE: &[u8] = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:spTree>
+<p:sp/><p:grpSp><p:grpSp><p:sp/><p:pic/></p:grpSp></p:grpSp><p:cxnSp/>
+</p:spTree></p:cSld></p:sld>"#;
+
+fn limits(max_group_depth: Option<usize>, max_elements_per_slide: Option<usize>) -> ResourceLimits {
+    ResourceLimits {
+        max_group_depth,
+        max_elements_per_slide,
+        ..ResourceLimits::default()
+    }
+}
+
+fn exceeded(result: Result<()>) -> Option<&'static str> {
+    match result {
+        Err(Error::LimitExceeded { limit, .. }) => Some(limit),
+        _ => None,
+    }
+}
+
+#[test]
+fn checks_group_depth_and_element_count_of_the_shape_tree() {
+    let check = |limits| exceeded(check_slide_limits(SLIDE, "ppt/slides/slide1.xml", &limits));
+    assert_eq!(check(limits(Some(2), Some(6))), None);
+    assert_eq!(check(limits(Some(1), None)), Some("max_group_depth"));
+    assert_eq!(check(limits(None, Some(5))), Some("max_elements_per_slide"));
+    assert_eq!(check(ResourceLimits::default()), None);
+}
+
+#[test]
+fn checks_the_node_count_of_the_slide_xml() {
+    let check = |limits| exceeded(check_slide_limits(SLIDE, "ppt/slides/slide1.xml", &limits));
+    let nodes = |max_slide_xml_nodes| ResourceLimits {
+        max_slide_xml_nodes,
+        ..ResourceLimits::default()
+    };
+    assert_eq!(check(nodes(Some(9))), None);
+    assert_eq!(check(nodes(Some(8))), Some("max_slide_xml_nodes"));
+}
+
+#[test]
+fn stops_reading_parts_beyond_the_size_limit() {
+    let limits = ResourceLimits {
+        max_part_size: Some(4),
+        ..ResourceLimits::default()
+    };
+    let mut budget = PackageBudget::default();
+    let mut buffer = Vec::new();
+    budget
+        .read(&b"1234"[..], 4, "part", &limits, &mut buffer)
+        .expect("read within limit");
+    assert_eq!(buffer, b"1234");
+
+    buffer.clear();
+    let error = budget.read(&b"12345"[..], 4, "other", &limits, &mut buffer);
+    assert_eq!(exceeded(error), Some("max_part_size"));
+}
+
+#[test]
+fn counts_the_bytes_actually_read_against_the_total_limit() {
+    let limits = ResourceLimits {
+        max_total_uncompressed_bytes: Some(6),
+        ..ResourceLimits::default()
+    };
+    let mut budget = PackageBudget::default();
+    let mut read = |part, data: &[u8]| budget.read(data, 1, part, &limits, &mut Vec::new());
+    assert_eq!(exceeded(read("a", b"1234")), None);
+    assert_eq!(exceeded(read("a", b"1234")), None);
+    assert_eq!(
+        exceeded(read("b", b"1234")),
+        Some("max_total_uncompressed_bytes")
+    );
+    assert_eq!(exceeded(read("c", b"12")), None);
+}
+
+#[test]
+fn does_not_trust_declared_sizes_for_reservations() {
+    let limits = ResourceLimits {
+        max_part_size: Some(4),
+        ..ResourceLimits::default()
+    };
+    let mut buffer = Vec::new();
+    PackageBudget::default()
+        .read(&b"12"[..], u64::MAX, "part", &limits, &mut buffer)
+        .expect("read part");
+    assert_eq!(buffer, b"12");
+    assert!(buffer.capacity() <= 16);
+
+    let mut buffer = Vec::new();
+    read_capped(&b"12"[..], u64::MAX, None, &mut buffer).expect("read part");
+    assert!(buffer.capacity() as u64 <= MAX_RESERVATION);
+}
+
+#[test]
+fn checks_declared_part_and_total_sizes_of_the_package() {
+    let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    for name in ["a.xml", "b.xml"] {
+        writer
+            .start_file(name, zip::write::SimpleFileOptions::default())
+            .expect("start part");
+        writer.write_all(&[b'x'; 100]).expect("write part");
+    }
+    let mut archive =
+        zip::ZipArchive::new(writer.finish().expect("finish package")).expect("read package");
+
+    let check =
+        |archive: &mut zip::ZipArchive<_>, limits| exceeded(check_package_limits(archive, &limits));
+    let part = ResourceLimits {
+        max_part_size: Some(99),
+        ..ResourceLimits::default()
+    };
+    let total = ResourceLimits {
+        max_total_uncompressed_bytes: Some(150),
+        ..ResourceLimits::default()
+    };
+    assert_eq!(check(&mut archive, part), Some("max_part_size"));
+    assert_eq!(
+        check(&mut archive, total),
+        Some("max_total_uncompressed_bytes")
+    );
+    assert_eq!(check(&mut archive, ResourceLimits::default()), None);
+}
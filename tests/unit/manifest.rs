@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn round_trips_through_its_text_form() {
+    let mut manifest = PartManifest::default();
+    manifest.insert("ppt/slides/slide1.xml".to_string(), 0x1c29_1ca3, 2410);
+    manifest.insert("ppt/media/image 1.png".to_string(), 0x0000_00ff, 7);
+
+    let text = manifest.to_string();
+
+    assert_eq!(
+        text,
+        "000000ff 7 ppt/media/image 1.png\n1c291ca3 2410 ppt/slides/slide1.xml\n"
+    );
+    assert_eq!(text.parse::<PartManifest>().unwrap(), manifest);
+    assert_eq!(manifest.crc32("ppt/slides/slide1.xml"), Some(0x1c29_1ca3));
+    assert_eq!(manifest.size("ppt/slides/slide2.xml"), None);
+}
+
+#[test]
+fn rejects_malformed_lines() {
+    assert!(matches!(
+        "1c291ca3 ppt/slides/slide1.xml".parse::<PartManifest>(),
+        Err(Error::ParseError(_))
+    ));
+    assert!(matches!(
+        "xyz 1 ppt/slides/slide1.xml".parse::<PartManifest>(),
+        Err(Error::ParseError(_))
+    ));
+}
+
+#[test]
+fn detects_added_removed_and_modified_parts() {
+    let mut previous = PartManifest::default();
+    previous.insert("a.xml".to_string(), 1, 10);
+    previous.insert("b.xml".to_string(), 2, 10);
+    let mut current = PartManifest::default();
+    current.insert("a.xml".to_string(), 1, 10);
+    current.insert("b.xml".to_string(), 3, 10);
+    current.insert("c.xml".to_string(), 4, 10);
+
+    assert!(!current.differs(&previous, "a.xml"));
+    assert!(current.differs(&previous, "b.xml"));
+    assert!(current.differs(&previous, "c.xml"));
+    assert!(PartManifest::default().differs(&previous, "a.xml"));
+}
@@ -174,3 +174,71 @@ fn protects_link_destinations_with_spaces_and_parentheses() {
         "[destination](<https://example.com/a path_(draft)>)"
     );
 }
+
+#[test]
+fn renders_underline_according_to_the_underline_style() {
+    let underlined = |italic: bool| Run {
+        text: "word".to_string(),
+        formatting: Formatting {
+            underlined: true,
+            italic,
+            ..Formatting::default()
+        },
+        link_target: None,
+    };
+    let render = |run: Run, underline: UnderlineStyle| {
+        let options = MarkdownOptions {
+            underline_style: underline,
+            ..MarkdownOptions::default()
+        };
+        render_runs_with(
+            &[run],
+            MarkdownContext::Flow,
+            RunStyle::from_options(&options),
+        )
+    };
+
+    assert_eq!(render(underlined(false), UnderlineStyle::U), "<u>word</u>");
+    assert_eq!(
+        render(underlined(false), UnderlineStyle::Ins),
+        "<ins>word</ins>"
+    );
+    assert_eq!(
+        render(underlined(false), UnderlineStyle::Emphasis),
+        "_word_"
+    );
+    assert_eq!(render(underlined(true), UnderlineStyle::Emphasis), "_word_");
+    assert_eq!(render(underlined(false), UnderlineStyle::Omit), "word");
+}
+
+#[test]
+fn renders_emphasis_as_html_tags_when_enabled() {
+    let options = MarkdownOptions {
+        html_emphasis: true,
+        ..MarkdownOptions::default()
+    };
+    let formatted = |text: &str, bold: bool, italic: bool| Run {
+        text: text.to_string(),
+        formatting: Formatting {
+            bold,
+            italic,
+            ..Formatting::default()
+        },
+        link_target: None,
+    };
+    let runs = [
+        formatted("2*3", true, false),
+        formatted(" and ", false, false),
+        formatted("snake_case", false, true),
+        formatted(" both", true, true),
+    ];
+
+    assert_eq!(
+        render_runs_with(
+            &runs,
+            MarkdownContext::Flow,
+            RunStyle::from_options(&options)
+        ),
+        r"<strong>2\*3</strong> and <em>snake\_case</em> <strong><em>both</em></strong>"
+    );
+}
@@ -0,0 +1,105 @@
+use super::*;
+use crate::test_support::write_package;
+use crate::{ElementMeta, Paragraph, ParserConfig, SlideBlock, TextBlock, TextElement};
+use std::collections::HashMap;
+
+#[test]
+fn renumbers_slides_and_slide_jump_links() {
+    let link = |target: &str| Run {
+        text: "Jump".to_string(),
+        formatting: Default::default(),
+        link_target: Some(target.to_string()),
+    };
+    let mut slide = Slide::new(
+        "ppt/slides/slide2.xml".to_string(),
+        2,
+        vec![SlideElement::Text(
+            TextElement::from_runs(vec![link("#slide-3")]),
+            Default::default(),
+            ElementMeta::default(),
+        )],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        ParserConfig::default(),
+    );
+    slide.blocks = vec![SlideBlock {
+        bounds: Default::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role: Default::default(),
+            paragraphs: vec![Paragraph::plain(vec![
+                link("#slide-1"),
+                link("https://example.com/#slide-1"),
+            ])],
+            from_template: false,
+        }),
+    }];
+
+    renumber_slide(&mut slide, 10);
+
+    assert_eq!(slide.slide_number, 12);
+    let SlideBlockContent::Text(text) = &slide.blocks[0].content else {
+        panic!("expected text block");
+    };
+    let targets: Vec<_> = text.paragraphs[0]
+        .runs
+        .iter()
+        .map(|run| run.link_target.as_deref().unwrap())
+        .collect();
+    assert_eq!(targets, ["#slide-11", "https://example.com/#slide-1"]);
+    let SlideElement::Text(text, _, _) = &slide.elements[0] else {
+        panic!("expected text element");
+    };
+    assert_eq!(text.runs()[0].link_target.as_deref(), Some("#slide-13"));
+}
+
+#[test]
+fn continues_after_the_highest_slide_number_of_decks_with_gaps() {
+    let deck = |slides: &[(&str, &str)]| {
+        let slides: Vec<_> = slides
+            .iter()
+            .map(|(name, text)| {
+                (
+                    format!("ppt/slides/{name}.xml"),
+                    format!(
+                        r#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>{text}</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#
+                    ),
+                )
+            })
+            .collect();
+        let mut parts: Vec<(&str, &[u8])> = vec![
+            ("[Content_Types].xml", b"<Types/>"),
+            (
+                "ppt/presentation.xml",
+                br#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"/>"#,
+            ),
+        ];
+        parts.extend(
+            slides
+                .iter()
+                .map(|(name, xml)| (name.as_str(), xml.as_bytes())),
+        );
+        write_package(&parts)
+    };
+    let gap = deck(&[("slide1", "First"), ("slide5", "Fifth")]);
+    let single = deck(&[("slide1", "Next")]);
+    let config = ParserConfig::builder()
+        .include_slide_number_as_comment(true)
+        .build();
+
+    let markdown = merge_to_md(&[&gap, &single], config).expect("merge packages");
+
+    assert!(markdown.contains(", slides 1-5 -->"), "{markdown}");
+    assert!(markdown.contains(", slides 6-6 -->"), "{markdown}");
+    let slides: Vec<_> = markdown
+        .match_indices("<!-- Slide ")
+        .map(|(index, _)| &markdown[index..index + 14])
+        .collect();
+    assert_eq!(
+        slides,
+        ["<!-- Slide 1 -", "<!-- Slide 5 -", "<!-- Slide 6 -"]
+    );
+}
@@ -3,13 +3,22 @@ use super::*;
 #[test]
 fn parses_pptx_core_properties() {
     let core = br#"<cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/"><dc:title>Deck</dc:title><dc:creator>Ada</dc:creator><cp:lastModifiedBy>Grace</cp:lastModifiedBy><dc:subject>Subject</dc:subject><dc:description>Description</dc:description><cp:keywords>rust; slides</cp:keywords><dcterms:created>2026-01-01T00:00:00Z</dcterms:created><dcterms:modified>2026-01-02T00:00:00Z</dcterms:modified></cp:coreProperties>"#;
-    let metadata = parse_pptx_metadata(Some(core)).unwrap();
+    let metadata = parse_pptx_metadata(Some(core), None).unwrap();
     assert_eq!(metadata.title.as_deref(), Some("Deck"));
     assert_eq!(metadata.author.as_deref(), Some("Ada"));
     assert_eq!(metadata.last_modified_by.as_deref(), Some("Grace"));
     assert_eq!(metadata.keywords, vec!["rust; slides"]);
 }
 
+#[test]
+fn parses_company_slide_count_and_slide_titles_from_pptx_app_properties() {
+    let app = br#"<Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes"><Application>Microsoft Office PowerPoint</Application><Slides>3</Slides><HeadingPairs><vt:vector size="6" baseType="variant"><vt:variant><vt:lpstr>Fonts Used</vt:lpstr></vt:variant><vt:variant><vt:i4>2</vt:i4></vt:variant><vt:variant><vt:lpstr>Theme</vt:lpstr></vt:variant><vt:variant><vt:i4>1</vt:i4></vt:variant><vt:variant><vt:lpstr>Slide Titles</vt:lpstr></vt:variant><vt:variant><vt:i4>3</vt:i4></vt:variant></vt:vector></HeadingPairs><TitlesOfParts><vt:vector size="6" baseType="lpstr"><vt:lpstr>Arial</vt:lpstr><vt:lpstr>Calibri</vt:lpstr><vt:lpstr>Office Theme</vt:lpstr><vt:lpstr>Roadmap</vt:lpstr><vt:lpstr/><vt:lpstr>Q&amp;A</vt:lpstr></vt:vector></TitlesOfParts><Company>Example Corp</Company></Properties>"#;
+    let metadata = parse_pptx_metadata(None, Some(app)).unwrap();
+    assert_eq!(metadata.company.as_deref(), Some("Example Corp"));
+    assert_eq!(metadata.slide_count, Some(3));
+    assert_eq!(metadata.slide_titles, vec!["Roadmap", "", "Q&A"]);
+}
+
 #[test]
 fn parses_odp_metadata() {
     let meta = br#"<office:document-meta xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:meta="urn:oasis:names:tc:opendocument:xmlns:meta:1.0"><office:meta><dc:title>Deck</dc:title><meta:initial-creator>Ada</meta:initial-creator><dc:creator>Grace</dc:creator><meta:keyword>rust</meta:keyword><meta:keyword>slides</meta:keyword></office:meta></office:document-meta>"#;
@@ -25,18 +34,35 @@ fn metadata_comment_is_safe_and_optional() {
         title: Some("Deck --> injected\nline".to_string()),
         ..PresentationMetadata::default()
     };
-    let rendered = render_metadata_comment(&metadata).unwrap();
+    let rendered = render_metadata_comment(&metadata, None).unwrap();
     assert!(!rendered[4..rendered.len() - 3].contains("--"));
     assert!(rendered.contains("Deck &#45;&#45;> injected line"));
-    assert!(render_metadata_comment(&PresentationMetadata::default()).is_none());
+    assert!(render_metadata_comment(&PresentationMetadata::default(), None).is_none());
+}
+
+#[test]
+fn metadata_comment_prefers_the_document_title() {
+    let metadata = PresentationMetadata {
+        title: Some("PowerPoint Presentation".to_string()),
+        ..PresentationMetadata::default()
+    };
+    let rendered = render_metadata_comment(&metadata, Some("Quarterly Review")).unwrap();
+    assert!(rendered.contains("Title: Quarterly Review"));
+    assert!(!rendered.contains("PowerPoint Presentation"));
+    assert!(
+        render_metadata_comment(&PresentationMetadata::default(), Some("Launch"))
+            .unwrap()
+            .contains("Title: Launch")
+    );
 }
 
 #[test]
 fn absent_metadata_is_empty_and_malformed_metadata_is_an_error() {
     assert_eq!(
-        parse_pptx_metadata(None).unwrap(),
+        parse_pptx_metadata(None, None).unwrap(),
         PresentationMetadata::default()
     );
-    assert!(parse_pptx_metadata(Some(b"<broken>")).is_err());
+    assert!(parse_pptx_metadata(Some(b"<broken>"), None).is_err());
+    assert!(parse_pptx_metadata(None, Some(b"<broken>")).is_err());
     assert!(parse_odp_metadata(Some(b"<broken>")).is_err());
 }
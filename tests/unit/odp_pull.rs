@@ -43,26 +43,33 @@ fn parses_fixture_page_styles_lists_tables_and_position() {
             _,
             ElementPosition {
                 x: 360_000,
-                y: 720_000
-            }
+                y: 720_000,
+                width: 0,
+                height: 0,
+            },
+            _
         )
     )));
     assert!(
         parsed
             .elements
             .iter()
-            .any(|element| matches!(element, SlideElement::List(_, _)))
+            .any(|element| matches!(element, SlideElement::List(_, _, _)))
     );
     let table = parsed
         .elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::Table(table, _) => Some(table),
+            SlideElement::Table(table, _, _) => Some(table),
             _ => None,
         })
         .unwrap();
     assert_eq!(table.rows[0].cells.len(), 3);
-    assert!(table.rows[0].cells[0].runs[0].formatting.bold);
+    assert!(table.rows[0].cells[0].runs()[0].formatting.bold);
+    assert_eq!(
+        table.rows[0].cells[0].runs()[0].formatting.color,
+        Some(crate::Color::Rgb("1F4E79".to_string()))
+    );
 }
 
 #[test]
@@ -71,18 +78,18 @@ fn parses_links_special_text_notes_and_comments_in_one_page_pass() {
     let pages = index_pages(xml).unwrap();
     let fragment = page_fragment(&xml[pages[0].range.clone()], &pages[0].namespaces);
     let parsed = parse_page_fragment(&fragment, &StyleResolver::default()).unwrap();
-    assert_eq!(parsed.speaker_notes[0].runs[0].text, "Note\n");
-    assert_eq!(parsed.comments[0].runs[0].text, "Comment\n");
-    let SlideElement::Text(text, _) = &parsed.elements[0] else {
+    assert_eq!(parsed.speaker_notes[0].runs()[0].text, "Note\n");
+    assert_eq!(parsed.comments[0].runs()[0].text, "Comment\n");
+    let SlideElement::Text(text, _, _) = &parsed.elements[0] else {
         panic!()
     };
     assert!(
-        text.runs
+        text.runs()
             .iter()
             .any(|run| run.link_target.as_deref() == Some("https://example.com"))
     );
     assert_eq!(
-        text.runs
+        text.runs()
             .iter()
             .map(|run| run.text.as_str())
             .collect::<String>(),
@@ -94,13 +101,15 @@ fn parses_links_special_text_notes_and_comments_in_one_page_pass() {
 fn expands_repeated_and_spanned_table_cells() {
     let xml = br#"<table:table xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0"><table:table-row table:number-rows-repeated="2"><table:table-cell table:number-columns-repeated="2" table:number-columns-spanned="2"><text:p>X</text:p></table:table-cell><table:covered-table-cell/></table:table-row></table:table>"#;
     let mut reader = reader(xml);
-    loop {
+    let start = loop {
         match event(&mut reader, "test").unwrap() {
-            Event::Start(element) if element_is(&reader, &element, TABLE_NS, b"table") => break,
+            Event::Start(element) if element_is(&reader, &element, TABLE_NS, b"table") => {
+                break element.into_owned();
+            }
             _ => {}
         }
-    }
-    let table = parse_table(&mut reader, &StyleResolver::default()).unwrap();
+    };
+    let table = parse_table(&mut reader, &start, &StyleResolver::default()).unwrap();
     assert_eq!(table.rows.len(), 2);
     assert_eq!(table.rows[0].cells.len(), 5);
     assert_eq!(table.rows[0].cells[0].column_span, 2);
@@ -118,7 +127,7 @@ fn maps_odp_roles_bounds_and_unsupported_elements_to_semantic_blocks() {
         panic!("expected title block")
     };
     assert_eq!(title.role, TextRole::Title);
-    assert_eq!(title.paragraphs[0].text(), "Semantic title\n");
+    assert_eq!(title.paragraphs[0].text(), "Semantic title");
     assert_eq!(parsed.blocks[0].bounds.width, 3_600_000);
     assert_eq!(parsed.blocks[0].bounds.height, 1_080_000);
     assert!(matches!(
@@ -136,7 +145,9 @@ fn converts_lengths_transforms_and_reports_bad_xml() {
         parse_translate("translate(1cm 2cm)"),
         ElementPosition {
             x: 360_000,
-            y: 720_000
+            y: 720_000,
+            width: 0,
+            height: 0,
         }
     );
     assert!(index_pages(b"<broken").is_err());
@@ -189,12 +200,12 @@ fn parses_empty_images_accessible_titles_frame_tables_and_headings() {
     let SlideBlockContent::Table(table) = &parsed.blocks[2].content else {
         panic!("expected frame table")
     };
-    assert_eq!(table.rows[0].cells[0].paragraphs[0].text(), "Frame cell\n");
+    assert_eq!(table.rows[0].cells[0].paragraphs[0].text(), "Frame cell");
 
     let SlideBlockContent::Text(heading) = &parsed.blocks[3].content else {
         panic!("expected heading")
     };
     assert_eq!(heading.role, TextRole::Heading);
-    assert_eq!(heading.paragraphs[0].text(), "Section heading\n");
+    assert_eq!(heading.paragraphs[0].text(), "Section heading");
     assert!(parsed.speaker_notes.is_empty());
 }
@@ -0,0 +1,64 @@
+use super::*;
+use crate::parse_rels::parse_relationships;
+
+#[test]
+fn resolves_section_slides_through_presentation_relationships() {
+    let presentation = br#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:p14="http://schemas.microsoft.com/office/powerpoint/2010/main"><p:sldIdLst><p:sldId id="256" r:id="rId3"/><p:sldId id="257" r:id="rId2"/><p:sldId id="258" r:id="rId4"/></p:sldIdLst><p:extLst><p:ext uri="{521415D9-36F7-43E2-AB2F-B90AF26B5E84}"><p14:sectionLst><p14:section name="Intro" id="{A}"><p14:sldIdLst><p14:sldId id="256"/><p14:sldId id="257"/></p14:sldIdLst></p14:section><p14:section name="Results &amp; Outlook" id="{B}"><p14:sldIdLst><p14:sldId id="258"/></p14:sldIdLst></p14:section><p14:section name="Empty" id="{C}"><p14:sldIdLst/></p14:section></p14:sectionLst></p:ext></p:extLst></p:presentation>"#;
+    let rels = br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide1.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide2.xml"/><Relationship Id="rId4" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="/ppt/slides/slide3.xml"/></Relationships>"#;
+
+    let sections = parse_pptx_sections(presentation, &parse_relationships(rels).unwrap()).unwrap();
+
+    assert_eq!(
+        sections,
+        vec![
+            PresentationSection {
+                name: "Intro".to_string(),
+                slide_numbers: vec![2, 1],
+            },
+            PresentationSection {
+                name: "Results & Outlook".to_string(),
+                slide_numbers: vec![3],
+            },
+            PresentationSection {
+                name: "Empty".to_string(),
+                slide_numbers: Vec::new(),
+            },
+        ]
+    );
+    assert_eq!(sections[0].slide_range(), Some(1..=2));
+    assert_eq!(sections[2].slide_range(), None);
+}
+
+#[test]
+fn reads_the_slide_order_from_the_slide_id_list() {
+    let presentation = br#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:sldMasterIdLst><p:sldMasterId id="2147483648" r:id="rId1"/></p:sldMasterIdLst><p:sldIdLst><p:sldId id="256" r:id="rId4"/><p:sldId id="257" r:id="rId9"/><p:sldId id="258" r:id="rId2"/></p:sldIdLst></p:presentation>"#;
+    let rels = br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideMaster" Target="slideMasters/slideMaster1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide1.xml"/><Relationship Id="rId4" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide12.xml"/></Relationships>"#;
+
+    let order = parse_slide_order(presentation, &parse_relationships(rels).unwrap()).unwrap();
+
+    assert_eq!(
+        order,
+        vec!["ppt/slides/slide12.xml", "ppt/slides/slide1.xml"]
+    );
+}
+
+#[test]
+fn reads_the_slide_size_in_emu() {
+    let presentation = br#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:sldIdLst/><p:sldSz cx="12192000" cy="6858000"/><p:notesSz cx="6858000" cy="9144000"/></p:presentation>"#;
+
+    assert_eq!(
+        parse_slide_size(presentation).unwrap(),
+        Some((12_192_000, 6_858_000))
+    );
+    assert_eq!(
+        parse_slide_size(br#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"/>"#).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn presentations_without_section_list_have_no_sections() {
+    let presentation = br#"<p:presentation xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:sldIdLst><p:sldId id="256" r:id="rId2"/></p:sldIdLst></p:presentation>"#;
+
+    assert!(parse_pptx_sections(presentation, &[]).unwrap().is_empty());
+}
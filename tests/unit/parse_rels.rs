@@ -58,6 +58,18 @@ fn parses_hyperlink_relationships() {
     assert_eq!(hyperlinks.len(), 1);
 }
 
+#[test]
+fn resolves_slide_jump_relationships_to_slide_anchors() {
+    let xml = br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slide12.xml"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout" Target="../slideLayouts/slideLayout1.xml"/></Relationships>"#;
+    let hyperlinks = parse_hyperlink_rels(xml).unwrap();
+
+    assert_eq!(
+        hyperlinks.get("rId2").map(String::as_str),
+        Some("#slide-12")
+    );
+    assert_eq!(hyperlinks.len(), 1);
+}
+
 #[test]
 fn rejects_unclosed_relationship_xml_and_unescapes_attributes() {
     assert!(parse_relationships(b"<Relationships>").is_err());
@@ -65,3 +77,14 @@ fn rejects_unclosed_relationship_xml_and_unescapes_attributes() {
     let relationships = parse_relationships(xml).unwrap();
     assert_eq!(relationships[0].target, "https://example.com/?a=1&b=2");
 }
+
+#[test]
+fn reads_the_target_mode_of_relationships() {
+    let xml = br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="link" Target="https://example.com" TargetMode="External"/><Relationship Id="rId2" Type="link" Target="../media/a://b.png"/></Relationships>"#;
+    let relationships = parse_relationships(xml).unwrap();
+    let external: Vec<bool> = relationships
+        .iter()
+        .map(|relationship| relationship.external)
+        .collect();
+    assert_eq!(external, [true, false]);
+}
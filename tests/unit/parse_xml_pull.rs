@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
 use {
     super::*,
-    crate::Baseline,
+    crate::{Baseline, ParagraphProperties},
     std::{fs, path::PathBuf},
 };
 
@@ -29,14 +29,17 @@ fn at_element<'a>(data: &'a [u8], namespace: &str, name: &[u8]) -> XmlReader<'a>
 fn parses_text_runs_and_paragraph_boundaries() {
     let data = fixture("tx_body.xml");
     let mut xml = at_element(&data, P_NAMESPACE, b"txBody");
-    let text = content_to_text(parse_text_body(&mut xml, true, &HashMap::new()).unwrap());
-    assert_eq!(text.runs.len(), 3);
-    assert_eq!(text.runs[0].text, "Hello");
-    assert_eq!(text.runs[2].text, "!\n");
+    let text = content_to_text(parse_text_body(&mut xml, &HashMap::new()).unwrap());
+    assert_eq!(text.runs().len(), 3);
+    assert_eq!(text.runs()[0].text, "Hello");
+    assert_eq!(text.runs()[2].text, "!\n");
+    assert_eq!(text.paragraphs.len(), 1);
+    assert_eq!(text.paragraphs[0].text(), "HelloWorld!");
 
     let data = fixture("paragraph_multiple.xml");
     let mut xml = at_element(&data, A_NAMESPACE, b"p");
-    let paragraph = parse_paragraph_events(&mut xml, false, &HashMap::new()).unwrap();
+    let paragraph =
+        parse_paragraph_events(&mut xml, &HashMap::new(), &LevelFormatting::new()).unwrap();
     assert_eq!(paragraph.runs.len(), 3);
     assert!(paragraph.runs[1].formatting.bold);
     assert!(paragraph.runs[2].formatting.italic);
@@ -58,13 +61,19 @@ fn parses_run_formatting_text_and_hyperlink() {
     let run = parse_run_events(&mut xml, &links).unwrap();
     assert_eq!(run.text, "Example & Co");
     assert_eq!(run.link_target.as_deref(), Some("https://example.com"));
+    assert_eq!(run.formatting.font, None);
+
+    let data = br#"<a:r xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:rPr><a:latin typeface="Calibri"/></a:rPr><a:t>Font</a:t></a:r>"#;
+    let mut xml = at_element(data, A_NAMESPACE, b"r");
+    let run = parse_run_events(&mut xml, &HashMap::new()).unwrap();
+    assert_eq!(run.formatting.font.as_deref(), Some("Calibri"));
 }
 
 #[test]
 fn parses_lists_with_existing_marker_semantics() {
     let data = fixture("multilevel_list.xml");
     let mut xml = at_element(&data, P_NAMESPACE, b"txBody");
-    let block = parse_text_body(&mut xml, true, &HashMap::new()).unwrap();
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
     assert_eq!(block.paragraphs.len(), 5);
     assert_eq!(block.paragraphs[1].list.as_ref().unwrap().level, 1);
     assert!(matches!(
@@ -75,7 +84,7 @@ fn parses_lists_with_existing_marker_semantics() {
         block.paragraphs[1].list.as_ref().unwrap().kind,
         ListKind::Bullet { .. }
     ));
-    assert!(block.paragraphs[0]
+    assert!(!block.paragraphs[0]
         .runs
         .last()
         .unwrap()
@@ -83,6 +92,40 @@ fn parses_lists_with_existing_marker_semantics() {
         .ends_with('\n'));
 }
 
+#[test]
+fn infers_list_levels_from_left_margins_without_lvl() {
+    let data = br#"<p:txBody xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:p><a:pPr marL="342900" indent="-342900"><a:buChar char="-"/></a:pPr><a:r><a:t>Top</a:t></a:r></a:p><a:p><a:pPr marL="742950" indent="-285750"><a:buChar char="-"/></a:pPr><a:r><a:t>Nested</a:t></a:r></a:p><a:p><a:pPr marL="1143000" indent="-228600"><a:buChar char="-"/></a:pPr><a:r><a:t>Deeper</a:t></a:r></a:p><a:p><a:pPr marL="742950"><a:buNone/></a:pPr><a:r><a:t>Plain</a:t></a:r></a:p><a:p><a:pPr marL="1143000"/><a:r><a:t>Indented</a:t></a:r></a:p></p:txBody>"#;
+    let mut xml = at_element(data, P_NAMESPACE, b"txBody");
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
+    let levels: Vec<Option<u32>> = block
+        .paragraphs
+        .iter()
+        .map(|paragraph| paragraph.list.as_ref().map(|list| list.level))
+        .collect();
+    assert_eq!(levels, [Some(0), Some(1), Some(2), None, None]);
+
+    let data = br#"<p:txBody xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:p><a:pPr lvl="0" marL="342900"/><a:r><a:t>Top</a:t></a:r></a:p><a:p><a:pPr marL="742950"/><a:r><a:t>Explicit levels win</a:t></a:r></a:p></p:txBody>"#;
+    let mut xml = at_element(data, P_NAMESPACE, b"txBody");
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
+    assert!(block.paragraphs[1].list.is_none());
+}
+
+#[test]
+fn groups_mixed_text_and_list_paragraphs_into_consecutive_elements() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>Intro</a:t></a:r></a:p><a:p><a:pPr><a:buChar char="-"/></a:pPr><a:r><a:t>First</a:t></a:r></a:p><a:p><a:pPr><a:buChar char="-"/></a:pPr><a:r><a:t>Second</a:t></a:r></a:p><a:p><a:r><a:t>Outro</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    let elements = parse_slide_xml(slide).unwrap();
+
+    assert_eq!(elements.len(), 3);
+    assert!(matches!(&elements[0], SlideElement::Text(text, _, _) if text.runs()[0].text == "Intro\n"));
+    let SlideElement::List(list, _, _) = &elements[1] else {
+        panic!("expected list element")
+    };
+    assert_eq!(list.items.len(), 2);
+    assert!(list.items.iter().all(|item| !item.is_ordered));
+    assert!(matches!(&elements[2], SlideElement::Text(text, _, _) if text.runs()[0].text == "Outro\n"));
+}
+
 #[test]
 fn inherits_list_styles_and_preserves_explicit_list_overrides() {
     let layout = br#"<p:sldLayout xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:pPr lvl="0"/></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sldLayout>"#;
@@ -105,15 +148,163 @@ fn inherits_list_styles_and_preserves_explicit_list_overrides() {
     assert!(text.paragraphs[1].list.is_none());
     assert!(matches!(
         text.paragraphs[2].list.as_ref().map(|list| &list.kind),
-        Some(ListKind::Ordered { start: 4, .. })
+        Some(ListKind::Ordered { start: Some(4), .. })
     ));
 }
 
+#[test]
+fn demotes_single_inherited_list_items_to_plain_text_when_enabled() {
+    let layout = br#"<p:sldLayout xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:pPr lvl="0"/></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sldLayout>"#;
+    let mut inherited =
+        extract_inherited_positions(layout, &InheritedPositions::default()).unwrap();
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Caption</a:t></a:r></a:p><a:p><a:endParaRPr/></a:p></p:txBody></p:sp><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:pPr><a:buChar char="-"/></a:pPr><a:r><a:t>Explicit</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    let parsed = parse_slide_xml_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    assert!(matches!(parsed[0], SlideElement::List(..)));
+
+    inherited.set_demote_single_item_lists(true);
+    let parsed =
+        parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    assert!(matches!(&parsed.elements[0], SlideElement::Text(text, _, _) if text.text() == "Caption"));
+    assert!(matches!(parsed.elements[1], SlideElement::List(..)));
+    let SlideBlockContent::Text(text) = &parsed.blocks[0].content else {
+        panic!("expected semantic text block")
+    };
+    assert!(text.paragraphs.iter().all(|paragraph| paragraph.list.is_none()));
+}
+
+#[test]
+fn resolves_list_levels_from_layout_and_master_list_styles() {
+    let master = br#"<p:sldMaster xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree/></p:cSld><p:txStyles><p:bodyStyle><a:lvl1pPr marL="228600"><a:buChar char="-"/></a:lvl1pPr><a:lvl2pPr marL="685800"><a:buChar char="o"/></a:lvl2pPr><a:lvl3pPr><a:buChar char="+"/></a:lvl3pPr></p:bodyStyle></p:txStyles></p:sldMaster>"#;
+    let master = extract_inherited_positions(master, &InheritedPositions::default()).unwrap();
+    let layout = br#"<p:sldLayout xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:lstStyle><a:lvl2pPr><a:buAutoNum type="arabicPeriod"/></a:lvl2pPr><a:lvl3pPr><a:buNone/></a:lvl3pPr></a:lstStyle></p:txBody></p:sp></p:spTree></p:cSld></p:sldLayout>"#;
+    let inherited = extract_inherited_positions(layout, &master).unwrap();
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Top</a:t></a:r></a:p><a:p><a:pPr lvl="1"/><a:r><a:t>Nested</a:t></a:r></a:p><a:p><a:pPr lvl="2"/><a:r><a:t>Plain</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    let parsed =
+        parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    let SlideBlockContent::Text(text) = &parsed.blocks[0].content else {
+        panic!("expected semantic text block")
+    };
+
+    assert_eq!(
+        text.paragraphs[0].list,
+        Some(ListInfo {
+            level: 0,
+            kind: ListKind::Bullet {
+                character: Some("-".to_string())
+            }
+        })
+    );
+    assert_eq!(
+        text.paragraphs[1].list,
+        Some(ListInfo {
+            level: 1,
+            kind: ListKind::Ordered {
+                style: Some("arabicPeriod".to_string()),
+                start: None
+            }
+        })
+    );
+    assert!(text.paragraphs[2].list.is_none());
+}
+
+#[test]
+fn emits_picture_filled_shapes_as_images() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:cNvPr id="2" name="Rounded Rectangle 1" descr="Team photo"/></p:nvSpPr><p:spPr><a:xfrm><a:off x="10" y="20"/><a:ext cx="300" cy="400"/></a:xfrm><a:blipFill><a:blip r:embed="rId2"/><a:stretch/></a:blipFill></p:spPr><p:txBody><a:p><a:r><a:t>Caption</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:spPr/><p:txBody><a:p><a:pPr><a:buBlip><a:blip r:embed="rId3"/></a:buBlip></a:pPr><a:r><a:t>Picture bullet</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    let parsed = parse_slide_document_with_hyperlinks(
+        slide,
+        &InheritedPositions::default(),
+        &HashMap::new(),
+    )
+    .unwrap();
+
+    assert!(matches!(&parsed.elements[0], SlideElement::Image(image, _, _) if image.id == "rId2"));
+    assert!(matches!(&parsed.elements[1], SlideElement::Text(..)));
+    assert!(matches!(&parsed.elements[2], SlideElement::Text(..)));
+    let SlideBlockContent::Image(image) = &parsed.blocks[0].content else {
+        panic!("expected semantic image block")
+    };
+    assert_eq!(image.alt_text.as_deref(), Some("Team photo"));
+    assert_eq!(parsed.blocks[0].bounds, Bounds { x: 10, y: 20, width: 300, height: 400 });
+    assert!(matches!(parsed.blocks[1].content, SlideBlockContent::Text(_)));
+    assert_eq!(parsed.blocks.len(), 3);
+}
+
+#[cfg(feature = "symbol-fonts")]
+#[test]
+fn maps_symbol_font_runs_to_unicode() {
+    let slide = "<p:sld xmlns:p=\"http://schemas.openxmlformats.org/presentationml/2006/main\" xmlns:a=\"http://schemas.openxmlformats.org/drawingml/2006/main\"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:rPr lang=\"en-US\"><a:latin typeface=\"Calibri\"/><a:sym typeface=\"Wingdings\"/></a:rPr><a:t>\u{F0FC}</a:t></a:r><a:r><a:rPr><a:latin typeface=\"Calibri\"/></a:rPr><a:t> Done \u{F0FC}</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>";
+
+    let elements = parse_slide_xml(slide.as_bytes()).unwrap();
+
+    let SlideElement::Text(text, _, _) = &elements[0] else {
+        panic!("expected text element")
+    };
+    assert_eq!(text.runs()[0].text, "✓");
+    assert_eq!(text.runs()[1].text, " Done \u{F0FC}\n");
+}
+
+#[test]
+fn marks_text_repeated_from_layout_as_template_text() {
+    let layout = br#"<p:sldLayout xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>ACME  Confidential</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sldLayout>"#;
+    let inherited =
+        extract_inherited_positions(layout, &InheritedPositions::default()).unwrap();
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:txBody><a:p><a:r><a:t>ACME Confidential</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:txBody><a:p><a:r><a:t>Click to edit Master title style</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:txBody><a:p><a:r><a:t>ACME Confidential</a:t></a:r></a:p><a:p><a:r><a:t>Quarterly results</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    let parsed =
+        parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    let from_template: Vec<_> = parsed
+        .blocks
+        .iter()
+        .map(|block| match &block.content {
+            SlideBlockContent::Text(text) => text.from_template,
+            _ => panic!("expected semantic text block"),
+        })
+        .collect();
+
+    assert_eq!(from_template, [true, true, false]);
+}
+
+#[test]
+fn inherits_run_formatting_from_list_styles_layout_and_master() {
+    let master = br#"<p:sldMaster xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="body" idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:lstStyle><a:lvl2pPr><a:defRPr i="1"/></a:lvl2pPr></a:lstStyle></p:txBody></p:sp></p:spTree></p:cSld><p:txStyles><p:titleStyle><a:lvl1pPr><a:defRPr b="1" sz="4400"/></a:lvl1pPr></p:titleStyle><p:bodyStyle><a:lvl1pPr><a:defRPr sz="2800" lang="de-DE"/></a:lvl1pPr><a:lvl2pPr><a:defRPr sz="2400"/></a:lvl2pPr></p:bodyStyle><p:otherStyle><a:lvl1pPr><a:defRPr sz="1800"/></a:lvl1pPr></p:otherStyle></p:txStyles></p:sldMaster>"#;
+    let layout = br#"<p:sldLayout xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:lstStyle><a:lvl1pPr><a:defRPr u="sng"/></a:lvl1pPr></a:lstStyle></p:txBody></p:sp></p:spTree></p:cSld></p:sldLayout>"#;
+    let master = extract_inherited_positions(master, &InheritedPositions::default()).unwrap();
+    let inherited = extract_inherited_positions(layout, &master).unwrap();
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="title"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Title</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:nvSpPr><p:nvPr><p:ph idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:lstStyle><a:lvl1pPr><a:defRPr strike="sngStrike"/></a:lvl1pPr></a:lstStyle><a:p><a:r><a:t>First</a:t></a:r></a:p><a:p><a:pPr lvl="1"/><a:r><a:t>Second</a:t></a:r></a:p><a:p><a:pPr><a:defRPr u="none"/></a:pPr><a:r><a:rPr sz="1200"/><a:t>Third</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:txBody><a:p><a:r><a:t>Box</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    let parsed =
+        parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    let runs: Vec<_> = parsed
+        .blocks
+        .iter()
+        .flat_map(|block| match &block.content {
+            SlideBlockContent::Text(text) => text.paragraphs.iter().flat_map(|p| &p.runs),
+            _ => panic!("expected semantic text block"),
+        })
+        .map(|run| &run.formatting)
+        .collect();
+
+    assert!(runs[0].bold);
+    assert_eq!(runs[0].font_size_points, Some(44.0));
+    assert!(runs[1].underlined && runs[1].strikethrough && !runs[1].italic);
+    assert_eq!(runs[1].font_size_points, Some(28.0));
+    assert_eq!(runs[1].lang, "de-DE");
+    assert!(runs[2].italic && !runs[2].underlined);
+    assert_eq!(runs[2].font_size_points, Some(24.0));
+    assert!(!runs[3].underlined && runs[3].strikethrough);
+    assert_eq!(runs[3].font_size_points, Some(12.0));
+    assert!(!runs[4].bold);
+    assert_eq!(runs[4].font_size_points, Some(18.0));
+}
+
 #[test]
 fn parses_fields_breaks_and_extended_run_formatting() {
     let data = br#"<p:txBody xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:p><a:fld><a:rPr strike="sngStrike" baseline="30000" sz="1850"/><a:t>Field</a:t></a:fld><a:br/><a:r><a:t>Next</a:t></a:r></a:p></p:txBody>"#;
     let mut xml = at_element(data, P_NAMESPACE, b"txBody");
-    let block = parse_text_body(&mut xml, false, &HashMap::new()).unwrap();
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
 
     assert_eq!(block.paragraphs[0].text(), "Field\nNext");
     assert!(block.paragraphs[0].runs[0].formatting.strikethrough);
@@ -128,34 +319,110 @@ fn parses_fields_breaks_and_extended_run_formatting() {
 
     let inherited = br#"<p:txBody xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:p><a:pPr><a:defRPr b="1" i="1"/></a:pPr><a:r><a:rPr b="0"/><a:t>Override</a:t></a:r><a:r><a:t>Inherited</a:t></a:r></a:p></p:txBody>"#;
     let mut xml = at_element(inherited, P_NAMESPACE, b"txBody");
-    let block = parse_text_body(&mut xml, false, &HashMap::new()).unwrap();
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
     assert!(!block.paragraphs[0].runs[0].formatting.bold);
     assert!(block.paragraphs[0].runs[0].formatting.italic);
     assert!(block.paragraphs[0].runs[1].formatting.bold);
     assert!(block.paragraphs[0].runs[1].formatting.italic);
 }
 
+#[test]
+fn reads_paragraph_alignment_and_indentation() {
+    let data = br#"<p:txBody xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:p><a:pPr algn="ctr"/><a:r><a:t>Caption</a:t></a:r></a:p><a:p><a:pPr lvl="1" marL="685800" indent="-228600" algn="just"/><a:r><a:t>Quote</a:t></a:r></a:p></p:txBody>"#;
+    let mut xml = at_element(data, P_NAMESPACE, b"txBody");
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
+
+    assert_eq!(
+        block.paragraphs[0].properties(),
+        ParagraphProperties {
+            alignment: ParagraphAlignment::Center,
+            margin_left: None,
+            indent: None,
+        }
+    );
+    let item = paragraph_to_list_item(block.paragraphs[1].clone());
+    assert_eq!(item.properties.alignment, ParagraphAlignment::Justify);
+    assert_eq!(item.properties.margin_left, Some(685_800));
+    assert_eq!(item.properties.indent, Some(-228_600));
+}
+
+#[test]
+fn parses_the_text_color_but_not_outline_or_highlight_colors() {
+    let data = br#"<p:txBody xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:p><a:r><a:rPr sz="2400"><a:ln><a:solidFill><a:srgbClr val="00FF00"/></a:solidFill></a:ln><a:solidFill><a:srgbClr val="FF0000"/></a:solidFill><a:latin typeface="Arial"/></a:rPr><a:t>Red</a:t></a:r><a:r><a:rPr><a:solidFill><a:schemeClr val="accent1"><a:lumMod val="75000"/></a:schemeClr></a:solidFill></a:rPr><a:t>Accent</a:t></a:r><a:r><a:rPr><a:highlight><a:srgbClr val="FFFF00"/></a:highlight></a:rPr><a:t>Plain</a:t></a:r></a:p></p:txBody>"#;
+    let mut xml = at_element(data, P_NAMESPACE, b"txBody");
+    let block = parse_text_body(&mut xml, &HashMap::new()).unwrap();
+    let runs = &block.paragraphs[0].runs;
+
+    assert_eq!(runs[0].formatting.color, Some(Color::Rgb("FF0000".to_string())));
+    assert_eq!(runs[0].formatting.font.as_deref(), Some("Arial"));
+    assert_eq!(runs[0].formatting.font_size_points, Some(24.0));
+    assert_eq!(
+        runs[1].formatting.color,
+        Some(Color::Scheme("accent1".to_string()))
+    );
+    assert_eq!(runs[2].formatting.color, None);
+}
+
 #[test]
 fn parses_tables_and_empty_cells() {
     let data = fixture("complex_table.xml");
     let mut xml = at_element(&data, A_NAMESPACE, b"tbl");
-    let table = parse_table_events(&mut xml, &HashMap::new()).unwrap();
+    let table = parse_table_events(&mut xml, &HashMap::new(), &TableStyles::default()).unwrap();
     assert_eq!(table.rows.len(), 2);
     assert_eq!(table.rows[0].cells.len(), 3);
-    assert!(table.rows[0].cells[0].runs[0].formatting.bold);
-    assert_eq!(table.rows[1].cells[0].runs.len(), 3);
+    assert!(table.rows[0].cells[0].runs()[0].formatting.bold);
+    assert_eq!(table.rows[1].cells[0].runs().len(), 3);
 
     let data = fixture("empty_table.xml");
     let mut xml = at_element(&data, A_NAMESPACE, b"tbl");
-    let table = parse_table_events(&mut xml, &HashMap::new()).unwrap();
-    assert!(table.rows[0].cells.iter().all(|cell| cell.runs.is_empty()));
+    let table = parse_table_events(&mut xml, &HashMap::new(), &TableStyles::default()).unwrap();
+    assert!(table.rows[0].cells.iter().all(|cell| cell.runs().is_empty()));
+}
+
+#[test]
+fn detects_table_headers_from_the_first_row_flag_and_table_style() {
+    let styles = crate::parse_presentation::parse_table_styles(br#"<a:tblStyleLst xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" def="{HEADER}"><a:tblStyle styleId="{HEADER}"><a:wholeTbl/><a:firstRow/></a:tblStyle><a:tblStyle styleId="{PLAIN}"><a:wholeTbl/></a:tblStyle></a:tblStyleLst>"#).unwrap();
+    let has_header = |properties: &str| {
+        let data = format!(
+            r#"<a:tbl xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main">{properties}<a:tr><a:tc><a:txBody><a:p><a:r><a:t>A</a:t></a:r></a:p></a:txBody></a:tc></a:tr></a:tbl>"#
+        );
+        let mut xml = at_element(data.as_bytes(), A_NAMESPACE, b"tbl");
+        parse_table_events(&mut xml, &HashMap::new(), &styles)
+            .unwrap()
+            .has_header
+    };
+
+    assert!(has_header(r#"<a:tblPr firstRow="1"/>"#));
+    assert!(has_header(
+        r#"<a:tblPr firstRow="1"><a:tableStyleId>{BUILTIN}</a:tableStyleId></a:tblPr>"#
+    ));
+    assert!(!has_header(
+        r#"<a:tblPr firstRow="1"><a:tableStyleId>{PLAIN}</a:tableStyleId></a:tblPr>"#
+    ));
+    assert!(!has_header(
+        r#"<a:tblPr bandRow="1"><a:tableStyleId>{HEADER}</a:tableStyleId></a:tblPr>"#
+    ));
+    assert!(!has_header(""));
+}
+
+#[test]
+fn reads_table_column_widths_from_the_grid() {
+    let data = br#"<a:tbl xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:tblGrid><a:gridCol w="3048000"/><a:gridCol w="1524000"><a:extLst/></a:gridCol></a:tblGrid><a:tr><a:tc><a:txBody><a:p><a:r><a:t>A</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>B</a:t></a:r></a:p></a:txBody></a:tc></a:tr></a:tbl>"#;
+    let mut xml = at_element(data, A_NAMESPACE, b"tbl");
+    let table = parse_table_events(&mut xml, &HashMap::new(), &TableStyles::default()).unwrap();
+
+    assert_eq!(table.column_widths, vec![3_048_000, 1_524_000]);
+    assert_eq!(
+        legacy_table_to_semantic(&table).column_widths,
+        table.column_widths
+    );
 }
 
 #[test]
 fn preserves_pptx_table_spans_for_html_rendering() {
     let data = br#"<a:tbl xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:tr><a:tc gridSpan="2" rowSpan="3"><a:txBody><a:p><a:r><a:t>Merged</a:t></a:r></a:p></a:txBody></a:tc><a:tc hMerge="1"></a:tc></a:tr></a:tbl>"#;
     let mut xml = at_element(data, A_NAMESPACE, b"tbl");
-    let table = parse_table_events(&mut xml, &HashMap::new()).unwrap();
+    let table = parse_table_events(&mut xml, &HashMap::new(), &TableStyles::default()).unwrap();
 
     assert_eq!(table.rows[0].cells[0].column_span, 2);
     assert_eq!(table.rows[0].cells[0].row_span, 3);
@@ -169,17 +436,33 @@ fn parses_graphics_and_picture_failures() {
     let data =
         format!(r#"<p:graphicFrame xmlns:p="{P_NAMESPACE}">{table_fixture}</p:graphicFrame>"#);
     let mut xml = at_element(data.as_bytes(), P_NAMESPACE, b"graphicFrame");
-    let (table, _) = parse_graphic_frame(&mut xml, &HashMap::new()).unwrap();
-    assert_eq!(table.unwrap().rows.len(), 2);
+    let (content, _) = parse_graphic_frame(&mut xml, &HashMap::new(), &TableStyles::default(), &HashMap::new()).unwrap();
+    let Some(FrameContent::Table(table)) = content else { panic!("expected a table") };
+    assert_eq!(table.rows.len(), 2);
 
     let data = fixture("pic_with_image.xml");
     let mut xml = at_element(&data, P_NAMESPACE, b"pic");
-    assert_eq!(parse_picture(&mut xml).unwrap().0.id, "rId2");
+    assert_eq!(parse_picture(&mut xml, &HashMap::new()).unwrap().image.id, "rId2");
+
+    let data = br#"<p:pic xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:nvPicPr><p:cNvPr id="4" name="Logo"><a:hlinkClick r:id="rId3"/></p:cNvPr></p:nvPicPr><p:blipFill><a:blip r:embed="rId2"/></p:blipFill></p:pic>"#;
+    let mut xml = at_element(data, P_NAMESPACE, b"pic");
+    let links = HashMap::from([("rId3".into(), "https://example.com".into())]);
+    let picture = parse_picture(&mut xml, &links).unwrap();
+    assert_eq!(picture.image.link_target.as_deref(), Some("https://example.com"));
+    assert_eq!(picture.alt_text.as_deref(), Some("Logo"));
+    assert_eq!(picture.image.alt_text(), None);
+
+    let data = br#"<p:pic xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:nvPicPr><p:cNvPr id="5" name="Picture 4" descr="Team photo at the offsite" title="Team"/></p:nvPicPr><p:blipFill><a:blip r:embed="rId2"/></p:blipFill></p:pic>"#;
+    let mut xml = at_element(data, P_NAMESPACE, b"pic");
+    let picture = parse_picture(&mut xml, &HashMap::new()).unwrap();
+    assert_eq!(picture.image.description.as_deref(), Some("Team photo at the offsite"));
+    assert_eq!(picture.image.title.as_deref(), Some("Team"));
+    assert_eq!(picture.alt_text.as_deref(), Some("Team photo at the offsite"));
 
     for name in ["pic_without_embed.xml", "pic_without_blip.xml"] {
         let data = fixture(name);
         let mut xml = at_element(&data, P_NAMESPACE, b"pic");
-        assert!(matches!(parse_picture(&mut xml), Err(Error::ImageNotFound)));
+        assert!(matches!(parse_picture(&mut xml, &HashMap::new()), Err(Error::ImageNotFound)));
     }
 }
 
@@ -188,18 +471,39 @@ fn parses_positions_groups_and_alternative_prefixes() {
     let xml = br#"
       <q:sld xmlns:q="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:d="http://schemas.openxmlformats.org/drawingml/2006/main">
         <q:cSld><q:spTree><q:grpSp><q:grpSpPr><d:xfrm><d:off x="100" y="200"/><d:ext cx="200" cy="400"/><d:chOff x="10" y="20"/><d:chExt cx="100" cy="200"/></d:xfrm></q:grpSpPr>
-          <q:sp><q:nvSpPr/><q:spPr><d:xfrm><d:off x="20" y="30"/></d:xfrm></q:spPr><q:txBody><d:p><d:r><d:t>Grouped</d:t></d:r></d:p></q:txBody></q:sp>
+          <q:sp><q:nvSpPr/><q:spPr><d:xfrm><d:off x="20" y="30"/><d:ext cx="30" cy="40"/></d:xfrm></q:spPr><q:txBody><d:p><d:r><d:t>Grouped</d:t></d:r></d:p></q:txBody></q:sp>
         </q:grpSp></q:spTree></q:cSld>
       </q:sld>"#;
     let elements = parse_slide_xml(xml).unwrap();
     let grouped = elements
         .iter()
         .find_map(|element| match element {
-            SlideElement::Text(_, position) => Some(position),
+            SlideElement::Text(_, position, _) => Some(position),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(*grouped, ElementPosition { x: 120, y: 220, width: 60, height: 80 });
+}
+
+#[test]
+fn accumulates_the_transforms_of_nested_groups() {
+    let xml = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+      <p:grpSp><p:grpSpPr><a:xfrm><a:off x="1000" y="2000"/><a:ext cx="200" cy="200"/><a:chOff x="0" y="0"/><a:chExt cx="100" cy="100"/></a:xfrm></p:grpSpPr>
+        <p:grpSp><p:grpSpPr><a:xfrm><a:off x="10" y="10"/><a:ext cx="50" cy="50"/><a:chOff x="0" y="0"/><a:chExt cx="100" cy="100"/></a:xfrm></p:grpSpPr>
+          <p:sp><p:spPr><a:xfrm><a:off x="20" y="40"/><a:ext cx="40" cy="20"/></a:xfrm></p:spPr><p:txBody><a:p><a:r><a:t>Nested</a:t></a:r></a:p></p:txBody></p:sp>
+        </p:grpSp>
+      </p:grpSp>
+    </p:spTree></p:cSld></p:sld>"#;
+    let position = parse_slide_xml(xml)
+        .unwrap()
+        .iter()
+        .find_map(|element| match element {
+            SlideElement::Text(_, position, _) => Some(*position),
             _ => None,
         })
         .unwrap();
-    assert_eq!(*grouped, ElementPosition { x: 120, y: 220 });
+    assert_eq!((position.x, position.y), (1040, 2060));
+    assert_eq!((position.width, position.height), (40, 20));
 }
 
 #[test]
@@ -208,7 +512,31 @@ fn resolves_layout_and_master_placeholder_positions() {
     let inherited = extract_inherited_positions(master, &InheritedPositions::default()).unwrap();
     let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="title" idx="1"/></p:nvPr></p:nvSpPr><p:spPr/><p:txBody><a:p><a:r><a:t>Title</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
     let elements = parse_slide_xml_with_inherited_positions(slide, &inherited).unwrap();
-    assert_eq!(elements[0].position(), ElementPosition { x: 42, y: 84 });
+    assert_eq!(elements[0].position(), ElementPosition { x: 42, y: 84, width: 0, height: 0 });
+    assert_eq!(elements[0].meta().placeholder, Some(crate::PlaceholderType::Title));
+}
+
+#[test]
+fn records_the_placeholder_type_of_parsed_elements() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+      <p:sp><p:nvSpPr><p:nvPr><p:ph type="ctrTitle"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Title</a:t></a:r></a:p></p:txBody></p:sp>
+      <p:sp><p:nvSpPr><p:nvPr><p:ph type="subTitle" idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Subtitle</a:t></a:r></a:p></p:txBody></p:sp>
+      <p:sp><p:nvSpPr><p:nvPr><p:ph idx="2"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Content</a:t></a:r></a:p></p:txBody></p:sp>
+      <p:sp><p:txBody><a:p><a:r><a:t>Box</a:t></a:r></a:p></p:txBody></p:sp>
+    </p:spTree></p:cSld></p:sld>"#;
+    let parsed = parse_slide_document_with_hyperlinks(slide, &InheritedPositions::default(), &HashMap::new()).unwrap();
+    let placeholders: Vec<_> = parsed.elements.iter().map(|element| element.meta().placeholder).collect();
+    assert_eq!(
+        placeholders,
+        vec![
+            Some(crate::PlaceholderType::CenteredTitle),
+            Some(crate::PlaceholderType::Subtitle),
+            Some(crate::PlaceholderType::Object),
+            None,
+        ]
+    );
+    assert!(placeholders[0].is_some_and(crate::PlaceholderType::is_title));
+    assert_eq!(crate::PlaceholderType::from_attr(Some("unknown")), None);
 }
 
 #[test]
@@ -216,7 +544,13 @@ fn reads_only_body_notes_and_rejects_malformed_xml() {
     let xml = br#"<p:notes xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="body"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Note</a:t></a:r></a:p></p:txBody></p:sp><p:sp><p:nvSpPr><p:nvPr><p:ph type="sldNum"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>7</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:notes>"#;
     let notes = parse_speaker_notes_xml(xml).unwrap();
     assert_eq!(notes.len(), 1);
-    assert_eq!(notes[0].runs[0].text, "Note\n");
+    assert_eq!(notes[0].runs()[0].text, "Note\n");
+
+    let xml = br#"<p:notes xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld><p:spTree><p:pic><p:nvPicPr><p:cNvPr id="4" name="Picture 3" descr="Answer key"/></p:nvPicPr><p:blipFill><a:blip r:embed="rId3"/></p:blipFill></p:pic></p:spTree></p:cSld></p:notes>"#;
+    let notes = parse_speaker_notes_xml_with_hyperlinks(xml, &HashMap::new()).unwrap();
+    assert!(notes.text.is_empty());
+    assert_eq!(notes.images[0].reference.id, "rId3");
+    assert_eq!(notes.images[0].alt_text.as_deref(), Some("Answer key"));
     assert!(parse_slide_xml(b"<p:sld").is_err());
 }
 
@@ -262,8 +596,8 @@ fn preserves_semantics_for_tables_images_and_fallbacks_inside_groups() {
     )
     .unwrap();
 
-    assert_eq!(parsed.blocks.len(), 6);
-    assert_eq!(parsed.diagnostics.len(), 3);
+    assert_eq!(parsed.blocks.len(), 5);
+    assert_eq!(parsed.diagnostics.len(), 2);
     assert_eq!(parsed.blocks[1].bounds, Bounds { x: 140, y: 240, width: 200, height: 100 });
     let SlideBlockContent::Table(table) = &parsed.blocks[1].content else {
         panic!("expected grouped table")
@@ -285,7 +619,243 @@ fn preserves_semantics_for_tables_images_and_fallbacks_inside_groups() {
     let SlideBlockContent::Text(nested) = &parsed.blocks[4].content else {
         panic!("expected nested group text")
     };
-    assert_eq!(nested.paragraphs[0].text(), "Nested text\n");
+    assert_eq!(nested.paragraphs[0].text(), "Nested text");
     assert!(parsed.diagnostics.iter().any(|diagnostic| diagnostic.message.contains("graphicFrame")));
-    assert!(parsed.diagnostics.iter().any(|diagnostic| diagnostic.message.contains("cxnSp")));
+    assert!(!parsed.diagnostics.iter().any(|diagnostic| diagnostic.message.contains("cxnSp")));
+    assert!(matches!(parsed.elements.last(), Some(SlideElement::Connector(..))));
+}
+
+#[test]
+fn parses_connectors_with_their_end_points_and_text() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+      <p:cxnSp>
+        <p:nvCxnSpPr><p:cNvPr id="7" name="Arrow 6"/><p:cNvCxnSpPr><a:stCxn id="2" idx="3"/><a:endCxn id="3" idx="1"/></p:cNvCxnSpPr><p:nvPr/></p:nvCxnSpPr>
+        <p:spPr><a:xfrm flipH="1"><a:off x="100" y="200"/><a:ext cx="300" cy="50"/></a:xfrm></p:spPr>
+        <p:txBody><a:p><a:r><a:t>yes</a:t></a:r></a:p></p:txBody>
+      </p:cxnSp>
+      <p:cxnSp><p:spPr><a:xfrm flipV="1"><a:off x="10" y="20"/><a:ext cx="30" cy="40"/></a:xfrm></p:spPr></p:cxnSp>
+      <p:cxnSp><p:spPr><a:xfrm rot="5400000"><a:off x="0" y="0"/><a:ext cx="100" cy="20"/></a:xfrm></p:spPr></p:cxnSp>
+      <p:cxnSp><p:spPr><a:xfrm rot="5400000" flipH="1"><a:off x="0" y="0"/><a:ext cx="100" cy="20"/></a:xfrm></p:spPr></p:cxnSp>
+    </p:spTree></p:cSld></p:sld>"#;
+
+    let parsed = parse_slide_document_with_hyperlinks(
+        slide,
+        &InheritedPositions::default(),
+        &HashMap::new(),
+    )
+    .unwrap();
+
+    let SlideElement::Connector(labelled, position, _) = &parsed.elements[0] else {
+        panic!("expected connector")
+    };
+    assert_eq!((labelled.start, labelled.end), ((400, 200), (100, 250)));
+    assert_eq!((labelled.start_shape_id, labelled.end_shape_id), (Some(2), Some(3)));
+    assert_eq!(labelled.text.as_ref().unwrap().runs()[0].text, "yes\n");
+    assert_eq!((position.x, position.y, position.width, position.height), (100, 200, 300, 50));
+
+    let SlideElement::Connector(plain, _, _) = &parsed.elements[1] else {
+        panic!("expected connector")
+    };
+    assert_eq!((plain.start, plain.end), ((10, 60), (40, 20)));
+    assert_eq!((plain.start_shape_id, plain.text.is_none()), (None, true));
+
+    let rotated: Vec<_> = parsed.elements[2..]
+        .iter()
+        .map(|element| match element {
+            SlideElement::Connector(connector, _, _) => (connector.start, connector.end),
+            _ => panic!("expected connector"),
+        })
+        .collect();
+    assert_eq!(rotated, [((60, -40), (40, 60)), ((60, 60), (40, -40))]);
+
+    assert_eq!(parsed.blocks.len(), 1);
+    assert!(matches!(parsed.blocks[0].content, SlideBlockContent::Text(_)));
+    let source_orders: Vec<_> = parsed.elements.iter().map(|element| element.meta().source_order).collect();
+    assert_eq!(source_orders, [Some(0), Some(1), Some(2), Some(3)]);
+    assert!(parsed.diagnostics.is_empty());
+}
+
+#[test]
+fn keeps_group_hierarchy_when_groups_are_preserved() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+      <p:grpSp><p:nvGrpSpPr><p:cNvPr id="4" name="Group 4"/><p:cNvGrpSpPr/><p:nvPr/></p:nvGrpSpPr>
+        <p:grpSpPr><a:xfrm><a:off x="100" y="200"/><a:ext cx="200" cy="400"/><a:chOff x="10" y="20"/><a:chExt cx="100" cy="200"/></a:xfrm></p:grpSpPr>
+        <p:sp><p:txBody><a:p><a:r><a:t>Outer</a:t></a:r></a:p></p:txBody></p:sp>
+        <p:grpSp><p:nvGrpSpPr><p:cNvPr id="5" name=""/></p:nvGrpSpPr><p:grpSpPr><a:xfrm><a:off x="20" y="30"/></a:xfrm></p:grpSpPr>
+          <p:sp><p:txBody><a:p><a:r><a:t>Inner</a:t></a:r></a:p></p:txBody></p:sp>
+        </p:grpSp>
+      </p:grpSp>
+      <p:sp><p:txBody><a:p><a:r><a:t>Loose</a:t></a:r></a:p></p:txBody></p:sp>
+    </p:spTree></p:cSld></p:sld>"#;
+    let mut inherited = InheritedPositions::default();
+    let flat = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    assert_eq!(flat.elements.len(), 3);
+    assert!(flat.diagnostics.is_empty());
+
+    inherited.set_preserve_groups(true);
+    let parsed = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    assert_eq!(parsed.elements.len(), 2);
+    assert_eq!(parsed.blocks.len(), 3);
+    let SlideElement::Group { children, position, meta, name } = &parsed.elements[0] else {
+        panic!("expected a group")
+    };
+    assert_eq!(name.as_deref(), Some("Group 4"));
+    assert_eq!((position.x, position.y), (100, 200));
+    assert_eq!(meta.id.map(|id| id.shape), Some(ShapeRef::ShapeId(4)));
+    assert_eq!(children.len(), 2);
+    let SlideElement::Group { children: inner, position, name, .. } = &children[1] else {
+        panic!("expected a nested group")
+    };
+    assert_eq!(*name, None);
+    assert_eq!((position.x, position.y), (120, 220));
+    assert!(matches!(&inner[0], SlideElement::Text(text, _, _) if text.text() == "Inner"));
+    let leaves: Vec<_> = parsed.elements.iter().flat_map(SlideElement::leaves).collect();
+    assert_eq!(leaves.len(), 3);
+    assert!(matches!(leaves[2], SlideElement::Text(text, _, _) if text.text() == "Loose"));
+}
+
+#[test]
+fn turns_graphic_frames_with_parsed_charts_into_chart_elements() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:c="http://schemas.openxmlformats.org/drawingml/2006/chart" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld><p:spTree>
+      <p:graphicFrame><p:nvGraphicFramePr><p:cNvPr id="7" name="Chart 6"/></p:nvGraphicFramePr><p:xfrm><a:off x="10" y="20"/><a:ext cx="300" cy="200"/></p:xfrm>
+        <a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart"><c:chart r:id="rId3"/></a:graphicData></a:graphic>
+      </p:graphicFrame>
+    </p:spTree></p:cSld></p:sld>"#;
+    let mut inherited = InheritedPositions::default();
+    let unresolved = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    assert!(matches!(&unresolved.blocks[0].content, SlideBlockContent::Unsupported(block) if block.content_type.as_deref() == Some("chart")));
+
+    let chart = ChartElement {
+        relationship_id: "rId3".to_string(),
+        chart_type: Some("pieChart".to_string()),
+        title: None,
+        categories: vec!["Yes".to_string(), "No".to_string()],
+        series: vec![crate::ChartSeries { name: Some("Votes".to_string()), values: vec![Some(7.0), Some(3.0)] }],
+    };
+    inherited.set_charts(HashMap::from([("rId3".to_string(), chart.clone())]));
+    let parsed = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    let SlideElement::Chart(element, position, _) = &parsed.elements[0] else { panic!("expected a chart") };
+    assert_eq!(*element, chart);
+    assert_eq!((position.x, position.y), (10, 20));
+    let SlideBlockContent::Table(table) = &parsed.blocks[0].content else { panic!("expected a table block") };
+    assert!(table.has_header);
+    assert_eq!(table.rows.len(), 3);
+    assert_eq!(table.rows[1].cells[0].paragraphs[0].text(), "Yes");
+    assert_eq!(table.rows[1].cells[1].paragraphs[0].text(), "7");
+}
+
+#[test]
+fn turns_pictures_linking_resolved_clips_into_media_elements() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld><p:spTree>
+      <p:pic><p:nvPicPr><p:cNvPr id="4" name="intro.mp4" descr="Product demo"/><p:cNvPicPr/><p:nvPr><a:videoFile r:link="rId2"/></p:nvPr></p:nvPicPr><p:blipFill><a:blip r:embed="rId3"/></p:blipFill><p:spPr><a:xfrm><a:off x="10" y="20"/><a:ext cx="300" cy="200"/></a:xfrm></p:spPr></p:pic>
+      <p:pic><p:nvPicPr><p:cNvPr id="5" name="chime.wav"/><p:cNvPicPr/><p:nvPr><a:audioFile r:link="rId9"/></p:nvPr></p:nvPicPr><p:blipFill><a:blip r:embed="rId4"/></p:blipFill></p:pic>
+    </p:spTree></p:cSld></p:sld>"#;
+    let mut inherited = InheritedPositions::default();
+    inherited.set_media(HashMap::from([(
+        "rId2".to_string(),
+        MediaReference {
+            kind: MediaKind::Video,
+            id: "rId2".to_string(),
+            target: "ppt/media/media1.mp4".to_string(),
+            external: false,
+            mime_type: Some("video/mp4".to_string()),
+            poster: None,
+            data: Some(vec![1, 2, 3]),
+        },
+    )]), Vec::new());
+    let parsed = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+
+    let SlideElement::Media(media, position, _) = &parsed.elements[0] else { panic!("expected a media element") };
+    assert_eq!(media.kind, MediaKind::Video);
+    assert_eq!(media.target, "ppt/media/media1.mp4");
+    assert_eq!(media.data.as_deref(), Some(&[1, 2, 3][..]));
+    assert_eq!(media.poster.as_ref().map(|poster| poster.id.as_str()), Some("rId3"));
+    assert_eq!((position.x, position.y), (10, 20));
+    let SlideBlockContent::Image(poster) = &parsed.blocks[0].content else { panic!("expected the poster frame") };
+    assert_eq!(poster.alt_text.as_deref(), Some("Product demo"));
+
+    // A clip whose relationship is missing stays a picture.
+    assert!(matches!(&parsed.elements[1], SlideElement::Image(image, _, _) if image.id == "rId4"));
+}
+
+#[test]
+fn records_the_source_order_of_flattened_and_grouped_elements() {
+    let slide = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+      <p:sp><p:spPr><a:xfrm><a:off x="0" y="900"/></a:xfrm></p:spPr><p:txBody><a:p><a:r><a:t>Footer</a:t></a:r></a:p></p:txBody></p:sp>
+      <p:grpSp><p:grpSpPr/>
+        <p:sp><p:txBody><a:p><a:r><a:t>First</a:t></a:r></a:p></p:txBody></p:sp>
+        <p:sp><p:txBody><a:p><a:r><a:t>Second</a:t></a:r></a:p></p:txBody></p:sp>
+      </p:grpSp>
+      <p:sp><p:spPr><a:xfrm><a:off x="0" y="0"/></a:xfrm></p:spPr><p:txBody><a:p><a:r><a:t>Header</a:t></a:r></a:p></p:txBody></p:sp>
+    </p:spTree></p:cSld></p:sld>"#;
+    let mut inherited = InheritedPositions::default();
+    let flat = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    let orders: Vec<_> = flat
+        .elements
+        .iter()
+        .map(|element| element.meta().source_order)
+        .collect();
+    assert_eq!(orders, [Some(0), Some(1), Some(2), Some(3)]);
+    let block_orders: Vec<_> = flat.blocks.iter().map(|block| Some(block.source_order)).collect();
+    assert_eq!(orders, block_orders);
+    assert_eq!(parse_slide_xml(slide).unwrap()[0].meta().source_order, None);
+
+    inherited.set_preserve_groups(true);
+    let grouped = parse_slide_document_with_hyperlinks(slide, &inherited, &HashMap::new()).unwrap();
+    assert_eq!(grouped.elements[1].meta().source_order, Some(1));
+    assert_eq!(grouped.elements[1].children()[1].meta().source_order, Some(2));
+    assert_eq!(grouped.elements[2].meta().source_order, Some(3));
+}
+
+#[test]
+fn scans_only_the_title_placeholder_text() {
+    let xml = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:sp><p:nvSpPr><p:nvPr><p:ph type="body" idx="1"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Body</a:t></a:r></a:p></p:txBody></p:sp><p:graphicFrame><a:graphic><a:graphicData><a:tbl><a:tr><a:tc><a:txBody><a:p><a:r><a:t>Cell</a:t></a:r></a:p></a:txBody></a:tc></a:tr></a:tbl></a:graphicData></a:graphic></p:graphicFrame><p:sp><p:nvSpPr><p:nvPr><p:ph type="ctrTitle"/></p:nvPr></p:nvSpPr><p:txBody><a:p><a:r><a:t>Quarterly </a:t></a:r><a:r><a:t>Report</a:t></a:r></a:p><a:p><a:r><a:t>2024</a:t></a:r></a:p></p:txBody></p:sp></p:spTree></p:cSld></p:sld>"#;
+
+    assert_eq!(
+        parse_slide_title(xml).unwrap().as_deref(),
+        Some("Quarterly Report 2024")
+    );
+    assert_eq!(
+        parse_slide_title(br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"/>"#).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn reads_the_common_slide_data_name() {
+    let layout = br#"<p:sldLayout xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld name="Title and Content"><p:spTree/></p:cSld></p:sldLayout>"#;
+    let master = br#"<p:sldMaster xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:spTree/></p:cSld></p:sldMaster>"#;
+
+    assert_eq!(
+        parse_part_name(layout, "layout.xml").unwrap().as_deref(),
+        Some("Title and Content")
+    );
+    assert_eq!(parse_part_name(master, "master.xml").unwrap(), None);
+}
+
+#[test]
+fn attaches_the_source_xml_of_every_block() {
+    let xml = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree>
+      <p:sp><p:txBody><a:p><a:r><a:t>First</a:t></a:r></a:p></p:txBody></p:sp>
+      <p:grpSp><p:grpSpPr/><p:sp><p:txBody><a:p><a:r><a:t>Grouped</a:t></a:r></a:p></p:txBody></p:sp></p:grpSp>
+      <p:cxnSp><p:nvCxnSpPr/></p:cxnSp>
+    </p:spTree></p:cSld></p:sld>"#;
+    let mut parsed =
+        parse_slide_document_with_hyperlinks(xml, &InheritedPositions::default(), &HashMap::new())
+            .unwrap();
+    assert!(parsed.blocks.iter().all(|block| block.raw.is_none()));
+
+    parsed.attach_raw_xml(xml);
+    let raw: Vec<_> = parsed
+        .blocks
+        .iter()
+        .map(|block| block.raw.as_deref().unwrap())
+        .collect();
+    assert_eq!(
+        raw,
+        [
+            "<p:sp><p:txBody><a:p><a:r><a:t>First</a:t></a:r></a:p></p:txBody></p:sp>",
+            "<p:sp><p:txBody><a:p><a:r><a:t>Grouped</a:t></a:r></a:p></p:txBody></p:sp>",
+        ]
+    );
 }
@@ -13,6 +13,26 @@ fn default_config_uses_documented_values() {
     assert!(!config.include_speaker_notes);
     assert!(!config.include_comments);
     assert!(config.include_presentation_metadata);
+    assert!(!config.exclude_template_text);
+    assert!(!config.legacy_list_classification);
+    assert!(!config.preserve_raw_xml);
+    assert!(config.ocr_provider.is_none());
+    assert_eq!(config.image_naming, ImageNaming::SlideIndex);
+    assert_eq!(config.max_total_media_bytes, None);
+    assert_eq!(config.resource_limits, ResourceLimits::default());
+    assert!(!config.lossy_utf8);
+    assert_eq!(config.on_slide_error, OnSlideError::Fail);
+    assert_eq!(config.slide_heading, None);
+    assert_eq!(config.slide_anchors, SlideAnchors::Title);
+    assert_eq!(config.paragraph_spacing, ParagraphSpacing::BlankLine);
+    assert!(!config.continue_list_numbering);
+    assert!(!config.demote_single_item_lists);
+    assert_eq!(config.underline_style, UnderlineStyle::U);
+    assert!(!config.html_emphasis);
+    assert!(!config.preserve_groups);
+    assert!(!config.include_layout_content);
+    assert_eq!(config.title_heading_level, 2);
+    assert!(!config.extract_media);
 }
 
 #[test]
@@ -28,6 +48,29 @@ fn builder_applies_every_override() {
         .include_speaker_notes(true)
         .include_comments(true)
         .include_presentation_metadata(false)
+        .exclude_template_text(true)
+        .legacy_list_classification(true)
+        .preserve_raw_xml(true)
+        .ocr_provider(|_: &[u8], _: &str| Some("text".to_string()))
+        .image_naming(ImageNaming::ContentHash)
+        .max_total_media_bytes(1024)
+        .resource_limits(ResourceLimits {
+            max_group_depth: Some(8),
+            ..ResourceLimits::default()
+        })
+        .lossy_utf8(true)
+        .on_slide_error(OnSlideError::Skip)
+        .slide_heading("## Slide {n}: {title}")
+        .slide_anchors(SlideAnchors::Standalone)
+        .paragraph_spacing(ParagraphSpacing::LineBreak)
+        .continue_list_numbering(true)
+        .demote_single_item_lists(true)
+        .underline_style(UnderlineStyle::Emphasis)
+        .html_emphasis(true)
+        .preserve_groups(true)
+        .include_layout_content(true)
+        .title_heading_level(1)
+        .extract_media(true)
         .build();
 
     assert!(!config.extract_images);
@@ -39,4 +82,165 @@ fn builder_applies_every_override() {
     assert!(config.include_speaker_notes);
     assert!(config.include_comments);
     assert!(!config.include_presentation_metadata);
+    assert!(config.exclude_template_text);
+    assert!(config.legacy_list_classification);
+    assert!(config.preserve_raw_xml);
+    let provider = config.ocr_provider.expect("OCR provider");
+    assert_eq!(provider.ocr(b"", "image/png").as_deref(), Some("text"));
+    assert_eq!(config.image_naming, ImageNaming::ContentHash);
+    assert_eq!(config.max_total_media_bytes, Some(1024));
+    assert_eq!(config.resource_limits.max_group_depth, Some(8));
+    assert!(config.lossy_utf8);
+    assert_eq!(config.on_slide_error, OnSlideError::Skip);
+    assert_eq!(
+        config.slide_heading.as_deref(),
+        Some("## Slide {n}: {title}")
+    );
+    assert_eq!(config.slide_anchors, SlideAnchors::Standalone);
+    assert_eq!(config.paragraph_spacing, ParagraphSpacing::LineBreak);
+    assert!(config.continue_list_numbering);
+    assert!(config.demote_single_item_lists);
+    assert_eq!(config.underline_style, UnderlineStyle::Emphasis);
+    assert!(config.html_emphasis);
+    assert!(config.preserve_groups);
+    assert!(config.include_layout_content);
+    assert_eq!(config.title_heading_level, 1);
+    assert!(config.extract_media);
+}
+
+#[test]
+fn build_checked_rejects_contradictory_options() {
+    assert_eq!(
+        ParserConfig::builder()
+            .quality(101)
+            .build_checked()
+            .unwrap_err(),
+        ConfigError::InvalidQuality(101)
+    );
+    assert_eq!(
+        ParserConfig::builder()
+            .image_handling_mode(ImageHandlingMode::Save)
+            .build_checked()
+            .unwrap_err(),
+        ConfigError::MissingImageOutputPath
+    );
+    assert_eq!(
+        ParserConfig::builder()
+            .extract_images(false)
+            .image_handling_mode(ImageHandlingMode::InMarkdown)
+            .build_checked()
+            .unwrap_err(),
+        ConfigError::ImagesNotExtracted(ImageHandlingMode::InMarkdown)
+    );
+
+    let file = std::env::temp_dir().join(format!("pptx-to-md-config-{}", std::process::id()));
+    std::fs::write(&file, b"not a directory").expect("write temporary file");
+    let result = ParserConfig::builder()
+        .image_handling_mode(ImageHandlingMode::Save)
+        .image_output_path(file.join("images"))
+        .build_checked();
+    std::fs::remove_file(&file).expect("remove temporary file");
+    assert_eq!(
+        result.unwrap_err(),
+        ConfigError::OutputPathNotWritable(file.join("images"))
+    );
+}
+
+#[test]
+fn build_checked_accepts_valid_configurations() {
+    let config = ParserConfig::builder()
+        .quality(100)
+        .image_handling_mode(ImageHandlingMode::Save)
+        .image_output_path(std::env::temp_dir().join("pptx-to-md-not-created-yet"))
+        .build_checked()
+        .expect("valid configuration");
+    assert_eq!(config.quality, 100);
+    assert!(
+        ParserConfig::builder()
+            .extract_images(false)
+            .build_checked()
+            .is_ok()
+    );
+}
+
+#[test]
+fn presets_combine_the_documented_options() {
+    let llm = ParserConfig::for_llm();
+    assert!(!llm.extract_images);
+    assert!(llm.include_speaker_notes);
+    assert!(llm.exclude_template_text);
+
+    let lossless = ParserConfig::lossless();
+    assert!(lossless.extract_images);
+    assert!(!lossless.compress_images);
+    assert_eq!(lossless.image_handling_mode, ImageHandlingMode::InMarkdown);
+
+    let fast = ParserConfig::fast_text_only();
+    assert!(!fast.extract_images);
+    assert!(!fast.include_presentation_metadata);
+}
+
+#[test]
+fn splits_into_parse_and_render_options_and_back() {
+    let config = ParserConfig::builder()
+        .extract_images(false)
+        .quality(55)
+        .include_slide_number_as_comment(false)
+        .include_presentation_metadata(false)
+        .build();
+
+    let parse = config.parse_options();
+    let render = config.render_options();
+    assert!(!parse.extract_images);
+    assert_eq!(render.quality, 55);
+    assert!(!render.markdown.include_slide_number_as_comment);
+    assert!(!render.include_presentation_metadata);
+
+    let combined = ParserConfig::from_options(parse, render);
+    assert!(!combined.extract_images);
+    assert_eq!(combined.quality, 55);
+    assert!(!combined.include_slide_number_as_comment);
+    assert!(!combined.include_presentation_metadata);
+}
+
+#[test]
+fn parse_and_render_options_builders_match_the_parser_config_builder() {
+    let parse = ParseOptions::builder()
+        .extract_images(false)
+        .lossy_utf8(true)
+        .on_slide_error(OnSlideError::Skip)
+        .build();
+    let render = RenderOptions::builder()
+        .quality(55)
+        .include_speaker_notes(true)
+        .title_heading_level(9)
+        .build();
+    let config = ParserConfig::builder()
+        .extract_images(false)
+        .lossy_utf8(true)
+        .on_slide_error(OnSlideError::Skip)
+        .quality(55)
+        .include_speaker_notes(true)
+        .title_heading_level(9)
+        .build();
+
+    assert_eq!(parse, config.parse_options());
+    assert_eq!(ParseOptions::builder().build(), ParseOptions::default());
+    assert_eq!(render.quality, config.render_options().quality);
+    assert!(render.markdown.include_speaker_notes);
+    assert_eq!(render.markdown.title_heading_level, 5);
+    assert_eq!(
+        RenderOptions::builder()
+            .quality(101)
+            .build_checked()
+            .unwrap_err(),
+        ConfigError::InvalidQuality(101)
+    );
+    assert_eq!(
+        RenderOptions::builder()
+            .image_handling_mode(ImageHandlingMode::Save)
+            .build_checked()
+            .unwrap_err(),
+        ConfigError::MissingImageOutputPath
+    );
 }
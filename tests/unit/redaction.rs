@@ -0,0 +1,168 @@
+use super::*;
+use crate::{ElementMeta, Formatting, ParserConfig, SlideBlock, TextBlock};
+use std::collections::HashMap;
+
+fn run(text: &str, bold: bool) -> Run {
+    Run {
+        text: text.to_string(),
+        formatting: Formatting {
+            bold,
+            ..Formatting::default()
+        },
+        link_target: None,
+    }
+}
+
+#[test]
+fn redacts_matches_spanning_several_runs() {
+    let mut runs = vec![
+        run("Mail jane.", false),
+        run("doe@example.com", true),
+        run(" or call +49 123", false),
+    ];
+    let patterns = [
+        Regex::new(r"[\w.]+@[\w.]+").unwrap(),
+        Regex::new(r"\+\d+ \d+").unwrap(),
+    ];
+
+    let matches = redact_runs(&mut runs, &patterns, "[X]");
+
+    assert_eq!(
+        matches,
+        [
+            (0, "jane.doe@example.com".to_string()),
+            (1, "+49 123".to_string())
+        ]
+    );
+    let texts: Vec<_> = runs.iter().map(|run| run.text.as_str()).collect();
+    assert_eq!(texts, ["Mail [X]", "", " or call [X]"]);
+}
+
+#[test]
+fn reports_hits_in_blocks_and_notes_and_redacts_legacy_elements() {
+    let text = TextElement::from_runs(vec![run("Contact: jane@example.com", false)]);
+    let mut slide = Slide::new(
+        "ppt/slides/slide2.xml".to_string(),
+        2,
+        vec![SlideElement::Text(
+            text.clone(),
+            Default::default(),
+            ElementMeta::default(),
+        )],
+        vec![text],
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        ParserConfig::default(),
+    );
+    slide.blocks.push(SlideBlock {
+        bounds: Default::default(),
+        source_order: 1,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role: Default::default(),
+            paragraphs: vec![
+                Paragraph::plain(vec![run("Intro", false)]),
+                Paragraph::plain(vec![run("bob@example.com", false)]),
+            ],
+            from_template: false,
+        }),
+    });
+
+    let hits = slide.redact(&[Regex::new(r"\S+@\S+").unwrap()]);
+
+    let locations: Vec<_> = hits.iter().map(|hit| hit.location).collect();
+    assert_eq!(
+        locations,
+        [
+            RedactionLocation::Text {
+                block: 0,
+                paragraph: 0
+            },
+            RedactionLocation::Text {
+                block: 1,
+                paragraph: 1
+            },
+            RedactionLocation::SpeakerNotes { paragraph: 0 },
+        ]
+    );
+    assert!(hits.iter().all(|hit| hit.slide_number == 2));
+    assert_eq!(slide.text(), "Contact: [REDACTED]\n\nIntro\n[REDACTED]");
+    assert!(
+        matches!(&slide.elements[0], SlideElement::Text(text, _, _) if text.text() == "Contact: [REDACTED]")
+    );
+    assert_eq!(slide.speaker_notes[0].text(), "Contact: [REDACTED]");
+}
+
+#[test]
+fn redacts_image_alt_texts_and_counts_notes_paragraphs_across_frames() {
+    let reference = ImageReference {
+        id: "rId2".to_string(),
+        target: "../media/image1.png".to_string(),
+        dimensions: None,
+        display_size: None,
+        link_target: None,
+        description: Some("Badge of jane@example.com".to_string()),
+        title: Some("jane@example.com".to_string()),
+    };
+    let notes = |text: &str| {
+        TextElement::from_paragraphs(vec![
+            Paragraph::plain(vec![run("Notes", false)]),
+            Paragraph::plain(vec![run(text, false)]),
+        ])
+    };
+    let mut slide = Slide::new(
+        "ppt/slides/slide1.xml".to_string(),
+        1,
+        vec![SlideElement::Image(
+            reference.clone(),
+            Default::default(),
+            ElementMeta::default(),
+        )],
+        vec![notes("none"), notes("bob@example.com")],
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        ParserConfig::default(),
+    );
+    let image = ImageBlock {
+        alt_text: reference.alt_text().map(str::to_string),
+        reference,
+        mime_type: None,
+    };
+    slide.blocks = vec![SlideBlock {
+        bounds: Default::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Image(image.clone()),
+    }];
+    slide.notes_images = vec![image];
+
+    let hits = slide.redact(&[Regex::new(r"\S+@\S+").unwrap()]);
+
+    let locations: Vec<_> = hits.iter().map(|hit| hit.location).collect();
+    assert_eq!(
+        locations,
+        [
+            RedactionLocation::ImageAltText { block: 0 },
+            RedactionLocation::SpeakerNotes { paragraph: 3 },
+            RedactionLocation::NotesImageAltText { index: 0 },
+        ]
+    );
+    let SlideBlockContent::Image(image) = &slide.blocks[0].content else {
+        panic!("expected image block")
+    };
+    assert_eq!(image.alt_text.as_deref(), Some("Badge of [REDACTED]"));
+    assert_eq!(image.reference.title.as_deref(), Some("[REDACTED]"));
+    let SlideElement::Image(reference, _, _) = &slide.elements[0] else {
+        panic!("expected image element")
+    };
+    assert_eq!(
+        reference.description.as_deref(),
+        Some("Badge of [REDACTED]")
+    );
+    assert_eq!(
+        slide.notes_images[0].alt_text.as_deref(),
+        Some("Badge of [REDACTED]")
+    );
+}
@@ -1,11 +1,12 @@
 use super::*;
 use crate::{
-    ElementPosition, Formatting, ListElement, ListItem, Run, TableCell, TableElement, TableRow,
-    TextElement,
+    ElementMeta, ElementPosition, Formatting, ListElement, ListItem, ParagraphProperties, Run,
+    SemanticTableCell, SemanticTableRow, TableCell, TableElement, TableRow, TextElement,
 };
 use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 fn load_test_data(filename: &str) -> String {
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
@@ -33,12 +34,19 @@ fn mock_slide() -> Slide {
         slide_number: 1,
         elements: vec![],
         speaker_notes: vec![],
+        notes_images: vec![],
         comments: vec![],
         images: vec![],
         image_data: HashMap::new(),
-        config: ParserConfig::default(),
+        render_options: RenderOptions::default(),
         blocks: vec![],
         diagnostics: vec![],
+        background: None,
+        theme: None,
+        layout_name: None,
+        layout_type: None,
+        master_name: None,
+        slide_size: None,
     }
 }
 
@@ -56,8 +64,14 @@ fn image_element(id: &str, target: &str) -> SlideElement {
         ImageReference {
             id: id.to_string(),
             target: target.to_string(),
+            dimensions: None,
+            display_size: None,
+            link_target: None,
+            description: None,
+            title: None,
         },
         ElementPosition::default(),
+        ElementMeta::default(),
     )
 }
 
@@ -68,31 +82,33 @@ fn test_markdown_table_conversion() {
         slide_number: 1,
         elements: vec![SlideElement::Table(
             TableElement {
+                has_header: true,
+                column_widths: Vec::new(),
                 rows: vec![
                     TableRow {
                         cells: vec![
                             TableCell {
-                                runs: vec![Run {
+                                paragraphs: vec![Paragraph::plain(vec![Run {
                                     text: "First name".into(),
                                     formatting: Formatting::default(),
                                     link_target: None,
-                                }],
+                                }])],
                                 ..TableCell::default()
                             },
                             TableCell {
-                                runs: vec![Run {
+                                paragraphs: vec![Paragraph::plain(vec![Run {
                                     text: "Last name".into(),
                                     formatting: Formatting::default(),
                                     link_target: None,
-                                }],
+                                }])],
                                 ..TableCell::default()
                             },
                             TableCell {
-                                runs: vec![Run {
+                                paragraphs: vec![Paragraph::plain(vec![Run {
                                     text: "Age".into(),
                                     formatting: Formatting::default(),
                                     link_target: None,
-                                }],
+                                }])],
                                 ..TableCell::default()
                             },
                         ],
@@ -100,27 +116,27 @@ fn test_markdown_table_conversion() {
                     TableRow {
                         cells: vec![
                             TableCell {
-                                runs: vec![Run {
+                                paragraphs: vec![Paragraph::plain(vec![Run {
                                     text: "John".into(),
                                     formatting: Formatting::default(),
                                     link_target: None,
-                                }],
+                                }])],
                                 ..TableCell::default()
                             },
                             TableCell {
-                                runs: vec![Run {
+                                paragraphs: vec![Paragraph::plain(vec![Run {
                                     text: "Doe".into(),
                                     formatting: Formatting::default(),
                                     link_target: None,
-                                }],
+                                }])],
                                 ..TableCell::default()
                             },
                             TableCell {
-                                runs: vec![Run {
+                                paragraphs: vec![Paragraph::plain(vec![Run {
                                     text: "21".into(),
                                     formatting: Formatting::default(),
                                     link_target: None,
-                                }],
+                                }])],
                                 ..TableCell::default()
                             },
                         ],
@@ -128,14 +144,22 @@ fn test_markdown_table_conversion() {
                 ],
             },
             ElementPosition::default(),
+            ElementMeta::default(),
         )],
         speaker_notes: vec![],
+        notes_images: vec![],
         comments: vec![],
         images: vec![],
         image_data: HashMap::new(),
-        config: ParserConfig::default(),
+        render_options: RenderOptions::default(),
         blocks: vec![],
         diagnostics: vec![],
+        background: None,
+        theme: None,
+        layout_name: None,
+        layout_type: None,
+        master_name: None,
+        slide_size: None,
     };
     let md_result = slide.convert_to_md().unwrap();
 
@@ -158,6 +182,7 @@ fn test_markdown_list_conversion() {
                     ListItem {
                         level: 0,
                         is_ordered: false,
+                        properties: ParagraphProperties::default(),
                         runs: vec![Run {
                             text: "Layer 1 Element 1".into(),
                             formatting: Formatting::default(),
@@ -167,6 +192,7 @@ fn test_markdown_list_conversion() {
                     ListItem {
                         level: 1,
                         is_ordered: false,
+                        properties: ParagraphProperties::default(),
                         runs: vec![Run {
                             text: "Layer 2 Element 1".into(),
                             formatting: Formatting::default(),
@@ -176,6 +202,7 @@ fn test_markdown_list_conversion() {
                     ListItem {
                         level: 1,
                         is_ordered: false,
+                        properties: ParagraphProperties::default(),
                         runs: vec![Run {
                             text: "Layer 2 Element 2".into(),
                             formatting: Formatting::default(),
@@ -185,6 +212,7 @@ fn test_markdown_list_conversion() {
                     ListItem {
                         level: 0,
                         is_ordered: false,
+                        properties: ParagraphProperties::default(),
                         runs: vec![Run {
                             text: "Layer 1 Element 2".into(),
                             formatting: Formatting::default(),
@@ -194,14 +222,22 @@ fn test_markdown_list_conversion() {
                 ],
             },
             ElementPosition::default(),
+            ElementMeta::default(),
         )],
         speaker_notes: vec![],
+        notes_images: vec![],
         comments: vec![],
         images: vec![],
         image_data: HashMap::new(),
-        config: ParserConfig::default(),
+        render_options: RenderOptions::default(),
         blocks: vec![],
         diagnostics: vec![],
+        background: None,
+        theme: None,
+        layout_name: None,
+        layout_type: None,
+        master_name: None,
+        slide_size: None,
     };
 
     let md_result = slide.convert_to_md().unwrap();
@@ -220,86 +256,88 @@ fn test_formatting_conversion() {
         slide_number: 1,
         elements: vec![
             SlideElement::Text(
-                TextElement {
-                    runs: vec![Run {
-                        text: "bold\n".into(),
-                        formatting: Formatting {
-                            bold: true,
-                            lang: "en-US".into(),
-                            ..Formatting::default()
-                        },
-                        link_target: None,
-                    }],
-                },
+                TextElement::from_runs(vec![Run {
+                    text: "bold\n".into(),
+                    formatting: Formatting {
+                        bold: true,
+                        lang: "en-US".into(),
+                        ..Formatting::default()
+                    },
+                    link_target: None,
+                }]),
                 ElementPosition::default(),
+                ElementMeta::default(),
             ),
             SlideElement::Text(
-                TextElement {
-                    runs: vec![Run {
-                        text: "cursive\n".into(),
-                        formatting: Formatting {
-                            italic: true,
-                            lang: "en-US".into(),
-                            ..Formatting::default()
-                        },
-                        link_target: None,
-                    }],
-                },
+                TextElement::from_runs(vec![Run {
+                    text: "cursive\n".into(),
+                    formatting: Formatting {
+                        italic: true,
+                        lang: "en-US".into(),
+                        ..Formatting::default()
+                    },
+                    link_target: None,
+                }]),
                 ElementPosition::default(),
+                ElementMeta::default(),
             ),
             SlideElement::Text(
-                TextElement {
-                    runs: vec![Run {
-                        text: "underlined\n".into(),
-                        formatting: Formatting {
-                            underlined: true,
-                            lang: "en-US".into(),
-                            ..Formatting::default()
-                        },
-                        link_target: None,
-                    }],
-                },
+                TextElement::from_runs(vec![Run {
+                    text: "underlined\n".into(),
+                    formatting: Formatting {
+                        underlined: true,
+                        lang: "en-US".into(),
+                        ..Formatting::default()
+                    },
+                    link_target: None,
+                }]),
                 ElementPosition::default(),
+                ElementMeta::default(),
             ),
             SlideElement::Text(
-                TextElement {
-                    runs: vec![Run {
-                        text: "bold and cursive\n".into(),
-                        formatting: Formatting {
-                            bold: true,
-                            italic: true,
-                            lang: "en-US".into(),
-                            ..Formatting::default()
-                        },
-                        link_target: None,
-                    }],
-                },
+                TextElement::from_runs(vec![Run {
+                    text: "bold and cursive\n".into(),
+                    formatting: Formatting {
+                        bold: true,
+                        italic: true,
+                        lang: "en-US".into(),
+                        ..Formatting::default()
+                    },
+                    link_target: None,
+                }]),
                 ElementPosition::default(),
+                ElementMeta::default(),
             ),
             SlideElement::Text(
-                TextElement {
-                    runs: vec![Run {
-                        text: "bold, cursive and underlined\n".into(),
-                        formatting: Formatting {
-                            bold: true,
-                            italic: true,
-                            underlined: true,
-                            lang: "en-US".into(),
-                            ..Formatting::default()
-                        },
-                        link_target: None,
-                    }],
-                },
+                TextElement::from_runs(vec![Run {
+                    text: "bold, cursive and underlined\n".into(),
+                    formatting: Formatting {
+                        bold: true,
+                        italic: true,
+                        underlined: true,
+                        lang: "en-US".into(),
+                        ..Formatting::default()
+                    },
+                    link_target: None,
+                }]),
                 ElementPosition::default(),
+                ElementMeta::default(),
             ),
         ],
         speaker_notes: vec![],
+        notes_images: vec![],
         comments: vec![],
         images: vec![],
         image_data: HashMap::new(),
-        config: ParserConfig::default(),
+        render_options: RenderOptions::default(),
         blocks: vec![],
         diagnostics: vec![],
+        background: None,
+        theme: None,
+        layout_name: None,
+        layout_type: None,
+        master_name: None,
+        slide_size: None,
     };
 
     let md_result = slide.convert_to_md().unwrap();
@@ -321,37 +359,44 @@ fn renders_links_in_all_text_markdown_contexts() {
     let mut slide = mock_slide();
     slide.elements = vec![
         SlideElement::Text(
-            TextElement { runs: vec![link()] },
+            TextElement::from_runs(vec![link()]),
             ElementPosition::default(),
+            ElementMeta::default(),
         ),
         SlideElement::List(
             ListElement {
                 items: vec![ListItem {
                     level: 0,
                     is_ordered: false,
+                    properties: ParagraphProperties::default(),
                     runs: vec![link()],
                 }],
             },
             ElementPosition::default(),
+            ElementMeta::default(),
         ),
         SlideElement::Table(
             TableElement {
+                has_header: true,
+                column_widths: Vec::new(),
                 rows: vec![TableRow {
                     cells: vec![TableCell {
-                        runs: vec![link()],
+                        paragraphs: vec![Paragraph::plain(vec![link()])],
                         ..TableCell::default()
                     }],
                 }],
             },
             ElementPosition::default(),
+            ElementMeta::default(),
         ),
     ];
-    slide.speaker_notes = vec![TextElement { runs: vec![link()] }];
-    slide.comments = vec![TextElement { runs: vec![link()] }];
-    slide.config = ParserConfig::builder()
+    slide.speaker_notes = vec![TextElement::from_runs(vec![link()])];
+    slide.comments = vec![TextElement::from_runs(vec![link()])];
+    slide.render_options = ParserConfig::builder()
         .include_speaker_notes(true)
         .include_comments(true)
-        .build();
+        .build()
+        .into();
 
     let markdown = slide.convert_to_md().expect("render markdown");
     assert_eq!(
@@ -368,46 +413,47 @@ fn escapes_raw_markdown_in_all_text_contexts() {
         link_target: None,
     };
     let mut slide = mock_slide();
-    slide.config = ParserConfig::builder()
+    slide.render_options = ParserConfig::builder()
         .include_slide_number_as_comment(false)
         .include_speaker_notes(true)
         .include_comments(true)
-        .build();
+        .build()
+        .into();
     slide.elements = vec![
         SlideElement::Text(
-            TextElement {
-                runs: vec![special()],
-            },
+            TextElement::from_runs(vec![special()]),
             ElementPosition::default(),
+            ElementMeta::default(),
         ),
         SlideElement::List(
             ListElement {
                 items: vec![ListItem {
                     level: 0,
                     is_ordered: false,
+                    properties: ParagraphProperties::default(),
                     runs: vec![special()],
                 }],
             },
             ElementPosition::default(),
+            ElementMeta::default(),
         ),
         SlideElement::Table(
             TableElement {
+                has_header: true,
+                column_widths: Vec::new(),
                 rows: vec![TableRow {
                     cells: vec![TableCell {
-                        runs: vec![special()],
+                        paragraphs: vec![Paragraph::plain(vec![special()])],
                         ..TableCell::default()
                     }],
                 }],
             },
             ElementPosition::default(),
+            ElementMeta::default(),
         ),
     ];
-    slide.speaker_notes = vec![TextElement {
-        runs: vec![special()],
-    }];
-    slide.comments = vec![TextElement {
-        runs: vec![special()],
-    }];
+    slide.speaker_notes = vec![TextElement::from_runs(vec![special()])];
+    slide.comments = vec![TextElement::from_runs(vec![special()])];
 
     let markdown = slide.convert_to_md().expect("render markdown");
     assert_eq!(
@@ -423,21 +469,27 @@ fn escapes_raw_markdown_in_all_text_contexts() {
 #[test]
 fn escapes_table_pipes_without_creating_columns() {
     let mut slide = mock_slide();
-    slide.config.include_slide_number_as_comment = false;
+    slide
+        .render_options
+        .markdown
+        .include_slide_number_as_comment = false;
     slide.elements = vec![SlideElement::Table(
         TableElement {
+            has_header: true,
+            column_widths: Vec::new(),
             rows: vec![TableRow {
                 cells: vec![TableCell {
-                    runs: vec![Run {
+                    paragraphs: vec![Paragraph::plain(vec![Run {
                         text: "left | right\r\nnext".into(),
                         formatting: Formatting::default(),
                         link_target: None,
-                    }],
+                    }])],
                     ..TableCell::default()
                 }],
             }],
         },
         ElementPosition::default(),
+        ElementMeta::default(),
     )];
 
     assert_eq!(
@@ -446,6 +498,68 @@ fn escapes_table_pipes_without_creating_columns() {
     );
 }
 
+#[test]
+fn renders_tricky_pptx_cell_content_inside_the_markdown_grid() {
+    let xml = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:graphicFrame><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/table"><a:tbl><a:tblPr firstRow="1"/><a:tr><a:tc><a:txBody><a:p><a:r><a:t>Key</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>Value</a:t></a:r></a:p></a:txBody></a:tc></a:tr><a:tr><a:tc><a:txBody><a:p><a:r><a:t>a | b</a:t></a:r></a:p><a:p><a:r><a:t>- second</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>line 1</a:t></a:r><a:br/><a:r><a:t>C:\temp\|x</a:t></a:r></a:p></a:txBody></a:tc></a:tr></a:tbl></a:graphicData></a:graphic></p:graphicFrame></p:spTree></p:cSld></p:sld>"#;
+    let parsed = crate::parse_xml::parse_slide_document_with_hyperlinks(
+        xml,
+        &Default::default(),
+        &HashMap::new(),
+    )
+    .unwrap();
+    let SlideElement::Table(table, _, _) = &parsed.elements[0] else {
+        panic!("expected table element")
+    };
+    assert_eq!(table.rows[1].cells[0].runs()[0].text, "a | b\n");
+
+    let expected = "| Key | Value |\n| --- | --- |\n| a \\| b<br>- second | line 1<br>C:\\\\temp\\\\\\|x |\n\n";
+    let mut slide = mock_slide();
+    slide
+        .render_options
+        .markdown
+        .include_slide_number_as_comment = false;
+    slide.elements = parsed.elements.clone();
+    assert_eq!(slide.convert_to_md().unwrap(), expected);
+    slide.blocks = parsed.blocks;
+    assert_eq!(slide.convert_to_md().unwrap(), expected);
+}
+
+#[test]
+fn renders_an_empty_header_row_for_tables_without_header() {
+    let mut slide = mock_slide();
+    slide
+        .render_options
+        .markdown
+        .include_slide_number_as_comment = false;
+    let row = |left: &str, right: &str| TableRow {
+        cells: [left, right]
+            .into_iter()
+            .map(|text| TableCell {
+                paragraphs: vec![Paragraph::plain(vec![Run {
+                    text: text.into(),
+                    formatting: Formatting::default(),
+                    link_target: None,
+                }])],
+                ..TableCell::default()
+            })
+            .collect(),
+    };
+    slide.elements = vec![SlideElement::Table(
+        TableElement {
+            has_header: false,
+            column_widths: Vec::new(),
+            rows: vec![row("A1", "B1"), row("A2", "B2")],
+        },
+        ElementPosition::default(),
+        ElementMeta::default(),
+    )];
+
+    assert_eq!(
+        slide.convert_to_md().unwrap(),
+        "|   |   |\n| --- | --- |\n| A1 | B1 |\n| A2 | B2 |\n\n"
+    );
+}
+
 #[test]
 fn extracts_slide_number_from_path() {
     assert_eq!(
@@ -468,18 +582,29 @@ fn links_image_elements_to_relationship_targets() {
     slide.images.push(ImageReference {
         id: "rId2".to_string(),
         target: "../media/image1.png".to_string(),
+        dimensions: None,
+        display_size: None,
+        link_target: None,
+        description: None,
+        title: None,
     });
     slide.elements.push(SlideElement::Image(
         ImageReference {
             id: "rId2".to_string(),
             target: String::new(),
+            dimensions: None,
+            display_size: None,
+            link_target: None,
+            description: None,
+            title: None,
         },
         ElementPosition::default(),
+        ElementMeta::default(),
     ));
 
     slide.link_images();
 
-    let SlideElement::Image(reference, _) = &slide.elements[0] else {
+    let SlideElement::Image(reference, _, _) = &slide.elements[0] else {
         panic!("expected image element");
     };
     assert_eq!(reference.target, "../media/image1.png");
@@ -488,7 +613,7 @@ fn links_image_elements_to_relationship_targets() {
 #[test]
 fn compresses_images_to_smaller_valid_jpegs() {
     let mut slide = mock_slide();
-    slide.config.quality = 50;
+    slide.render_options.quality = 50;
     let raw_image = load_binary_test_data("example-image.jpg");
 
     let compressed = slide.compress_image(&raw_image).expect("compress image");
@@ -496,25 +621,66 @@ fn compresses_images_to_smaller_valid_jpegs() {
     assert!(image::load_from_memory(&compressed).is_ok());
 }
 
+#[test]
+fn compresses_every_distinct_rendered_image_once() {
+    let mut slide = mock_slide();
+    slide.render_options.quality = 50;
+    let raw_image = load_binary_test_data("example-image.jpg");
+    slide.elements = vec![
+        image_element("first", "../media/first.jpg"),
+        image_element("second", "../media/second.jpg"),
+        image_element("first", "../media/first.jpg"),
+        image_element("missing", "../media/missing.jpg"),
+    ];
+    for id in ["first", "second"] {
+        slide.image_data.insert(id.to_string(), raw_image.clone());
+    }
+    let blocks = slide.semantic_blocks();
+
+    let compressed = slide.compress_block_images(&blocks, &slide.render_options.clone());
+    let mut ids: Vec<&str> = compressed.keys().copied().collect();
+    ids.sort_unstable();
+    assert_eq!(ids, ["first", "second"]);
+    assert_eq!(compressed["first"], slide.compress_image(&raw_image));
+
+    let mut options = slide.render_options.clone();
+    options.image_handling_mode = ImageHandlingMode::Manually;
+    assert!(slide.compress_block_images(&blocks, &options).is_empty());
+}
+
+#[test]
+fn writes_base64_in_chunks_like_the_standard_engine() {
+    for length in [0, 1, 2, 3, 49_151, 49_152, 49_153, 100_000] {
+        let data: Vec<u8> = (0..length).map(|index| (index % 251) as u8).collect();
+        let mut encoded = Vec::new();
+        write_base64(&mut encoded, &data).expect("write base64");
+        assert_eq!(
+            encoded,
+            general_purpose::STANDARD.encode(&data).into_bytes()
+        );
+    }
+}
+
 #[test]
 fn renders_speaker_notes_as_markdown_blockquotes_when_enabled() {
     let mut slide = mock_slide();
-    slide.config.include_slide_number_as_comment = false;
-    slide.config.include_speaker_notes = true;
-    slide.speaker_notes = vec![TextElement {
-        runs: vec![
-            Run {
-                text: "First note\n".to_string(),
-                formatting: Formatting::default(),
-                link_target: None,
-            },
-            Run {
-                text: "Second note".to_string(),
-                formatting: Formatting::default(),
-                link_target: None,
-            },
-        ],
-    }];
+    slide
+        .render_options
+        .markdown
+        .include_slide_number_as_comment = false;
+    slide.render_options.markdown.include_speaker_notes = true;
+    slide.speaker_notes = vec![TextElement::from_runs(vec![
+        Run {
+            text: "First note\n".to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        },
+        Run {
+            text: "Second note".to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        },
+    ])];
 
     assert_eq!(
         slide.convert_to_md().unwrap(),
@@ -525,14 +691,15 @@ fn renders_speaker_notes_as_markdown_blockquotes_when_enabled() {
 #[test]
 fn does_not_render_speaker_notes_by_default() {
     let mut slide = mock_slide();
-    slide.config.include_slide_number_as_comment = false;
-    slide.speaker_notes = vec![TextElement {
-        runs: vec![Run {
-            text: "Hidden note".to_string(),
-            formatting: Formatting::default(),
-            link_target: None,
-        }],
-    }];
+    slide
+        .render_options
+        .markdown
+        .include_slide_number_as_comment = false;
+    slide.speaker_notes = vec![TextElement::from_runs(vec![Run {
+        text: "Hidden note".to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    }])];
 
     assert_eq!(slide.convert_to_md().unwrap(), String::new());
 }
@@ -540,23 +707,22 @@ fn does_not_render_speaker_notes_by_default() {
 #[test]
 fn renders_comments_separately_from_speaker_notes() {
     let mut slide = mock_slide();
-    slide.config.include_slide_number_as_comment = false;
-    slide.config.include_speaker_notes = true;
-    slide.config.include_comments = true;
-    slide.speaker_notes = vec![TextElement {
-        runs: vec![Run {
-            text: "Speaker notes".to_string(),
-            formatting: Formatting::default(),
-            link_target: None,
-        }],
-    }];
-    slide.comments = vec![TextElement {
-        runs: vec![Run {
-            text: "Comment".to_string(),
-            formatting: Formatting::default(),
-            link_target: None,
-        }],
-    }];
+    slide
+        .render_options
+        .markdown
+        .include_slide_number_as_comment = false;
+    slide.render_options.markdown.include_speaker_notes = true;
+    slide.render_options.markdown.include_comments = true;
+    slide.speaker_notes = vec![TextElement::from_runs(vec![Run {
+        text: "Speaker notes".to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    }])];
+    slide.comments = vec![TextElement::from_runs(vec![Run {
+        text: "Comment".to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    }])];
 
     assert_eq!(
         slide.convert_to_md().unwrap(),
@@ -568,10 +734,11 @@ fn renders_comments_separately_from_speaker_notes() {
 fn loads_images_manually_without_compression_and_skips_missing_data() {
     let image_bytes = load_binary_test_data("example-image.jpg");
     let mut slide = mock_slide();
-    slide.config = ParserConfig::builder()
+    slide.render_options = ParserConfig::builder()
         .compress_images(false)
         .image_handling_mode(ImageHandlingMode::Manually)
-        .build();
+        .build()
+        .into();
     slide.elements = vec![
         image_element("present", "../media/example-image.jpg"),
         image_element("missing", "../media/missing.png"),
@@ -588,16 +755,28 @@ fn loads_images_manually_without_compression_and_skips_missing_data() {
         images[0].base64_content,
         base64::engine::general_purpose::STANDARD.encode(image_bytes)
     );
+    assert_eq!(images[0].token, slide.image_token(0, &images[0].img_ref));
+    let markdown = slide.convert_to_md().unwrap();
+    assert!(markdown.contains(&format!("<!-- {} -->", images[0].token)));
+
+    slide
+        .elements
+        .push(image_element("present", "../media/example-image.jpg"));
+    let images = slide.load_images_manually().expect("load images manually");
+    assert_eq!(images.len(), 2);
+    assert_ne!(images[0].token, images[1].token);
+    assert_eq!(images[1].token, slide.image_token(2, &images[1].img_ref));
 }
 
 #[test]
 fn loads_and_compresses_images_manually() {
     let mut slide = mock_slide();
-    slide.config = ParserConfig::builder()
+    slide.render_options = ParserConfig::builder()
         .compress_images(true)
         .quality(60)
         .image_handling_mode(ImageHandlingMode::Manually)
-        .build();
+        .build()
+        .into();
     slide.elements = vec![image_element("image", "../media/example-image.jpg")];
     slide.image_data.insert(
         "image".to_string(),
@@ -613,6 +792,38 @@ fn loads_and_compresses_images_manually() {
     assert!(image::load_from_memory(&compressed).is_ok());
 }
 
+#[test]
+fn uses_the_picture_description_as_markdown_alt_text() {
+    let mut slide = mock_slide();
+    slide.render_options = ParserConfig::builder()
+        .compress_images(false)
+        .build()
+        .into();
+    let mut element = image_element("rId1", "../media/example-image.jpg");
+    if let SlideElement::Image(image, _, _) = &mut element {
+        image.title = Some("Revenue".to_string());
+        image.description = Some("Revenue by quarter".to_string());
+    }
+    slide.elements = vec![element];
+    slide.image_data.insert(
+        "rId1".to_string(),
+        load_binary_test_data("example-image.jpg"),
+    );
+
+    let markdown = slide.convert_to_md().expect("render slide");
+
+    assert!(markdown.contains("![Revenue by quarter](data:image/"));
+}
+
+#[test]
+fn reads_image_dimensions_from_the_header() {
+    let dimensions = read_image_dimensions(&load_binary_test_data("example-image.jpg"))
+        .expect("image dimensions");
+    assert_eq!(dimensions.format, "jpg");
+    assert!(dimensions.width > 0 && dimensions.height > 0);
+    assert_eq!(read_image_dimensions(b"not an image"), None);
+}
+
 #[test]
 fn invalid_image_data_cannot_be_compressed() {
     assert!(mock_slide().compress_image(b"not an image").is_none());
@@ -630,11 +841,12 @@ fn save_mode_writes_the_image_and_renders_a_file_link() {
     ));
     let image_bytes = load_binary_test_data("example-image.jpg");
     let mut slide = mock_slide();
-    slide.config = ParserConfig::builder()
+    slide.render_options = ParserConfig::builder()
         .compress_images(false)
         .image_handling_mode(ImageHandlingMode::Save)
         .image_output_path(output_dir.clone())
-        .build();
+        .build()
+        .into();
     slide.elements = vec![image_element("rId1", "../media/example-image.jpg")];
     slide
         .image_data
@@ -653,18 +865,62 @@ fn save_mode_writes_the_image_and_renders_a_file_link() {
     fs::remove_dir_all(output_dir).expect("remove image output directory");
 }
 
+#[test]
+fn content_hash_naming_writes_identical_images_once() {
+    let unique = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system time")
+        .as_nanos();
+    let output_dir = std::env::temp_dir().join(format!(
+        "pptx-to-md-slide-hash-{}-{unique}",
+        std::process::id()
+    ));
+    let image_bytes = load_binary_test_data("example-image.jpg");
+    let mut slide = mock_slide();
+    slide.render_options = ParserConfig::builder()
+        .compress_images(false)
+        .image_handling_mode(ImageHandlingMode::Save)
+        .image_output_path(output_dir.clone())
+        .image_naming(ImageNaming::ContentHash)
+        .build()
+        .into();
+    slide.elements = vec![
+        image_element("rId1", "../media/example-image.jpg"),
+        image_element("rId2", "../media/copy.jpg"),
+    ];
+    for id in ["rId1", "rId2"] {
+        slide.image_data.insert(id.to_string(), image_bytes.clone());
+    }
+
+    let markdown = slide.convert_to_md().expect("render slide");
+    let file_name = format!("{:016x}.jpg", content_hash(&image_bytes));
+
+    let saved: Vec<_> = fs::read_dir(&output_dir)
+        .expect("read image output directory")
+        .collect();
+    assert_eq!(saved.len(), 1);
+    assert_eq!(
+        fs::read(output_dir.join(&file_name)).expect("read saved image"),
+        image_bytes
+    );
+    assert_eq!(markdown.matches(&format!("{file_name})")).count(), 2);
+    assert_eq!(slide.convert_to_md().expect("render slide again"), markdown);
+
+    fs::remove_dir_all(output_dir).expect("remove image output directory");
+}
+
 #[test]
 fn separates_multiple_elements_inside_quoted_sections() {
-    let note = |text: &str| TextElement {
-        runs: vec![Run {
+    let note = |text: &str| {
+        TextElement::from_runs(vec![Run {
             text: text.to_string(),
             formatting: Formatting::default(),
             link_target: None,
-        }],
+        }])
     };
     let mut slide = mock_slide();
-    slide.config.include_speaker_notes = true;
-    slide.config.include_comments = true;
+    slide.render_options.markdown.include_speaker_notes = true;
+    slide.render_options.markdown.include_comments = true;
     slide.speaker_notes = vec![note("First note"), note("Second note")];
     slide.comments = vec![note("First comment"), note("Second comment")];
 
@@ -682,139 +938,529 @@ fn semantic_text(text: &str, role: TextRole) -> SlideBlockContent {
             formatting: Formatting::default(),
             link_target: None,
         }])],
+        from_template: false,
     })
 }
 
 #[test]
-fn semantic_renderer_uses_roles_and_configurable_reading_order() {
+fn title_prefers_title_placeholder_and_falls_back_to_headings() {
+    let block = |content, source_order| SlideBlock {
+        bounds: Bounds::default(),
+        source_order,
+        raw: None,
+        content,
+    };
     let mut slide = mock_slide();
     slide.blocks = vec![
-        SlideBlock {
-            bounds: Bounds {
-                x: 0,
-                y: 0,
-                width: 600,
-                height: 50,
-            },
-            source_order: 0,
-            content: semantic_text("Title", TextRole::Title),
-        },
-        SlideBlock {
-            bounds: Bounds {
-                x: 500,
-                y: 100,
-                width: 100,
-                height: 100,
-            },
-            source_order: 1,
-            content: semantic_text("Right column", TextRole::Body),
-        },
-        SlideBlock {
-            bounds: Bounds {
-                x: 0,
-                y: 200,
-                width: 100,
-                height: 100,
-            },
-            source_order: 2,
-            content: semantic_text("Left column", TextRole::Body),
-        },
+        block(semantic_text("Chapter  one", TextRole::Heading), 0),
+        block(semantic_text(" Quarterly\nresults ", TextRole::Title), 1),
     ];
-    let mut options = MarkdownOptions {
-        include_slide_number_as_comment: false,
-        ..MarkdownOptions::default()
-    };
+    assert_eq!(slide.title().as_deref(), Some("Quarterly results"));
 
-    let spatial = slide.to_markdown(&options).unwrap();
-    assert!(spatial.starts_with("## Title"));
-    assert!(spatial.find("Left column").unwrap() < spatial.find("Right column").unwrap());
+    slide.blocks.remove(1);
+    assert_eq!(slide.title().as_deref(), Some("Chapter one"));
 
-    options.reading_order = ReadingOrder::Source;
-    let source = slide.to_markdown(&options).unwrap();
-    assert!(source.find("Right column").unwrap() < source.find("Left column").unwrap());
+    slide.blocks = vec![block(semantic_text("Body", TextRole::Body), 0)];
+    assert_eq!(slide.title(), None);
 }
 
 #[test]
-fn semantic_renderer_uses_html_for_merged_tables_and_reports_unknown_blocks() {
+fn renders_slide_jump_links_and_anchors_the_title_heading() {
     let mut slide = mock_slide();
+    slide.slide_number = 3;
     slide.blocks = vec![
         SlideBlock {
             bounds: Bounds::default(),
             source_order: 0,
-            content: SlideBlockContent::Table(SemanticTable {
-                rows: vec![SemanticTableRow {
-                    cells: vec![SemanticTableCell {
-                        paragraphs: vec![Paragraph::plain(vec![Run {
-                            text: "Merged".to_string(),
-                            formatting: Formatting::default(),
-                            link_target: None,
-                        }])],
-                        row_span: 2,
-                        column_span: 3,
-                        covered: false,
-                    }],
-                }],
-            }),
+            raw: None,
+            content: semantic_text("Overview", TextRole::Title),
         },
         SlideBlock {
             bounds: Bounds::default(),
             source_order: 1,
-            content: SlideBlockContent::Unsupported(UnsupportedBlock {
-                kind: "chart".to_string(),
-                fallback_text: Some("Revenue 2026".to_string()),
+            raw: None,
+            content: SlideBlockContent::Text(TextBlock {
+                role: TextRole::Body,
+                paragraphs: vec![Paragraph::plain(vec![Run {
+                    text: "see details".to_string(),
+                    formatting: Formatting::default(),
+                    link_target: Some("#slide-12".to_string()),
+                }])],
+                from_template: false,
             }),
         },
     ];
-    let markdown = slide
-        .to_markdown(&MarkdownOptions {
-            reading_order: ReadingOrder::Source,
-            include_slide_number_as_comment: false,
-            ..MarkdownOptions::default()
-        })
-        .unwrap();
+    let options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        ..MarkdownOptions::default()
+    };
 
-    assert!(markdown.contains("<td rowspan=\"2\" colspan=\"3\">Merged</td>"));
-    assert!(markdown.contains("Revenue 2026"));
-    assert!(markdown.contains("<!-- Unsupported slide element: chart -->"));
+    let markdown = slide.to_markdown(&options).unwrap();
+
+    assert_eq!(
+        markdown,
+        "## Overview <a id=\"slide-3\"></a>\n\n[see details](#slide-12)\n\n"
+    );
 }
 
 #[test]
-fn legacy_constructor_builds_semantic_blocks_for_every_element_kind() {
-    let run = |text: &str| Run {
-        text: text.to_string(),
-        formatting: Formatting::default(),
-        link_target: None,
+fn emits_a_slide_heading_from_the_template_instead_of_the_title_block() {
+    let block = |content, source_order| SlideBlock {
+        bounds: Bounds::default(),
+        source_order,
+        raw: None,
+        content,
     };
-    let elements = vec![
-        SlideElement::Text(
-            TextElement {
-                runs: vec![run("Text")],
-            },
-            ElementPosition { x: 1, y: 2 },
-        ),
-        SlideElement::List(
-            ListElement {
-                items: vec![ListItem {
-                    level: 1,
-                    is_ordered: true,
-                    runs: vec![run("Second")],
-                }],
-            },
-            ElementPosition { x: 3, y: 4 },
-        ),
-        SlideElement::Table(
-            TableElement {
-                rows: vec![TableRow {
-                    cells: vec![TableCell {
-                        runs: vec![run("Cell")],
-                        ..TableCell::default()
-                    }],
-                }],
-            },
-            ElementPosition { x: 5, y: 6 },
-        ),
-        image_element("image", "../media/image.png"),
-        SlideElement::Unknown,
+    let mut slide = mock_slide();
+    slide.slide_number = 4;
+    slide.blocks = vec![
+        block(semantic_text("Overview", TextRole::Title), 0),
+        block(semantic_text("Body text", TextRole::Body), 1),
+    ];
+    let options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        slide_heading: Some("## Slide {n}: {title}".to_string()),
+        ..MarkdownOptions::default()
+    };
+
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "## Slide 4: Overview <a id=\"slide-4\"></a>\n\nBody text\n\n"
+    );
+
+    slide.blocks.remove(0);
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "## Slide 4 <a id=\"slide-4\"></a>\n\nBody text\n\n"
+    );
+}
+
+#[test]
+fn renders_titles_at_the_configured_heading_level() {
+    let block = |content, source_order| SlideBlock {
+        bounds: Bounds::default(),
+        source_order,
+        raw: None,
+        content,
+    };
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        block(semantic_text("Overview", TextRole::Title), 0),
+        block(semantic_text("Details", TextRole::Heading), 1),
+    ];
+    let mut options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        slide_anchors: SlideAnchors::Off,
+        ..MarkdownOptions::default()
+    };
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "## Overview\n\n### Details\n\n"
+    );
+
+    options.title_heading_level = 1;
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "# Overview\n\n## Details\n\n"
+    );
+}
+
+#[test]
+fn places_slide_anchors_according_to_the_anchor_mode() {
+    let mut slide = mock_slide();
+    slide.slide_number = 2;
+    slide.blocks = vec![SlideBlock {
+        bounds: Bounds::default(),
+        source_order: 0,
+        raw: None,
+        content: semantic_text("Overview", TextRole::Title),
+    }];
+    let mut options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        slide_anchors: SlideAnchors::Standalone,
+        ..MarkdownOptions::default()
+    };
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "<a id=\"slide-2\"></a>\n\n## Overview\n\n"
+    );
+
+    options.slide_anchors = SlideAnchors::Off;
+    assert_eq!(slide.to_markdown(&options).unwrap(), "## Overview\n\n");
+
+    options.slide_anchors = SlideAnchors::Title;
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "## Overview <a id=\"slide-2\"></a>\n\n"
+    );
+
+    options.slide_anchors = SlideAnchors::TitleOrStandalone;
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "## Overview <a id=\"slide-2\"></a>\n\n"
+    );
+
+    slide.blocks[0].content = semantic_text("Body text", TextRole::Body);
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "<a id=\"slide-2\"></a>\n\nBody text\n\n"
+    );
+
+    options.slide_anchors = SlideAnchors::Title;
+    assert_eq!(slide.to_markdown(&options).unwrap(), "Body text\n\n");
+}
+
+#[test]
+fn separates_paragraphs_according_to_the_paragraph_spacing() {
+    let paragraph = |text: &str| {
+        Paragraph::plain(vec![Run {
+            text: text.to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        }])
+    };
+    let mut slide = mock_slide();
+    slide.blocks = vec![SlideBlock {
+        bounds: Bounds::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role: TextRole::Body,
+            paragraphs: vec![paragraph("First"), paragraph("Second")],
+            from_template: false,
+        }),
+    }];
+    let mut options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        ..MarkdownOptions::default()
+    };
+    let mut render = |spacing| {
+        options.paragraph_spacing = spacing;
+        slide.to_markdown(&options).unwrap()
+    };
+
+    assert_eq!(render(ParagraphSpacing::BlankLine), "First\n\nSecond\n\n");
+    assert_eq!(render(ParagraphSpacing::Newline), "First\nSecond\n\n");
+    assert_eq!(render(ParagraphSpacing::LineBreak), "First<br>\nSecond\n\n");
+}
+
+#[test]
+fn semantic_renderer_uses_roles_and_configurable_reading_order() {
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        SlideBlock {
+            bounds: Bounds {
+                x: 0,
+                y: 0,
+                width: 600,
+                height: 50,
+            },
+            source_order: 0,
+            raw: None,
+            content: semantic_text("Title", TextRole::Title),
+        },
+        SlideBlock {
+            bounds: Bounds {
+                x: 500,
+                y: 100,
+                width: 100,
+                height: 100,
+            },
+            source_order: 1,
+            raw: None,
+            content: semantic_text("Right column", TextRole::Body),
+        },
+        SlideBlock {
+            bounds: Bounds {
+                x: 0,
+                y: 200,
+                width: 100,
+                height: 100,
+            },
+            source_order: 2,
+            raw: None,
+            content: semantic_text("Left column", TextRole::Body),
+        },
+    ];
+    let mut options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        ..MarkdownOptions::default()
+    };
+
+    let spatial = slide.to_markdown(&options).unwrap();
+    assert!(spatial.starts_with("## Title"));
+    assert!(spatial.find("Left column").unwrap() < spatial.find("Right column").unwrap());
+
+    options.reading_order = ReadingOrder::Source;
+    let source = slide.to_markdown(&options).unwrap();
+    assert!(source.find("Right column").unwrap() < source.find("Left column").unwrap());
+}
+
+#[test]
+fn renders_bullet_characters_as_unordered_unless_legacy_classification_is_enabled() {
+    let paragraph = |text: &str, kind: ListKind| Paragraph {
+        list: Some(ListInfo { level: 0, kind }),
+        ..Paragraph::plain(vec![Run {
+            text: text.to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        }])
+    };
+    let mut slide = mock_slide();
+    slide.blocks = vec![SlideBlock {
+        bounds: Bounds::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role: TextRole::Body,
+            paragraphs: vec![
+                paragraph(
+                    "First",
+                    ListKind::Bullet {
+                        character: Some("•".to_string()),
+                    },
+                ),
+                paragraph(
+                    "Second",
+                    ListKind::Bullet {
+                        character: Some("•".to_string()),
+                    },
+                ),
+                paragraph("Implicit", ListKind::Bullet { character: None }),
+            ],
+            from_template: false,
+        }),
+    }];
+    let mut options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        ..MarkdownOptions::default()
+    };
+
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "- First\n- Second\n- Implicit\n\n"
+    );
+
+    options.legacy_list_classification = true;
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "1. First\n2. Second\n- Implicit\n\n"
+    );
+}
+
+#[test]
+fn continues_ordered_list_numbering_across_adjacent_text_blocks_when_enabled() {
+    let item = |text: &str, start: Option<u32>| Paragraph {
+        list: Some(ListInfo {
+            level: 0,
+            kind: ListKind::Ordered { style: None, start },
+        }),
+        ..Paragraph::plain(vec![Run {
+            text: text.to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        }])
+    };
+    let block = |x: i64, source_order: usize, paragraphs: Vec<Paragraph>| SlideBlock {
+        bounds: Bounds {
+            x,
+            y: 0,
+            width: 100,
+            height: 100,
+        },
+        source_order,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role: TextRole::Body,
+            paragraphs,
+            from_template: false,
+        }),
+    };
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        block(200, 0, vec![item("Third", None)]),
+        block(0, 1, vec![item("First", None), item("Second", None)]),
+        block(400, 2, vec![item("Tenth", Some(10))]),
+        block(600, 3, vec![item("Restarted", Some(1))]),
+    ];
+    let mut options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        ..MarkdownOptions::default()
+    };
+
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "1. First\n2. Second\n\n1. Third\n\n10. Tenth\n\n1. Restarted\n\n"
+    );
+
+    options.continue_list_numbering = true;
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "1. First\n2. Second\n\n3. Third\n\n10. Tenth\n\n1. Restarted\n\n"
+    );
+}
+
+#[test]
+fn reports_language_spans_as_byte_ranges_of_the_slide_text() {
+    let run = |text: &str, lang: &str| Run {
+        text: text.to_string(),
+        formatting: Formatting {
+            lang: lang.to_string(),
+            ..Formatting::default()
+        },
+        link_target: None,
+    };
+    let block = |y: i64, paragraphs: Vec<Paragraph>| SlideBlock {
+        bounds: Bounds {
+            x: 0,
+            y,
+            width: 100,
+            height: 10,
+        },
+        source_order: y as usize,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role: TextRole::Body,
+            paragraphs,
+            from_template: false,
+        }),
+    };
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        block(
+            0,
+            vec![
+                Paragraph::plain(vec![run("Hello ", "en-US"), run("world", "en-US")]),
+                Paragraph::plain(vec![run("Again", "en-US")]),
+            ],
+        ),
+        block(
+            20,
+            vec![Paragraph::plain(vec![
+                run("Grüße", "de-DE"),
+                run(" ", "en-US"),
+                run("untagged", ""),
+            ])],
+        ),
+    ];
+
+    let text = slide.text();
+    let spans = slide.language_spans();
+    let segments: Vec<(&str, &str)> = spans
+        .iter()
+        .map(|span| (span.lang.as_str(), &text[span.range.clone()]))
+        .collect();
+    assert_eq!(
+        segments,
+        [("en-US", "Hello world\nAgain"), ("de-DE", "Grüße")]
+    );
+}
+
+#[test]
+fn semantic_renderer_uses_html_for_merged_tables_and_reports_unknown_blocks() {
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 0,
+            raw: None,
+            content: SlideBlockContent::Table(SemanticTable {
+                has_header: true,
+                column_widths: Vec::new(),
+                rows: vec![SemanticTableRow {
+                    cells: vec![SemanticTableCell {
+                        paragraphs: vec![Paragraph::plain(vec![Run {
+                            text: "Merged".to_string(),
+                            formatting: Formatting::default(),
+                            link_target: None,
+                        }])],
+                        row_span: 2,
+                        column_span: 3,
+                        covered: false,
+                    }],
+                }],
+            }),
+        },
+        SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 1,
+            raw: None,
+            content: SlideBlockContent::Unsupported(UnsupportedBlock {
+                kind: "chart".to_string(),
+                fallback_text: Some("Revenue 2026".to_string()),
+                content_type: None,
+            }),
+        },
+    ];
+    let markdown = slide
+        .to_markdown(&MarkdownOptions {
+            reading_order: ReadingOrder::Source,
+            include_slide_number_as_comment: false,
+            ..MarkdownOptions::default()
+        })
+        .unwrap();
+
+    assert!(markdown.contains("<td rowspan=\"2\" colspan=\"3\">Merged</td>"));
+    assert!(markdown.contains("Revenue 2026"));
+    assert!(markdown.contains("<!-- Unsupported slide element: chart -->"));
+}
+
+#[test]
+fn legacy_constructor_builds_semantic_blocks_for_every_element_kind() {
+    let run = |text: &str| Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    };
+    let elements = vec![
+        SlideElement::Text(
+            TextElement::from_runs(vec![run("Text")]),
+            ElementPosition {
+                x: 1,
+                y: 2,
+                width: 0,
+                height: 0,
+            },
+            ElementMeta::default(),
+        ),
+        SlideElement::List(
+            ListElement {
+                items: vec![ListItem {
+                    level: 1,
+                    is_ordered: true,
+                    properties: ParagraphProperties::default(),
+                    runs: vec![run("Second")],
+                }],
+            },
+            ElementPosition {
+                x: 3,
+                y: 4,
+                width: 0,
+                height: 0,
+            },
+            ElementMeta::default(),
+        ),
+        SlideElement::Table(
+            TableElement {
+                has_header: true,
+                column_widths: Vec::new(),
+                rows: vec![TableRow {
+                    cells: vec![TableCell {
+                        paragraphs: vec![Paragraph::plain(vec![run("Cell")])],
+                        ..TableCell::default()
+                    }],
+                }],
+            },
+            ElementPosition {
+                x: 5,
+                y: 6,
+                width: 0,
+                height: 0,
+            },
+            ElementMeta::default(),
+        ),
+        image_element("image", "../media/image.png"),
+        SlideElement::Unknown,
     ];
 
     let slide = Slide::new(
@@ -834,7 +1480,7 @@ fn legacy_constructor_builds_semantic_blocks_for_every_element_kind() {
     };
     assert!(matches!(
         list.paragraphs[0].list.as_ref().map(|list| &list.kind),
-        Some(ListKind::Ordered { start: 1, .. })
+        Some(ListKind::Ordered { start: None, .. })
     ));
     assert!(matches!(
         slide.blocks[2].content,
@@ -850,11 +1496,62 @@ fn legacy_constructor_builds_semantic_blocks_for_every_element_kind() {
     ));
 }
 
+#[test]
+fn resolves_the_display_size_of_every_placement_of_an_image() {
+    let placement = |width: i64, height: i64| {
+        let SlideElement::Image(reference, _, meta) = image_element("image", "../media/image.png")
+        else {
+            unreachable!()
+        };
+        SlideElement::Image(
+            reference,
+            ElementPosition {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            },
+            meta,
+        )
+    };
+
+    let slide = Slide::new(
+        "ppt/slides/slide1.xml".to_string(),
+        1,
+        vec![placement(200, 100), placement(50, 25), placement(0, 0)],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        ParserConfig::default(),
+    );
+
+    let display_sizes: Vec<_> = slide
+        .elements
+        .iter()
+        .map(|element| match element {
+            SlideElement::Image(reference, _, _) => reference.display_size,
+            _ => panic!("expected an image element"),
+        })
+        .collect();
+    assert_eq!(display_sizes, [Some((200, 100)), Some((50, 25)), None]);
+    let block_sizes: Vec<_> = slide
+        .blocks
+        .iter()
+        .map(|block| match &block.content {
+            SlideBlockContent::Image(image) => image.reference.display_size,
+            _ => panic!("expected an image block"),
+        })
+        .collect();
+    assert_eq!(block_sizes, display_sizes);
+}
+
 #[test]
 fn spatial_order_handles_dimensionless_blocks_and_full_width_separators() {
     let block = |text: &str, bounds: Bounds, source_order: usize| SlideBlock {
         bounds,
         source_order,
+        raw: None,
         content: semantic_text(text, TextRole::Body),
     };
     let mut slide = mock_slide();
@@ -947,15 +1644,146 @@ fn spatial_order_handles_dimensionless_blocks_and_full_width_separators() {
     assert!(spatial.find("Left below").unwrap() < spatial.find("Right below").unwrap());
 }
 
+#[test]
+fn wraps_linked_pictures_in_a_markdown_link() {
+    let mut slide = mock_slide();
+    slide.blocks = vec![SlideBlock {
+        bounds: Bounds::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Image(ImageBlock {
+            reference: ImageReference {
+                id: "logo".to_string(),
+                target: "../media/logo.png".to_string(),
+                dimensions: None,
+                display_size: None,
+                link_target: Some("https://example.com/home page".to_string()),
+                description: None,
+                title: None,
+            },
+            alt_text: Some("Logo".to_string()),
+            mime_type: Some("image/png".to_string()),
+        }),
+    }];
+    slide.image_data.insert("logo".to_string(), b"png".to_vec());
+    slide.render_options.compress_images = false;
+    let options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        ..MarkdownOptions::default()
+    };
+
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "[![Logo](data:image/png;base64,cG5n)](<https://example.com/home page>)\n"
+    );
+}
+
+#[test]
+fn renders_pictures_of_the_speaker_notes_inside_the_notes_quote() {
+    let mut slide = mock_slide();
+    slide.blocks = Vec::new();
+    slide.speaker_notes = vec![TextElement::from_runs(vec![Run {
+        text: "Answer key".to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    }])];
+    slide.notes_images = vec![ImageBlock {
+        reference: ImageReference {
+            id: "notes-rId2".to_string(),
+            target: "/ppt/media/answer.png".to_string(),
+            dimensions: None,
+            display_size: None,
+            link_target: None,
+            description: None,
+            title: None,
+        },
+        alt_text: Some("Solution".to_string()),
+        mime_type: Some("image/png".to_string()),
+    }];
+    slide
+        .image_data
+        .insert("notes-rId2".to_string(), b"png".to_vec());
+    slide.render_options.compress_images = false;
+    let options = MarkdownOptions {
+        include_slide_number_as_comment: false,
+        include_speaker_notes: true,
+        ..MarkdownOptions::default()
+    };
+
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "> **Speaker Notes**\n>\n> Answer key\n>\n> ![Solution](data:image/png;base64,cG5n)\n"
+    );
+
+    slide.speaker_notes.clear();
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        "> **Speaker Notes**\n>\n> ![Solution](data:image/png;base64,cG5n)\n"
+    );
+}
+
+#[test]
+fn exposes_the_speaker_notes_with_their_runs_and_pictures() {
+    let mut slide = mock_slide();
+    assert!(slide.notes().is_none());
+
+    let formatting = Formatting {
+        bold: true,
+        ..Formatting::default()
+    };
+    slide.speaker_notes = vec![
+        TextElement::from_runs(vec![Run {
+            text: "Pause here".to_string(),
+            formatting,
+            link_target: None,
+        }]),
+        TextElement::default(),
+        TextElement::from_runs(vec![Run {
+            text: "Ask for questions".to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        }]),
+    ];
+    let notes = slide.notes().expect("speaker notes");
+    assert_eq!(notes.text(), "Pause here\nAsk for questions");
+    assert_eq!(notes.text_frames.len(), 3);
+    assert!(notes.iter_runs().next().expect("first run").formatting.bold);
+    assert!(notes.images.is_empty());
+
+    slide.speaker_notes.clear();
+    slide.notes_images = vec![ImageBlock {
+        reference: ImageReference {
+            id: "notes-rId2".to_string(),
+            target: "/ppt/media/answer.png".to_string(),
+            dimensions: None,
+            display_size: None,
+            link_target: None,
+            description: None,
+            title: None,
+        },
+        alt_text: None,
+        mime_type: None,
+    }];
+    let notes = slide.notes().expect("notes with a picture");
+    assert_eq!(notes.text(), "");
+    assert_eq!(notes.images.len(), 1);
+}
+
 #[test]
 fn semantic_image_rendering_covers_missing_manual_and_invalid_compression_paths() {
     let image = SlideBlock {
         bounds: Bounds::default(),
         source_order: 0,
+        raw: None,
         content: SlideBlockContent::Image(ImageBlock {
             reference: ImageReference {
                 id: "image".to_string(),
                 target: "../media/image.png".to_string(),
+                dimensions: None,
+                display_size: None,
+                link_target: None,
+                description: None,
+                title: None,
             },
             alt_text: Some("Diagram".to_string()),
             mime_type: Some("image/png".to_string()),
@@ -968,18 +1796,23 @@ fn semantic_image_rendering_covers_missing_manual_and_invalid_compression_paths(
 
     let mut slide = mock_slide();
     slide.blocks = vec![image.clone()];
-    assert!(
-        slide
-            .to_markdown(&options)
-            .unwrap()
-            .contains("Image unavailable: Diagram")
-    );
+    let markdown = slide.to_markdown(&options).unwrap();
+    assert!(markdown.contains("Image unavailable: Diagram"));
+    assert!(!markdown.contains("img:"));
 
-    slide.config.image_handling_mode = ImageHandlingMode::Manually;
-    assert_eq!(slide.to_markdown(&options).unwrap(), "\n");
+    slide.render_options.image_handling_mode = ImageHandlingMode::Manually;
+    let SlideBlockContent::Image(image_block) = &image.content else {
+        unreachable!()
+    };
+    let token = slide.image_token(0, &image_block.reference);
+    assert!(token.starts_with("img:1:0:"));
+    assert_eq!(
+        slide.to_markdown(&options).unwrap(),
+        format!("<!-- {token} -->\n")
+    );
 
-    slide.config.image_handling_mode = ImageHandlingMode::InMarkdown;
-    slide.config.compress_images = true;
+    slide.render_options.image_handling_mode = ImageHandlingMode::InMarkdown;
+    slide.render_options.compress_images = true;
     slide
         .image_data
         .insert("image".to_string(), b"invalid image".to_vec());
@@ -990,7 +1823,7 @@ fn semantic_image_rendering_covers_missing_manual_and_invalid_compression_paths(
             .contains("Image unavailable: Diagram")
     );
 
-    slide.config.image_handling_mode = ImageHandlingMode::Save;
+    slide.render_options.image_handling_mode = ImageHandlingMode::Save;
     assert!(
         slide
             .to_markdown(&options)
@@ -998,3 +1831,305 @@ fn semantic_image_rendering_covers_missing_manual_and_invalid_compression_paths(
             .contains("Image unavailable: Diagram")
     );
 }
+
+#[test]
+fn renders_recognized_image_text_below_the_image() {
+    let mut slide = mock_slide();
+    slide.blocks = vec![SlideBlock {
+        bounds: Bounds::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Image(ImageBlock {
+            reference: ImageReference {
+                id: "image".to_string(),
+                target: "../media/image.png".to_string(),
+                dimensions: None,
+                display_size: None,
+                link_target: None,
+                description: None,
+                title: None,
+            },
+            alt_text: Some("Screenshot".to_string()),
+            mime_type: None,
+        }),
+    }];
+    slide
+        .image_data
+        .insert("image".to_string(), b"png bytes".to_vec());
+    let options = RenderOptions {
+        markdown: MarkdownOptions {
+            include_slide_number_as_comment: false,
+            ..MarkdownOptions::default()
+        },
+        compress_images: false,
+        ocr_provider: Some(Arc::new(|image: &[u8], mime: &str| {
+            assert_eq!((image, mime), (&b"png bytes"[..], "image/png"));
+            Some("Quarterly report\nConfidential\n".to_string())
+        })),
+        ..RenderOptions::default()
+    };
+
+    let markdown = slide.convert_to_md_with(&options).unwrap();
+
+    assert!(
+        markdown.ends_with(")\n\n> Quarterly report\n> Confidential\n"),
+        "{markdown}"
+    );
+}
+
+#[test]
+fn stats_count_text_lists_tables_and_images() {
+    let run = |text: &str| Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    };
+    let list_paragraph = |text: &str| Paragraph {
+        list: Some(ListInfo {
+            level: 0,
+            kind: ListKind::Bullet { character: None },
+        }),
+        ..Paragraph::plain(vec![run(text)])
+    };
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 0,
+            raw: None,
+            content: SlideBlockContent::Text(TextBlock {
+                role: TextRole::Body,
+                paragraphs: vec![
+                    Paragraph::plain(vec![run("Intro text\n")]),
+                    list_paragraph("First item"),
+                    list_paragraph("Second item"),
+                ],
+                from_template: false,
+            }),
+        },
+        SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 1,
+            raw: None,
+            content: SlideBlockContent::Table(SemanticTable {
+                has_header: true,
+                column_widths: Vec::new(),
+                rows: vec![SemanticTableRow {
+                    cells: vec![SemanticTableCell {
+                        paragraphs: vec![Paragraph::plain(vec![run("Cell")])],
+                        row_span: 1,
+                        column_span: 1,
+                        covered: false,
+                    }],
+                }],
+            }),
+        },
+    ];
+
+    let stats = slide.stats();
+    assert_eq!(stats.word_count, 7);
+    assert_eq!(stats.character_count, 35);
+    assert_eq!(stats.run_count, 4);
+    assert_eq!(stats.list_count, 1);
+    assert_eq!(stats.table_count, 1);
+    assert_eq!(stats.image_count, 0);
+    assert!(stats.is_text_dominant());
+
+    slide.blocks = vec![SlideBlock {
+        bounds: Bounds {
+            x: 0,
+            y: 0,
+            width: 600,
+            height: 400,
+        },
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Image(ImageBlock {
+            reference: ImageReference {
+                id: "rId1".to_string(),
+                target: "../media/image1.png".to_string(),
+                dimensions: None,
+                display_size: None,
+                link_target: None,
+                description: None,
+                title: None,
+            },
+            alt_text: None,
+            mime_type: None,
+        }),
+    }];
+    assert!(slide.stats().is_image_dominant());
+    assert_eq!(
+        mock_slide().stats().dominant_content,
+        ContentDominance::Empty
+    );
+}
+
+#[test]
+fn extracts_plain_text_and_all_runs_including_tables_and_lists() {
+    let run = |text: &str| Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    };
+    let mut slide = mock_slide();
+    slide.elements = vec![
+        SlideElement::Text(
+            TextElement::from_runs(vec![run("Hello "), run("world")]),
+            ElementPosition {
+                x: 0,
+                y: 0,
+                width: 0,
+                height: 0,
+            },
+            ElementMeta::default(),
+        ),
+        SlideElement::List(
+            ListElement {
+                items: vec![ListItem {
+                    level: 0,
+                    is_ordered: false,
+                    properties: ParagraphProperties::default(),
+                    runs: vec![run("Point")],
+                }],
+            },
+            ElementPosition {
+                x: 0,
+                y: 100,
+                width: 0,
+                height: 0,
+            },
+            ElementMeta::default(),
+        ),
+        SlideElement::Table(
+            TableElement {
+                has_header: true,
+                column_widths: Vec::new(),
+                rows: vec![TableRow {
+                    cells: vec![
+                        TableCell {
+                            paragraphs: vec![Paragraph::plain(vec![run("A")])],
+                            ..TableCell::default()
+                        },
+                        TableCell {
+                            paragraphs: vec![Paragraph::plain(vec![run("B")])],
+                            ..TableCell::default()
+                        },
+                    ],
+                }],
+            },
+            ElementPosition {
+                x: 0,
+                y: 200,
+                width: 0,
+                height: 0,
+            },
+            ElementMeta::default(),
+        ),
+    ];
+
+    assert_eq!(slide.text(), "Hello world\n\nPoint\n\nA\tB");
+    let runs: Vec<&str> = slide.runs().map(|run| run.text.as_str()).collect();
+    assert_eq!(runs, ["Hello ", "world", "Point", "A", "B"]);
+}
+
+#[test]
+fn reports_skipped_elements_per_tag_and_graphic_type() {
+    let xml = br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><p:cSld><p:spTree><p:graphicFrame><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart"/></a:graphic></p:graphicFrame><p:graphicFrame><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/diagram"/></a:graphic></p:graphicFrame><p:graphicFrame><a:graphic><a:graphicData uri="http://schemas.openxmlformats.org/drawingml/2006/chart"/></a:graphic></p:graphicFrame><p:cxnSp><p:nvCxnSpPr/></p:cxnSp></p:spTree></p:cSld></p:sld>"#;
+    let parsed = crate::parse_xml::parse_slide_document_with_hyperlinks(
+        xml,
+        &Default::default(),
+        &HashMap::new(),
+    )
+    .unwrap();
+    let slide = Slide::new_semantic(
+        "ppt/slides/slide4.xml".to_string(),
+        4,
+        parsed.elements,
+        parsed.blocks,
+        vec![],
+        vec![],
+        vec![],
+        HashMap::new(),
+        ParserConfig::default(),
+        parsed.diagnostics,
+    );
+
+    let report = slide.parse_report();
+    assert_eq!(report.slide_number, 4);
+    assert_eq!(
+        report.skipped.into_iter().collect::<Vec<_>>(),
+        [
+            ("graphicFrame/chart".to_string(), 2),
+            ("graphicFrame/diagram".to_string(), 1),
+        ]
+    );
+    assert!(mock_slide().parse_report().skipped.is_empty());
+}
+
+#[test]
+fn content_hash_ignores_positions_and_rendering_options() {
+    let mut slide = mock_slide();
+    slide.blocks = vec![
+        SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 0,
+            raw: None,
+            content: semantic_text("Overview", TextRole::Title),
+        },
+        SlideBlock {
+            bounds: Bounds::default(),
+            source_order: 1,
+            raw: None,
+            content: semantic_text("Revenue grew", TextRole::Body),
+        },
+    ];
+    let hash = slide.content_hash();
+
+    slide.blocks[1].bounds = Bounds {
+        x: 100,
+        y: 200,
+        width: 300,
+        height: 400,
+    };
+    slide.blocks[1].raw = Some("<p:sp/>".to_string());
+    slide.render_options = ParserConfig::builder()
+        .include_slide_number_as_comment(false)
+        .paragraph_spacing(ParagraphSpacing::LineBreak)
+        .build()
+        .into();
+    assert_eq!(slide.content_hash(), hash);
+
+    slide.blocks[1].content = semantic_text("Revenue fell", TextRole::Body);
+    assert_ne!(slide.content_hash(), hash);
+
+    slide.blocks[1].content = semantic_text("Revenue grew", TextRole::Body);
+    if let SlideBlockContent::Text(text) = &mut slide.blocks[1].content {
+        text.paragraphs[0].runs[0].formatting.bold = true;
+    }
+    assert_ne!(slide.content_hash(), hash);
+}
+
+#[test]
+fn keeps_only_the_render_options_of_the_parser_config() {
+    let config = ParserConfig::builder()
+        .quality(50)
+        .extract_images(false)
+        .build();
+    let slide = Slide::new(
+        "ppt/slides/slide1.xml".to_string(),
+        1,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        HashMap::new(),
+        config,
+    );
+    assert_eq!(slide.render_options.quality, 50);
+
+    #[allow(deprecated)]
+    let config = slide.config();
+    assert_eq!(config.quality, 50);
+    assert!(config.extract_images);
+}
@@ -0,0 +1,23 @@
+use std::io::Write;
+use tempfile::TempPath;
+use zip::write::SimpleFileOptions;
+
+/// Writes `parts` as the entries of a temporary PPTX package.
+///
+/// The package is deleted when the returned path is dropped, so a failing
+/// assertion does not leave it behind in the temporary directory.
+pub(crate) fn write_package(parts: &[(&str, &[u8])]) -> TempPath {
+    let file = tempfile::Builder::new()
+        .prefix("pptx-to-md-")
+        .suffix(".pptx")
+        .tempfile()
+        .expect("create temporary PPTX");
+    let mut archive = zip::ZipWriter::new(file.as_file());
+    let options = SimpleFileOptions::default();
+    for (name, data) in parts {
+        archive.start_file(*name, options).expect("start entry");
+        archive.write_all(data).expect("write entry");
+    }
+    archive.finish().expect("finish temporary PPTX");
+    file.into_temp_path()
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+#[test]
+fn maps_ascii_and_private_use_symbol_characters() {
+    assert_eq!(map_symbol_text("Wingdings", "\u{F0FC} \u{00FB}"), "✓ ✗");
+    assert_eq!(map_symbol_text("Symbol", "a \u{F0AE} b"), "α → β");
+    assert_eq!(map_symbol_text("Webdings", "4"), "▶");
+}
+
+#[test]
+fn keeps_text_of_regular_fonts_and_unknown_symbols() {
+    assert_eq!(map_symbol_text("Calibri", "\u{F0FC}"), "\u{F0FC}");
+    assert_eq!(map_symbol_text("Wingdings", "\u{F0FA}"), "\u{F0FA}");
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+const THEME: &[u8] = br#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" name="Office"><a:themeElements><a:clrScheme name="Blue"><a:dk1><a:sysClr val="windowText" lastClr="000000"/></a:dk1><a:lt1><a:sysClr val="window" lastClr="FFFFFF"/></a:lt1><a:accent1><a:srgbClr val="156082"/></a:accent1><a:accent2><a:srgbClr val="E97132"/></a:accent2></a:clrScheme></a:themeElements></a:theme>"#;
+
+fn slide(background: &str) -> String {
+    format!(
+        r#"<p:sld xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><p:cSld>{background}<p:spTree/></p:cSld></p:sld>"#
+    )
+}
+
+#[test]
+fn reads_theme_name_and_accent_colors() {
+    let theme = parse_theme(THEME, "ppt/theme/theme1.xml").unwrap();
+
+    assert_eq!(theme.summary.name.as_deref(), Some("Office"));
+    assert_eq!(theme.summary.color_scheme.as_deref(), Some("Blue"));
+    assert_eq!(
+        theme.summary.accents,
+        vec![Color::Rgb("156082".into()), Color::Rgb("E97132".into())]
+    );
+    assert_eq!(
+        theme.resolve(Color::Scheme("bg1".into())),
+        Color::Rgb("FFFFFF".into())
+    );
+    assert_eq!(
+        theme.resolve(Color::Scheme("accent6".into())),
+        Color::Scheme("accent6".into())
+    );
+}
+
+#[test]
+fn reads_solid_gradient_and_image_backgrounds() {
+    let theme = parse_theme(THEME, "ppt/theme/theme1.xml").unwrap();
+    let part = "ppt/slides/slide1.xml";
+
+    let solid = slide(r#"<p:bg><p:bgRef idx="1001"><a:schemeClr val="bg1"/></p:bgRef></p:bg>"#);
+    let background = parse_background(solid.as_bytes()).unwrap();
+    assert_eq!(
+        resolve_background(background, part, &[], Some(&theme)),
+        Some(Background::Solid(Color::Rgb("FFFFFF".into())))
+    );
+
+    let gradient = slide(
+        r#"<p:bg><p:bgPr><a:gradFill><a:gsLst><a:gs pos="0"><a:srgbClr val="000000"/></a:gs><a:gs pos="100000"><a:schemeClr val="accent1"><a:lumMod val="75000"/></a:schemeClr></a:gs></a:gsLst></a:gradFill></p:bgPr></p:bg>"#,
+    );
+    let background = parse_background(gradient.as_bytes()).unwrap();
+    assert_eq!(
+        resolve_background(background, part, &[], Some(&theme)),
+        Some(Background::Gradient(vec![
+            GradientStop {
+                position: 0,
+                color: Color::Rgb("000000".into())
+            },
+            GradientStop {
+                position: 100_000,
+                color: Color::Rgb("156082".into())
+            },
+        ]))
+    );
+
+    let image =
+        slide(r#"<p:bg><p:bgPr><a:blipFill><a:blip r:embed="rId2"/></a:blipFill></p:bgPr></p:bg>"#);
+    let relationships = [Relationship {
+        id: "rId2".into(),
+        rel_type: crate::constants::IMAGE_NAMESPACE.into(),
+        target: "../media/image3.png".into(),
+        external: false,
+    }];
+    let background = parse_background(image.as_bytes()).unwrap();
+    assert_eq!(
+        resolve_background(background, part, &relationships, None),
+        Some(Background::Image("ppt/media/image3.png".into()))
+    );
+
+    assert_eq!(parse_background(slide("").as_bytes()), None);
+}
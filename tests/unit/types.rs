@@ -10,3 +10,294 @@ fn run_extract_returns_the_unmodified_text() {
 
     assert_eq!(run.extract(), "Text with <markup> & whitespace");
 }
+
+#[test]
+fn text_elements_flatten_paragraphs_into_legacy_runs() {
+    let run = |text: &str| Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    };
+    let text = TextElement::from_paragraphs(vec![
+        Paragraph {
+            alignment: ParagraphAlignment::Center,
+            ..Paragraph::plain(vec![run("Hello "), run("World")])
+        },
+        Paragraph::default(),
+        Paragraph::plain(vec![run("Again")]),
+    ]);
+
+    let runs: Vec<String> = text.runs().into_iter().map(|run| run.text).collect();
+    assert_eq!(runs, ["Hello ", "World\n", "Again\n"]);
+    assert_eq!(text.iter_runs().count(), 3);
+    assert_eq!(text.text(), "Hello World\nAgain");
+    assert!(!text.is_empty());
+    assert!(TextElement::default().is_empty());
+
+    let restored = TextElement::from_runs(text.runs());
+    let paragraphs: Vec<String> = restored.paragraphs.iter().map(Paragraph::text).collect();
+    assert_eq!(paragraphs, ["Hello World", "Again"]);
+}
+
+#[test]
+fn table_cells_join_paragraphs_in_legacy_runs() {
+    let run = |text: &str| Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    };
+    let cell = TableCell {
+        paragraphs: vec![
+            Paragraph::plain(vec![run("First")]),
+            Paragraph::default(),
+            Paragraph::plain(vec![run("Second")]),
+        ],
+        ..TableCell::default()
+    };
+
+    let runs: Vec<String> = cell.runs().into_iter().map(|run| run.text).collect();
+    assert_eq!(runs, ["First\n", "Second"]);
+    assert_eq!(cell.text(), "First\nSecond");
+
+    let restored = TableCell::from_runs(cell.runs());
+    assert_eq!(restored.paragraphs.len(), 2);
+    assert_eq!((restored.row_span, restored.column_span), (1, 1));
+}
+
+#[test]
+fn assigns_slide_scoped_element_ids_with_parts_per_shape() {
+    let shape_meta = ElementMeta {
+        id: Some(ElementId {
+            slide_number: 0,
+            shape: ShapeRef::ShapeId(5),
+            part: 0,
+        }),
+        ..ElementMeta::default()
+    };
+    let text = TextElement::default;
+    let position = ElementPosition::default;
+    let mut elements = vec![
+        SlideElement::Text(text(), position(), shape_meta),
+        SlideElement::List(ListElement { items: Vec::new() }, position(), shape_meta),
+        SlideElement::Unknown,
+        SlideElement::Text(text(), position(), ElementMeta::default()),
+    ];
+
+    assign_element_ids(&mut elements, 3);
+
+    let ids: Vec<String> = elements
+        .iter()
+        .filter_map(SlideElement::id)
+        .map(|id| id.to_string())
+        .collect();
+    assert_eq!(ids, ["slide3/shape5", "slide3/shape5.1", "slide3/index3"]);
+    assert_eq!(elements[2].id(), None);
+}
+
+#[test]
+fn collects_hyperlinks_with_their_anchor_text_and_slide() {
+    let run = |text: &str, link_target: Option<&str>| Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: link_target.map(str::to_string),
+    };
+    let slide = |slide_number: u32, runs: Vec<Run>| {
+        crate::Slide::new_semantic(
+            format!("ppt/slides/slide{slide_number}.xml"),
+            slide_number,
+            Vec::new(),
+            vec![SlideBlock {
+                bounds: Bounds::default(),
+                source_order: 0,
+                raw: None,
+                content: SlideBlockContent::Text(TextBlock {
+                    role: TextRole::Body,
+                    paragraphs: vec![Paragraph::plain(runs)],
+                    from_template: false,
+                }),
+            }],
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            std::collections::HashMap::new(),
+            crate::ParserConfig::default(),
+            Vec::new(),
+        )
+    };
+    let presentation = Presentation {
+        metadata: Default::default(),
+        slides: vec![
+            slide(
+                1,
+                vec![
+                    run("Visit ", None),
+                    run("our ", Some("https://example.com")),
+                    run("site", Some("https://example.com")),
+                    run(" or ", None),
+                    run("the agenda\n", Some("#slide-2")),
+                ],
+            ),
+            slide(2, vec![run("No links", None)]),
+        ],
+        sections: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+
+    assert_eq!(
+        presentation.hyperlinks(),
+        vec![
+            Hyperlink {
+                slide_number: 1,
+                text: "our site".to_string(),
+                target: HyperlinkTarget::External("https://example.com".to_string()),
+            },
+            Hyperlink {
+                slide_number: 1,
+                text: "the agenda".to_string(),
+                target: HyperlinkTarget::Slide(2),
+            },
+        ]
+    );
+}
+
+#[test]
+fn prefers_the_title_slide_for_the_document_title() {
+    let text = |role: TextRole, value: &str| SlideBlock {
+        bounds: Bounds::default(),
+        source_order: 0,
+        raw: None,
+        content: SlideBlockContent::Text(TextBlock {
+            role,
+            paragraphs: vec![Paragraph::plain(vec![Run {
+                text: value.to_string(),
+                formatting: Formatting::default(),
+                link_target: None,
+            }])],
+            from_template: false,
+        }),
+    };
+    let slide = |slide_number: u32, blocks: Vec<SlideBlock>, layout_type: Option<&str>| {
+        let mut slide = crate::Slide::new_semantic(
+            format!("ppt/slides/slide{slide_number}.xml"),
+            slide_number,
+            Vec::new(),
+            blocks,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            std::collections::HashMap::new(),
+            crate::ParserConfig::default(),
+            Vec::new(),
+        );
+        slide.layout_type = layout_type.map(str::to_string);
+        slide
+    };
+    let mut presentation = Presentation {
+        metadata: crate::PresentationMetadata {
+            title: Some("PowerPoint Presentation".to_string()),
+            ..Default::default()
+        },
+        slides: vec![
+            slide(1, vec![text(TextRole::Title, "Agenda")], Some("obj")),
+            slide(
+                2,
+                vec![text(TextRole::Title, "Quarterly Review")],
+                Some("title"),
+            ),
+        ],
+        sections: Vec::new(),
+        diagnostics: Vec::new(),
+    };
+
+    assert_eq!(presentation.title_slide().unwrap().slide_number, 2);
+    assert_eq!(
+        presentation.document_title().as_deref(),
+        Some("Quarterly Review")
+    );
+
+    presentation.slides = vec![
+        slide(
+            1,
+            vec![
+                text(TextRole::Title, "Launch"),
+                text(TextRole::Subtitle, "2024"),
+            ],
+            None,
+        ),
+        slide(2, vec![text(TextRole::Title, "Agenda")], None),
+    ];
+    assert_eq!(presentation.document_title().as_deref(), Some("Launch"));
+
+    presentation.slides.remove(0);
+    assert!(presentation.title_slide().is_none());
+    assert_eq!(
+        presentation.document_title().as_deref(),
+        Some("PowerPoint Presentation")
+    );
+}
+
+#[test]
+fn writes_tables_as_quoted_csv_and_tsv() {
+    let cell = |text: &str, covered: bool| TableCell {
+        paragraphs: vec![Paragraph::plain(vec![Run {
+            text: text.to_string(),
+            formatting: Formatting::default(),
+            link_target: None,
+        }])],
+        covered,
+        ..TableCell::default()
+    };
+    let table = TableElement {
+        rows: vec![
+            TableRow {
+                cells: vec![cell("Name\n", false), cell("Note", false), cell("x", true)],
+            },
+            TableRow {
+                cells: vec![
+                    cell("Smith, J.", false),
+                    cell("said \"hi\"\nthen left", false),
+                    cell("a\tb", false),
+                ],
+            },
+        ],
+        has_header: true,
+        column_widths: Vec::new(),
+    };
+
+    assert_eq!(
+        table.to_csv(),
+        "Name,Note,\n\"Smith, J.\",\"said \"\"hi\"\"\nthen left\",a\tb\n"
+    );
+    assert_eq!(
+        table.to_tsv(),
+        "Name\tNote\t\nSmith, J.\t\"said \"\"hi\"\"\nthen left\"\t\"a\tb\"\n"
+    );
+}
+
+#[test]
+fn reduces_titles_to_file_name_parts() {
+    assert_eq!(
+        file_name_part(Some("Q3 Results: Sales & Growth")),
+        "q3-results-sales-growth"
+    );
+    assert_eq!(file_name_part(Some(" ?! ")), "untitled");
+    assert_eq!(file_name_part(None), "untitled");
+}
+
+#[test]
+fn converts_element_positions_from_emu() {
+    let position = ElementPosition {
+        x: 914_400,
+        y: 457_200,
+        width: 0,
+        height: 0,
+    };
+
+    assert_eq!(position.to_points(), (72.0, 36.0));
+    assert_eq!(position.to_pixels(96.0), (96.0, 48.0));
+    assert_eq!(
+        position.to_percent((9_144_000, 6_858_000)),
+        Some((10.0, 100.0 / 15.0))
+    );
+    assert_eq!(position.to_percent((0, 6_858_000)), None);
+}
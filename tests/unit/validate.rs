@@ -0,0 +1,58 @@
+use super::*;
+use crate::test_support::write_package;
+
+#[test]
+fn derives_the_source_part_of_relationship_parts() {
+    assert_eq!(
+        source_part("ppt/slides/_rels/slide1.xml.rels"),
+        "ppt/slides/slide1.xml"
+    );
+    assert_eq!(source_part("_rels/.rels"), "");
+}
+
+#[test]
+fn reports_missing_parts_targets_media_and_malformed_slides() {
+    let path = write_package(&[
+        ("[Content_Types].xml", b"<Types/>"),
+        (
+            "_rels/.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="ppt/presentation.xml"/></Relationships>"#,
+        ),
+        (
+            "ppt/slides/slide1.xml",
+            br#"<p:sld xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main"><p:cSld><p:spTree/></p:cSld></p:sld>"#,
+        ),
+        (
+            "ppt/slides/_rels/slide1.xml.rels",
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slideLayout" Target="../slideLayouts/slideLayout1.xml"/><Relationship Id="rId2" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/image" Target="../media/image1.png"/><Relationship Id="rId3" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/hyperlink" Target="https://example.com" TargetMode="External"/></Relationships>"#,
+        ),
+        ("ppt/slides/slide2.xml", b"<p:sld><p:cSld></p:sld>"),
+    ]);
+
+    let mut container =
+        PptxContainer::open(&path, crate::ParserConfig::default()).expect("open temporary PPTX");
+    let report = container.validate().expect("validate package");
+    let issues: Vec<(ValidationIssueKind, &str)> = report
+        .issues
+        .iter()
+        .map(|issue| (issue.kind, issue.part.as_str()))
+        .collect();
+
+    assert!(!report.is_valid());
+    assert_eq!(
+        issues,
+        vec![
+            (ValidationIssueKind::MissingPart, "ppt/presentation.xml"),
+            (ValidationIssueKind::UnresolvedRelationship, "_rels/.rels"),
+            (
+                ValidationIssueKind::UnresolvedRelationship,
+                "ppt/slides/_rels/slide1.xml.rels"
+            ),
+            (
+                ValidationIssueKind::MissingMedia,
+                "ppt/slides/_rels/slide1.xml.rels"
+            ),
+            (ValidationIssueKind::MalformedXml, "ppt/slides/slide2.xml"),
+        ]
+    );
+}
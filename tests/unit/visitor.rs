@@ -0,0 +1,115 @@
+use super::*;
+use crate::{
+    ElementMeta, Formatting, ListItem, Paragraph, ParagraphProperties, ParserConfig, Run, Slide,
+    SlideElement, TableRow,
+};
+use std::collections::HashMap;
+
+fn run(text: &str) -> Run {
+    Run {
+        text: text.to_string(),
+        formatting: Formatting::default(),
+        link_target: None,
+    }
+}
+
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl SlideVisitor for Recorder {
+    fn visit_text(&mut self, text: &TextElement, _position: ElementPosition) {
+        self.0.push(format!("text {}", text.text()));
+    }
+
+    fn visit_table(&mut self, table: &TableElement, _position: ElementPosition) {
+        self.0.push(format!("table {}", table.rows.len()));
+    }
+
+    fn visit_table_cell(&mut self, cell: &TableCell, row: usize, column: usize) {
+        self.0.push(format!("cell {row},{column} {}", cell.text()));
+    }
+
+    fn visit_list_item(&mut self, item: &ListItem) {
+        self.0.push(format!("item {}", item.runs[0].text));
+    }
+
+    fn visit_image(&mut self, image: &ImageReference, _position: ElementPosition) {
+        self.0.push(format!("image {}", image.id));
+    }
+}
+
+#[test]
+fn visits_elements_with_nested_cells_and_items_in_order() {
+    let position = ElementPosition::default();
+    let cell = |text: &str| TableCell {
+        paragraphs: vec![Paragraph::plain(vec![run(text)])],
+        ..TableCell::default()
+    };
+    let slide = Slide::new(
+        "ppt/slides/slide1.xml".to_string(),
+        1,
+        vec![
+            SlideElement::Text(
+                TextElement::from_runs(vec![run("Title")]),
+                position,
+                ElementMeta::default(),
+            ),
+            SlideElement::Table(
+                TableElement {
+                    rows: vec![TableRow {
+                        cells: vec![cell("A"), cell("B")],
+                    }],
+                    has_header: true,
+                    column_widths: Vec::new(),
+                },
+                position,
+                ElementMeta::default(),
+            ),
+            SlideElement::List(
+                ListElement {
+                    items: vec![ListItem {
+                        level: 0,
+                        is_ordered: false,
+                        properties: ParagraphProperties::default(),
+                        runs: vec![run("Point")],
+                    }],
+                },
+                position,
+                ElementMeta::default(),
+            ),
+            SlideElement::Image(
+                ImageReference {
+                    id: "rId2".to_string(),
+                    target: "../media/image1.png".to_string(),
+                    dimensions: None,
+                    display_size: None,
+                    link_target: None,
+                    description: None,
+                    title: None,
+                },
+                position,
+                ElementMeta::default(),
+            ),
+            SlideElement::Unknown,
+        ],
+        vec![],
+        vec![],
+        vec![],
+        HashMap::new(),
+        ParserConfig::default(),
+    );
+
+    let mut recorder = Recorder::default();
+    slide.accept(&mut recorder);
+    assert_eq!(
+        recorder.0,
+        [
+            "text Title",
+            "table 1",
+            "cell 0,0 A",
+            "cell 0,1 B",
+            "item Point",
+            "image rId2"
+        ]
+    );
+}
@@ -13,3 +13,16 @@ fn skip_element_reports_unexpected_eof() {
         Err(Error::ParseError("Unexpected end of XML element"))
     ));
 }
+
+#[test]
+fn repairs_invalid_utf8_with_a_warning() {
+    let mut valid = "<a>Café</a>".as_bytes().to_vec();
+    assert!(repair_utf8(&mut valid, "test.xml").is_none());
+    assert_eq!(valid, "<a>Café</a>".as_bytes());
+
+    let mut invalid = b"<a>Caf\xe9</a>".to_vec();
+    let diagnostic = repair_utf8(&mut invalid, "test.xml").expect("warning");
+    assert_eq!(invalid, "<a>Caf\u{fffd}</a>".as_bytes());
+    assert_eq!(diagnostic.severity, DiagnosticSeverity::Warning);
+    assert!(diagnostic.message.contains("byte 6"));
+}